@@ -0,0 +1,50 @@
+CREATE TABLE offer_options (
+  offer_option_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  name VARCHAR NOT NULL,
+  ordering INT NOT NULL,
+  CONSTRAINT uq_offer_id_option_name UNIQUE (offer_id, name)
+);
+
+CREATE TABLE offer_option_values (
+  offer_option_value_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  offer_option_id UUID NOT NULL REFERENCES offer_options(offer_option_id) ON DELETE CASCADE,
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  value VARCHAR NOT NULL,
+  ordering INT NOT NULL,
+  CONSTRAINT uq_offer_option_id_value UNIQUE (offer_option_id, value)
+);
+
+CREATE TABLE offer_variants (
+  offer_variant_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  sku VARCHAR NOT NULL,
+  is_active BOOLEAN NOT NULL DEFAULT 't',
+  CONSTRAINT uq_offer_id_sku UNIQUE (offer_id, sku)
+);
+
+CREATE TABLE offer_variant_option_values (
+  offer_variant_id UUID NOT NULL REFERENCES offer_variants(offer_variant_id) ON DELETE CASCADE,
+  offer_option_value_id UUID NOT NULL REFERENCES offer_option_values(offer_option_value_id),
+  PRIMARY KEY (offer_variant_id, offer_option_value_id)
+);
+
+CREATE TABLE offer_variant_images (
+  offer_variant_id UUID NOT NULL REFERENCES offer_variants(offer_variant_id) ON DELETE CASCADE,
+  offer_image_id UUID NOT NULL REFERENCES offer_images(offer_image_id) ON DELETE CASCADE,
+  PRIMARY KEY (offer_variant_id, offer_image_id)
+);
+
+ALTER TABLE
+  offer_prices
+ADD
+  COLUMN offer_variant_id UUID REFERENCES offer_variants(offer_variant_id) ON DELETE CASCADE;
@@ -0,0 +1,34 @@
+ALTER TABLE
+  offers
+ADD
+  COLUMN inventory_policy VARCHAR NOT NULL DEFAULT 'INVENTORY_POLICY_DENY';
+
+CREATE TABLE inventories (
+  inventory_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  offer_variant_id UUID REFERENCES offer_variants(offer_variant_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  on_hand INT NOT NULL,
+  CONSTRAINT uq_inventories_offer_variant_id UNIQUE (offer_variant_id)
+);
+
+CREATE UNIQUE INDEX uq_inventories_offer_id ON inventories (offer_id)
+WHERE
+  offer_variant_id IS NULL;
+
+CREATE TABLE inventory_reservations (
+  inventory_reservation_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  inventory_id UUID NOT NULL REFERENCES inventories(inventory_id) ON DELETE CASCADE,
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  offer_variant_id UUID REFERENCES offer_variants(offer_variant_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  quantity INT NOT NULL,
+  status VARCHAR NOT NULL,
+  expires_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX idx_inventory_reservations_inventory_id_status ON inventory_reservations (inventory_id, status);
@@ -0,0 +1,28 @@
+CREATE TABLE carts (
+  cart_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  shop_id UUID NOT NULL REFERENCES shops(shop_id) ON DELETE CASCADE,
+  user_id VARCHAR,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  expires_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE UNIQUE INDEX uq_carts_shop_id_user_id ON carts (shop_id, user_id)
+WHERE
+  user_id IS NOT NULL;
+
+CREATE INDEX idx_carts_expires_at ON carts (expires_at);
+
+CREATE TABLE cart_items (
+  cart_item_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  cart_id UUID NOT NULL REFERENCES carts(cart_id) ON DELETE CASCADE,
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  offer_variant_id UUID REFERENCES offer_variants(offer_variant_id) ON DELETE CASCADE,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  quantity INT NOT NULL,
+  currency VARCHAR NOT NULL,
+  unit_amount INT NOT NULL
+);
+
+CREATE INDEX idx_cart_items_cart_id ON cart_items (cart_id);
@@ -0,0 +1,48 @@
+CREATE TABLE orders (
+  order_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  shop_id UUID NOT NULL,
+  shop_name VARCHAR NOT NULL,
+  user_id VARCHAR NOT NULL,
+  buyer_user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  status VARCHAR NOT NULL,
+  currency VARCHAR NOT NULL,
+  subtotal_amount INT NOT NULL,
+  shipping_amount INT NOT NULL,
+  total_amount INT NOT NULL,
+  shipping_country VARCHAR,
+  platform_fee_percent INT NOT NULL,
+  minimum_platform_fee_cent INT NOT NULL
+);
+
+CREATE INDEX idx_orders_shop_id ON orders (shop_id);
+
+CREATE INDEX idx_orders_buyer_user_id ON orders (buyer_user_id);
+
+CREATE TABLE order_items (
+  order_item_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  order_id UUID NOT NULL REFERENCES orders(order_id) ON DELETE CASCADE,
+  offer_id UUID NOT NULL,
+  offer_variant_id UUID,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  offer_name VARCHAR NOT NULL,
+  offer_variant_sku VARCHAR,
+  quantity INT NOT NULL,
+  currency VARCHAR NOT NULL,
+  unit_amount INT NOT NULL,
+  amount INT NOT NULL
+);
+
+CREATE INDEX idx_order_items_order_id ON order_items (order_id);
+
+CREATE TABLE order_status_transitions (
+  order_status_transition_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  order_id UUID NOT NULL REFERENCES orders(order_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  from_status VARCHAR,
+  to_status VARCHAR NOT NULL
+);
+
+CREATE INDEX idx_order_status_transitions_order_id ON order_status_transitions (order_id);
@@ -0,0 +1,6 @@
+ALTER TABLE
+  orders
+ADD
+  COLUMN platform_fee_amount INT NOT NULL DEFAULT 0,
+ADD
+  COLUMN net_amount INT NOT NULL DEFAULT 0;
@@ -0,0 +1,7 @@
+CREATE UNIQUE INDEX uq_offer_prices_offer_id_currency ON offer_prices (offer_id, currency)
+WHERE
+  offer_variant_id IS NULL;
+
+CREATE UNIQUE INDEX uq_offer_prices_offer_variant_id_currency ON offer_prices (offer_variant_id, currency)
+WHERE
+  offer_variant_id IS NOT NULL;
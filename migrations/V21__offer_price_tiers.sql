@@ -0,0 +1,24 @@
+ALTER TABLE
+  offer_prices
+ADD
+  COLUMN package_size INT;
+
+CREATE TABLE offer_price_tiers (
+  offer_price_tier_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  offer_price_id UUID NOT NULL REFERENCES offer_prices(offer_price_id) ON DELETE CASCADE,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  up_to INT,
+  unit_amount INT NOT NULL,
+  flat_amount INT NOT NULL DEFAULT 0,
+  CONSTRAINT uq_offer_price_id_up_to UNIQUE (offer_price_id, up_to)
+);
+
+ALTER TABLE
+  cart_items
+ADD
+  COLUMN amount INT NOT NULL DEFAULT 0;
+
+UPDATE
+  cart_items
+SET
+  amount = unit_amount * quantity;
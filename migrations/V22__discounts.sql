@@ -0,0 +1,62 @@
+CREATE TABLE discounts (
+  discount_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  shop_id UUID NOT NULL REFERENCES shops(shop_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  code VARCHAR,
+  discount_type VARCHAR NOT NULL,
+  percent INT,
+  amount INT,
+  currency VARCHAR,
+  starts_at TIMESTAMP WITH TIME ZONE,
+  ends_at TIMESTAMP WITH TIME ZONE,
+  max_redemptions INT,
+  max_redemptions_per_user INT,
+  minimum_order_amount INT,
+  redemption_count INT NOT NULL DEFAULT 0,
+  is_active BOOLEAN NOT NULL DEFAULT 't',
+  CONSTRAINT uq_shop_id_code UNIQUE (shop_id, code)
+);
+
+CREATE TABLE discount_offers (
+  discount_id UUID NOT NULL REFERENCES discounts(discount_id) ON DELETE CASCADE,
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  PRIMARY KEY (discount_id, offer_id)
+);
+
+CREATE TABLE discount_offer_types (
+  discount_id UUID NOT NULL REFERENCES discounts(discount_id) ON DELETE CASCADE,
+  offer_type VARCHAR NOT NULL,
+  PRIMARY KEY (discount_id, offer_type)
+);
+
+CREATE TABLE discount_redemptions (
+  discount_redemption_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  discount_id UUID NOT NULL REFERENCES discounts(discount_id) ON DELETE CASCADE,
+  order_id UUID NOT NULL REFERENCES orders(order_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  amount INT NOT NULL
+);
+
+CREATE INDEX idx_discount_redemptions_discount_id_user_id ON discount_redemptions (discount_id, user_id);
+
+CREATE INDEX idx_discount_redemptions_order_id ON discount_redemptions (order_id);
+
+ALTER TABLE
+  carts
+ADD
+  COLUMN discount_id UUID REFERENCES discounts(discount_id) ON DELETE
+SET
+  NULL;
+
+ALTER TABLE
+  orders
+ADD
+  COLUMN discount_id UUID;
+
+ALTER TABLE
+  orders
+ADD
+  COLUMN discount_amount INT NOT NULL DEFAULT 0;
@@ -0,0 +1,18 @@
+ALTER TABLE
+  offer_prices
+ADD
+  COLUMN compare_at_amount INT;
+
+ALTER TABLE
+  offer_prices
+ADD
+  COLUMN sale_starts_at TIMESTAMP WITH TIME ZONE;
+
+ALTER TABLE
+  offer_prices
+ADD
+  COLUMN sale_ends_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX idx_offer_prices_sale_starts_at ON offer_prices (sale_starts_at);
+
+CREATE INDEX idx_offer_prices_sale_ends_at ON offer_prices (sale_ends_at);
@@ -0,0 +1,112 @@
+CREATE TABLE tax_rates (
+  country VARCHAR NOT NULL,
+  tax_category VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  rate_basis_points INT NOT NULL,
+  PRIMARY KEY (country, tax_category)
+);
+
+INSERT INTO
+  tax_rates (country, tax_category, rate_basis_points)
+VALUES
+  ('SHIPPING_COUNTRY_AT', 'TAX_CATEGORY_STANDARD', 2000),
+  ('SHIPPING_COUNTRY_AT', 'TAX_CATEGORY_REDUCED', 1000),
+  ('SHIPPING_COUNTRY_AT', 'TAX_CATEGORY_DIGITAL_SERVICES', 2000),
+  ('SHIPPING_COUNTRY_BE', 'TAX_CATEGORY_STANDARD', 2100),
+  ('SHIPPING_COUNTRY_BE', 'TAX_CATEGORY_REDUCED', 600),
+  ('SHIPPING_COUNTRY_BE', 'TAX_CATEGORY_DIGITAL_SERVICES', 2100),
+  ('SHIPPING_COUNTRY_BG', 'TAX_CATEGORY_STANDARD', 2000),
+  ('SHIPPING_COUNTRY_BG', 'TAX_CATEGORY_REDUCED', 900),
+  ('SHIPPING_COUNTRY_BG', 'TAX_CATEGORY_DIGITAL_SERVICES', 2000),
+  ('SHIPPING_COUNTRY_HR', 'TAX_CATEGORY_STANDARD', 2500),
+  ('SHIPPING_COUNTRY_HR', 'TAX_CATEGORY_REDUCED', 1300),
+  ('SHIPPING_COUNTRY_HR', 'TAX_CATEGORY_DIGITAL_SERVICES', 2500),
+  ('SHIPPING_COUNTRY_CY', 'TAX_CATEGORY_STANDARD', 1900),
+  ('SHIPPING_COUNTRY_CY', 'TAX_CATEGORY_REDUCED', 900),
+  ('SHIPPING_COUNTRY_CY', 'TAX_CATEGORY_DIGITAL_SERVICES', 1900),
+  ('SHIPPING_COUNTRY_CZ', 'TAX_CATEGORY_STANDARD', 2100),
+  ('SHIPPING_COUNTRY_CZ', 'TAX_CATEGORY_REDUCED', 1200),
+  ('SHIPPING_COUNTRY_CZ', 'TAX_CATEGORY_DIGITAL_SERVICES', 2100),
+  ('SHIPPING_COUNTRY_DK', 'TAX_CATEGORY_STANDARD', 2500),
+  ('SHIPPING_COUNTRY_DK', 'TAX_CATEGORY_REDUCED', 2500),
+  ('SHIPPING_COUNTRY_DK', 'TAX_CATEGORY_DIGITAL_SERVICES', 2500),
+  ('SHIPPING_COUNTRY_EE', 'TAX_CATEGORY_STANDARD', 2400),
+  ('SHIPPING_COUNTRY_EE', 'TAX_CATEGORY_REDUCED', 900),
+  ('SHIPPING_COUNTRY_EE', 'TAX_CATEGORY_DIGITAL_SERVICES', 2400),
+  ('SHIPPING_COUNTRY_FI', 'TAX_CATEGORY_STANDARD', 2550),
+  ('SHIPPING_COUNTRY_FI', 'TAX_CATEGORY_REDUCED', 1400),
+  ('SHIPPING_COUNTRY_FI', 'TAX_CATEGORY_DIGITAL_SERVICES', 2550),
+  ('SHIPPING_COUNTRY_FR', 'TAX_CATEGORY_STANDARD', 2000),
+  ('SHIPPING_COUNTRY_FR', 'TAX_CATEGORY_REDUCED', 1000),
+  ('SHIPPING_COUNTRY_FR', 'TAX_CATEGORY_DIGITAL_SERVICES', 2000),
+  ('SHIPPING_COUNTRY_DE', 'TAX_CATEGORY_STANDARD', 1900),
+  ('SHIPPING_COUNTRY_DE', 'TAX_CATEGORY_REDUCED', 700),
+  ('SHIPPING_COUNTRY_DE', 'TAX_CATEGORY_DIGITAL_SERVICES', 1900),
+  ('SHIPPING_COUNTRY_GR', 'TAX_CATEGORY_STANDARD', 2400),
+  ('SHIPPING_COUNTRY_GR', 'TAX_CATEGORY_REDUCED', 1300),
+  ('SHIPPING_COUNTRY_GR', 'TAX_CATEGORY_DIGITAL_SERVICES', 2400),
+  ('SHIPPING_COUNTRY_HU', 'TAX_CATEGORY_STANDARD', 2700),
+  ('SHIPPING_COUNTRY_HU', 'TAX_CATEGORY_REDUCED', 1800),
+  ('SHIPPING_COUNTRY_HU', 'TAX_CATEGORY_DIGITAL_SERVICES', 2700),
+  ('SHIPPING_COUNTRY_IE', 'TAX_CATEGORY_STANDARD', 2300),
+  ('SHIPPING_COUNTRY_IE', 'TAX_CATEGORY_REDUCED', 1350),
+  ('SHIPPING_COUNTRY_IE', 'TAX_CATEGORY_DIGITAL_SERVICES', 2300),
+  ('SHIPPING_COUNTRY_IT', 'TAX_CATEGORY_STANDARD', 2200),
+  ('SHIPPING_COUNTRY_IT', 'TAX_CATEGORY_REDUCED', 1000),
+  ('SHIPPING_COUNTRY_IT', 'TAX_CATEGORY_DIGITAL_SERVICES', 2200),
+  ('SHIPPING_COUNTRY_LV', 'TAX_CATEGORY_STANDARD', 2100),
+  ('SHIPPING_COUNTRY_LV', 'TAX_CATEGORY_REDUCED', 1200),
+  ('SHIPPING_COUNTRY_LV', 'TAX_CATEGORY_DIGITAL_SERVICES', 2100),
+  ('SHIPPING_COUNTRY_LT', 'TAX_CATEGORY_STANDARD', 2100),
+  ('SHIPPING_COUNTRY_LT', 'TAX_CATEGORY_REDUCED', 900),
+  ('SHIPPING_COUNTRY_LT', 'TAX_CATEGORY_DIGITAL_SERVICES', 2100),
+  ('SHIPPING_COUNTRY_LU', 'TAX_CATEGORY_STANDARD', 1700),
+  ('SHIPPING_COUNTRY_LU', 'TAX_CATEGORY_REDUCED', 800),
+  ('SHIPPING_COUNTRY_LU', 'TAX_CATEGORY_DIGITAL_SERVICES', 1700),
+  ('SHIPPING_COUNTRY_MT', 'TAX_CATEGORY_STANDARD', 1800),
+  ('SHIPPING_COUNTRY_MT', 'TAX_CATEGORY_REDUCED', 700),
+  ('SHIPPING_COUNTRY_MT', 'TAX_CATEGORY_DIGITAL_SERVICES', 1800),
+  ('SHIPPING_COUNTRY_NL', 'TAX_CATEGORY_STANDARD', 2100),
+  ('SHIPPING_COUNTRY_NL', 'TAX_CATEGORY_REDUCED', 900),
+  ('SHIPPING_COUNTRY_NL', 'TAX_CATEGORY_DIGITAL_SERVICES', 2100),
+  ('SHIPPING_COUNTRY_PL', 'TAX_CATEGORY_STANDARD', 2300),
+  ('SHIPPING_COUNTRY_PL', 'TAX_CATEGORY_REDUCED', 800),
+  ('SHIPPING_COUNTRY_PL', 'TAX_CATEGORY_DIGITAL_SERVICES', 2300),
+  ('SHIPPING_COUNTRY_PT', 'TAX_CATEGORY_STANDARD', 2300),
+  ('SHIPPING_COUNTRY_PT', 'TAX_CATEGORY_REDUCED', 1300),
+  ('SHIPPING_COUNTRY_PT', 'TAX_CATEGORY_DIGITAL_SERVICES', 2300),
+  ('SHIPPING_COUNTRY_RO', 'TAX_CATEGORY_STANDARD', 2100),
+  ('SHIPPING_COUNTRY_RO', 'TAX_CATEGORY_REDUCED', 1100),
+  ('SHIPPING_COUNTRY_RO', 'TAX_CATEGORY_DIGITAL_SERVICES', 2100),
+  ('SHIPPING_COUNTRY_SK', 'TAX_CATEGORY_STANDARD', 2300),
+  ('SHIPPING_COUNTRY_SK', 'TAX_CATEGORY_REDUCED', 1900),
+  ('SHIPPING_COUNTRY_SK', 'TAX_CATEGORY_DIGITAL_SERVICES', 2300),
+  ('SHIPPING_COUNTRY_SI', 'TAX_CATEGORY_STANDARD', 2200),
+  ('SHIPPING_COUNTRY_SI', 'TAX_CATEGORY_REDUCED', 950),
+  ('SHIPPING_COUNTRY_SI', 'TAX_CATEGORY_DIGITAL_SERVICES', 2200),
+  ('SHIPPING_COUNTRY_ES', 'TAX_CATEGORY_STANDARD', 2100),
+  ('SHIPPING_COUNTRY_ES', 'TAX_CATEGORY_REDUCED', 1000),
+  ('SHIPPING_COUNTRY_ES', 'TAX_CATEGORY_DIGITAL_SERVICES', 2100),
+  ('SHIPPING_COUNTRY_SE', 'TAX_CATEGORY_STANDARD', 2500),
+  ('SHIPPING_COUNTRY_SE', 'TAX_CATEGORY_REDUCED', 1200),
+  ('SHIPPING_COUNTRY_SE', 'TAX_CATEGORY_DIGITAL_SERVICES', 2500),
+  ('SHIPPING_COUNTRY_GB', 'TAX_CATEGORY_STANDARD', 2000),
+  ('SHIPPING_COUNTRY_GB', 'TAX_CATEGORY_REDUCED', 500),
+  ('SHIPPING_COUNTRY_GB', 'TAX_CATEGORY_DIGITAL_SERVICES', 2000),
+  ('SHIPPING_COUNTRY_CH', 'TAX_CATEGORY_STANDARD', 810),
+  ('SHIPPING_COUNTRY_CH', 'TAX_CATEGORY_REDUCED', 260),
+  ('SHIPPING_COUNTRY_CH', 'TAX_CATEGORY_DIGITAL_SERVICES', 810),
+  ('SHIPPING_COUNTRY_NO', 'TAX_CATEGORY_STANDARD', 2500),
+  ('SHIPPING_COUNTRY_NO', 'TAX_CATEGORY_REDUCED', 1500),
+  ('SHIPPING_COUNTRY_NO', 'TAX_CATEGORY_DIGITAL_SERVICES', 2500);
+
+ALTER TABLE
+  offers
+ADD
+  COLUMN tax_category VARCHAR NOT NULL DEFAULT 'TAX_CATEGORY_STANDARD';
+
+ALTER TABLE
+  shops
+ADD
+  COLUMN prices_include_tax BOOLEAN NOT NULL DEFAULT 't';
@@ -0,0 +1,50 @@
+CREATE TABLE shipping_profiles (
+  shipping_profile_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  shop_id UUID NOT NULL REFERENCES shops(shop_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  name VARCHAR NOT NULL,
+  currency VARCHAR NOT NULL
+);
+
+CREATE INDEX idx_shipping_profiles_shop_id ON shipping_profiles (shop_id);
+
+CREATE TABLE shipping_zones (
+  shipping_zone_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  shipping_profile_id UUID NOT NULL REFERENCES shipping_profiles(shipping_profile_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  name VARCHAR NOT NULL,
+  free_shipping_threshold INT
+);
+
+CREATE INDEX idx_shipping_zones_shipping_profile_id ON shipping_zones (shipping_profile_id);
+
+CREATE TABLE shipping_zone_countries (
+  shipping_zone_id UUID NOT NULL REFERENCES shipping_zones(shipping_zone_id) ON DELETE CASCADE,
+  country VARCHAR NOT NULL,
+  PRIMARY KEY (shipping_zone_id, country)
+);
+
+CREATE TABLE shipping_zone_rules (
+  shipping_zone_rule_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  shipping_zone_id UUID NOT NULL REFERENCES shipping_zones(shipping_zone_id) ON DELETE CASCADE,
+  rule_type VARCHAR NOT NULL,
+  min_value INT NOT NULL,
+  max_value INT,
+  amount INT NOT NULL
+);
+
+CREATE INDEX idx_shipping_zone_rules_shipping_zone_id ON shipping_zone_rules (shipping_zone_id);
+
+ALTER TABLE
+  offers
+ADD
+  COLUMN weight_grams INT;
+
+ALTER TABLE
+  offers
+ADD
+  COLUMN shipping_profile_id UUID REFERENCES shipping_profiles(shipping_profile_id) ON DELETE SET NULL;
@@ -0,0 +1,2 @@
+ALTER TABLE
+  shipping_rates DROP COLUMN specific_countries;
@@ -0,0 +1,49 @@
+CREATE TABLE categories (
+  category_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  parent_id UUID REFERENCES categories(category_id) ON DELETE CASCADE,
+  ancestor_ids UUID[] NOT NULL DEFAULT ARRAY[]::UUID[],
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  name VARCHAR NOT NULL,
+  slug VARCHAR NOT NULL UNIQUE,
+  ordering INT NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_categories_parent_id ON categories (parent_id);
+
+CREATE TABLE offer_categories (
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  category_id UUID NOT NULL REFERENCES categories(category_id) ON DELETE CASCADE,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (offer_id, category_id)
+);
+
+CREATE INDEX idx_offer_categories_category_id ON offer_categories (category_id);
+
+CREATE TABLE collections (
+  collection_id UUID NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+  shop_id UUID NOT NULL REFERENCES shops(shop_id) ON DELETE CASCADE,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  name VARCHAR NOT NULL,
+  slug VARCHAR NOT NULL,
+  ordering INT NOT NULL DEFAULT 0,
+  collection_type VARCHAR NOT NULL,
+  rule_offer_type VARCHAR,
+  rule_category_id UUID REFERENCES categories(category_id) ON DELETE SET NULL,
+  rule_is_featured BOOL,
+  rule_currency VARCHAR,
+  rule_min_price INT,
+  rule_max_price INT,
+  CONSTRAINT uq_collections_shop_id_slug UNIQUE (shop_id, slug)
+);
+
+CREATE TABLE collection_offers (
+  collection_id UUID NOT NULL REFERENCES collections(collection_id) ON DELETE CASCADE,
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  ordering INT NOT NULL DEFAULT 0,
+  PRIMARY KEY (collection_id, offer_id)
+);
+
+CREATE INDEX idx_collection_offers_offer_id ON collection_offers (offer_id);
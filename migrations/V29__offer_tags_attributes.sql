@@ -0,0 +1,17 @@
+CREATE TABLE offer_tags (
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  tag VARCHAR NOT NULL,
+  PRIMARY KEY (offer_id, tag)
+);
+
+CREATE INDEX idx_offer_tags_tag ON offer_tags (tag);
+
+CREATE TABLE offer_attributes (
+  offer_id UUID NOT NULL REFERENCES offers(offer_id) ON DELETE CASCADE,
+  key VARCHAR NOT NULL,
+  attribute_type VARCHAR NOT NULL,
+  value VARCHAR NOT NULL,
+  PRIMARY KEY (offer_id, key)
+);
+
+CREATE INDEX idx_offer_attributes_key_value ON offer_attributes (key, value);
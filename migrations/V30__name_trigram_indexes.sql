@@ -0,0 +1,3 @@
+CREATE INDEX idx_offers_name_trgm ON offers USING GIN (name gin_trgm_ops);
+
+CREATE INDEX idx_shops_name_trgm ON shops USING GIN (name gin_trgm_ops);
@@ -0,0 +1,11 @@
+ALTER TABLE shops ADD COLUMN content_language VARCHAR NOT NULL DEFAULT 'CONTENT_LANGUAGE_UNSPECIFIED';
+
+ALTER TABLE offers ADD COLUMN content_language VARCHAR NOT NULL DEFAULT 'CONTENT_LANGUAGE_UNSPECIFIED';
+
+ALTER TABLE shops DROP COLUMN name_ts;
+
+ALTER TABLE shops DROP COLUMN description_ts;
+
+ALTER TABLE offers DROP COLUMN name_ts;
+
+ALTER TABLE offers DROP COLUMN description_ts;
@@ -0,0 +1,31 @@
+ALTER TABLE shops ADD COLUMN name_ts tsvector GENERATED ALWAYS AS (
+  CASE content_language
+    WHEN 'CONTENT_LANGUAGE_GERMAN' THEN to_tsvector('german', name)
+    WHEN 'CONTENT_LANGUAGE_ENGLISH' THEN to_tsvector('english', name)
+    ELSE to_tsvector('simple', name)
+  END
+) STORED;
+
+ALTER TABLE shops ADD COLUMN description_ts tsvector GENERATED ALWAYS AS (
+  CASE content_language
+    WHEN 'CONTENT_LANGUAGE_GERMAN' THEN to_tsvector('german', description)
+    WHEN 'CONTENT_LANGUAGE_ENGLISH' THEN to_tsvector('english', description)
+    ELSE to_tsvector('simple', description)
+  END
+) STORED;
+
+ALTER TABLE offers ADD COLUMN name_ts tsvector GENERATED ALWAYS AS (
+  CASE content_language
+    WHEN 'CONTENT_LANGUAGE_GERMAN' THEN to_tsvector('german', name)
+    WHEN 'CONTENT_LANGUAGE_ENGLISH' THEN to_tsvector('english', name)
+    ELSE to_tsvector('simple', name)
+  END
+) STORED;
+
+ALTER TABLE offers ADD COLUMN description_ts tsvector GENERATED ALWAYS AS (
+  CASE content_language
+    WHEN 'CONTENT_LANGUAGE_GERMAN' THEN to_tsvector('german', description)
+    WHEN 'CONTENT_LANGUAGE_ENGLISH' THEN to_tsvector('english', description)
+    ELSE to_tsvector('simple', description)
+  END
+) STORED;
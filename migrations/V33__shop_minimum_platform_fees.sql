@@ -0,0 +1,9 @@
+CREATE TABLE shop_minimum_platform_fees (
+  shop_id UUID NOT NULL REFERENCES shops(shop_id) ON DELETE CASCADE,
+  currency VARCHAR NOT NULL,
+  user_id VARCHAR NOT NULL,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  amount INT NOT NULL,
+  PRIMARY KEY (shop_id, currency)
+);
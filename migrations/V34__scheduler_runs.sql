@@ -0,0 +1,6 @@
+CREATE TABLE scheduler_runs (
+  scheduler VARCHAR PRIMARY KEY,
+  created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW() ON UPDATE NOW(),
+  last_run_until TIMESTAMP WITH TIME ZONE NOT NULL
+);
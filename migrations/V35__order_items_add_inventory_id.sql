@@ -0,0 +1,4 @@
+ALTER TABLE
+  order_items
+ADD
+  COLUMN inventory_id UUID;
@@ -0,0 +1,25 @@
+ALTER TABLE
+  offer_images
+ADD
+  COLUMN variant_widths INT[] NOT NULL DEFAULT ARRAY[]::INT[],
+ADD
+  COLUMN variant_content_types VARCHAR[] NOT NULL DEFAULT ARRAY[]::VARCHAR[];
+
+ALTER TABLE
+  shop_customizations
+ADD
+  COLUMN logo_image_light_variant_widths INT[] NOT NULL DEFAULT ARRAY[]::INT[],
+ADD
+  COLUMN logo_image_light_variant_content_types VARCHAR[] NOT NULL DEFAULT ARRAY[]::VARCHAR[],
+ADD
+  COLUMN logo_image_dark_variant_widths INT[] NOT NULL DEFAULT ARRAY[]::INT[],
+ADD
+  COLUMN logo_image_dark_variant_content_types VARCHAR[] NOT NULL DEFAULT ARRAY[]::VARCHAR[],
+ADD
+  COLUMN banner_image_light_variant_widths INT[] NOT NULL DEFAULT ARRAY[]::INT[],
+ADD
+  COLUMN banner_image_light_variant_content_types VARCHAR[] NOT NULL DEFAULT ARRAY[]::VARCHAR[],
+ADD
+  COLUMN banner_image_dark_variant_widths INT[] NOT NULL DEFAULT ARRAY[]::INT[],
+ADD
+  COLUMN banner_image_dark_variant_content_types VARCHAR[] NOT NULL DEFAULT ARRAY[]::VARCHAR[];
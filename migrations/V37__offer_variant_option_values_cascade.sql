@@ -0,0 +1,6 @@
+ALTER TABLE
+  offer_variant_option_values
+DROP
+  CONSTRAINT offer_variant_option_values_offer_option_value_id_fkey,
+ADD
+  CONSTRAINT offer_variant_option_values_offer_option_value_id_fkey FOREIGN KEY (offer_option_value_id) REFERENCES offer_option_values(offer_option_value_id) ON DELETE CASCADE;
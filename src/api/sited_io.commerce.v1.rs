@@ -1,3 +1,42 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FilterOperator {
+    Unspecified = 0,
+    And = 1,
+    Or = 2,
+}
+impl FilterOperator {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FilterOperator::Unspecified => "FILTER_OPERATOR_UNSPECIFIED",
+            FilterOperator::And => "FILTER_OPERATOR_AND",
+            FilterOperator::Or => "FILTER_OPERATOR_OR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FILTER_OPERATOR_UNSPECIFIED" => Some(Self::Unspecified),
+            "FILTER_OPERATOR_AND" => Some(Self::And),
+            "FILTER_OPERATOR_OR" => Some(Self::Or),
+            _ => None,
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImageVariantResponse {
+    #[prost(string, tag = "1")]
+    pub image_url: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub width: u32,
+    #[prost(string, tag = "3")]
+    pub content_type: ::prost::alloc::string::String,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ShopCustomizationResponse {
@@ -21,6 +60,14 @@ pub struct ShopCustomizationResponse {
     pub primary_color: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(enumeration = "ShopLayoutType", tag = "10")]
     pub layout_type: i32,
+    #[prost(message, repeated, tag = "11")]
+    pub logo_image_light_variants: ::prost::alloc::vec::Vec<ImageVariantResponse>,
+    #[prost(message, repeated, tag = "12")]
+    pub logo_image_dark_variants: ::prost::alloc::vec::Vec<ImageVariantResponse>,
+    #[prost(message, repeated, tag = "13")]
+    pub banner_image_light_variants: ::prost::alloc::vec::Vec<ImageVariantResponse>,
+    #[prost(message, repeated, tag = "14")]
+    pub banner_image_dark_variants: ::prost::alloc::vec::Vec<ImageVariantResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -690,6 +737,10 @@ pub struct ShopResponse {
     pub client_id: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, tag = "15")]
     pub website_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "16")]
+    pub prices_include_tax: bool,
+    #[prost(enumeration = "ContentLanguage", tag = "17")]
+    pub content_language: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -704,6 +755,10 @@ pub struct CreateShopRequest {
     pub platform_fee_percent: ::core::option::Option<u32>,
     #[prost(uint32, optional, tag = "5")]
     pub minimum_platform_fee_cent: ::core::option::Option<u32>,
+    #[prost(bool, optional, tag = "6")]
+    pub prices_include_tax: ::core::option::Option<bool>,
+    #[prost(enumeration = "ContentLanguage", optional, tag = "7")]
+    pub content_language: ::core::option::Option<i32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -752,6 +807,16 @@ pub struct ShopsFilter {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShopsFilterGroup {
+    #[prost(enumeration = "FilterOperator", tag = "1")]
+    pub operator: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub filters: ::prost::alloc::vec::Vec<ShopsFilter>,
+    #[prost(message, repeated, tag = "3")]
+    pub groups: ::prost::alloc::vec::Vec<ShopsFilterGroup>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListShopsRequest {
     #[prost(string, optional, tag = "1")]
     pub user_id: ::core::option::Option<::prost::alloc::string::String>,
@@ -763,6 +828,14 @@ pub struct ListShopsRequest {
     pub filter: ::core::option::Option<ShopsFilter>,
     #[prost(bool, optional, tag = "5")]
     pub extended: ::core::option::Option<bool>,
+    #[prost(message, repeated, tag = "6")]
+    pub filters: ::prost::alloc::vec::Vec<ShopsFilter>,
+    #[prost(message, optional, tag = "7")]
+    pub filter_group: ::core::option::Option<ShopsFilterGroup>,
+    #[prost(message, repeated, tag = "8")]
+    pub order_bys: ::prost::alloc::vec::Vec<ShopsOrderBy>,
+    #[prost(string, optional, tag = "9")]
+    pub random_seed: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -791,6 +864,10 @@ pub struct UpdateShopRequest {
     pub is_active: ::core::option::Option<bool>,
     #[prost(string, optional, tag = "8")]
     pub contact_email_address: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "9")]
+    pub prices_include_tax: ::core::option::Option<bool>,
+    #[prost(enumeration = "ContentLanguage", optional, tag = "10")]
+    pub content_language: ::core::option::Option<i32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -807,6 +884,79 @@ pub struct DeleteShopRequest {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteShopResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CalculateFeesRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub unit_amount: u32,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+    #[prost(uint32, tag = "4")]
+    pub shipping_amount: u32,
+    #[prost(enumeration = "Currency", tag = "5")]
+    pub currency: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CalculateFeesResponse {
+    #[prost(uint32, tag = "1")]
+    pub gross_amount: u32,
+    #[prost(uint32, tag = "2")]
+    pub platform_fee_amount: u32,
+    #[prost(uint32, tag = "3")]
+    pub net_amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MinimumPlatformFeeResponse {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "Currency", tag = "2")]
+    pub currency: i32,
+    #[prost(uint32, tag = "3")]
+    pub amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutMinimumPlatformFeeRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "Currency", tag = "2")]
+    pub currency: i32,
+    #[prost(uint32, tag = "3")]
+    pub amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutMinimumPlatformFeeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub minimum_platform_fee: ::core::option::Option<MinimumPlatformFeeResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveMinimumPlatformFeeRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "Currency", tag = "2")]
+    pub currency: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveMinimumPlatformFeeResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMinimumPlatformFeesRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMinimumPlatformFeesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub minimum_platform_fees: ::prost::alloc::vec::Vec<MinimumPlatformFeeResponse>,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ShopsOrderByField {
@@ -815,6 +965,7 @@ pub enum ShopsOrderByField {
     UpdatedAt = 2,
     Name = 3,
     Random = 4,
+    Relevance = 5,
 }
 impl ShopsOrderByField {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -828,6 +979,7 @@ impl ShopsOrderByField {
             ShopsOrderByField::UpdatedAt => "SHOPS_ORDER_BY_FIELD_UPDATED_AT",
             ShopsOrderByField::Name => "SHOPS_ORDER_BY_FIELD_NAME",
             ShopsOrderByField::Random => "SHOPS_ORDER_BY_FIELD_RANDOM",
+            ShopsOrderByField::Relevance => "SHOPS_ORDER_BY_FIELD_RELEVANCE",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -838,6 +990,7 @@ impl ShopsOrderByField {
             "SHOPS_ORDER_BY_FIELD_UPDATED_AT" => Some(Self::UpdatedAt),
             "SHOPS_ORDER_BY_FIELD_NAME" => Some(Self::Name),
             "SHOPS_ORDER_BY_FIELD_RANDOM" => Some(Self::Random),
+            "SHOPS_ORDER_BY_FIELD_RELEVANCE" => Some(Self::Relevance),
             _ => None,
         }
     }
@@ -876,6 +1029,35 @@ impl ShopsFilterField {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ContentLanguage {
+    Unspecified = 0,
+    German = 1,
+    English = 2,
+}
+impl ContentLanguage {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ContentLanguage::Unspecified => "CONTENT_LANGUAGE_UNSPECIFIED",
+            ContentLanguage::German => "CONTENT_LANGUAGE_GERMAN",
+            ContentLanguage::English => "CONTENT_LANGUAGE_ENGLISH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CONTENT_LANGUAGE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CONTENT_LANGUAGE_GERMAN" => Some(Self::German),
+            "CONTENT_LANGUAGE_ENGLISH" => Some(Self::English),
+            _ => None,
+        }
+    }
+}
 /// Generated server implementations.
 pub mod shop_service_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -915,6 +1097,34 @@ pub mod shop_service_server {
             tonic::Response<super::DeleteShopResponse>,
             tonic::Status,
         >;
+        async fn calculate_fees(
+            &self,
+            request: tonic::Request<super::CalculateFeesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CalculateFeesResponse>,
+            tonic::Status,
+        >;
+        async fn put_minimum_platform_fee(
+            &self,
+            request: tonic::Request<super::PutMinimumPlatformFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutMinimumPlatformFeeResponse>,
+            tonic::Status,
+        >;
+        async fn remove_minimum_platform_fee(
+            &self,
+            request: tonic::Request<super::RemoveMinimumPlatformFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveMinimumPlatformFeeResponse>,
+            tonic::Status,
+        >;
+        async fn list_minimum_platform_fees(
+            &self,
+            request: tonic::Request<super::ListMinimumPlatformFeesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMinimumPlatformFeesResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct ShopServiceServer<T: ShopService> {
@@ -1215,110 +1425,298 @@ pub mod shop_service_server {
                     };
                     Box::pin(fut)
                 }
-                _ => {
-                    Box::pin(async move {
-                        Ok(
-                            http::Response::builder()
-                                .status(200)
-                                .header("grpc-status", "12")
-                                .header("content-type", "application/grpc")
-                                .body(empty_body())
-                                .unwrap(),
-                        )
-                    })
+                "/sited_io.commerce.v1.ShopService/CalculateFees" => {
+                    #[allow(non_camel_case_types)]
+                    struct CalculateFeesSvc<T: ShopService>(pub Arc<T>);
+                    impl<
+                        T: ShopService,
+                    > tonic::server::UnaryService<super::CalculateFeesRequest>
+                    for CalculateFeesSvc<T> {
+                        type Response = super::CalculateFeesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CalculateFeesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).calculate_fees(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CalculateFeesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
                 }
-            }
-        }
-    }
-    impl<T: ShopService> Clone for ShopServiceServer<T> {
-        fn clone(&self) -> Self {
-            let inner = self.inner.clone();
-            Self {
-                inner,
-                accept_compression_encodings: self.accept_compression_encodings,
-                send_compression_encodings: self.send_compression_encodings,
-                max_decoding_message_size: self.max_decoding_message_size,
-                max_encoding_message_size: self.max_encoding_message_size,
-            }
-        }
-    }
-    impl<T: ShopService> Clone for _Inner<T> {
-        fn clone(&self) -> Self {
-            Self(Arc::clone(&self.0))
-        }
-    }
-    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{:?}", self.0)
-        }
-    }
-    impl<T: ShopService> tonic::server::NamedService for ShopServiceServer<T> {
-        const NAME: &'static str = "sited_io.commerce.v1.ShopService";
-    }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DomainStatusResponse {
-    #[prost(string, tag = "1")]
-    pub shop_id: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub domain: ::prost::alloc::string::String,
-    #[prost(enumeration = "DomainStatus", tag = "3")]
-    pub status: i32,
-    #[prost(string, optional, tag = "4")]
-    pub client_id: ::core::option::Option<::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AddDomainToShopRequest {
-    #[prost(string, tag = "1")]
-    pub shop_id: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub domain: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AddDomainToShopResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetDomainStatusRequest {
-    #[prost(string, tag = "1")]
-    pub shop_id: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetDomainStatusResponse {
-    #[prost(message, optional, tag = "1")]
-    pub domain_status: ::core::option::Option<DomainStatusResponse>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetClientIdForDomainRequest {
-    #[prost(string, tag = "1")]
-    pub domain: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetClientIdForDomainResponse {
-    #[prost(string, optional, tag = "1")]
-    pub client_id: ::core::option::Option<::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateDomainStatusRequest {
-    #[prost(string, tag = "1")]
-    pub shop_id: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub domain: ::prost::alloc::string::String,
-    #[prost(enumeration = "DomainStatus", tag = "3")]
-    pub status: i32,
-    #[prost(string, tag = "4")]
-    pub client_id: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateDomainStatusResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
+                "/sited_io.commerce.v1.ShopService/PutMinimumPlatformFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutMinimumPlatformFeeSvc<T: ShopService>(pub Arc<T>);
+                    impl<
+                        T: ShopService,
+                    > tonic::server::UnaryService<super::PutMinimumPlatformFeeRequest>
+                    for PutMinimumPlatformFeeSvc<T> {
+                        type Response = super::PutMinimumPlatformFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutMinimumPlatformFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_minimum_platform_fee(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutMinimumPlatformFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShopService/RemoveMinimumPlatformFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveMinimumPlatformFeeSvc<T: ShopService>(pub Arc<T>);
+                    impl<
+                        T: ShopService,
+                    > tonic::server::UnaryService<super::RemoveMinimumPlatformFeeRequest>
+                    for RemoveMinimumPlatformFeeSvc<T> {
+                        type Response = super::RemoveMinimumPlatformFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::RemoveMinimumPlatformFeeRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_minimum_platform_fee(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveMinimumPlatformFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShopService/ListMinimumPlatformFees" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMinimumPlatformFeesSvc<T: ShopService>(pub Arc<T>);
+                    impl<
+                        T: ShopService,
+                    > tonic::server::UnaryService<super::ListMinimumPlatformFeesRequest>
+                    for ListMinimumPlatformFeesSvc<T> {
+                        type Response = super::ListMinimumPlatformFeesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListMinimumPlatformFeesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).list_minimum_platform_fees(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListMinimumPlatformFeesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ShopService> Clone for ShopServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: ShopService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ShopService> tonic::server::NamedService for ShopServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.ShopService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DomainStatusResponse {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub domain: ::prost::alloc::string::String,
+    #[prost(enumeration = "DomainStatus", tag = "3")]
+    pub status: i32,
+    #[prost(string, optional, tag = "4")]
+    pub client_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddDomainToShopRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub domain: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddDomainToShopResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDomainStatusRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDomainStatusResponse {
+    #[prost(message, optional, tag = "1")]
+    pub domain_status: ::core::option::Option<DomainStatusResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetClientIdForDomainRequest {
+    #[prost(string, tag = "1")]
+    pub domain: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetClientIdForDomainResponse {
+    #[prost(string, optional, tag = "1")]
+    pub client_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateDomainStatusRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub domain: ::prost::alloc::string::String,
+    #[prost(enumeration = "DomainStatus", tag = "3")]
+    pub status: i32,
+    #[prost(string, tag = "4")]
+    pub client_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateDomainStatusResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RemoveDomainFromShopRequest {
     #[prost(string, tag = "1")]
@@ -1775,12 +2173,42 @@ pub struct Price {
     pub unit_amount: u32,
     #[prost(message, optional, tag = "5")]
     pub recurring: ::core::option::Option<Recurring>,
+    #[prost(message, repeated, tag = "6")]
+    pub tiers: ::prost::alloc::vec::Vec<PriceTier>,
+    #[prost(uint32, optional, tag = "7")]
+    pub package_size: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "8")]
+    pub compare_at_amount: ::core::option::Option<u32>,
+    #[prost(int64, optional, tag = "9")]
+    pub sale_starts_at: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "10")]
+    pub sale_ends_at: ::core::option::Option<i64>,
+    #[prost(bool, tag = "11")]
+    pub is_on_sale: bool,
+    #[prost(uint32, tag = "12")]
+    pub effective_unit_amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceTier {
+    #[prost(uint32, optional, tag = "1")]
+    pub up_to: ::core::option::Option<u32>,
+    #[prost(uint32, tag = "2")]
+    pub unit_amount: u32,
+    #[prost(uint32, tag = "3")]
+    pub flat_amount: u32,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum Currency {
     Unspecified = 0,
     Eur = 1,
+    Usd = 2,
+    Gbp = 3,
+    Chf = 4,
+    Sek = 5,
+    Pln = 6,
+    Jpy = 7,
 }
 impl Currency {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1791,6 +2219,12 @@ impl Currency {
         match self {
             Currency::Unspecified => "CURRENCY_UNSPECIFIED",
             Currency::Eur => "CURRENCY_EUR",
+            Currency::Usd => "CURRENCY_USD",
+            Currency::Gbp => "CURRENCY_GBP",
+            Currency::Chf => "CURRENCY_CHF",
+            Currency::Sek => "CURRENCY_SEK",
+            Currency::Pln => "CURRENCY_PLN",
+            Currency::Jpy => "CURRENCY_JPY",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1798,6 +2232,12 @@ impl Currency {
         match value {
             "CURRENCY_UNSPECIFIED" => Some(Self::Unspecified),
             "CURRENCY_EUR" => Some(Self::Eur),
+            "CURRENCY_USD" => Some(Self::Usd),
+            "CURRENCY_GBP" => Some(Self::Gbp),
+            "CURRENCY_CHF" => Some(Self::Chf),
+            "CURRENCY_SEK" => Some(Self::Sek),
+            "CURRENCY_PLN" => Some(Self::Pln),
+            "CURRENCY_JPY" => Some(Self::Jpy),
             _ => None,
         }
     }
@@ -1836,6 +2276,9 @@ impl PriceType {
 pub enum PriceBillingScheme {
     Unspecified = 0,
     PerUnit = 1,
+    Tiered = 2,
+    Volume = 3,
+    Package = 4,
 }
 impl PriceBillingScheme {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1846,6 +2289,9 @@ impl PriceBillingScheme {
         match self {
             PriceBillingScheme::Unspecified => "PRICE_BILLING_SCHEME_UNSPECIFIED",
             PriceBillingScheme::PerUnit => "PRICE_BILLING_SCHEME_PER_UNIT",
+            PriceBillingScheme::Tiered => "PRICE_BILLING_SCHEME_TIERED",
+            PriceBillingScheme::Volume => "PRICE_BILLING_SCHEME_VOLUME",
+            PriceBillingScheme::Package => "PRICE_BILLING_SCHEME_PACKAGE",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1853,6 +2299,9 @@ impl PriceBillingScheme {
         match value {
             "PRICE_BILLING_SCHEME_UNSPECIFIED" => Some(Self::Unspecified),
             "PRICE_BILLING_SCHEME_PER_UNIT" => Some(Self::PerUnit),
+            "PRICE_BILLING_SCHEME_TIERED" => Some(Self::Tiered),
+            "PRICE_BILLING_SCHEME_VOLUME" => Some(Self::Volume),
+            "PRICE_BILLING_SCHEME_PACKAGE" => Some(Self::Package),
             _ => None,
         }
     }
@@ -1894,390 +2343,261 @@ impl RecurringInterval {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OfferResponse {
+pub struct InventoryResponse {
     #[prost(string, tag = "1")]
-    pub offer_id: ::prost::alloc::string::String,
+    pub inventory_id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
-    pub shop_id: ::prost::alloc::string::String,
-    #[prost(string, tag = "3")]
-    pub shop_name: ::prost::alloc::string::String,
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, tag = "4")]
-    pub shop_slug: ::prost::alloc::string::String,
-    #[prost(string, optional, tag = "5")]
-    pub shop_domain: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(string, tag = "6")]
     pub user_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "5")]
+    pub on_hand: i64,
+    #[prost(int64, tag = "6")]
+    pub reserved: i64,
     #[prost(int64, tag = "7")]
-    pub created_at: i64,
-    #[prost(int64, tag = "8")]
-    pub updated_at: i64,
-    #[prost(string, tag = "9")]
-    pub name: ::prost::alloc::string::String,
-    #[prost(string, tag = "10")]
-    pub description: ::prost::alloc::string::String,
-    #[prost(bool, tag = "11")]
-    pub is_active: bool,
-    #[prost(bool, tag = "12")]
-    pub is_featured: bool,
-    #[prost(enumeration = "OfferType", tag = "13")]
-    pub r#type: i32,
-    #[prost(message, repeated, tag = "14")]
-    pub images: ::prost::alloc::vec::Vec<OfferImageResponse>,
-    #[prost(message, optional, tag = "15")]
-    pub price: ::core::option::Option<Price>,
+    pub available: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OfferImageResponse {
+pub struct InventoryReservationResponse {
     #[prost(string, tag = "1")]
-    pub offer_image_id: ::prost::alloc::string::String,
+    pub inventory_reservation_id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
-    pub image_url: ::prost::alloc::string::String,
-    #[prost(int64, tag = "3")]
-    pub ordering: i64,
+    pub inventory_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "5")]
+    pub quantity: u32,
+    #[prost(enumeration = "InventoryReservationStatus", tag = "6")]
+    pub status: i32,
+    #[prost(int64, tag = "7")]
+    pub expires_at: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CreateOfferRequest {
+pub struct PutInventoryRequest {
     #[prost(string, tag = "1")]
-    pub shop_id: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub name: ::prost::alloc::string::String,
-    #[prost(string, optional, tag = "3")]
-    pub description: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(enumeration = "OfferType", tag = "4")]
-    pub r#type: i32,
-    #[prost(bool, tag = "5")]
-    pub is_featured: bool,
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, tag = "3")]
+    pub on_hand: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CreateOfferResponse {
+pub struct PutInventoryResponse {
     #[prost(message, optional, tag = "1")]
-    pub offer: ::core::option::Option<OfferResponse>,
+    pub inventory: ::core::option::Option<InventoryResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetOfferRequest {
+pub struct GetInventoryRequest {
     #[prost(string, tag = "1")]
     pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetOfferResponse {
+pub struct GetInventoryResponse {
     #[prost(message, optional, tag = "1")]
-    pub offer: ::core::option::Option<OfferResponse>,
+    pub inventory: ::core::option::Option<InventoryResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetMyOfferRequest {
+pub struct ListInventoriesRequest {
     #[prost(string, tag = "1")]
     pub offer_id: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetMyOfferResponse {
-    #[prost(message, optional, tag = "1")]
-    pub offer: ::core::option::Option<OfferResponse>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OffersOrderBy {
-    #[prost(enumeration = "OffersOrderByField", tag = "1")]
-    pub field: i32,
-    #[prost(enumeration = "super::super::types::v1::Direction", tag = "2")]
-    pub direction: i32,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OffersFilter {
-    #[prost(enumeration = "OffersFilterField", tag = "1")]
-    pub field: i32,
-    #[prost(string, tag = "2")]
-    pub query: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListOffersRequest {
-    #[prost(string, optional, tag = "1")]
-    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(string, optional, tag = "2")]
-    pub shop_id: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(message, optional, tag = "3")]
-    pub pagination: ::core::option::Option<super::super::types::v1::PaginationRequest>,
-    #[prost(message, optional, tag = "4")]
-    pub order_by: ::core::option::Option<OffersOrderBy>,
-    #[prost(message, optional, tag = "5")]
-    pub filter: ::core::option::Option<OffersFilter>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListOffersResponse {
+pub struct ListInventoriesResponse {
     #[prost(message, repeated, tag = "1")]
-    pub offers: ::prost::alloc::vec::Vec<OfferResponse>,
-    #[prost(message, optional, tag = "2")]
-    pub pagination: ::core::option::Option<super::super::types::v1::PaginationResponse>,
+    pub inventories: ::prost::alloc::vec::Vec<InventoryResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateOfferRequest {
+pub struct DeleteInventoryRequest {
     #[prost(string, tag = "1")]
-    pub offer_id: ::prost::alloc::string::String,
-    #[prost(string, optional, tag = "2")]
-    pub name: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(string, optional, tag = "3")]
-    pub description: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(bool, optional, tag = "4")]
-    pub is_active: ::core::option::Option<bool>,
-    #[prost(enumeration = "OfferType", optional, tag = "5")]
-    pub r#type: ::core::option::Option<i32>,
-    #[prost(bool, optional, tag = "6")]
-    pub is_featured: ::core::option::Option<bool>,
+    pub inventory_id: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateOfferResponse {
-    #[prost(message, optional, tag = "1")]
-    pub offer: ::core::option::Option<OfferResponse>,
-}
+pub struct DeleteInventoryResponse {}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DeleteOfferRequest {
+pub struct ReserveInventoryRequest {
     #[prost(string, tag = "1")]
     pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+    #[prost(uint32, optional, tag = "4")]
+    pub ttl_seconds: ::core::option::Option<u32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DeleteOfferResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AddImageToOfferRequest {
-    #[prost(string, tag = "1")]
-    pub offer_id: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "2")]
-    pub image: ::core::option::Option<super::super::media::v1::MediaUpload>,
-    #[prost(int64, tag = "3")]
-    pub ordering: i64,
+pub struct ReserveInventoryResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<InventoryReservationResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AddImageToOfferResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct RemoveImageFromOfferRequest {
+pub struct CommitInventoryReservationRequest {
     #[prost(string, tag = "1")]
-    pub offer_image_id: ::prost::alloc::string::String,
+    pub inventory_reservation_id: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct RemoveImageFromOfferResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PutPriceToOfferRequest {
-    #[prost(string, tag = "1")]
-    pub offer_id: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "2")]
-    pub price: ::core::option::Option<Price>,
+pub struct CommitInventoryReservationResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<InventoryReservationResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PutPriceToOfferResponse {}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct RemovePriceFromOfferRequest {
+pub struct ReleaseInventoryReservationRequest {
     #[prost(string, tag = "1")]
-    pub offer_id: ::prost::alloc::string::String,
+    pub inventory_reservation_id: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct RemovePriceFromOfferResponse {}
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum OfferType {
-    Unspecified = 0,
-    Physical = 1,
-    Digital = 2,
-    Service = 3,
-}
-impl OfferType {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            OfferType::Unspecified => "OFFER_TYPE_UNSPECIFIED",
-            OfferType::Physical => "OFFER_TYPE_PHYSICAL",
-            OfferType::Digital => "OFFER_TYPE_DIGITAL",
-            OfferType::Service => "OFFER_TYPE_SERVICE",
-        }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "OFFER_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
-            "OFFER_TYPE_PHYSICAL" => Some(Self::Physical),
-            "OFFER_TYPE_DIGITAL" => Some(Self::Digital),
-            "OFFER_TYPE_SERVICE" => Some(Self::Service),
-            _ => None,
-        }
-    }
+pub struct ReleaseInventoryReservationResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<InventoryReservationResponse>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
-pub enum OffersOrderByField {
+pub enum InventoryPolicy {
     Unspecified = 0,
-    CreatedAt = 1,
-    UpdatedAt = 2,
-    Name = 3,
-    Random = 4,
+    Deny = 1,
+    AllowBackorder = 2,
 }
-impl OffersOrderByField {
+impl InventoryPolicy {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
-            OffersOrderByField::Unspecified => "OFFERS_ORDER_BY_FIELD_UNSPECIFIED",
-            OffersOrderByField::CreatedAt => "OFFERS_ORDER_BY_FIELD_CREATED_AT",
-            OffersOrderByField::UpdatedAt => "OFFERS_ORDER_BY_FIELD_UPDATED_AT",
-            OffersOrderByField::Name => "OFFERS_ORDER_BY_FIELD_NAME",
-            OffersOrderByField::Random => "OFFERS_ORDER_BY_FIELD_RANDOM",
+            InventoryPolicy::Unspecified => "INVENTORY_POLICY_UNSPECIFIED",
+            InventoryPolicy::Deny => "INVENTORY_POLICY_DENY",
+            InventoryPolicy::AllowBackorder => "INVENTORY_POLICY_ALLOW_BACKORDER",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
-            "OFFERS_ORDER_BY_FIELD_UNSPECIFIED" => Some(Self::Unspecified),
-            "OFFERS_ORDER_BY_FIELD_CREATED_AT" => Some(Self::CreatedAt),
-            "OFFERS_ORDER_BY_FIELD_UPDATED_AT" => Some(Self::UpdatedAt),
-            "OFFERS_ORDER_BY_FIELD_NAME" => Some(Self::Name),
-            "OFFERS_ORDER_BY_FIELD_RANDOM" => Some(Self::Random),
+            "INVENTORY_POLICY_UNSPECIFIED" => Some(Self::Unspecified),
+            "INVENTORY_POLICY_DENY" => Some(Self::Deny),
+            "INVENTORY_POLICY_ALLOW_BACKORDER" => Some(Self::AllowBackorder),
             _ => None,
         }
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
-pub enum OffersFilterField {
+pub enum InventoryReservationStatus {
     Unspecified = 0,
-    Name = 1,
-    Description = 2,
-    NameAndDescription = 3,
-    Type = 4,
-    IsFeatured = 5,
+    Reserved = 1,
+    Committed = 2,
+    Released = 3,
 }
-impl OffersFilterField {
+impl InventoryReservationStatus {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
-            OffersFilterField::Unspecified => "OFFERS_FILTER_FIELD_UNSPECIFIED",
-            OffersFilterField::Name => "OFFERS_FILTER_FIELD_NAME",
-            OffersFilterField::Description => "OFFERS_FILTER_FIELD_DESCRIPTION",
-            OffersFilterField::NameAndDescription => {
-                "OFFERS_FILTER_FIELD_NAME_AND_DESCRIPTION"
+            InventoryReservationStatus::Unspecified => {
+                "INVENTORY_RESERVATION_STATUS_UNSPECIFIED"
+            }
+            InventoryReservationStatus::Reserved => {
+                "INVENTORY_RESERVATION_STATUS_RESERVED"
+            }
+            InventoryReservationStatus::Committed => {
+                "INVENTORY_RESERVATION_STATUS_COMMITTED"
+            }
+            InventoryReservationStatus::Released => {
+                "INVENTORY_RESERVATION_STATUS_RELEASED"
             }
-            OffersFilterField::Type => "OFFERS_FILTER_FIELD_TYPE",
-            OffersFilterField::IsFeatured => "OFFERS_FILTER_FIELD_IS_FEATURED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
-            "OFFERS_FILTER_FIELD_UNSPECIFIED" => Some(Self::Unspecified),
-            "OFFERS_FILTER_FIELD_NAME" => Some(Self::Name),
-            "OFFERS_FILTER_FIELD_DESCRIPTION" => Some(Self::Description),
-            "OFFERS_FILTER_FIELD_NAME_AND_DESCRIPTION" => Some(Self::NameAndDescription),
-            "OFFERS_FILTER_FIELD_TYPE" => Some(Self::Type),
-            "OFFERS_FILTER_FIELD_IS_FEATURED" => Some(Self::IsFeatured),
+            "INVENTORY_RESERVATION_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "INVENTORY_RESERVATION_STATUS_RESERVED" => Some(Self::Reserved),
+            "INVENTORY_RESERVATION_STATUS_COMMITTED" => Some(Self::Committed),
+            "INVENTORY_RESERVATION_STATUS_RELEASED" => Some(Self::Released),
             _ => None,
         }
     }
 }
 /// Generated server implementations.
-pub mod offer_service_server {
+pub mod inventory_service_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with OfferServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with InventoryServiceServer.
     #[async_trait]
-    pub trait OfferService: Send + Sync + 'static {
-        async fn create_offer(
-            &self,
-            request: tonic::Request<super::CreateOfferRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateOfferResponse>,
-            tonic::Status,
-        >;
-        async fn get_offer(
-            &self,
-            request: tonic::Request<super::GetOfferRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetOfferResponse>,
-            tonic::Status,
-        >;
-        async fn get_my_offer(
-            &self,
-            request: tonic::Request<super::GetMyOfferRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetMyOfferResponse>,
-            tonic::Status,
-        >;
-        async fn list_offers(
+    pub trait InventoryService: Send + Sync + 'static {
+        async fn put_inventory(
             &self,
-            request: tonic::Request<super::ListOffersRequest>,
+            request: tonic::Request<super::PutInventoryRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListOffersResponse>,
+            tonic::Response<super::PutInventoryResponse>,
             tonic::Status,
         >;
-        async fn update_offer(
+        async fn get_inventory(
             &self,
-            request: tonic::Request<super::UpdateOfferRequest>,
+            request: tonic::Request<super::GetInventoryRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateOfferResponse>,
+            tonic::Response<super::GetInventoryResponse>,
             tonic::Status,
         >;
-        async fn delete_offer(
+        async fn list_inventories(
             &self,
-            request: tonic::Request<super::DeleteOfferRequest>,
+            request: tonic::Request<super::ListInventoriesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteOfferResponse>,
+            tonic::Response<super::ListInventoriesResponse>,
             tonic::Status,
         >;
-        async fn add_image_to_offer(
+        async fn delete_inventory(
             &self,
-            request: tonic::Request<super::AddImageToOfferRequest>,
+            request: tonic::Request<super::DeleteInventoryRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AddImageToOfferResponse>,
+            tonic::Response<super::DeleteInventoryResponse>,
             tonic::Status,
         >;
-        async fn remove_image_from_offer(
+        async fn reserve_inventory(
             &self,
-            request: tonic::Request<super::RemoveImageFromOfferRequest>,
+            request: tonic::Request<super::ReserveInventoryRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::RemoveImageFromOfferResponse>,
+            tonic::Response<super::ReserveInventoryResponse>,
             tonic::Status,
         >;
-        async fn put_price_to_offer(
+        async fn commit_inventory_reservation(
             &self,
-            request: tonic::Request<super::PutPriceToOfferRequest>,
+            request: tonic::Request<super::CommitInventoryReservationRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::PutPriceToOfferResponse>,
+            tonic::Response<super::CommitInventoryReservationResponse>,
             tonic::Status,
         >;
-        async fn remove_price_from_offer(
+        async fn release_inventory_reservation(
             &self,
-            request: tonic::Request<super::RemovePriceFromOfferRequest>,
+            request: tonic::Request<super::ReleaseInventoryReservationRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::RemovePriceFromOfferResponse>,
+            tonic::Response<super::ReleaseInventoryReservationResponse>,
             tonic::Status,
         >;
     }
     #[derive(Debug)]
-    pub struct OfferServiceServer<T: OfferService> {
+    pub struct InventoryServiceServer<T: InventoryService> {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
@@ -2285,7 +2605,7 @@ pub mod offer_service_server {
         max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: OfferService> OfferServiceServer<T> {
+    impl<T: InventoryService> InventoryServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -2337,9 +2657,9 @@ pub mod offer_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for OfferServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for InventoryServiceServer<T>
     where
-        T: OfferService,
+        T: InventoryService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -2355,25 +2675,25 @@ pub mod offer_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/sited_io.commerce.v1.OfferService/CreateOffer" => {
+                "/sited_io.commerce.v1.InventoryService/PutInventory" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateOfferSvc<T: OfferService>(pub Arc<T>);
+                    struct PutInventorySvc<T: InventoryService>(pub Arc<T>);
                     impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::CreateOfferRequest>
-                    for CreateOfferSvc<T> {
-                        type Response = super::CreateOfferResponse;
+                        T: InventoryService,
+                    > tonic::server::UnaryService<super::PutInventoryRequest>
+                    for PutInventorySvc<T> {
+                        type Response = super::PutInventoryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CreateOfferRequest>,
+                            request: tonic::Request<super::PutInventoryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).create_offer(request).await
+                                (*inner).put_inventory(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2385,7 +2705,7 @@ pub mod offer_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = CreateOfferSvc(inner);
+                        let method = PutInventorySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2401,159 +2721,25 @@ pub mod offer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.OfferService/GetOffer" => {
+                "/sited_io.commerce.v1.InventoryService/GetInventory" => {
                     #[allow(non_camel_case_types)]
-                    struct GetOfferSvc<T: OfferService>(pub Arc<T>);
+                    struct GetInventorySvc<T: InventoryService>(pub Arc<T>);
                     impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::GetOfferRequest>
-                    for GetOfferSvc<T> {
-                        type Response = super::GetOfferResponse;
+                        T: InventoryService,
+                    > tonic::server::UnaryService<super::GetInventoryRequest>
+                    for GetInventorySvc<T> {
+                        type Response = super::GetInventoryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetOfferRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_offer(request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = GetOfferSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/sited_io.commerce.v1.OfferService/GetMyOffer" => {
-                    #[allow(non_camel_case_types)]
-                    struct GetMyOfferSvc<T: OfferService>(pub Arc<T>);
-                    impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::GetMyOfferRequest>
-                    for GetMyOfferSvc<T> {
-                        type Response = super::GetMyOfferResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::GetMyOfferRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).get_my_offer(request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = GetMyOfferSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/sited_io.commerce.v1.OfferService/ListOffers" => {
-                    #[allow(non_camel_case_types)]
-                    struct ListOffersSvc<T: OfferService>(pub Arc<T>);
-                    impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::ListOffersRequest>
-                    for ListOffersSvc<T> {
-                        type Response = super::ListOffersResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::ListOffersRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).list_offers(request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = ListOffersSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/sited_io.commerce.v1.OfferService/UpdateOffer" => {
-                    #[allow(non_camel_case_types)]
-                    struct UpdateOfferSvc<T: OfferService>(pub Arc<T>);
-                    impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::UpdateOfferRequest>
-                    for UpdateOfferSvc<T> {
-                        type Response = super::UpdateOfferResponse;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::UpdateOfferRequest>,
+                            request: tonic::Request<super::GetInventoryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).update_offer(request).await
+                                (*inner).get_inventory(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2565,7 +2751,7 @@ pub mod offer_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateOfferSvc(inner);
+                        let method = GetInventorySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2581,25 +2767,25 @@ pub mod offer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.OfferService/DeleteOffer" => {
+                "/sited_io.commerce.v1.InventoryService/ListInventories" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteOfferSvc<T: OfferService>(pub Arc<T>);
+                    struct ListInventoriesSvc<T: InventoryService>(pub Arc<T>);
                     impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::DeleteOfferRequest>
-                    for DeleteOfferSvc<T> {
-                        type Response = super::DeleteOfferResponse;
+                        T: InventoryService,
+                    > tonic::server::UnaryService<super::ListInventoriesRequest>
+                    for ListInventoriesSvc<T> {
+                        type Response = super::ListInventoriesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteOfferRequest>,
+                            request: tonic::Request<super::ListInventoriesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).delete_offer(request).await
+                                (*inner).list_inventories(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2611,7 +2797,7 @@ pub mod offer_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DeleteOfferSvc(inner);
+                        let method = ListInventoriesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2627,25 +2813,25 @@ pub mod offer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.OfferService/AddImageToOffer" => {
+                "/sited_io.commerce.v1.InventoryService/DeleteInventory" => {
                     #[allow(non_camel_case_types)]
-                    struct AddImageToOfferSvc<T: OfferService>(pub Arc<T>);
+                    struct DeleteInventorySvc<T: InventoryService>(pub Arc<T>);
                     impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::AddImageToOfferRequest>
-                    for AddImageToOfferSvc<T> {
-                        type Response = super::AddImageToOfferResponse;
+                        T: InventoryService,
+                    > tonic::server::UnaryService<super::DeleteInventoryRequest>
+                    for DeleteInventorySvc<T> {
+                        type Response = super::DeleteInventoryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddImageToOfferRequest>,
+                            request: tonic::Request<super::DeleteInventoryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).add_image_to_offer(request).await
+                                (*inner).delete_inventory(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2657,7 +2843,7 @@ pub mod offer_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AddImageToOfferSvc(inner);
+                        let method = DeleteInventorySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2673,25 +2859,25 @@ pub mod offer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.OfferService/RemoveImageFromOffer" => {
+                "/sited_io.commerce.v1.InventoryService/ReserveInventory" => {
                     #[allow(non_camel_case_types)]
-                    struct RemoveImageFromOfferSvc<T: OfferService>(pub Arc<T>);
+                    struct ReserveInventorySvc<T: InventoryService>(pub Arc<T>);
                     impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::RemoveImageFromOfferRequest>
-                    for RemoveImageFromOfferSvc<T> {
-                        type Response = super::RemoveImageFromOfferResponse;
+                        T: InventoryService,
+                    > tonic::server::UnaryService<super::ReserveInventoryRequest>
+                    for ReserveInventorySvc<T> {
+                        type Response = super::ReserveInventoryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RemoveImageFromOfferRequest>,
+                            request: tonic::Request<super::ReserveInventoryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).remove_image_from_offer(request).await
+                                (*inner).reserve_inventory(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2703,7 +2889,7 @@ pub mod offer_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = RemoveImageFromOfferSvc(inner);
+                        let method = ReserveInventorySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2719,25 +2905,30 @@ pub mod offer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.OfferService/PutPriceToOffer" => {
+                "/sited_io.commerce.v1.InventoryService/CommitInventoryReservation" => {
                     #[allow(non_camel_case_types)]
-                    struct PutPriceToOfferSvc<T: OfferService>(pub Arc<T>);
+                    struct CommitInventoryReservationSvc<T: InventoryService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::PutPriceToOfferRequest>
-                    for PutPriceToOfferSvc<T> {
-                        type Response = super::PutPriceToOfferResponse;
+                        T: InventoryService,
+                    > tonic::server::UnaryService<
+                        super::CommitInventoryReservationRequest,
+                    > for CommitInventoryReservationSvc<T> {
+                        type Response = super::CommitInventoryReservationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PutPriceToOfferRequest>,
+                            request: tonic::Request<
+                                super::CommitInventoryReservationRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).put_price_to_offer(request).await
+                                (*inner).commit_inventory_reservation(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2749,7 +2940,7 @@ pub mod offer_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = PutPriceToOfferSvc(inner);
+                        let method = CommitInventoryReservationSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2765,25 +2956,30 @@ pub mod offer_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.OfferService/RemovePriceFromOffer" => {
+                "/sited_io.commerce.v1.InventoryService/ReleaseInventoryReservation" => {
                     #[allow(non_camel_case_types)]
-                    struct RemovePriceFromOfferSvc<T: OfferService>(pub Arc<T>);
+                    struct ReleaseInventoryReservationSvc<T: InventoryService>(
+                        pub Arc<T>,
+                    );
                     impl<
-                        T: OfferService,
-                    > tonic::server::UnaryService<super::RemovePriceFromOfferRequest>
-                    for RemovePriceFromOfferSvc<T> {
-                        type Response = super::RemovePriceFromOfferResponse;
+                        T: InventoryService,
+                    > tonic::server::UnaryService<
+                        super::ReleaseInventoryReservationRequest,
+                    > for ReleaseInventoryReservationSvc<T> {
+                        type Response = super::ReleaseInventoryReservationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RemovePriceFromOfferRequest>,
+                            request: tonic::Request<
+                                super::ReleaseInventoryReservationRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).remove_price_from_offer(request).await
+                                (*inner).release_inventory_reservation(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2795,7 +2991,7 @@ pub mod offer_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = RemovePriceFromOfferSvc(inner);
+                        let method = ReleaseInventoryReservationSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2826,7 +3022,7 @@ pub mod offer_service_server {
             }
         }
     }
-    impl<T: OfferService> Clone for OfferServiceServer<T> {
+    impl<T: InventoryService> Clone for InventoryServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2838,7 +3034,7 @@ pub mod offer_service_server {
             }
         }
     }
-    impl<T: OfferService> Clone for _Inner<T> {
+    impl<T: InventoryService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(Arc::clone(&self.0))
         }
@@ -2848,533 +3044,2961 @@ pub mod offer_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: OfferService> tonic::server::NamedService for OfferServiceServer<T> {
-        const NAME: &'static str = "sited_io.commerce.v1.OfferService";
+    impl<T: InventoryService> tonic::server::NamedService for InventoryServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.InventoryService";
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ShippingRateResponse {
+pub struct OfferResponse {
     #[prost(string, tag = "1")]
-    pub shipping_rate_id: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
     pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub shop_id: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
+    pub shop_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub shop_slug: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "5")]
+    pub shop_domain: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "6")]
     pub user_id: ::prost::alloc::string::String,
-    #[prost(uint32, tag = "4")]
-    pub amount: u32,
-    #[prost(enumeration = "Currency", tag = "5")]
-    pub currency: i32,
-    #[prost(bool, tag = "6")]
-    pub all_countries: bool,
-    #[prost(enumeration = "ShippingCountry", repeated, tag = "7")]
-    pub specific_countries: ::prost::alloc::vec::Vec<i32>,
+    #[prost(int64, tag = "7")]
+    pub created_at: i64,
+    #[prost(int64, tag = "8")]
+    pub updated_at: i64,
+    #[prost(string, tag = "9")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(bool, tag = "11")]
+    pub is_active: bool,
+    #[prost(bool, tag = "12")]
+    pub is_featured: bool,
+    #[prost(enumeration = "OfferType", tag = "13")]
+    pub r#type: i32,
+    #[prost(message, repeated, tag = "14")]
+    pub images: ::prost::alloc::vec::Vec<OfferImageResponse>,
+    #[prost(message, optional, tag = "15")]
+    pub price: ::core::option::Option<Price>,
+    #[prost(message, repeated, tag = "16")]
+    pub options: ::prost::alloc::vec::Vec<OfferOptionResponse>,
+    #[prost(message, repeated, tag = "17")]
+    pub variants: ::prost::alloc::vec::Vec<OfferVariantResponse>,
+    #[prost(enumeration = "InventoryPolicy", tag = "18")]
+    pub inventory_policy: i32,
+    #[prost(bool, tag = "19")]
+    pub is_sold_out: bool,
+    #[prost(message, repeated, tag = "20")]
+    pub prices: ::prost::alloc::vec::Vec<Price>,
+    #[prost(enumeration = "TaxCategory", tag = "21")]
+    pub tax_category: i32,
+    #[prost(uint32, optional, tag = "22")]
+    pub weight_grams: ::core::option::Option<u32>,
+    #[prost(string, optional, tag = "23")]
+    pub shipping_profile_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "24")]
+    pub category_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "25")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "26")]
+    pub attributes: ::prost::alloc::vec::Vec<OfferAttribute>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PutShippingRateRequest {
+pub struct OfferAttribute {
     #[prost(string, tag = "1")]
-    pub offer_id: ::prost::alloc::string::String,
-    #[prost(uint32, tag = "2")]
-    pub amount: u32,
-    #[prost(enumeration = "Currency", tag = "3")]
-    pub currency: i32,
-    #[prost(bool, tag = "4")]
-    pub all_countries: bool,
-    #[prost(enumeration = "ShippingCountry", repeated, tag = "5")]
-    pub specific_countries: ::prost::alloc::vec::Vec<i32>,
+    pub key: ::prost::alloc::string::String,
+    #[prost(enumeration = "OfferAttributeType", tag = "2")]
+    pub attribute_type: i32,
+    #[prost(string, tag = "3")]
+    pub value: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PutShippingRateResponse {}
+pub struct OfferImageResponse {
+    #[prost(string, tag = "1")]
+    pub offer_image_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub image_url: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub ordering: i64,
+    #[prost(message, repeated, tag = "4")]
+    pub image_variants: ::prost::alloc::vec::Vec<ImageVariantResponse>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetShippingRateRequest {
-    #[prost(string, optional, tag = "1")]
-    pub offer_id: ::core::option::Option<::prost::alloc::string::String>,
+pub struct OfferOptionResponse {
+    #[prost(string, tag = "1")]
+    pub offer_option_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub ordering: i64,
+    #[prost(message, repeated, tag = "4")]
+    pub values: ::prost::alloc::vec::Vec<OfferOptionValueResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetShippingRateResponse {
+pub struct OfferOptionValueResponse {
+    #[prost(string, tag = "1")]
+    pub offer_option_value_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub ordering: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OfferVariantResponse {
+    #[prost(string, tag = "1")]
+    pub offer_variant_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub sku: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub is_active: bool,
+    #[prost(message, optional, tag = "4")]
+    pub price: ::core::option::Option<Price>,
+    #[prost(string, repeated, tag = "5")]
+    pub offer_option_value_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "6")]
+    pub offer_image_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "7")]
+    pub is_sold_out: bool,
+    #[prost(message, repeated, tag = "8")]
+    pub prices: ::prost::alloc::vec::Vec<Price>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateOfferRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "OfferType", tag = "4")]
+    pub r#type: i32,
+    #[prost(bool, tag = "5")]
+    pub is_featured: bool,
+    #[prost(enumeration = "TaxCategory", optional, tag = "6")]
+    pub tax_category: ::core::option::Option<i32>,
+    #[prost(uint32, optional, tag = "7")]
+    pub weight_grams: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateOfferResponse {
     #[prost(message, optional, tag = "1")]
-    pub shipping_rate: ::core::option::Option<ShippingRateResponse>,
+    pub offer: ::core::option::Option<OfferResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DeleteShippingRateRequest {
+pub struct GetOfferRequest {
     #[prost(string, tag = "1")]
-    pub shipping_rate_id: ::prost::alloc::string::String,
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "Currency", optional, tag = "2")]
+    pub preferred_currency: ::core::option::Option<i32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DeleteShippingRateResponse {}
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum ShippingCountry {
-    Unspecified = 0,
-    Ac = 1,
-    Ad = 2,
-    Ae = 3,
-    Af = 4,
-    Ag = 5,
-    Ai = 6,
-    Al = 7,
-    Am = 8,
-    Ao = 9,
-    Aq = 10,
-    Ar = 11,
-    At = 12,
-    Au = 13,
-    Aw = 14,
-    Ax = 15,
-    Az = 16,
-    Ba = 17,
-    Bb = 18,
-    Bd = 19,
-    Be = 20,
-    Bf = 21,
-    Bg = 22,
-    Bh = 23,
-    Bi = 24,
-    Bj = 25,
-    Bl = 26,
-    Bm = 27,
-    Bn = 28,
-    Bo = 29,
-    Bq = 30,
-    Br = 31,
-    Bs = 32,
-    Bt = 33,
-    Bv = 34,
-    Bw = 35,
-    By = 36,
-    Bz = 37,
-    Ca = 38,
-    Cd = 39,
-    Cf = 40,
-    Cg = 41,
-    Ch = 42,
-    Ci = 43,
-    Ck = 44,
-    Cl = 45,
-    Cm = 46,
-    Cn = 47,
-    Co = 48,
-    Cr = 49,
-    Cv = 50,
-    Cw = 51,
-    Cy = 52,
-    Cz = 53,
-    De = 54,
-    Dj = 55,
-    Dk = 56,
-    Dm = 57,
-    Do = 58,
-    Dz = 59,
-    Ec = 60,
-    Ee = 61,
-    Eg = 62,
-    Eh = 63,
-    Er = 64,
-    Es = 65,
-    Et = 66,
-    Fi = 67,
-    Fj = 68,
-    Fk = 69,
-    Fo = 70,
-    Fr = 71,
-    Ga = 72,
-    Gb = 73,
-    Gd = 74,
-    Ge = 75,
-    Gf = 76,
-    Gg = 77,
-    Gh = 78,
-    Gi = 79,
-    Gl = 80,
-    Gm = 81,
-    Gn = 82,
-    Gp = 83,
-    Gq = 84,
-    Gr = 85,
-    Gs = 86,
-    Gt = 87,
-    Gu = 88,
-    Gw = 89,
-    Gy = 90,
-    Hk = 91,
-    Hn = 92,
-    Hr = 93,
-    Ht = 94,
-    Hu = 95,
-    Id = 96,
-    Ie = 97,
-    Il = 98,
-    Im = 99,
-    In = 100,
-    Io = 101,
-    Iq = 102,
-    Is = 103,
-    It = 104,
-    Je = 105,
-    Jm = 106,
-    Jo = 107,
-    Jp = 108,
-    Ke = 109,
-    Kg = 110,
-    Kh = 111,
-    Ki = 112,
-    Km = 113,
-    Kn = 114,
-    Kr = 115,
-    Kw = 116,
-    Ky = 117,
-    La = 118,
-    Lb = 119,
-    Lc = 120,
-    Li = 121,
-    Lk = 122,
-    Lr = 123,
-    Ls = 124,
-    Lt = 125,
-    Lu = 126,
-    Lv = 127,
-    Ly = 128,
-    Ma = 129,
-    Mc = 130,
-    Md = 131,
-    Me = 132,
-    Mf = 133,
-    Mg = 134,
-    Mk = 135,
-    Ml = 136,
-    Mm = 137,
-    Mn = 138,
-    Mo = 139,
-    Mq = 140,
-    Mr = 141,
-    Ms = 142,
-    Mt = 143,
-    Mu = 144,
-    Mv = 145,
-    Mw = 146,
-    Mx = 147,
-    My = 148,
-    Mz = 149,
-    Na = 150,
-    Nc = 151,
-    Ne = 152,
-    Ng = 153,
-    Ni = 154,
-    Nl = 155,
-    No = 156,
-    Np = 157,
-    Nr = 158,
-    Nu = 159,
-    Nz = 160,
-    Om = 161,
-    Pa = 162,
-    Pe = 163,
-    Pf = 164,
-    Pg = 165,
-    Ph = 166,
-    Pk = 167,
-    Pl = 168,
-    Pm = 169,
-    Pn = 170,
-    Pr = 171,
-    Ps = 172,
-    Pt = 173,
-    Py = 174,
-    Qa = 175,
-    Re = 176,
-    Ro = 177,
-    Rs = 178,
-    Ru = 179,
-    Rw = 180,
-    Sa = 181,
-    Sb = 182,
-    Sc = 183,
-    Se = 184,
-    Sg = 185,
-    Sh = 186,
-    Si = 187,
-    Sj = 188,
-    Sk = 189,
-    Sl = 190,
-    Sm = 191,
-    Sn = 192,
-    So = 193,
-    Sr = 194,
-    Ss = 195,
-    St = 196,
-    Sv = 197,
-    Sx = 198,
-    Sz = 199,
-    Ta = 200,
-    Tc = 201,
-    Td = 202,
-    Tf = 203,
-    Tg = 204,
-    Th = 205,
-    Tj = 206,
-    Tk = 207,
-    Tl = 208,
-    Tm = 209,
-    Tn = 210,
-    To = 211,
-    Tr = 212,
-    Tt = 213,
-    Tv = 214,
-    Tw = 215,
-    Tz = 216,
-    Ua = 217,
-    Ug = 218,
-    Us = 219,
-    Uy = 220,
-    Uz = 221,
-    Va = 222,
-    Vc = 223,
-    Ve = 224,
-    Vg = 225,
-    Vn = 226,
-    Vu = 227,
-    Wf = 228,
-    Ws = 229,
-    Xk = 230,
-    Ye = 231,
-    Yt = 232,
-    Za = 233,
-    Zm = 234,
-    Zw = 235,
-    Zz = 236,
+pub struct GetOfferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
 }
-impl ShippingCountry {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            ShippingCountry::Unspecified => "SHIPPING_COUNTRY_UNSPECIFIED",
-            ShippingCountry::Ac => "SHIPPING_COUNTRY_AC",
-            ShippingCountry::Ad => "SHIPPING_COUNTRY_AD",
-            ShippingCountry::Ae => "SHIPPING_COUNTRY_AE",
-            ShippingCountry::Af => "SHIPPING_COUNTRY_AF",
-            ShippingCountry::Ag => "SHIPPING_COUNTRY_AG",
-            ShippingCountry::Ai => "SHIPPING_COUNTRY_AI",
-            ShippingCountry::Al => "SHIPPING_COUNTRY_AL",
-            ShippingCountry::Am => "SHIPPING_COUNTRY_AM",
-            ShippingCountry::Ao => "SHIPPING_COUNTRY_AO",
-            ShippingCountry::Aq => "SHIPPING_COUNTRY_AQ",
-            ShippingCountry::Ar => "SHIPPING_COUNTRY_AR",
-            ShippingCountry::At => "SHIPPING_COUNTRY_AT",
-            ShippingCountry::Au => "SHIPPING_COUNTRY_AU",
-            ShippingCountry::Aw => "SHIPPING_COUNTRY_AW",
-            ShippingCountry::Ax => "SHIPPING_COUNTRY_AX",
-            ShippingCountry::Az => "SHIPPING_COUNTRY_AZ",
-            ShippingCountry::Ba => "SHIPPING_COUNTRY_BA",
-            ShippingCountry::Bb => "SHIPPING_COUNTRY_BB",
-            ShippingCountry::Bd => "SHIPPING_COUNTRY_BD",
-            ShippingCountry::Be => "SHIPPING_COUNTRY_BE",
-            ShippingCountry::Bf => "SHIPPING_COUNTRY_BF",
-            ShippingCountry::Bg => "SHIPPING_COUNTRY_BG",
-            ShippingCountry::Bh => "SHIPPING_COUNTRY_BH",
-            ShippingCountry::Bi => "SHIPPING_COUNTRY_BI",
-            ShippingCountry::Bj => "SHIPPING_COUNTRY_BJ",
-            ShippingCountry::Bl => "SHIPPING_COUNTRY_BL",
-            ShippingCountry::Bm => "SHIPPING_COUNTRY_BM",
-            ShippingCountry::Bn => "SHIPPING_COUNTRY_BN",
-            ShippingCountry::Bo => "SHIPPING_COUNTRY_BO",
-            ShippingCountry::Bq => "SHIPPING_COUNTRY_BQ",
-            ShippingCountry::Br => "SHIPPING_COUNTRY_BR",
-            ShippingCountry::Bs => "SHIPPING_COUNTRY_BS",
-            ShippingCountry::Bt => "SHIPPING_COUNTRY_BT",
-            ShippingCountry::Bv => "SHIPPING_COUNTRY_BV",
-            ShippingCountry::Bw => "SHIPPING_COUNTRY_BW",
-            ShippingCountry::By => "SHIPPING_COUNTRY_BY",
-            ShippingCountry::Bz => "SHIPPING_COUNTRY_BZ",
-            ShippingCountry::Ca => "SHIPPING_COUNTRY_CA",
-            ShippingCountry::Cd => "SHIPPING_COUNTRY_CD",
-            ShippingCountry::Cf => "SHIPPING_COUNTRY_CF",
-            ShippingCountry::Cg => "SHIPPING_COUNTRY_CG",
-            ShippingCountry::Ch => "SHIPPING_COUNTRY_CH",
-            ShippingCountry::Ci => "SHIPPING_COUNTRY_CI",
-            ShippingCountry::Ck => "SHIPPING_COUNTRY_CK",
-            ShippingCountry::Cl => "SHIPPING_COUNTRY_CL",
-            ShippingCountry::Cm => "SHIPPING_COUNTRY_CM",
-            ShippingCountry::Cn => "SHIPPING_COUNTRY_CN",
-            ShippingCountry::Co => "SHIPPING_COUNTRY_CO",
-            ShippingCountry::Cr => "SHIPPING_COUNTRY_CR",
-            ShippingCountry::Cv => "SHIPPING_COUNTRY_CV",
-            ShippingCountry::Cw => "SHIPPING_COUNTRY_CW",
-            ShippingCountry::Cy => "SHIPPING_COUNTRY_CY",
-            ShippingCountry::Cz => "SHIPPING_COUNTRY_CZ",
-            ShippingCountry::De => "SHIPPING_COUNTRY_DE",
-            ShippingCountry::Dj => "SHIPPING_COUNTRY_DJ",
-            ShippingCountry::Dk => "SHIPPING_COUNTRY_DK",
-            ShippingCountry::Dm => "SHIPPING_COUNTRY_DM",
-            ShippingCountry::Do => "SHIPPING_COUNTRY_DO",
-            ShippingCountry::Dz => "SHIPPING_COUNTRY_DZ",
-            ShippingCountry::Ec => "SHIPPING_COUNTRY_EC",
-            ShippingCountry::Ee => "SHIPPING_COUNTRY_EE",
-            ShippingCountry::Eg => "SHIPPING_COUNTRY_EG",
-            ShippingCountry::Eh => "SHIPPING_COUNTRY_EH",
-            ShippingCountry::Er => "SHIPPING_COUNTRY_ER",
-            ShippingCountry::Es => "SHIPPING_COUNTRY_ES",
-            ShippingCountry::Et => "SHIPPING_COUNTRY_ET",
-            ShippingCountry::Fi => "SHIPPING_COUNTRY_FI",
-            ShippingCountry::Fj => "SHIPPING_COUNTRY_FJ",
-            ShippingCountry::Fk => "SHIPPING_COUNTRY_FK",
-            ShippingCountry::Fo => "SHIPPING_COUNTRY_FO",
-            ShippingCountry::Fr => "SHIPPING_COUNTRY_FR",
-            ShippingCountry::Ga => "SHIPPING_COUNTRY_GA",
-            ShippingCountry::Gb => "SHIPPING_COUNTRY_GB",
-            ShippingCountry::Gd => "SHIPPING_COUNTRY_GD",
-            ShippingCountry::Ge => "SHIPPING_COUNTRY_GE",
-            ShippingCountry::Gf => "SHIPPING_COUNTRY_GF",
-            ShippingCountry::Gg => "SHIPPING_COUNTRY_GG",
-            ShippingCountry::Gh => "SHIPPING_COUNTRY_GH",
-            ShippingCountry::Gi => "SHIPPING_COUNTRY_GI",
-            ShippingCountry::Gl => "SHIPPING_COUNTRY_GL",
-            ShippingCountry::Gm => "SHIPPING_COUNTRY_GM",
-            ShippingCountry::Gn => "SHIPPING_COUNTRY_GN",
-            ShippingCountry::Gp => "SHIPPING_COUNTRY_GP",
-            ShippingCountry::Gq => "SHIPPING_COUNTRY_GQ",
-            ShippingCountry::Gr => "SHIPPING_COUNTRY_GR",
-            ShippingCountry::Gs => "SHIPPING_COUNTRY_GS",
-            ShippingCountry::Gt => "SHIPPING_COUNTRY_GT",
-            ShippingCountry::Gu => "SHIPPING_COUNTRY_GU",
-            ShippingCountry::Gw => "SHIPPING_COUNTRY_GW",
-            ShippingCountry::Gy => "SHIPPING_COUNTRY_GY",
-            ShippingCountry::Hk => "SHIPPING_COUNTRY_HK",
-            ShippingCountry::Hn => "SHIPPING_COUNTRY_HN",
-            ShippingCountry::Hr => "SHIPPING_COUNTRY_HR",
-            ShippingCountry::Ht => "SHIPPING_COUNTRY_HT",
-            ShippingCountry::Hu => "SHIPPING_COUNTRY_HU",
-            ShippingCountry::Id => "SHIPPING_COUNTRY_ID",
-            ShippingCountry::Ie => "SHIPPING_COUNTRY_IE",
-            ShippingCountry::Il => "SHIPPING_COUNTRY_IL",
-            ShippingCountry::Im => "SHIPPING_COUNTRY_IM",
-            ShippingCountry::In => "SHIPPING_COUNTRY_IN",
-            ShippingCountry::Io => "SHIPPING_COUNTRY_IO",
-            ShippingCountry::Iq => "SHIPPING_COUNTRY_IQ",
-            ShippingCountry::Is => "SHIPPING_COUNTRY_IS",
-            ShippingCountry::It => "SHIPPING_COUNTRY_IT",
-            ShippingCountry::Je => "SHIPPING_COUNTRY_JE",
-            ShippingCountry::Jm => "SHIPPING_COUNTRY_JM",
-            ShippingCountry::Jo => "SHIPPING_COUNTRY_JO",
-            ShippingCountry::Jp => "SHIPPING_COUNTRY_JP",
-            ShippingCountry::Ke => "SHIPPING_COUNTRY_KE",
-            ShippingCountry::Kg => "SHIPPING_COUNTRY_KG",
-            ShippingCountry::Kh => "SHIPPING_COUNTRY_KH",
-            ShippingCountry::Ki => "SHIPPING_COUNTRY_KI",
-            ShippingCountry::Km => "SHIPPING_COUNTRY_KM",
-            ShippingCountry::Kn => "SHIPPING_COUNTRY_KN",
-            ShippingCountry::Kr => "SHIPPING_COUNTRY_KR",
-            ShippingCountry::Kw => "SHIPPING_COUNTRY_KW",
-            ShippingCountry::Ky => "SHIPPING_COUNTRY_KY",
-            ShippingCountry::La => "SHIPPING_COUNTRY_LA",
-            ShippingCountry::Lb => "SHIPPING_COUNTRY_LB",
-            ShippingCountry::Lc => "SHIPPING_COUNTRY_LC",
-            ShippingCountry::Li => "SHIPPING_COUNTRY_LI",
-            ShippingCountry::Lk => "SHIPPING_COUNTRY_LK",
-            ShippingCountry::Lr => "SHIPPING_COUNTRY_LR",
-            ShippingCountry::Ls => "SHIPPING_COUNTRY_LS",
-            ShippingCountry::Lt => "SHIPPING_COUNTRY_LT",
-            ShippingCountry::Lu => "SHIPPING_COUNTRY_LU",
-            ShippingCountry::Lv => "SHIPPING_COUNTRY_LV",
-            ShippingCountry::Ly => "SHIPPING_COUNTRY_LY",
-            ShippingCountry::Ma => "SHIPPING_COUNTRY_MA",
-            ShippingCountry::Mc => "SHIPPING_COUNTRY_MC",
-            ShippingCountry::Md => "SHIPPING_COUNTRY_MD",
-            ShippingCountry::Me => "SHIPPING_COUNTRY_ME",
-            ShippingCountry::Mf => "SHIPPING_COUNTRY_MF",
-            ShippingCountry::Mg => "SHIPPING_COUNTRY_MG",
-            ShippingCountry::Mk => "SHIPPING_COUNTRY_MK",
-            ShippingCountry::Ml => "SHIPPING_COUNTRY_ML",
-            ShippingCountry::Mm => "SHIPPING_COUNTRY_MM",
-            ShippingCountry::Mn => "SHIPPING_COUNTRY_MN",
-            ShippingCountry::Mo => "SHIPPING_COUNTRY_MO",
-            ShippingCountry::Mq => "SHIPPING_COUNTRY_MQ",
-            ShippingCountry::Mr => "SHIPPING_COUNTRY_MR",
-            ShippingCountry::Ms => "SHIPPING_COUNTRY_MS",
-            ShippingCountry::Mt => "SHIPPING_COUNTRY_MT",
-            ShippingCountry::Mu => "SHIPPING_COUNTRY_MU",
-            ShippingCountry::Mv => "SHIPPING_COUNTRY_MV",
-            ShippingCountry::Mw => "SHIPPING_COUNTRY_MW",
-            ShippingCountry::Mx => "SHIPPING_COUNTRY_MX",
-            ShippingCountry::My => "SHIPPING_COUNTRY_MY",
-            ShippingCountry::Mz => "SHIPPING_COUNTRY_MZ",
-            ShippingCountry::Na => "SHIPPING_COUNTRY_NA",
-            ShippingCountry::Nc => "SHIPPING_COUNTRY_NC",
-            ShippingCountry::Ne => "SHIPPING_COUNTRY_NE",
-            ShippingCountry::Ng => "SHIPPING_COUNTRY_NG",
-            ShippingCountry::Ni => "SHIPPING_COUNTRY_NI",
-            ShippingCountry::Nl => "SHIPPING_COUNTRY_NL",
-            ShippingCountry::No => "SHIPPING_COUNTRY_NO",
-            ShippingCountry::Np => "SHIPPING_COUNTRY_NP",
-            ShippingCountry::Nr => "SHIPPING_COUNTRY_NR",
-            ShippingCountry::Nu => "SHIPPING_COUNTRY_NU",
-            ShippingCountry::Nz => "SHIPPING_COUNTRY_NZ",
-            ShippingCountry::Om => "SHIPPING_COUNTRY_OM",
-            ShippingCountry::Pa => "SHIPPING_COUNTRY_PA",
-            ShippingCountry::Pe => "SHIPPING_COUNTRY_PE",
-            ShippingCountry::Pf => "SHIPPING_COUNTRY_PF",
-            ShippingCountry::Pg => "SHIPPING_COUNTRY_PG",
-            ShippingCountry::Ph => "SHIPPING_COUNTRY_PH",
-            ShippingCountry::Pk => "SHIPPING_COUNTRY_PK",
-            ShippingCountry::Pl => "SHIPPING_COUNTRY_PL",
-            ShippingCountry::Pm => "SHIPPING_COUNTRY_PM",
-            ShippingCountry::Pn => "SHIPPING_COUNTRY_PN",
-            ShippingCountry::Pr => "SHIPPING_COUNTRY_PR",
-            ShippingCountry::Ps => "SHIPPING_COUNTRY_PS",
-            ShippingCountry::Pt => "SHIPPING_COUNTRY_PT",
-            ShippingCountry::Py => "SHIPPING_COUNTRY_PY",
-            ShippingCountry::Qa => "SHIPPING_COUNTRY_QA",
-            ShippingCountry::Re => "SHIPPING_COUNTRY_RE",
-            ShippingCountry::Ro => "SHIPPING_COUNTRY_RO",
-            ShippingCountry::Rs => "SHIPPING_COUNTRY_RS",
-            ShippingCountry::Ru => "SHIPPING_COUNTRY_RU",
-            ShippingCountry::Rw => "SHIPPING_COUNTRY_RW",
-            ShippingCountry::Sa => "SHIPPING_COUNTRY_SA",
-            ShippingCountry::Sb => "SHIPPING_COUNTRY_SB",
-            ShippingCountry::Sc => "SHIPPING_COUNTRY_SC",
-            ShippingCountry::Se => "SHIPPING_COUNTRY_SE",
-            ShippingCountry::Sg => "SHIPPING_COUNTRY_SG",
-            ShippingCountry::Sh => "SHIPPING_COUNTRY_SH",
-            ShippingCountry::Si => "SHIPPING_COUNTRY_SI",
-            ShippingCountry::Sj => "SHIPPING_COUNTRY_SJ",
-            ShippingCountry::Sk => "SHIPPING_COUNTRY_SK",
-            ShippingCountry::Sl => "SHIPPING_COUNTRY_SL",
-            ShippingCountry::Sm => "SHIPPING_COUNTRY_SM",
-            ShippingCountry::Sn => "SHIPPING_COUNTRY_SN",
-            ShippingCountry::So => "SHIPPING_COUNTRY_SO",
-            ShippingCountry::Sr => "SHIPPING_COUNTRY_SR",
-            ShippingCountry::Ss => "SHIPPING_COUNTRY_SS",
-            ShippingCountry::St => "SHIPPING_COUNTRY_ST",
-            ShippingCountry::Sv => "SHIPPING_COUNTRY_SV",
-            ShippingCountry::Sx => "SHIPPING_COUNTRY_SX",
-            ShippingCountry::Sz => "SHIPPING_COUNTRY_SZ",
-            ShippingCountry::Ta => "SHIPPING_COUNTRY_TA",
-            ShippingCountry::Tc => "SHIPPING_COUNTRY_TC",
-            ShippingCountry::Td => "SHIPPING_COUNTRY_TD",
-            ShippingCountry::Tf => "SHIPPING_COUNTRY_TF",
-            ShippingCountry::Tg => "SHIPPING_COUNTRY_TG",
-            ShippingCountry::Th => "SHIPPING_COUNTRY_TH",
-            ShippingCountry::Tj => "SHIPPING_COUNTRY_TJ",
-            ShippingCountry::Tk => "SHIPPING_COUNTRY_TK",
-            ShippingCountry::Tl => "SHIPPING_COUNTRY_TL",
-            ShippingCountry::Tm => "SHIPPING_COUNTRY_TM",
-            ShippingCountry::Tn => "SHIPPING_COUNTRY_TN",
-            ShippingCountry::To => "SHIPPING_COUNTRY_TO",
-            ShippingCountry::Tr => "SHIPPING_COUNTRY_TR",
-            ShippingCountry::Tt => "SHIPPING_COUNTRY_TT",
-            ShippingCountry::Tv => "SHIPPING_COUNTRY_TV",
-            ShippingCountry::Tw => "SHIPPING_COUNTRY_TW",
-            ShippingCountry::Tz => "SHIPPING_COUNTRY_TZ",
-            ShippingCountry::Ua => "SHIPPING_COUNTRY_UA",
-            ShippingCountry::Ug => "SHIPPING_COUNTRY_UG",
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMyOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMyOfferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OffersOrderBy {
+    #[prost(enumeration = "OffersOrderByField", tag = "1")]
+    pub field: i32,
+    #[prost(enumeration = "super::super::types::v1::Direction", tag = "2")]
+    pub direction: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OffersFilter {
+    #[prost(enumeration = "OffersFilterField", tag = "1")]
+    pub field: i32,
+    #[prost(string, tag = "2")]
+    pub query: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OffersFilterGroup {
+    #[prost(enumeration = "FilterOperator", tag = "1")]
+    pub operator: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub filters: ::prost::alloc::vec::Vec<OffersFilter>,
+    #[prost(message, repeated, tag = "3")]
+    pub groups: ::prost::alloc::vec::Vec<OffersFilterGroup>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOffersRequest {
+    #[prost(string, optional, tag = "1")]
+    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub shop_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<super::super::types::v1::PaginationRequest>,
+    #[prost(message, optional, tag = "4")]
+    pub order_by: ::core::option::Option<OffersOrderBy>,
+    #[prost(message, optional, tag = "5")]
+    pub filter: ::core::option::Option<OffersFilter>,
+    #[prost(enumeration = "Currency", optional, tag = "6")]
+    pub preferred_currency: ::core::option::Option<i32>,
+    #[prost(message, repeated, tag = "7")]
+    pub filters: ::prost::alloc::vec::Vec<OffersFilter>,
+    #[prost(message, optional, tag = "8")]
+    pub filter_group: ::core::option::Option<OffersFilterGroup>,
+    #[prost(message, repeated, tag = "9")]
+    pub order_bys: ::prost::alloc::vec::Vec<OffersOrderBy>,
+    #[prost(bool, optional, tag = "10")]
+    pub with_facets: ::core::option::Option<bool>,
+    #[prost(uint32, repeated, tag = "11")]
+    pub facet_price_bounds: ::prost::alloc::vec::Vec<u32>,
+    #[prost(string, optional, tag = "12")]
+    pub random_seed: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OffersFacetCount {
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OffersPriceBucketCount {
+    #[prost(uint32, optional, tag = "1")]
+    pub min_price: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "2")]
+    pub max_price: ::core::option::Option<u32>,
+    #[prost(uint64, tag = "3")]
+    pub count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OffersFacets {
+    #[prost(message, repeated, tag = "1")]
+    pub types: ::prost::alloc::vec::Vec<OffersFacetCount>,
+    #[prost(message, repeated, tag = "2")]
+    pub price_buckets: ::prost::alloc::vec::Vec<OffersPriceBucketCount>,
+    #[prost(message, repeated, tag = "3")]
+    pub tags: ::prost::alloc::vec::Vec<OffersFacetCount>,
+    #[prost(message, repeated, tag = "4")]
+    pub is_featured: ::prost::alloc::vec::Vec<OffersFacetCount>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOffersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub offers: ::prost::alloc::vec::Vec<OfferResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::types::v1::PaginationResponse>,
+    #[prost(message, optional, tag = "3")]
+    pub facets: ::core::option::Option<OffersFacets>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AutocompleteOffersRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub query: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "3")]
+    pub limit: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OfferNameSuggestion {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AutocompleteOffersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub suggestions: ::prost::alloc::vec::Vec<OfferNameSuggestion>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "4")]
+    pub is_active: ::core::option::Option<bool>,
+    #[prost(enumeration = "OfferType", optional, tag = "5")]
+    pub r#type: ::core::option::Option<i32>,
+    #[prost(bool, optional, tag = "6")]
+    pub is_featured: ::core::option::Option<bool>,
+    #[prost(enumeration = "InventoryPolicy", optional, tag = "7")]
+    pub inventory_policy: ::core::option::Option<i32>,
+    #[prost(enumeration = "TaxCategory", optional, tag = "8")]
+    pub tax_category: ::core::option::Option<i32>,
+    #[prost(uint32, optional, tag = "9")]
+    pub weight_grams: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOfferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddImageToOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub image: ::core::option::Option<super::super::media::v1::MediaUpload>,
+    #[prost(int64, tag = "3")]
+    pub ordering: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddImageToOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveImageFromOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_image_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveImageFromOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutPriceToOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub price: ::core::option::Option<Price>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutPriceToOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemovePriceFromOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "Currency", optional, tag = "2")]
+    pub currency: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemovePriceFromOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutShippingProfileToOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub shipping_profile_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutShippingProfileToOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveShippingProfileFromOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveShippingProfileFromOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddCategoryToOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub category_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddCategoryToOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveCategoryFromOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub category_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveCategoryFromOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutTagsToOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "2")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutTagsToOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutAttributesToOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub attributes: ::prost::alloc::vec::Vec<OfferAttribute>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutAttributesToOfferResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddOptionToOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub ordering: i64,
+    #[prost(string, repeated, tag = "4")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddOptionToOfferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOfferOptionRequest {
+    #[prost(string, tag = "1")]
+    pub offer_option_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "3")]
+    pub ordering: ::core::option::Option<i64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOfferOptionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveOptionFromOfferRequest {
+    #[prost(string, tag = "1")]
+    pub offer_option_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveOptionFromOfferResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddValueToOfferOptionRequest {
+    #[prost(string, tag = "1")]
+    pub offer_option_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub ordering: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddValueToOfferOptionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveValueFromOfferOptionRequest {
+    #[prost(string, tag = "1")]
+    pub offer_option_value_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveValueFromOfferOptionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateOfferVariantRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub sku: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub price: ::core::option::Option<Price>,
+    #[prost(bool, tag = "4")]
+    pub is_active: bool,
+    #[prost(string, repeated, tag = "5")]
+    pub offer_option_value_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "6")]
+    pub offer_image_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateOfferVariantResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateOfferVariantsRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub price: ::core::option::Option<Price>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateOfferVariantsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOfferVariantRequest {
+    #[prost(string, tag = "1")]
+    pub offer_variant_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub sku: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "3")]
+    pub price: ::core::option::Option<Price>,
+    #[prost(bool, optional, tag = "4")]
+    pub is_active: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOfferVariantResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutImagesToOfferVariantRequest {
+    #[prost(string, tag = "1")]
+    pub offer_variant_id: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "2")]
+    pub offer_image_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutImagesToOfferVariantResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteOfferVariantRequest {
+    #[prost(string, tag = "1")]
+    pub offer_variant_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteOfferVariantResponse {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<OfferResponse>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OfferType {
+    Unspecified = 0,
+    Physical = 1,
+    Digital = 2,
+    Service = 3,
+}
+impl OfferType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OfferType::Unspecified => "OFFER_TYPE_UNSPECIFIED",
+            OfferType::Physical => "OFFER_TYPE_PHYSICAL",
+            OfferType::Digital => "OFFER_TYPE_DIGITAL",
+            OfferType::Service => "OFFER_TYPE_SERVICE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OFFER_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "OFFER_TYPE_PHYSICAL" => Some(Self::Physical),
+            "OFFER_TYPE_DIGITAL" => Some(Self::Digital),
+            "OFFER_TYPE_SERVICE" => Some(Self::Service),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OffersOrderByField {
+    Unspecified = 0,
+    CreatedAt = 1,
+    UpdatedAt = 2,
+    Name = 3,
+    Random = 4,
+    CollectionOrdering = 5,
+    Price = 6,
+    Relevance = 7,
+}
+impl OffersOrderByField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OffersOrderByField::Unspecified => "OFFERS_ORDER_BY_FIELD_UNSPECIFIED",
+            OffersOrderByField::CreatedAt => "OFFERS_ORDER_BY_FIELD_CREATED_AT",
+            OffersOrderByField::UpdatedAt => "OFFERS_ORDER_BY_FIELD_UPDATED_AT",
+            OffersOrderByField::Name => "OFFERS_ORDER_BY_FIELD_NAME",
+            OffersOrderByField::Random => "OFFERS_ORDER_BY_FIELD_RANDOM",
+            OffersOrderByField::CollectionOrdering => {
+                "OFFERS_ORDER_BY_FIELD_COLLECTION_ORDERING"
+            }
+            OffersOrderByField::Price => "OFFERS_ORDER_BY_FIELD_PRICE",
+            OffersOrderByField::Relevance => "OFFERS_ORDER_BY_FIELD_RELEVANCE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OFFERS_ORDER_BY_FIELD_UNSPECIFIED" => Some(Self::Unspecified),
+            "OFFERS_ORDER_BY_FIELD_CREATED_AT" => Some(Self::CreatedAt),
+            "OFFERS_ORDER_BY_FIELD_UPDATED_AT" => Some(Self::UpdatedAt),
+            "OFFERS_ORDER_BY_FIELD_NAME" => Some(Self::Name),
+            "OFFERS_ORDER_BY_FIELD_RANDOM" => Some(Self::Random),
+            "OFFERS_ORDER_BY_FIELD_COLLECTION_ORDERING" => Some(Self::CollectionOrdering),
+            "OFFERS_ORDER_BY_FIELD_PRICE" => Some(Self::Price),
+            "OFFERS_ORDER_BY_FIELD_RELEVANCE" => Some(Self::Relevance),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OffersFilterField {
+    Unspecified = 0,
+    Name = 1,
+    Description = 2,
+    NameAndDescription = 3,
+    Type = 4,
+    IsFeatured = 5,
+    IsSoldOut = 6,
+    ShipsToCountry = 7,
+    Category = 8,
+    Collection = 9,
+    Tag = 10,
+    Attribute = 11,
+    MinPrice = 12,
+    MaxPrice = 13,
+}
+impl OffersFilterField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OffersFilterField::Unspecified => "OFFERS_FILTER_FIELD_UNSPECIFIED",
+            OffersFilterField::Name => "OFFERS_FILTER_FIELD_NAME",
+            OffersFilterField::Description => "OFFERS_FILTER_FIELD_DESCRIPTION",
+            OffersFilterField::NameAndDescription => {
+                "OFFERS_FILTER_FIELD_NAME_AND_DESCRIPTION"
+            }
+            OffersFilterField::Type => "OFFERS_FILTER_FIELD_TYPE",
+            OffersFilterField::IsFeatured => "OFFERS_FILTER_FIELD_IS_FEATURED",
+            OffersFilterField::IsSoldOut => "OFFERS_FILTER_FIELD_IS_SOLD_OUT",
+            OffersFilterField::ShipsToCountry => "OFFERS_FILTER_FIELD_SHIPS_TO_COUNTRY",
+            OffersFilterField::Category => "OFFERS_FILTER_FIELD_CATEGORY",
+            OffersFilterField::Collection => "OFFERS_FILTER_FIELD_COLLECTION",
+            OffersFilterField::Tag => "OFFERS_FILTER_FIELD_TAG",
+            OffersFilterField::Attribute => "OFFERS_FILTER_FIELD_ATTRIBUTE",
+            OffersFilterField::MinPrice => "OFFERS_FILTER_FIELD_MIN_PRICE",
+            OffersFilterField::MaxPrice => "OFFERS_FILTER_FIELD_MAX_PRICE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OFFERS_FILTER_FIELD_UNSPECIFIED" => Some(Self::Unspecified),
+            "OFFERS_FILTER_FIELD_NAME" => Some(Self::Name),
+            "OFFERS_FILTER_FIELD_DESCRIPTION" => Some(Self::Description),
+            "OFFERS_FILTER_FIELD_NAME_AND_DESCRIPTION" => Some(Self::NameAndDescription),
+            "OFFERS_FILTER_FIELD_TYPE" => Some(Self::Type),
+            "OFFERS_FILTER_FIELD_IS_FEATURED" => Some(Self::IsFeatured),
+            "OFFERS_FILTER_FIELD_IS_SOLD_OUT" => Some(Self::IsSoldOut),
+            "OFFERS_FILTER_FIELD_SHIPS_TO_COUNTRY" => Some(Self::ShipsToCountry),
+            "OFFERS_FILTER_FIELD_CATEGORY" => Some(Self::Category),
+            "OFFERS_FILTER_FIELD_COLLECTION" => Some(Self::Collection),
+            "OFFERS_FILTER_FIELD_TAG" => Some(Self::Tag),
+            "OFFERS_FILTER_FIELD_ATTRIBUTE" => Some(Self::Attribute),
+            "OFFERS_FILTER_FIELD_MIN_PRICE" => Some(Self::MinPrice),
+            "OFFERS_FILTER_FIELD_MAX_PRICE" => Some(Self::MaxPrice),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OfferAttributeType {
+    Unspecified = 0,
+    Text = 1,
+    Number = 2,
+    Boolean = 3,
+}
+impl OfferAttributeType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OfferAttributeType::Unspecified => "OFFER_ATTRIBUTE_TYPE_UNSPECIFIED",
+            OfferAttributeType::Text => "OFFER_ATTRIBUTE_TYPE_TEXT",
+            OfferAttributeType::Number => "OFFER_ATTRIBUTE_TYPE_NUMBER",
+            OfferAttributeType::Boolean => "OFFER_ATTRIBUTE_TYPE_BOOLEAN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OFFER_ATTRIBUTE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "OFFER_ATTRIBUTE_TYPE_TEXT" => Some(Self::Text),
+            "OFFER_ATTRIBUTE_TYPE_NUMBER" => Some(Self::Number),
+            "OFFER_ATTRIBUTE_TYPE_BOOLEAN" => Some(Self::Boolean),
+            _ => None,
+        }
+    }
+}
+/// Generated server implementations.
+pub mod offer_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with OfferServiceServer.
+    #[async_trait]
+    pub trait OfferService: Send + Sync + 'static {
+        async fn create_offer(
+            &self,
+            request: tonic::Request<super::CreateOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateOfferResponse>,
+            tonic::Status,
+        >;
+        async fn get_offer(
+            &self,
+            request: tonic::Request<super::GetOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOfferResponse>,
+            tonic::Status,
+        >;
+        async fn get_my_offer(
+            &self,
+            request: tonic::Request<super::GetMyOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMyOfferResponse>,
+            tonic::Status,
+        >;
+        async fn list_offers(
+            &self,
+            request: tonic::Request<super::ListOffersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOffersResponse>,
+            tonic::Status,
+        >;
+        async fn autocomplete_offers(
+            &self,
+            request: tonic::Request<super::AutocompleteOffersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AutocompleteOffersResponse>,
+            tonic::Status,
+        >;
+        async fn update_offer(
+            &self,
+            request: tonic::Request<super::UpdateOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateOfferResponse>,
+            tonic::Status,
+        >;
+        async fn delete_offer(
+            &self,
+            request: tonic::Request<super::DeleteOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteOfferResponse>,
+            tonic::Status,
+        >;
+        async fn add_image_to_offer(
+            &self,
+            request: tonic::Request<super::AddImageToOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddImageToOfferResponse>,
+            tonic::Status,
+        >;
+        async fn remove_image_from_offer(
+            &self,
+            request: tonic::Request<super::RemoveImageFromOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveImageFromOfferResponse>,
+            tonic::Status,
+        >;
+        async fn put_price_to_offer(
+            &self,
+            request: tonic::Request<super::PutPriceToOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutPriceToOfferResponse>,
+            tonic::Status,
+        >;
+        async fn remove_price_from_offer(
+            &self,
+            request: tonic::Request<super::RemovePriceFromOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemovePriceFromOfferResponse>,
+            tonic::Status,
+        >;
+        async fn put_shipping_profile_to_offer(
+            &self,
+            request: tonic::Request<super::PutShippingProfileToOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutShippingProfileToOfferResponse>,
+            tonic::Status,
+        >;
+        async fn remove_shipping_profile_from_offer(
+            &self,
+            request: tonic::Request<super::RemoveShippingProfileFromOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveShippingProfileFromOfferResponse>,
+            tonic::Status,
+        >;
+        async fn add_category_to_offer(
+            &self,
+            request: tonic::Request<super::AddCategoryToOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddCategoryToOfferResponse>,
+            tonic::Status,
+        >;
+        async fn remove_category_from_offer(
+            &self,
+            request: tonic::Request<super::RemoveCategoryFromOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveCategoryFromOfferResponse>,
+            tonic::Status,
+        >;
+        async fn put_tags_to_offer(
+            &self,
+            request: tonic::Request<super::PutTagsToOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutTagsToOfferResponse>,
+            tonic::Status,
+        >;
+        async fn put_attributes_to_offer(
+            &self,
+            request: tonic::Request<super::PutAttributesToOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutAttributesToOfferResponse>,
+            tonic::Status,
+        >;
+        async fn add_option_to_offer(
+            &self,
+            request: tonic::Request<super::AddOptionToOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddOptionToOfferResponse>,
+            tonic::Status,
+        >;
+        async fn update_offer_option(
+            &self,
+            request: tonic::Request<super::UpdateOfferOptionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateOfferOptionResponse>,
+            tonic::Status,
+        >;
+        async fn remove_option_from_offer(
+            &self,
+            request: tonic::Request<super::RemoveOptionFromOfferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveOptionFromOfferResponse>,
+            tonic::Status,
+        >;
+        async fn add_value_to_offer_option(
+            &self,
+            request: tonic::Request<super::AddValueToOfferOptionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddValueToOfferOptionResponse>,
+            tonic::Status,
+        >;
+        async fn remove_value_from_offer_option(
+            &self,
+            request: tonic::Request<super::RemoveValueFromOfferOptionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveValueFromOfferOptionResponse>,
+            tonic::Status,
+        >;
+        async fn create_offer_variant(
+            &self,
+            request: tonic::Request<super::CreateOfferVariantRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateOfferVariantResponse>,
+            tonic::Status,
+        >;
+        async fn generate_offer_variants(
+            &self,
+            request: tonic::Request<super::GenerateOfferVariantsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GenerateOfferVariantsResponse>,
+            tonic::Status,
+        >;
+        async fn update_offer_variant(
+            &self,
+            request: tonic::Request<super::UpdateOfferVariantRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateOfferVariantResponse>,
+            tonic::Status,
+        >;
+        async fn put_images_to_offer_variant(
+            &self,
+            request: tonic::Request<super::PutImagesToOfferVariantRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutImagesToOfferVariantResponse>,
+            tonic::Status,
+        >;
+        async fn delete_offer_variant(
+            &self,
+            request: tonic::Request<super::DeleteOfferVariantRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteOfferVariantResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct OfferServiceServer<T: OfferService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: OfferService> OfferServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for OfferServiceServer<T>
+    where
+        T: OfferService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.OfferService/CreateOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::CreateOfferRequest>
+                    for CreateOfferSvc<T> {
+                        type Response = super::CreateOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).create_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/GetOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::GetOfferRequest>
+                    for GetOfferSvc<T> {
+                        type Response = super::GetOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_offer(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/GetMyOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetMyOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::GetMyOfferRequest>
+                    for GetMyOfferSvc<T> {
+                        type Response = super::GetMyOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetMyOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_my_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetMyOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/ListOffers" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListOffersSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::ListOffersRequest>
+                    for ListOffersSvc<T> {
+                        type Response = super::ListOffersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListOffersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).list_offers(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListOffersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/AutocompleteOffers" => {
+                    #[allow(non_camel_case_types)]
+                    struct AutocompleteOffersSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::AutocompleteOffersRequest>
+                    for AutocompleteOffersSvc<T> {
+                        type Response = super::AutocompleteOffersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AutocompleteOffersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).autocomplete_offers(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AutocompleteOffersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/UpdateOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::UpdateOfferRequest>
+                    for UpdateOfferSvc<T> {
+                        type Response = super::UpdateOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/DeleteOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::DeleteOfferRequest>
+                    for DeleteOfferSvc<T> {
+                        type Response = super::DeleteOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/AddImageToOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddImageToOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::AddImageToOfferRequest>
+                    for AddImageToOfferSvc<T> {
+                        type Response = super::AddImageToOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddImageToOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_image_to_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddImageToOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/RemoveImageFromOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveImageFromOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::RemoveImageFromOfferRequest>
+                    for RemoveImageFromOfferSvc<T> {
+                        type Response = super::RemoveImageFromOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveImageFromOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_image_from_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveImageFromOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/PutPriceToOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutPriceToOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::PutPriceToOfferRequest>
+                    for PutPriceToOfferSvc<T> {
+                        type Response = super::PutPriceToOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutPriceToOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_price_to_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutPriceToOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/RemovePriceFromOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemovePriceFromOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::RemovePriceFromOfferRequest>
+                    for RemovePriceFromOfferSvc<T> {
+                        type Response = super::RemovePriceFromOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemovePriceFromOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_price_from_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemovePriceFromOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/PutShippingProfileToOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutShippingProfileToOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<
+                        super::PutShippingProfileToOfferRequest,
+                    > for PutShippingProfileToOfferSvc<T> {
+                        type Response = super::PutShippingProfileToOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::PutShippingProfileToOfferRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_shipping_profile_to_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutShippingProfileToOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/RemoveShippingProfileFromOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveShippingProfileFromOfferSvc<T: OfferService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<
+                        super::RemoveShippingProfileFromOfferRequest,
+                    > for RemoveShippingProfileFromOfferSvc<T> {
+                        type Response = super::RemoveShippingProfileFromOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::RemoveShippingProfileFromOfferRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_shipping_profile_from_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveShippingProfileFromOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/AddCategoryToOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddCategoryToOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::AddCategoryToOfferRequest>
+                    for AddCategoryToOfferSvc<T> {
+                        type Response = super::AddCategoryToOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddCategoryToOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_category_to_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddCategoryToOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/RemoveCategoryFromOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveCategoryFromOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::RemoveCategoryFromOfferRequest>
+                    for RemoveCategoryFromOfferSvc<T> {
+                        type Response = super::RemoveCategoryFromOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::RemoveCategoryFromOfferRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_category_from_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveCategoryFromOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/PutTagsToOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutTagsToOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::PutTagsToOfferRequest>
+                    for PutTagsToOfferSvc<T> {
+                        type Response = super::PutTagsToOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutTagsToOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_tags_to_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutTagsToOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/PutAttributesToOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutAttributesToOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::PutAttributesToOfferRequest>
+                    for PutAttributesToOfferSvc<T> {
+                        type Response = super::PutAttributesToOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutAttributesToOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_attributes_to_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutAttributesToOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/AddOptionToOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddOptionToOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::AddOptionToOfferRequest>
+                    for AddOptionToOfferSvc<T> {
+                        type Response = super::AddOptionToOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddOptionToOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_option_to_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddOptionToOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/UpdateOfferOption" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateOfferOptionSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::UpdateOfferOptionRequest>
+                    for UpdateOfferOptionSvc<T> {
+                        type Response = super::UpdateOfferOptionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateOfferOptionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_offer_option(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateOfferOptionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/RemoveOptionFromOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveOptionFromOfferSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::RemoveOptionFromOfferRequest>
+                    for RemoveOptionFromOfferSvc<T> {
+                        type Response = super::RemoveOptionFromOfferResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveOptionFromOfferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_option_from_offer(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveOptionFromOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/AddValueToOfferOption" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddValueToOfferOptionSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::AddValueToOfferOptionRequest>
+                    for AddValueToOfferOptionSvc<T> {
+                        type Response = super::AddValueToOfferOptionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddValueToOfferOptionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_value_to_offer_option(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddValueToOfferOptionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/RemoveValueFromOfferOption" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveValueFromOfferOptionSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<
+                        super::RemoveValueFromOfferOptionRequest,
+                    > for RemoveValueFromOfferOptionSvc<T> {
+                        type Response = super::RemoveValueFromOfferOptionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::RemoveValueFromOfferOptionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_value_from_offer_option(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveValueFromOfferOptionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/CreateOfferVariant" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateOfferVariantSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::CreateOfferVariantRequest>
+                    for CreateOfferVariantSvc<T> {
+                        type Response = super::CreateOfferVariantResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateOfferVariantRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).create_offer_variant(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateOfferVariantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/GenerateOfferVariants" => {
+                    #[allow(non_camel_case_types)]
+                    struct GenerateOfferVariantsSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::GenerateOfferVariantsRequest>
+                    for GenerateOfferVariantsSvc<T> {
+                        type Response = super::GenerateOfferVariantsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GenerateOfferVariantsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).generate_offer_variants(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GenerateOfferVariantsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/UpdateOfferVariant" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateOfferVariantSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::UpdateOfferVariantRequest>
+                    for UpdateOfferVariantSvc<T> {
+                        type Response = super::UpdateOfferVariantResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateOfferVariantRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_offer_variant(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateOfferVariantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/PutImagesToOfferVariant" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutImagesToOfferVariantSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::PutImagesToOfferVariantRequest>
+                    for PutImagesToOfferVariantSvc<T> {
+                        type Response = super::PutImagesToOfferVariantResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::PutImagesToOfferVariantRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_images_to_offer_variant(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutImagesToOfferVariantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OfferService/DeleteOfferVariant" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteOfferVariantSvc<T: OfferService>(pub Arc<T>);
+                    impl<
+                        T: OfferService,
+                    > tonic::server::UnaryService<super::DeleteOfferVariantRequest>
+                    for DeleteOfferVariantSvc<T> {
+                        type Response = super::DeleteOfferVariantResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteOfferVariantRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_offer_variant(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteOfferVariantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: OfferService> Clone for OfferServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: OfferService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: OfferService> tonic::server::NamedService for OfferServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.OfferService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShippingRateResponse {
+    #[prost(string, tag = "1")]
+    pub shipping_rate_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "4")]
+    pub amount: u32,
+    #[prost(enumeration = "Currency", tag = "5")]
+    pub currency: i32,
+    #[prost(bool, tag = "6")]
+    pub all_countries: bool,
+    #[prost(enumeration = "ShippingCountry", repeated, tag = "7")]
+    pub specific_countries: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutShippingRateRequest {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub amount: u32,
+    #[prost(enumeration = "Currency", tag = "3")]
+    pub currency: i32,
+    #[prost(bool, tag = "4")]
+    pub all_countries: bool,
+    #[prost(enumeration = "ShippingCountry", repeated, tag = "5")]
+    pub specific_countries: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutShippingRateResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetShippingRateRequest {
+    #[prost(string, optional, tag = "1")]
+    pub offer_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "Currency", optional, tag = "2")]
+    pub currency: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetShippingRateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub shipping_rate: ::core::option::Option<ShippingRateResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteShippingRateRequest {
+    #[prost(string, tag = "1")]
+    pub shipping_rate_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteShippingRateResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShippingQuoteItem {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuoteShippingRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<ShippingQuoteItem>,
+    #[prost(enumeration = "ShippingCountry", tag = "2")]
+    pub shipping_country: i32,
+    #[prost(enumeration = "Currency", optional, tag = "3")]
+    pub currency: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShippingQuoteShopResponse {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub amount: u32,
+    #[prost(string, repeated, tag = "3")]
+    pub offer_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShippingQuoteErrorResponse {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "ShippingQuoteErrorCode", tag = "2")]
+    pub code: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuoteShippingResponse {
+    #[prost(enumeration = "Currency", tag = "1")]
+    pub currency: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub shops: ::prost::alloc::vec::Vec<ShippingQuoteShopResponse>,
+    #[prost(uint32, tag = "3")]
+    pub total_amount: u32,
+    #[prost(message, repeated, tag = "4")]
+    pub errors: ::prost::alloc::vec::Vec<ShippingQuoteErrorResponse>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ShippingQuoteErrorCode {
+    Unspecified = 0,
+    OfferNotFound = 1,
+    OfferVariantNotFound = 2,
+    NoPrice = 3,
+    NotShippable = 4,
+}
+impl ShippingQuoteErrorCode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ShippingQuoteErrorCode::Unspecified => {
+                "SHIPPING_QUOTE_ERROR_CODE_UNSPECIFIED"
+            }
+            ShippingQuoteErrorCode::OfferNotFound => {
+                "SHIPPING_QUOTE_ERROR_CODE_OFFER_NOT_FOUND"
+            }
+            ShippingQuoteErrorCode::OfferVariantNotFound => {
+                "SHIPPING_QUOTE_ERROR_CODE_OFFER_VARIANT_NOT_FOUND"
+            }
+            ShippingQuoteErrorCode::NoPrice => "SHIPPING_QUOTE_ERROR_CODE_NO_PRICE",
+            ShippingQuoteErrorCode::NotShippable => {
+                "SHIPPING_QUOTE_ERROR_CODE_NOT_SHIPPABLE"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SHIPPING_QUOTE_ERROR_CODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SHIPPING_QUOTE_ERROR_CODE_OFFER_NOT_FOUND" => Some(Self::OfferNotFound),
+            "SHIPPING_QUOTE_ERROR_CODE_OFFER_VARIANT_NOT_FOUND" => {
+                Some(Self::OfferVariantNotFound)
+            }
+            "SHIPPING_QUOTE_ERROR_CODE_NO_PRICE" => Some(Self::NoPrice),
+            "SHIPPING_QUOTE_ERROR_CODE_NOT_SHIPPABLE" => Some(Self::NotShippable),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ShippingCountry {
+    Unspecified = 0,
+    Ac = 1,
+    Ad = 2,
+    Ae = 3,
+    Af = 4,
+    Ag = 5,
+    Ai = 6,
+    Al = 7,
+    Am = 8,
+    Ao = 9,
+    Aq = 10,
+    Ar = 11,
+    At = 12,
+    Au = 13,
+    Aw = 14,
+    Ax = 15,
+    Az = 16,
+    Ba = 17,
+    Bb = 18,
+    Bd = 19,
+    Be = 20,
+    Bf = 21,
+    Bg = 22,
+    Bh = 23,
+    Bi = 24,
+    Bj = 25,
+    Bl = 26,
+    Bm = 27,
+    Bn = 28,
+    Bo = 29,
+    Bq = 30,
+    Br = 31,
+    Bs = 32,
+    Bt = 33,
+    Bv = 34,
+    Bw = 35,
+    By = 36,
+    Bz = 37,
+    Ca = 38,
+    Cd = 39,
+    Cf = 40,
+    Cg = 41,
+    Ch = 42,
+    Ci = 43,
+    Ck = 44,
+    Cl = 45,
+    Cm = 46,
+    Cn = 47,
+    Co = 48,
+    Cr = 49,
+    Cv = 50,
+    Cw = 51,
+    Cy = 52,
+    Cz = 53,
+    De = 54,
+    Dj = 55,
+    Dk = 56,
+    Dm = 57,
+    Do = 58,
+    Dz = 59,
+    Ec = 60,
+    Ee = 61,
+    Eg = 62,
+    Eh = 63,
+    Er = 64,
+    Es = 65,
+    Et = 66,
+    Fi = 67,
+    Fj = 68,
+    Fk = 69,
+    Fo = 70,
+    Fr = 71,
+    Ga = 72,
+    Gb = 73,
+    Gd = 74,
+    Ge = 75,
+    Gf = 76,
+    Gg = 77,
+    Gh = 78,
+    Gi = 79,
+    Gl = 80,
+    Gm = 81,
+    Gn = 82,
+    Gp = 83,
+    Gq = 84,
+    Gr = 85,
+    Gs = 86,
+    Gt = 87,
+    Gu = 88,
+    Gw = 89,
+    Gy = 90,
+    Hk = 91,
+    Hn = 92,
+    Hr = 93,
+    Ht = 94,
+    Hu = 95,
+    Id = 96,
+    Ie = 97,
+    Il = 98,
+    Im = 99,
+    In = 100,
+    Io = 101,
+    Iq = 102,
+    Is = 103,
+    It = 104,
+    Je = 105,
+    Jm = 106,
+    Jo = 107,
+    Jp = 108,
+    Ke = 109,
+    Kg = 110,
+    Kh = 111,
+    Ki = 112,
+    Km = 113,
+    Kn = 114,
+    Kr = 115,
+    Kw = 116,
+    Ky = 117,
+    La = 118,
+    Lb = 119,
+    Lc = 120,
+    Li = 121,
+    Lk = 122,
+    Lr = 123,
+    Ls = 124,
+    Lt = 125,
+    Lu = 126,
+    Lv = 127,
+    Ly = 128,
+    Ma = 129,
+    Mc = 130,
+    Md = 131,
+    Me = 132,
+    Mf = 133,
+    Mg = 134,
+    Mk = 135,
+    Ml = 136,
+    Mm = 137,
+    Mn = 138,
+    Mo = 139,
+    Mq = 140,
+    Mr = 141,
+    Ms = 142,
+    Mt = 143,
+    Mu = 144,
+    Mv = 145,
+    Mw = 146,
+    Mx = 147,
+    My = 148,
+    Mz = 149,
+    Na = 150,
+    Nc = 151,
+    Ne = 152,
+    Ng = 153,
+    Ni = 154,
+    Nl = 155,
+    No = 156,
+    Np = 157,
+    Nr = 158,
+    Nu = 159,
+    Nz = 160,
+    Om = 161,
+    Pa = 162,
+    Pe = 163,
+    Pf = 164,
+    Pg = 165,
+    Ph = 166,
+    Pk = 167,
+    Pl = 168,
+    Pm = 169,
+    Pn = 170,
+    Pr = 171,
+    Ps = 172,
+    Pt = 173,
+    Py = 174,
+    Qa = 175,
+    Re = 176,
+    Ro = 177,
+    Rs = 178,
+    Ru = 179,
+    Rw = 180,
+    Sa = 181,
+    Sb = 182,
+    Sc = 183,
+    Se = 184,
+    Sg = 185,
+    Sh = 186,
+    Si = 187,
+    Sj = 188,
+    Sk = 189,
+    Sl = 190,
+    Sm = 191,
+    Sn = 192,
+    So = 193,
+    Sr = 194,
+    Ss = 195,
+    St = 196,
+    Sv = 197,
+    Sx = 198,
+    Sz = 199,
+    Ta = 200,
+    Tc = 201,
+    Td = 202,
+    Tf = 203,
+    Tg = 204,
+    Th = 205,
+    Tj = 206,
+    Tk = 207,
+    Tl = 208,
+    Tm = 209,
+    Tn = 210,
+    To = 211,
+    Tr = 212,
+    Tt = 213,
+    Tv = 214,
+    Tw = 215,
+    Tz = 216,
+    Ua = 217,
+    Ug = 218,
+    Us = 219,
+    Uy = 220,
+    Uz = 221,
+    Va = 222,
+    Vc = 223,
+    Ve = 224,
+    Vg = 225,
+    Vn = 226,
+    Vu = 227,
+    Wf = 228,
+    Ws = 229,
+    Xk = 230,
+    Ye = 231,
+    Yt = 232,
+    Za = 233,
+    Zm = 234,
+    Zw = 235,
+    Zz = 236,
+}
+impl ShippingCountry {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ShippingCountry::Unspecified => "SHIPPING_COUNTRY_UNSPECIFIED",
+            ShippingCountry::Ac => "SHIPPING_COUNTRY_AC",
+            ShippingCountry::Ad => "SHIPPING_COUNTRY_AD",
+            ShippingCountry::Ae => "SHIPPING_COUNTRY_AE",
+            ShippingCountry::Af => "SHIPPING_COUNTRY_AF",
+            ShippingCountry::Ag => "SHIPPING_COUNTRY_AG",
+            ShippingCountry::Ai => "SHIPPING_COUNTRY_AI",
+            ShippingCountry::Al => "SHIPPING_COUNTRY_AL",
+            ShippingCountry::Am => "SHIPPING_COUNTRY_AM",
+            ShippingCountry::Ao => "SHIPPING_COUNTRY_AO",
+            ShippingCountry::Aq => "SHIPPING_COUNTRY_AQ",
+            ShippingCountry::Ar => "SHIPPING_COUNTRY_AR",
+            ShippingCountry::At => "SHIPPING_COUNTRY_AT",
+            ShippingCountry::Au => "SHIPPING_COUNTRY_AU",
+            ShippingCountry::Aw => "SHIPPING_COUNTRY_AW",
+            ShippingCountry::Ax => "SHIPPING_COUNTRY_AX",
+            ShippingCountry::Az => "SHIPPING_COUNTRY_AZ",
+            ShippingCountry::Ba => "SHIPPING_COUNTRY_BA",
+            ShippingCountry::Bb => "SHIPPING_COUNTRY_BB",
+            ShippingCountry::Bd => "SHIPPING_COUNTRY_BD",
+            ShippingCountry::Be => "SHIPPING_COUNTRY_BE",
+            ShippingCountry::Bf => "SHIPPING_COUNTRY_BF",
+            ShippingCountry::Bg => "SHIPPING_COUNTRY_BG",
+            ShippingCountry::Bh => "SHIPPING_COUNTRY_BH",
+            ShippingCountry::Bi => "SHIPPING_COUNTRY_BI",
+            ShippingCountry::Bj => "SHIPPING_COUNTRY_BJ",
+            ShippingCountry::Bl => "SHIPPING_COUNTRY_BL",
+            ShippingCountry::Bm => "SHIPPING_COUNTRY_BM",
+            ShippingCountry::Bn => "SHIPPING_COUNTRY_BN",
+            ShippingCountry::Bo => "SHIPPING_COUNTRY_BO",
+            ShippingCountry::Bq => "SHIPPING_COUNTRY_BQ",
+            ShippingCountry::Br => "SHIPPING_COUNTRY_BR",
+            ShippingCountry::Bs => "SHIPPING_COUNTRY_BS",
+            ShippingCountry::Bt => "SHIPPING_COUNTRY_BT",
+            ShippingCountry::Bv => "SHIPPING_COUNTRY_BV",
+            ShippingCountry::Bw => "SHIPPING_COUNTRY_BW",
+            ShippingCountry::By => "SHIPPING_COUNTRY_BY",
+            ShippingCountry::Bz => "SHIPPING_COUNTRY_BZ",
+            ShippingCountry::Ca => "SHIPPING_COUNTRY_CA",
+            ShippingCountry::Cd => "SHIPPING_COUNTRY_CD",
+            ShippingCountry::Cf => "SHIPPING_COUNTRY_CF",
+            ShippingCountry::Cg => "SHIPPING_COUNTRY_CG",
+            ShippingCountry::Ch => "SHIPPING_COUNTRY_CH",
+            ShippingCountry::Ci => "SHIPPING_COUNTRY_CI",
+            ShippingCountry::Ck => "SHIPPING_COUNTRY_CK",
+            ShippingCountry::Cl => "SHIPPING_COUNTRY_CL",
+            ShippingCountry::Cm => "SHIPPING_COUNTRY_CM",
+            ShippingCountry::Cn => "SHIPPING_COUNTRY_CN",
+            ShippingCountry::Co => "SHIPPING_COUNTRY_CO",
+            ShippingCountry::Cr => "SHIPPING_COUNTRY_CR",
+            ShippingCountry::Cv => "SHIPPING_COUNTRY_CV",
+            ShippingCountry::Cw => "SHIPPING_COUNTRY_CW",
+            ShippingCountry::Cy => "SHIPPING_COUNTRY_CY",
+            ShippingCountry::Cz => "SHIPPING_COUNTRY_CZ",
+            ShippingCountry::De => "SHIPPING_COUNTRY_DE",
+            ShippingCountry::Dj => "SHIPPING_COUNTRY_DJ",
+            ShippingCountry::Dk => "SHIPPING_COUNTRY_DK",
+            ShippingCountry::Dm => "SHIPPING_COUNTRY_DM",
+            ShippingCountry::Do => "SHIPPING_COUNTRY_DO",
+            ShippingCountry::Dz => "SHIPPING_COUNTRY_DZ",
+            ShippingCountry::Ec => "SHIPPING_COUNTRY_EC",
+            ShippingCountry::Ee => "SHIPPING_COUNTRY_EE",
+            ShippingCountry::Eg => "SHIPPING_COUNTRY_EG",
+            ShippingCountry::Eh => "SHIPPING_COUNTRY_EH",
+            ShippingCountry::Er => "SHIPPING_COUNTRY_ER",
+            ShippingCountry::Es => "SHIPPING_COUNTRY_ES",
+            ShippingCountry::Et => "SHIPPING_COUNTRY_ET",
+            ShippingCountry::Fi => "SHIPPING_COUNTRY_FI",
+            ShippingCountry::Fj => "SHIPPING_COUNTRY_FJ",
+            ShippingCountry::Fk => "SHIPPING_COUNTRY_FK",
+            ShippingCountry::Fo => "SHIPPING_COUNTRY_FO",
+            ShippingCountry::Fr => "SHIPPING_COUNTRY_FR",
+            ShippingCountry::Ga => "SHIPPING_COUNTRY_GA",
+            ShippingCountry::Gb => "SHIPPING_COUNTRY_GB",
+            ShippingCountry::Gd => "SHIPPING_COUNTRY_GD",
+            ShippingCountry::Ge => "SHIPPING_COUNTRY_GE",
+            ShippingCountry::Gf => "SHIPPING_COUNTRY_GF",
+            ShippingCountry::Gg => "SHIPPING_COUNTRY_GG",
+            ShippingCountry::Gh => "SHIPPING_COUNTRY_GH",
+            ShippingCountry::Gi => "SHIPPING_COUNTRY_GI",
+            ShippingCountry::Gl => "SHIPPING_COUNTRY_GL",
+            ShippingCountry::Gm => "SHIPPING_COUNTRY_GM",
+            ShippingCountry::Gn => "SHIPPING_COUNTRY_GN",
+            ShippingCountry::Gp => "SHIPPING_COUNTRY_GP",
+            ShippingCountry::Gq => "SHIPPING_COUNTRY_GQ",
+            ShippingCountry::Gr => "SHIPPING_COUNTRY_GR",
+            ShippingCountry::Gs => "SHIPPING_COUNTRY_GS",
+            ShippingCountry::Gt => "SHIPPING_COUNTRY_GT",
+            ShippingCountry::Gu => "SHIPPING_COUNTRY_GU",
+            ShippingCountry::Gw => "SHIPPING_COUNTRY_GW",
+            ShippingCountry::Gy => "SHIPPING_COUNTRY_GY",
+            ShippingCountry::Hk => "SHIPPING_COUNTRY_HK",
+            ShippingCountry::Hn => "SHIPPING_COUNTRY_HN",
+            ShippingCountry::Hr => "SHIPPING_COUNTRY_HR",
+            ShippingCountry::Ht => "SHIPPING_COUNTRY_HT",
+            ShippingCountry::Hu => "SHIPPING_COUNTRY_HU",
+            ShippingCountry::Id => "SHIPPING_COUNTRY_ID",
+            ShippingCountry::Ie => "SHIPPING_COUNTRY_IE",
+            ShippingCountry::Il => "SHIPPING_COUNTRY_IL",
+            ShippingCountry::Im => "SHIPPING_COUNTRY_IM",
+            ShippingCountry::In => "SHIPPING_COUNTRY_IN",
+            ShippingCountry::Io => "SHIPPING_COUNTRY_IO",
+            ShippingCountry::Iq => "SHIPPING_COUNTRY_IQ",
+            ShippingCountry::Is => "SHIPPING_COUNTRY_IS",
+            ShippingCountry::It => "SHIPPING_COUNTRY_IT",
+            ShippingCountry::Je => "SHIPPING_COUNTRY_JE",
+            ShippingCountry::Jm => "SHIPPING_COUNTRY_JM",
+            ShippingCountry::Jo => "SHIPPING_COUNTRY_JO",
+            ShippingCountry::Jp => "SHIPPING_COUNTRY_JP",
+            ShippingCountry::Ke => "SHIPPING_COUNTRY_KE",
+            ShippingCountry::Kg => "SHIPPING_COUNTRY_KG",
+            ShippingCountry::Kh => "SHIPPING_COUNTRY_KH",
+            ShippingCountry::Ki => "SHIPPING_COUNTRY_KI",
+            ShippingCountry::Km => "SHIPPING_COUNTRY_KM",
+            ShippingCountry::Kn => "SHIPPING_COUNTRY_KN",
+            ShippingCountry::Kr => "SHIPPING_COUNTRY_KR",
+            ShippingCountry::Kw => "SHIPPING_COUNTRY_KW",
+            ShippingCountry::Ky => "SHIPPING_COUNTRY_KY",
+            ShippingCountry::La => "SHIPPING_COUNTRY_LA",
+            ShippingCountry::Lb => "SHIPPING_COUNTRY_LB",
+            ShippingCountry::Lc => "SHIPPING_COUNTRY_LC",
+            ShippingCountry::Li => "SHIPPING_COUNTRY_LI",
+            ShippingCountry::Lk => "SHIPPING_COUNTRY_LK",
+            ShippingCountry::Lr => "SHIPPING_COUNTRY_LR",
+            ShippingCountry::Ls => "SHIPPING_COUNTRY_LS",
+            ShippingCountry::Lt => "SHIPPING_COUNTRY_LT",
+            ShippingCountry::Lu => "SHIPPING_COUNTRY_LU",
+            ShippingCountry::Lv => "SHIPPING_COUNTRY_LV",
+            ShippingCountry::Ly => "SHIPPING_COUNTRY_LY",
+            ShippingCountry::Ma => "SHIPPING_COUNTRY_MA",
+            ShippingCountry::Mc => "SHIPPING_COUNTRY_MC",
+            ShippingCountry::Md => "SHIPPING_COUNTRY_MD",
+            ShippingCountry::Me => "SHIPPING_COUNTRY_ME",
+            ShippingCountry::Mf => "SHIPPING_COUNTRY_MF",
+            ShippingCountry::Mg => "SHIPPING_COUNTRY_MG",
+            ShippingCountry::Mk => "SHIPPING_COUNTRY_MK",
+            ShippingCountry::Ml => "SHIPPING_COUNTRY_ML",
+            ShippingCountry::Mm => "SHIPPING_COUNTRY_MM",
+            ShippingCountry::Mn => "SHIPPING_COUNTRY_MN",
+            ShippingCountry::Mo => "SHIPPING_COUNTRY_MO",
+            ShippingCountry::Mq => "SHIPPING_COUNTRY_MQ",
+            ShippingCountry::Mr => "SHIPPING_COUNTRY_MR",
+            ShippingCountry::Ms => "SHIPPING_COUNTRY_MS",
+            ShippingCountry::Mt => "SHIPPING_COUNTRY_MT",
+            ShippingCountry::Mu => "SHIPPING_COUNTRY_MU",
+            ShippingCountry::Mv => "SHIPPING_COUNTRY_MV",
+            ShippingCountry::Mw => "SHIPPING_COUNTRY_MW",
+            ShippingCountry::Mx => "SHIPPING_COUNTRY_MX",
+            ShippingCountry::My => "SHIPPING_COUNTRY_MY",
+            ShippingCountry::Mz => "SHIPPING_COUNTRY_MZ",
+            ShippingCountry::Na => "SHIPPING_COUNTRY_NA",
+            ShippingCountry::Nc => "SHIPPING_COUNTRY_NC",
+            ShippingCountry::Ne => "SHIPPING_COUNTRY_NE",
+            ShippingCountry::Ng => "SHIPPING_COUNTRY_NG",
+            ShippingCountry::Ni => "SHIPPING_COUNTRY_NI",
+            ShippingCountry::Nl => "SHIPPING_COUNTRY_NL",
+            ShippingCountry::No => "SHIPPING_COUNTRY_NO",
+            ShippingCountry::Np => "SHIPPING_COUNTRY_NP",
+            ShippingCountry::Nr => "SHIPPING_COUNTRY_NR",
+            ShippingCountry::Nu => "SHIPPING_COUNTRY_NU",
+            ShippingCountry::Nz => "SHIPPING_COUNTRY_NZ",
+            ShippingCountry::Om => "SHIPPING_COUNTRY_OM",
+            ShippingCountry::Pa => "SHIPPING_COUNTRY_PA",
+            ShippingCountry::Pe => "SHIPPING_COUNTRY_PE",
+            ShippingCountry::Pf => "SHIPPING_COUNTRY_PF",
+            ShippingCountry::Pg => "SHIPPING_COUNTRY_PG",
+            ShippingCountry::Ph => "SHIPPING_COUNTRY_PH",
+            ShippingCountry::Pk => "SHIPPING_COUNTRY_PK",
+            ShippingCountry::Pl => "SHIPPING_COUNTRY_PL",
+            ShippingCountry::Pm => "SHIPPING_COUNTRY_PM",
+            ShippingCountry::Pn => "SHIPPING_COUNTRY_PN",
+            ShippingCountry::Pr => "SHIPPING_COUNTRY_PR",
+            ShippingCountry::Ps => "SHIPPING_COUNTRY_PS",
+            ShippingCountry::Pt => "SHIPPING_COUNTRY_PT",
+            ShippingCountry::Py => "SHIPPING_COUNTRY_PY",
+            ShippingCountry::Qa => "SHIPPING_COUNTRY_QA",
+            ShippingCountry::Re => "SHIPPING_COUNTRY_RE",
+            ShippingCountry::Ro => "SHIPPING_COUNTRY_RO",
+            ShippingCountry::Rs => "SHIPPING_COUNTRY_RS",
+            ShippingCountry::Ru => "SHIPPING_COUNTRY_RU",
+            ShippingCountry::Rw => "SHIPPING_COUNTRY_RW",
+            ShippingCountry::Sa => "SHIPPING_COUNTRY_SA",
+            ShippingCountry::Sb => "SHIPPING_COUNTRY_SB",
+            ShippingCountry::Sc => "SHIPPING_COUNTRY_SC",
+            ShippingCountry::Se => "SHIPPING_COUNTRY_SE",
+            ShippingCountry::Sg => "SHIPPING_COUNTRY_SG",
+            ShippingCountry::Sh => "SHIPPING_COUNTRY_SH",
+            ShippingCountry::Si => "SHIPPING_COUNTRY_SI",
+            ShippingCountry::Sj => "SHIPPING_COUNTRY_SJ",
+            ShippingCountry::Sk => "SHIPPING_COUNTRY_SK",
+            ShippingCountry::Sl => "SHIPPING_COUNTRY_SL",
+            ShippingCountry::Sm => "SHIPPING_COUNTRY_SM",
+            ShippingCountry::Sn => "SHIPPING_COUNTRY_SN",
+            ShippingCountry::So => "SHIPPING_COUNTRY_SO",
+            ShippingCountry::Sr => "SHIPPING_COUNTRY_SR",
+            ShippingCountry::Ss => "SHIPPING_COUNTRY_SS",
+            ShippingCountry::St => "SHIPPING_COUNTRY_ST",
+            ShippingCountry::Sv => "SHIPPING_COUNTRY_SV",
+            ShippingCountry::Sx => "SHIPPING_COUNTRY_SX",
+            ShippingCountry::Sz => "SHIPPING_COUNTRY_SZ",
+            ShippingCountry::Ta => "SHIPPING_COUNTRY_TA",
+            ShippingCountry::Tc => "SHIPPING_COUNTRY_TC",
+            ShippingCountry::Td => "SHIPPING_COUNTRY_TD",
+            ShippingCountry::Tf => "SHIPPING_COUNTRY_TF",
+            ShippingCountry::Tg => "SHIPPING_COUNTRY_TG",
+            ShippingCountry::Th => "SHIPPING_COUNTRY_TH",
+            ShippingCountry::Tj => "SHIPPING_COUNTRY_TJ",
+            ShippingCountry::Tk => "SHIPPING_COUNTRY_TK",
+            ShippingCountry::Tl => "SHIPPING_COUNTRY_TL",
+            ShippingCountry::Tm => "SHIPPING_COUNTRY_TM",
+            ShippingCountry::Tn => "SHIPPING_COUNTRY_TN",
+            ShippingCountry::To => "SHIPPING_COUNTRY_TO",
+            ShippingCountry::Tr => "SHIPPING_COUNTRY_TR",
+            ShippingCountry::Tt => "SHIPPING_COUNTRY_TT",
+            ShippingCountry::Tv => "SHIPPING_COUNTRY_TV",
+            ShippingCountry::Tw => "SHIPPING_COUNTRY_TW",
+            ShippingCountry::Tz => "SHIPPING_COUNTRY_TZ",
+            ShippingCountry::Ua => "SHIPPING_COUNTRY_UA",
+            ShippingCountry::Ug => "SHIPPING_COUNTRY_UG",
             ShippingCountry::Us => "SHIPPING_COUNTRY_US",
             ShippingCountry::Uy => "SHIPPING_COUNTRY_UY",
             ShippingCountry::Uz => "SHIPPING_COUNTRY_UZ",
@@ -3394,282 +6018,4316 @@ impl ShippingCountry {
             ShippingCountry::Zw => "SHIPPING_COUNTRY_ZW",
             ShippingCountry::Zz => "SHIPPING_COUNTRY_ZZ",
         }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "SHIPPING_COUNTRY_UNSPECIFIED" => Some(Self::Unspecified),
-            "SHIPPING_COUNTRY_AC" => Some(Self::Ac),
-            "SHIPPING_COUNTRY_AD" => Some(Self::Ad),
-            "SHIPPING_COUNTRY_AE" => Some(Self::Ae),
-            "SHIPPING_COUNTRY_AF" => Some(Self::Af),
-            "SHIPPING_COUNTRY_AG" => Some(Self::Ag),
-            "SHIPPING_COUNTRY_AI" => Some(Self::Ai),
-            "SHIPPING_COUNTRY_AL" => Some(Self::Al),
-            "SHIPPING_COUNTRY_AM" => Some(Self::Am),
-            "SHIPPING_COUNTRY_AO" => Some(Self::Ao),
-            "SHIPPING_COUNTRY_AQ" => Some(Self::Aq),
-            "SHIPPING_COUNTRY_AR" => Some(Self::Ar),
-            "SHIPPING_COUNTRY_AT" => Some(Self::At),
-            "SHIPPING_COUNTRY_AU" => Some(Self::Au),
-            "SHIPPING_COUNTRY_AW" => Some(Self::Aw),
-            "SHIPPING_COUNTRY_AX" => Some(Self::Ax),
-            "SHIPPING_COUNTRY_AZ" => Some(Self::Az),
-            "SHIPPING_COUNTRY_BA" => Some(Self::Ba),
-            "SHIPPING_COUNTRY_BB" => Some(Self::Bb),
-            "SHIPPING_COUNTRY_BD" => Some(Self::Bd),
-            "SHIPPING_COUNTRY_BE" => Some(Self::Be),
-            "SHIPPING_COUNTRY_BF" => Some(Self::Bf),
-            "SHIPPING_COUNTRY_BG" => Some(Self::Bg),
-            "SHIPPING_COUNTRY_BH" => Some(Self::Bh),
-            "SHIPPING_COUNTRY_BI" => Some(Self::Bi),
-            "SHIPPING_COUNTRY_BJ" => Some(Self::Bj),
-            "SHIPPING_COUNTRY_BL" => Some(Self::Bl),
-            "SHIPPING_COUNTRY_BM" => Some(Self::Bm),
-            "SHIPPING_COUNTRY_BN" => Some(Self::Bn),
-            "SHIPPING_COUNTRY_BO" => Some(Self::Bo),
-            "SHIPPING_COUNTRY_BQ" => Some(Self::Bq),
-            "SHIPPING_COUNTRY_BR" => Some(Self::Br),
-            "SHIPPING_COUNTRY_BS" => Some(Self::Bs),
-            "SHIPPING_COUNTRY_BT" => Some(Self::Bt),
-            "SHIPPING_COUNTRY_BV" => Some(Self::Bv),
-            "SHIPPING_COUNTRY_BW" => Some(Self::Bw),
-            "SHIPPING_COUNTRY_BY" => Some(Self::By),
-            "SHIPPING_COUNTRY_BZ" => Some(Self::Bz),
-            "SHIPPING_COUNTRY_CA" => Some(Self::Ca),
-            "SHIPPING_COUNTRY_CD" => Some(Self::Cd),
-            "SHIPPING_COUNTRY_CF" => Some(Self::Cf),
-            "SHIPPING_COUNTRY_CG" => Some(Self::Cg),
-            "SHIPPING_COUNTRY_CH" => Some(Self::Ch),
-            "SHIPPING_COUNTRY_CI" => Some(Self::Ci),
-            "SHIPPING_COUNTRY_CK" => Some(Self::Ck),
-            "SHIPPING_COUNTRY_CL" => Some(Self::Cl),
-            "SHIPPING_COUNTRY_CM" => Some(Self::Cm),
-            "SHIPPING_COUNTRY_CN" => Some(Self::Cn),
-            "SHIPPING_COUNTRY_CO" => Some(Self::Co),
-            "SHIPPING_COUNTRY_CR" => Some(Self::Cr),
-            "SHIPPING_COUNTRY_CV" => Some(Self::Cv),
-            "SHIPPING_COUNTRY_CW" => Some(Self::Cw),
-            "SHIPPING_COUNTRY_CY" => Some(Self::Cy),
-            "SHIPPING_COUNTRY_CZ" => Some(Self::Cz),
-            "SHIPPING_COUNTRY_DE" => Some(Self::De),
-            "SHIPPING_COUNTRY_DJ" => Some(Self::Dj),
-            "SHIPPING_COUNTRY_DK" => Some(Self::Dk),
-            "SHIPPING_COUNTRY_DM" => Some(Self::Dm),
-            "SHIPPING_COUNTRY_DO" => Some(Self::Do),
-            "SHIPPING_COUNTRY_DZ" => Some(Self::Dz),
-            "SHIPPING_COUNTRY_EC" => Some(Self::Ec),
-            "SHIPPING_COUNTRY_EE" => Some(Self::Ee),
-            "SHIPPING_COUNTRY_EG" => Some(Self::Eg),
-            "SHIPPING_COUNTRY_EH" => Some(Self::Eh),
-            "SHIPPING_COUNTRY_ER" => Some(Self::Er),
-            "SHIPPING_COUNTRY_ES" => Some(Self::Es),
-            "SHIPPING_COUNTRY_ET" => Some(Self::Et),
-            "SHIPPING_COUNTRY_FI" => Some(Self::Fi),
-            "SHIPPING_COUNTRY_FJ" => Some(Self::Fj),
-            "SHIPPING_COUNTRY_FK" => Some(Self::Fk),
-            "SHIPPING_COUNTRY_FO" => Some(Self::Fo),
-            "SHIPPING_COUNTRY_FR" => Some(Self::Fr),
-            "SHIPPING_COUNTRY_GA" => Some(Self::Ga),
-            "SHIPPING_COUNTRY_GB" => Some(Self::Gb),
-            "SHIPPING_COUNTRY_GD" => Some(Self::Gd),
-            "SHIPPING_COUNTRY_GE" => Some(Self::Ge),
-            "SHIPPING_COUNTRY_GF" => Some(Self::Gf),
-            "SHIPPING_COUNTRY_GG" => Some(Self::Gg),
-            "SHIPPING_COUNTRY_GH" => Some(Self::Gh),
-            "SHIPPING_COUNTRY_GI" => Some(Self::Gi),
-            "SHIPPING_COUNTRY_GL" => Some(Self::Gl),
-            "SHIPPING_COUNTRY_GM" => Some(Self::Gm),
-            "SHIPPING_COUNTRY_GN" => Some(Self::Gn),
-            "SHIPPING_COUNTRY_GP" => Some(Self::Gp),
-            "SHIPPING_COUNTRY_GQ" => Some(Self::Gq),
-            "SHIPPING_COUNTRY_GR" => Some(Self::Gr),
-            "SHIPPING_COUNTRY_GS" => Some(Self::Gs),
-            "SHIPPING_COUNTRY_GT" => Some(Self::Gt),
-            "SHIPPING_COUNTRY_GU" => Some(Self::Gu),
-            "SHIPPING_COUNTRY_GW" => Some(Self::Gw),
-            "SHIPPING_COUNTRY_GY" => Some(Self::Gy),
-            "SHIPPING_COUNTRY_HK" => Some(Self::Hk),
-            "SHIPPING_COUNTRY_HN" => Some(Self::Hn),
-            "SHIPPING_COUNTRY_HR" => Some(Self::Hr),
-            "SHIPPING_COUNTRY_HT" => Some(Self::Ht),
-            "SHIPPING_COUNTRY_HU" => Some(Self::Hu),
-            "SHIPPING_COUNTRY_ID" => Some(Self::Id),
-            "SHIPPING_COUNTRY_IE" => Some(Self::Ie),
-            "SHIPPING_COUNTRY_IL" => Some(Self::Il),
-            "SHIPPING_COUNTRY_IM" => Some(Self::Im),
-            "SHIPPING_COUNTRY_IN" => Some(Self::In),
-            "SHIPPING_COUNTRY_IO" => Some(Self::Io),
-            "SHIPPING_COUNTRY_IQ" => Some(Self::Iq),
-            "SHIPPING_COUNTRY_IS" => Some(Self::Is),
-            "SHIPPING_COUNTRY_IT" => Some(Self::It),
-            "SHIPPING_COUNTRY_JE" => Some(Self::Je),
-            "SHIPPING_COUNTRY_JM" => Some(Self::Jm),
-            "SHIPPING_COUNTRY_JO" => Some(Self::Jo),
-            "SHIPPING_COUNTRY_JP" => Some(Self::Jp),
-            "SHIPPING_COUNTRY_KE" => Some(Self::Ke),
-            "SHIPPING_COUNTRY_KG" => Some(Self::Kg),
-            "SHIPPING_COUNTRY_KH" => Some(Self::Kh),
-            "SHIPPING_COUNTRY_KI" => Some(Self::Ki),
-            "SHIPPING_COUNTRY_KM" => Some(Self::Km),
-            "SHIPPING_COUNTRY_KN" => Some(Self::Kn),
-            "SHIPPING_COUNTRY_KR" => Some(Self::Kr),
-            "SHIPPING_COUNTRY_KW" => Some(Self::Kw),
-            "SHIPPING_COUNTRY_KY" => Some(Self::Ky),
-            "SHIPPING_COUNTRY_LA" => Some(Self::La),
-            "SHIPPING_COUNTRY_LB" => Some(Self::Lb),
-            "SHIPPING_COUNTRY_LC" => Some(Self::Lc),
-            "SHIPPING_COUNTRY_LI" => Some(Self::Li),
-            "SHIPPING_COUNTRY_LK" => Some(Self::Lk),
-            "SHIPPING_COUNTRY_LR" => Some(Self::Lr),
-            "SHIPPING_COUNTRY_LS" => Some(Self::Ls),
-            "SHIPPING_COUNTRY_LT" => Some(Self::Lt),
-            "SHIPPING_COUNTRY_LU" => Some(Self::Lu),
-            "SHIPPING_COUNTRY_LV" => Some(Self::Lv),
-            "SHIPPING_COUNTRY_LY" => Some(Self::Ly),
-            "SHIPPING_COUNTRY_MA" => Some(Self::Ma),
-            "SHIPPING_COUNTRY_MC" => Some(Self::Mc),
-            "SHIPPING_COUNTRY_MD" => Some(Self::Md),
-            "SHIPPING_COUNTRY_ME" => Some(Self::Me),
-            "SHIPPING_COUNTRY_MF" => Some(Self::Mf),
-            "SHIPPING_COUNTRY_MG" => Some(Self::Mg),
-            "SHIPPING_COUNTRY_MK" => Some(Self::Mk),
-            "SHIPPING_COUNTRY_ML" => Some(Self::Ml),
-            "SHIPPING_COUNTRY_MM" => Some(Self::Mm),
-            "SHIPPING_COUNTRY_MN" => Some(Self::Mn),
-            "SHIPPING_COUNTRY_MO" => Some(Self::Mo),
-            "SHIPPING_COUNTRY_MQ" => Some(Self::Mq),
-            "SHIPPING_COUNTRY_MR" => Some(Self::Mr),
-            "SHIPPING_COUNTRY_MS" => Some(Self::Ms),
-            "SHIPPING_COUNTRY_MT" => Some(Self::Mt),
-            "SHIPPING_COUNTRY_MU" => Some(Self::Mu),
-            "SHIPPING_COUNTRY_MV" => Some(Self::Mv),
-            "SHIPPING_COUNTRY_MW" => Some(Self::Mw),
-            "SHIPPING_COUNTRY_MX" => Some(Self::Mx),
-            "SHIPPING_COUNTRY_MY" => Some(Self::My),
-            "SHIPPING_COUNTRY_MZ" => Some(Self::Mz),
-            "SHIPPING_COUNTRY_NA" => Some(Self::Na),
-            "SHIPPING_COUNTRY_NC" => Some(Self::Nc),
-            "SHIPPING_COUNTRY_NE" => Some(Self::Ne),
-            "SHIPPING_COUNTRY_NG" => Some(Self::Ng),
-            "SHIPPING_COUNTRY_NI" => Some(Self::Ni),
-            "SHIPPING_COUNTRY_NL" => Some(Self::Nl),
-            "SHIPPING_COUNTRY_NO" => Some(Self::No),
-            "SHIPPING_COUNTRY_NP" => Some(Self::Np),
-            "SHIPPING_COUNTRY_NR" => Some(Self::Nr),
-            "SHIPPING_COUNTRY_NU" => Some(Self::Nu),
-            "SHIPPING_COUNTRY_NZ" => Some(Self::Nz),
-            "SHIPPING_COUNTRY_OM" => Some(Self::Om),
-            "SHIPPING_COUNTRY_PA" => Some(Self::Pa),
-            "SHIPPING_COUNTRY_PE" => Some(Self::Pe),
-            "SHIPPING_COUNTRY_PF" => Some(Self::Pf),
-            "SHIPPING_COUNTRY_PG" => Some(Self::Pg),
-            "SHIPPING_COUNTRY_PH" => Some(Self::Ph),
-            "SHIPPING_COUNTRY_PK" => Some(Self::Pk),
-            "SHIPPING_COUNTRY_PL" => Some(Self::Pl),
-            "SHIPPING_COUNTRY_PM" => Some(Self::Pm),
-            "SHIPPING_COUNTRY_PN" => Some(Self::Pn),
-            "SHIPPING_COUNTRY_PR" => Some(Self::Pr),
-            "SHIPPING_COUNTRY_PS" => Some(Self::Ps),
-            "SHIPPING_COUNTRY_PT" => Some(Self::Pt),
-            "SHIPPING_COUNTRY_PY" => Some(Self::Py),
-            "SHIPPING_COUNTRY_QA" => Some(Self::Qa),
-            "SHIPPING_COUNTRY_RE" => Some(Self::Re),
-            "SHIPPING_COUNTRY_RO" => Some(Self::Ro),
-            "SHIPPING_COUNTRY_RS" => Some(Self::Rs),
-            "SHIPPING_COUNTRY_RU" => Some(Self::Ru),
-            "SHIPPING_COUNTRY_RW" => Some(Self::Rw),
-            "SHIPPING_COUNTRY_SA" => Some(Self::Sa),
-            "SHIPPING_COUNTRY_SB" => Some(Self::Sb),
-            "SHIPPING_COUNTRY_SC" => Some(Self::Sc),
-            "SHIPPING_COUNTRY_SE" => Some(Self::Se),
-            "SHIPPING_COUNTRY_SG" => Some(Self::Sg),
-            "SHIPPING_COUNTRY_SH" => Some(Self::Sh),
-            "SHIPPING_COUNTRY_SI" => Some(Self::Si),
-            "SHIPPING_COUNTRY_SJ" => Some(Self::Sj),
-            "SHIPPING_COUNTRY_SK" => Some(Self::Sk),
-            "SHIPPING_COUNTRY_SL" => Some(Self::Sl),
-            "SHIPPING_COUNTRY_SM" => Some(Self::Sm),
-            "SHIPPING_COUNTRY_SN" => Some(Self::Sn),
-            "SHIPPING_COUNTRY_SO" => Some(Self::So),
-            "SHIPPING_COUNTRY_SR" => Some(Self::Sr),
-            "SHIPPING_COUNTRY_SS" => Some(Self::Ss),
-            "SHIPPING_COUNTRY_ST" => Some(Self::St),
-            "SHIPPING_COUNTRY_SV" => Some(Self::Sv),
-            "SHIPPING_COUNTRY_SX" => Some(Self::Sx),
-            "SHIPPING_COUNTRY_SZ" => Some(Self::Sz),
-            "SHIPPING_COUNTRY_TA" => Some(Self::Ta),
-            "SHIPPING_COUNTRY_TC" => Some(Self::Tc),
-            "SHIPPING_COUNTRY_TD" => Some(Self::Td),
-            "SHIPPING_COUNTRY_TF" => Some(Self::Tf),
-            "SHIPPING_COUNTRY_TG" => Some(Self::Tg),
-            "SHIPPING_COUNTRY_TH" => Some(Self::Th),
-            "SHIPPING_COUNTRY_TJ" => Some(Self::Tj),
-            "SHIPPING_COUNTRY_TK" => Some(Self::Tk),
-            "SHIPPING_COUNTRY_TL" => Some(Self::Tl),
-            "SHIPPING_COUNTRY_TM" => Some(Self::Tm),
-            "SHIPPING_COUNTRY_TN" => Some(Self::Tn),
-            "SHIPPING_COUNTRY_TO" => Some(Self::To),
-            "SHIPPING_COUNTRY_TR" => Some(Self::Tr),
-            "SHIPPING_COUNTRY_TT" => Some(Self::Tt),
-            "SHIPPING_COUNTRY_TV" => Some(Self::Tv),
-            "SHIPPING_COUNTRY_TW" => Some(Self::Tw),
-            "SHIPPING_COUNTRY_TZ" => Some(Self::Tz),
-            "SHIPPING_COUNTRY_UA" => Some(Self::Ua),
-            "SHIPPING_COUNTRY_UG" => Some(Self::Ug),
-            "SHIPPING_COUNTRY_US" => Some(Self::Us),
-            "SHIPPING_COUNTRY_UY" => Some(Self::Uy),
-            "SHIPPING_COUNTRY_UZ" => Some(Self::Uz),
-            "SHIPPING_COUNTRY_VA" => Some(Self::Va),
-            "SHIPPING_COUNTRY_VC" => Some(Self::Vc),
-            "SHIPPING_COUNTRY_VE" => Some(Self::Ve),
-            "SHIPPING_COUNTRY_VG" => Some(Self::Vg),
-            "SHIPPING_COUNTRY_VN" => Some(Self::Vn),
-            "SHIPPING_COUNTRY_VU" => Some(Self::Vu),
-            "SHIPPING_COUNTRY_WF" => Some(Self::Wf),
-            "SHIPPING_COUNTRY_WS" => Some(Self::Ws),
-            "SHIPPING_COUNTRY_XK" => Some(Self::Xk),
-            "SHIPPING_COUNTRY_YE" => Some(Self::Ye),
-            "SHIPPING_COUNTRY_YT" => Some(Self::Yt),
-            "SHIPPING_COUNTRY_ZA" => Some(Self::Za),
-            "SHIPPING_COUNTRY_ZM" => Some(Self::Zm),
-            "SHIPPING_COUNTRY_ZW" => Some(Self::Zw),
-            "SHIPPING_COUNTRY_ZZ" => Some(Self::Zz),
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SHIPPING_COUNTRY_UNSPECIFIED" => Some(Self::Unspecified),
+            "SHIPPING_COUNTRY_AC" => Some(Self::Ac),
+            "SHIPPING_COUNTRY_AD" => Some(Self::Ad),
+            "SHIPPING_COUNTRY_AE" => Some(Self::Ae),
+            "SHIPPING_COUNTRY_AF" => Some(Self::Af),
+            "SHIPPING_COUNTRY_AG" => Some(Self::Ag),
+            "SHIPPING_COUNTRY_AI" => Some(Self::Ai),
+            "SHIPPING_COUNTRY_AL" => Some(Self::Al),
+            "SHIPPING_COUNTRY_AM" => Some(Self::Am),
+            "SHIPPING_COUNTRY_AO" => Some(Self::Ao),
+            "SHIPPING_COUNTRY_AQ" => Some(Self::Aq),
+            "SHIPPING_COUNTRY_AR" => Some(Self::Ar),
+            "SHIPPING_COUNTRY_AT" => Some(Self::At),
+            "SHIPPING_COUNTRY_AU" => Some(Self::Au),
+            "SHIPPING_COUNTRY_AW" => Some(Self::Aw),
+            "SHIPPING_COUNTRY_AX" => Some(Self::Ax),
+            "SHIPPING_COUNTRY_AZ" => Some(Self::Az),
+            "SHIPPING_COUNTRY_BA" => Some(Self::Ba),
+            "SHIPPING_COUNTRY_BB" => Some(Self::Bb),
+            "SHIPPING_COUNTRY_BD" => Some(Self::Bd),
+            "SHIPPING_COUNTRY_BE" => Some(Self::Be),
+            "SHIPPING_COUNTRY_BF" => Some(Self::Bf),
+            "SHIPPING_COUNTRY_BG" => Some(Self::Bg),
+            "SHIPPING_COUNTRY_BH" => Some(Self::Bh),
+            "SHIPPING_COUNTRY_BI" => Some(Self::Bi),
+            "SHIPPING_COUNTRY_BJ" => Some(Self::Bj),
+            "SHIPPING_COUNTRY_BL" => Some(Self::Bl),
+            "SHIPPING_COUNTRY_BM" => Some(Self::Bm),
+            "SHIPPING_COUNTRY_BN" => Some(Self::Bn),
+            "SHIPPING_COUNTRY_BO" => Some(Self::Bo),
+            "SHIPPING_COUNTRY_BQ" => Some(Self::Bq),
+            "SHIPPING_COUNTRY_BR" => Some(Self::Br),
+            "SHIPPING_COUNTRY_BS" => Some(Self::Bs),
+            "SHIPPING_COUNTRY_BT" => Some(Self::Bt),
+            "SHIPPING_COUNTRY_BV" => Some(Self::Bv),
+            "SHIPPING_COUNTRY_BW" => Some(Self::Bw),
+            "SHIPPING_COUNTRY_BY" => Some(Self::By),
+            "SHIPPING_COUNTRY_BZ" => Some(Self::Bz),
+            "SHIPPING_COUNTRY_CA" => Some(Self::Ca),
+            "SHIPPING_COUNTRY_CD" => Some(Self::Cd),
+            "SHIPPING_COUNTRY_CF" => Some(Self::Cf),
+            "SHIPPING_COUNTRY_CG" => Some(Self::Cg),
+            "SHIPPING_COUNTRY_CH" => Some(Self::Ch),
+            "SHIPPING_COUNTRY_CI" => Some(Self::Ci),
+            "SHIPPING_COUNTRY_CK" => Some(Self::Ck),
+            "SHIPPING_COUNTRY_CL" => Some(Self::Cl),
+            "SHIPPING_COUNTRY_CM" => Some(Self::Cm),
+            "SHIPPING_COUNTRY_CN" => Some(Self::Cn),
+            "SHIPPING_COUNTRY_CO" => Some(Self::Co),
+            "SHIPPING_COUNTRY_CR" => Some(Self::Cr),
+            "SHIPPING_COUNTRY_CV" => Some(Self::Cv),
+            "SHIPPING_COUNTRY_CW" => Some(Self::Cw),
+            "SHIPPING_COUNTRY_CY" => Some(Self::Cy),
+            "SHIPPING_COUNTRY_CZ" => Some(Self::Cz),
+            "SHIPPING_COUNTRY_DE" => Some(Self::De),
+            "SHIPPING_COUNTRY_DJ" => Some(Self::Dj),
+            "SHIPPING_COUNTRY_DK" => Some(Self::Dk),
+            "SHIPPING_COUNTRY_DM" => Some(Self::Dm),
+            "SHIPPING_COUNTRY_DO" => Some(Self::Do),
+            "SHIPPING_COUNTRY_DZ" => Some(Self::Dz),
+            "SHIPPING_COUNTRY_EC" => Some(Self::Ec),
+            "SHIPPING_COUNTRY_EE" => Some(Self::Ee),
+            "SHIPPING_COUNTRY_EG" => Some(Self::Eg),
+            "SHIPPING_COUNTRY_EH" => Some(Self::Eh),
+            "SHIPPING_COUNTRY_ER" => Some(Self::Er),
+            "SHIPPING_COUNTRY_ES" => Some(Self::Es),
+            "SHIPPING_COUNTRY_ET" => Some(Self::Et),
+            "SHIPPING_COUNTRY_FI" => Some(Self::Fi),
+            "SHIPPING_COUNTRY_FJ" => Some(Self::Fj),
+            "SHIPPING_COUNTRY_FK" => Some(Self::Fk),
+            "SHIPPING_COUNTRY_FO" => Some(Self::Fo),
+            "SHIPPING_COUNTRY_FR" => Some(Self::Fr),
+            "SHIPPING_COUNTRY_GA" => Some(Self::Ga),
+            "SHIPPING_COUNTRY_GB" => Some(Self::Gb),
+            "SHIPPING_COUNTRY_GD" => Some(Self::Gd),
+            "SHIPPING_COUNTRY_GE" => Some(Self::Ge),
+            "SHIPPING_COUNTRY_GF" => Some(Self::Gf),
+            "SHIPPING_COUNTRY_GG" => Some(Self::Gg),
+            "SHIPPING_COUNTRY_GH" => Some(Self::Gh),
+            "SHIPPING_COUNTRY_GI" => Some(Self::Gi),
+            "SHIPPING_COUNTRY_GL" => Some(Self::Gl),
+            "SHIPPING_COUNTRY_GM" => Some(Self::Gm),
+            "SHIPPING_COUNTRY_GN" => Some(Self::Gn),
+            "SHIPPING_COUNTRY_GP" => Some(Self::Gp),
+            "SHIPPING_COUNTRY_GQ" => Some(Self::Gq),
+            "SHIPPING_COUNTRY_GR" => Some(Self::Gr),
+            "SHIPPING_COUNTRY_GS" => Some(Self::Gs),
+            "SHIPPING_COUNTRY_GT" => Some(Self::Gt),
+            "SHIPPING_COUNTRY_GU" => Some(Self::Gu),
+            "SHIPPING_COUNTRY_GW" => Some(Self::Gw),
+            "SHIPPING_COUNTRY_GY" => Some(Self::Gy),
+            "SHIPPING_COUNTRY_HK" => Some(Self::Hk),
+            "SHIPPING_COUNTRY_HN" => Some(Self::Hn),
+            "SHIPPING_COUNTRY_HR" => Some(Self::Hr),
+            "SHIPPING_COUNTRY_HT" => Some(Self::Ht),
+            "SHIPPING_COUNTRY_HU" => Some(Self::Hu),
+            "SHIPPING_COUNTRY_ID" => Some(Self::Id),
+            "SHIPPING_COUNTRY_IE" => Some(Self::Ie),
+            "SHIPPING_COUNTRY_IL" => Some(Self::Il),
+            "SHIPPING_COUNTRY_IM" => Some(Self::Im),
+            "SHIPPING_COUNTRY_IN" => Some(Self::In),
+            "SHIPPING_COUNTRY_IO" => Some(Self::Io),
+            "SHIPPING_COUNTRY_IQ" => Some(Self::Iq),
+            "SHIPPING_COUNTRY_IS" => Some(Self::Is),
+            "SHIPPING_COUNTRY_IT" => Some(Self::It),
+            "SHIPPING_COUNTRY_JE" => Some(Self::Je),
+            "SHIPPING_COUNTRY_JM" => Some(Self::Jm),
+            "SHIPPING_COUNTRY_JO" => Some(Self::Jo),
+            "SHIPPING_COUNTRY_JP" => Some(Self::Jp),
+            "SHIPPING_COUNTRY_KE" => Some(Self::Ke),
+            "SHIPPING_COUNTRY_KG" => Some(Self::Kg),
+            "SHIPPING_COUNTRY_KH" => Some(Self::Kh),
+            "SHIPPING_COUNTRY_KI" => Some(Self::Ki),
+            "SHIPPING_COUNTRY_KM" => Some(Self::Km),
+            "SHIPPING_COUNTRY_KN" => Some(Self::Kn),
+            "SHIPPING_COUNTRY_KR" => Some(Self::Kr),
+            "SHIPPING_COUNTRY_KW" => Some(Self::Kw),
+            "SHIPPING_COUNTRY_KY" => Some(Self::Ky),
+            "SHIPPING_COUNTRY_LA" => Some(Self::La),
+            "SHIPPING_COUNTRY_LB" => Some(Self::Lb),
+            "SHIPPING_COUNTRY_LC" => Some(Self::Lc),
+            "SHIPPING_COUNTRY_LI" => Some(Self::Li),
+            "SHIPPING_COUNTRY_LK" => Some(Self::Lk),
+            "SHIPPING_COUNTRY_LR" => Some(Self::Lr),
+            "SHIPPING_COUNTRY_LS" => Some(Self::Ls),
+            "SHIPPING_COUNTRY_LT" => Some(Self::Lt),
+            "SHIPPING_COUNTRY_LU" => Some(Self::Lu),
+            "SHIPPING_COUNTRY_LV" => Some(Self::Lv),
+            "SHIPPING_COUNTRY_LY" => Some(Self::Ly),
+            "SHIPPING_COUNTRY_MA" => Some(Self::Ma),
+            "SHIPPING_COUNTRY_MC" => Some(Self::Mc),
+            "SHIPPING_COUNTRY_MD" => Some(Self::Md),
+            "SHIPPING_COUNTRY_ME" => Some(Self::Me),
+            "SHIPPING_COUNTRY_MF" => Some(Self::Mf),
+            "SHIPPING_COUNTRY_MG" => Some(Self::Mg),
+            "SHIPPING_COUNTRY_MK" => Some(Self::Mk),
+            "SHIPPING_COUNTRY_ML" => Some(Self::Ml),
+            "SHIPPING_COUNTRY_MM" => Some(Self::Mm),
+            "SHIPPING_COUNTRY_MN" => Some(Self::Mn),
+            "SHIPPING_COUNTRY_MO" => Some(Self::Mo),
+            "SHIPPING_COUNTRY_MQ" => Some(Self::Mq),
+            "SHIPPING_COUNTRY_MR" => Some(Self::Mr),
+            "SHIPPING_COUNTRY_MS" => Some(Self::Ms),
+            "SHIPPING_COUNTRY_MT" => Some(Self::Mt),
+            "SHIPPING_COUNTRY_MU" => Some(Self::Mu),
+            "SHIPPING_COUNTRY_MV" => Some(Self::Mv),
+            "SHIPPING_COUNTRY_MW" => Some(Self::Mw),
+            "SHIPPING_COUNTRY_MX" => Some(Self::Mx),
+            "SHIPPING_COUNTRY_MY" => Some(Self::My),
+            "SHIPPING_COUNTRY_MZ" => Some(Self::Mz),
+            "SHIPPING_COUNTRY_NA" => Some(Self::Na),
+            "SHIPPING_COUNTRY_NC" => Some(Self::Nc),
+            "SHIPPING_COUNTRY_NE" => Some(Self::Ne),
+            "SHIPPING_COUNTRY_NG" => Some(Self::Ng),
+            "SHIPPING_COUNTRY_NI" => Some(Self::Ni),
+            "SHIPPING_COUNTRY_NL" => Some(Self::Nl),
+            "SHIPPING_COUNTRY_NO" => Some(Self::No),
+            "SHIPPING_COUNTRY_NP" => Some(Self::Np),
+            "SHIPPING_COUNTRY_NR" => Some(Self::Nr),
+            "SHIPPING_COUNTRY_NU" => Some(Self::Nu),
+            "SHIPPING_COUNTRY_NZ" => Some(Self::Nz),
+            "SHIPPING_COUNTRY_OM" => Some(Self::Om),
+            "SHIPPING_COUNTRY_PA" => Some(Self::Pa),
+            "SHIPPING_COUNTRY_PE" => Some(Self::Pe),
+            "SHIPPING_COUNTRY_PF" => Some(Self::Pf),
+            "SHIPPING_COUNTRY_PG" => Some(Self::Pg),
+            "SHIPPING_COUNTRY_PH" => Some(Self::Ph),
+            "SHIPPING_COUNTRY_PK" => Some(Self::Pk),
+            "SHIPPING_COUNTRY_PL" => Some(Self::Pl),
+            "SHIPPING_COUNTRY_PM" => Some(Self::Pm),
+            "SHIPPING_COUNTRY_PN" => Some(Self::Pn),
+            "SHIPPING_COUNTRY_PR" => Some(Self::Pr),
+            "SHIPPING_COUNTRY_PS" => Some(Self::Ps),
+            "SHIPPING_COUNTRY_PT" => Some(Self::Pt),
+            "SHIPPING_COUNTRY_PY" => Some(Self::Py),
+            "SHIPPING_COUNTRY_QA" => Some(Self::Qa),
+            "SHIPPING_COUNTRY_RE" => Some(Self::Re),
+            "SHIPPING_COUNTRY_RO" => Some(Self::Ro),
+            "SHIPPING_COUNTRY_RS" => Some(Self::Rs),
+            "SHIPPING_COUNTRY_RU" => Some(Self::Ru),
+            "SHIPPING_COUNTRY_RW" => Some(Self::Rw),
+            "SHIPPING_COUNTRY_SA" => Some(Self::Sa),
+            "SHIPPING_COUNTRY_SB" => Some(Self::Sb),
+            "SHIPPING_COUNTRY_SC" => Some(Self::Sc),
+            "SHIPPING_COUNTRY_SE" => Some(Self::Se),
+            "SHIPPING_COUNTRY_SG" => Some(Self::Sg),
+            "SHIPPING_COUNTRY_SH" => Some(Self::Sh),
+            "SHIPPING_COUNTRY_SI" => Some(Self::Si),
+            "SHIPPING_COUNTRY_SJ" => Some(Self::Sj),
+            "SHIPPING_COUNTRY_SK" => Some(Self::Sk),
+            "SHIPPING_COUNTRY_SL" => Some(Self::Sl),
+            "SHIPPING_COUNTRY_SM" => Some(Self::Sm),
+            "SHIPPING_COUNTRY_SN" => Some(Self::Sn),
+            "SHIPPING_COUNTRY_SO" => Some(Self::So),
+            "SHIPPING_COUNTRY_SR" => Some(Self::Sr),
+            "SHIPPING_COUNTRY_SS" => Some(Self::Ss),
+            "SHIPPING_COUNTRY_ST" => Some(Self::St),
+            "SHIPPING_COUNTRY_SV" => Some(Self::Sv),
+            "SHIPPING_COUNTRY_SX" => Some(Self::Sx),
+            "SHIPPING_COUNTRY_SZ" => Some(Self::Sz),
+            "SHIPPING_COUNTRY_TA" => Some(Self::Ta),
+            "SHIPPING_COUNTRY_TC" => Some(Self::Tc),
+            "SHIPPING_COUNTRY_TD" => Some(Self::Td),
+            "SHIPPING_COUNTRY_TF" => Some(Self::Tf),
+            "SHIPPING_COUNTRY_TG" => Some(Self::Tg),
+            "SHIPPING_COUNTRY_TH" => Some(Self::Th),
+            "SHIPPING_COUNTRY_TJ" => Some(Self::Tj),
+            "SHIPPING_COUNTRY_TK" => Some(Self::Tk),
+            "SHIPPING_COUNTRY_TL" => Some(Self::Tl),
+            "SHIPPING_COUNTRY_TM" => Some(Self::Tm),
+            "SHIPPING_COUNTRY_TN" => Some(Self::Tn),
+            "SHIPPING_COUNTRY_TO" => Some(Self::To),
+            "SHIPPING_COUNTRY_TR" => Some(Self::Tr),
+            "SHIPPING_COUNTRY_TT" => Some(Self::Tt),
+            "SHIPPING_COUNTRY_TV" => Some(Self::Tv),
+            "SHIPPING_COUNTRY_TW" => Some(Self::Tw),
+            "SHIPPING_COUNTRY_TZ" => Some(Self::Tz),
+            "SHIPPING_COUNTRY_UA" => Some(Self::Ua),
+            "SHIPPING_COUNTRY_UG" => Some(Self::Ug),
+            "SHIPPING_COUNTRY_US" => Some(Self::Us),
+            "SHIPPING_COUNTRY_UY" => Some(Self::Uy),
+            "SHIPPING_COUNTRY_UZ" => Some(Self::Uz),
+            "SHIPPING_COUNTRY_VA" => Some(Self::Va),
+            "SHIPPING_COUNTRY_VC" => Some(Self::Vc),
+            "SHIPPING_COUNTRY_VE" => Some(Self::Ve),
+            "SHIPPING_COUNTRY_VG" => Some(Self::Vg),
+            "SHIPPING_COUNTRY_VN" => Some(Self::Vn),
+            "SHIPPING_COUNTRY_VU" => Some(Self::Vu),
+            "SHIPPING_COUNTRY_WF" => Some(Self::Wf),
+            "SHIPPING_COUNTRY_WS" => Some(Self::Ws),
+            "SHIPPING_COUNTRY_XK" => Some(Self::Xk),
+            "SHIPPING_COUNTRY_YE" => Some(Self::Ye),
+            "SHIPPING_COUNTRY_YT" => Some(Self::Yt),
+            "SHIPPING_COUNTRY_ZA" => Some(Self::Za),
+            "SHIPPING_COUNTRY_ZM" => Some(Self::Zm),
+            "SHIPPING_COUNTRY_ZW" => Some(Self::Zw),
+            "SHIPPING_COUNTRY_ZZ" => Some(Self::Zz),
+            _ => None,
+        }
+    }
+}
+/// Generated server implementations.
+pub mod shipping_rate_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ShippingRateServiceServer.
+    #[async_trait]
+    pub trait ShippingRateService: Send + Sync + 'static {
+        async fn put_shipping_rate(
+            &self,
+            request: tonic::Request<super::PutShippingRateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutShippingRateResponse>,
+            tonic::Status,
+        >;
+        async fn get_shipping_rate(
+            &self,
+            request: tonic::Request<super::GetShippingRateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetShippingRateResponse>,
+            tonic::Status,
+        >;
+        async fn delete_shipping_rate(
+            &self,
+            request: tonic::Request<super::DeleteShippingRateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteShippingRateResponse>,
+            tonic::Status,
+        >;
+        async fn quote_shipping(
+            &self,
+            request: tonic::Request<super::QuoteShippingRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QuoteShippingResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct ShippingRateServiceServer<T: ShippingRateService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ShippingRateService> ShippingRateServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ShippingRateServiceServer<T>
+    where
+        T: ShippingRateService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.ShippingRateService/PutShippingRate" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutShippingRateSvc<T: ShippingRateService>(pub Arc<T>);
+                    impl<
+                        T: ShippingRateService,
+                    > tonic::server::UnaryService<super::PutShippingRateRequest>
+                    for PutShippingRateSvc<T> {
+                        type Response = super::PutShippingRateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutShippingRateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_shipping_rate(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutShippingRateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingRateService/GetShippingRate" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetShippingRateSvc<T: ShippingRateService>(pub Arc<T>);
+                    impl<
+                        T: ShippingRateService,
+                    > tonic::server::UnaryService<super::GetShippingRateRequest>
+                    for GetShippingRateSvc<T> {
+                        type Response = super::GetShippingRateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetShippingRateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_shipping_rate(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetShippingRateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingRateService/DeleteShippingRate" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteShippingRateSvc<T: ShippingRateService>(pub Arc<T>);
+                    impl<
+                        T: ShippingRateService,
+                    > tonic::server::UnaryService<super::DeleteShippingRateRequest>
+                    for DeleteShippingRateSvc<T> {
+                        type Response = super::DeleteShippingRateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteShippingRateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_shipping_rate(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteShippingRateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingRateService/QuoteShipping" => {
+                    #[allow(non_camel_case_types)]
+                    struct QuoteShippingSvc<T: ShippingRateService>(pub Arc<T>);
+                    impl<
+                        T: ShippingRateService,
+                    > tonic::server::UnaryService<super::QuoteShippingRequest>
+                    for QuoteShippingSvc<T> {
+                        type Response = super::QuoteShippingResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QuoteShippingRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).quote_shipping(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = QuoteShippingSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ShippingRateService> Clone for ShippingRateServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: ShippingRateService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ShippingRateService> tonic::server::NamedService
+    for ShippingRateServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.ShippingRateService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CartResponse {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, tag = "4")]
+    pub created_at: i64,
+    #[prost(int64, tag = "5")]
+    pub updated_at: i64,
+    #[prost(int64, tag = "6")]
+    pub expires_at: i64,
+    #[prost(message, repeated, tag = "7")]
+    pub items: ::prost::alloc::vec::Vec<CartItemResponse>,
+    #[prost(message, optional, tag = "8")]
+    pub totals: ::core::option::Option<CartTotalsResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CartItemResponse {
+    #[prost(string, tag = "1")]
+    pub cart_item_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "4")]
+    pub quantity: u32,
+    #[prost(uint32, tag = "5")]
+    pub unit_amount: u32,
+    #[prost(enumeration = "Currency", tag = "6")]
+    pub currency: i32,
+    #[prost(uint32, tag = "7")]
+    pub amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CartTotalsResponse {
+    #[prost(enumeration = "Currency", tag = "1")]
+    pub currency: i32,
+    #[prost(uint32, tag = "2")]
+    pub subtotal_amount: u32,
+    #[prost(uint32, tag = "3")]
+    pub shipping_amount: u32,
+    #[prost(uint32, tag = "4")]
+    pub total_amount: u32,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "5")]
+    pub shipping_country: ::core::option::Option<i32>,
+    #[prost(string, repeated, tag = "6")]
+    pub unshippable_offer_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "7")]
+    pub discount_amount: u32,
+    #[prost(string, optional, tag = "8")]
+    pub discount_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCartRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCartResponse {
+    #[prost(message, optional, tag = "1")]
+    pub cart: ::core::option::Option<CartResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCartRequest {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "2")]
+    pub shipping_country: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCartResponse {
+    #[prost(message, optional, tag = "1")]
+    pub cart: ::core::option::Option<CartResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddItemToCartRequest {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "4")]
+    pub quantity: u32,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "5")]
+    pub shipping_country: ::core::option::Option<i32>,
+    #[prost(enumeration = "Currency", optional, tag = "6")]
+    pub currency: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddItemToCartResponse {
+    #[prost(message, optional, tag = "1")]
+    pub cart: ::core::option::Option<CartResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCartItemRequest {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cart_item_id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "4")]
+    pub shipping_country: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCartItemResponse {
+    #[prost(message, optional, tag = "1")]
+    pub cart: ::core::option::Option<CartResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveItemFromCartRequest {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cart_item_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "3")]
+    pub shipping_country: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveItemFromCartResponse {
+    #[prost(message, optional, tag = "1")]
+    pub cart: ::core::option::Option<CartResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeCartRequest {
+    #[prost(string, tag = "1")]
+    pub anonymous_cart_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "2")]
+    pub shipping_country: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeCartResponse {
+    #[prost(message, optional, tag = "1")]
+    pub cart: ::core::option::Option<CartResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCartRequest {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCartResponse {}
+/// Generated server implementations.
+pub mod cart_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CartServiceServer.
+    #[async_trait]
+    pub trait CartService: Send + Sync + 'static {
+        async fn create_cart(
+            &self,
+            request: tonic::Request<super::CreateCartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateCartResponse>,
+            tonic::Status,
+        >;
+        async fn get_cart(
+            &self,
+            request: tonic::Request<super::GetCartRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetCartResponse>, tonic::Status>;
+        async fn add_item_to_cart(
+            &self,
+            request: tonic::Request<super::AddItemToCartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddItemToCartResponse>,
+            tonic::Status,
+        >;
+        async fn update_cart_item(
+            &self,
+            request: tonic::Request<super::UpdateCartItemRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateCartItemResponse>,
+            tonic::Status,
+        >;
+        async fn remove_item_from_cart(
+            &self,
+            request: tonic::Request<super::RemoveItemFromCartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveItemFromCartResponse>,
+            tonic::Status,
+        >;
+        async fn merge_cart(
+            &self,
+            request: tonic::Request<super::MergeCartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MergeCartResponse>,
+            tonic::Status,
+        >;
+        async fn delete_cart(
+            &self,
+            request: tonic::Request<super::DeleteCartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteCartResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct CartServiceServer<T: CartService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: CartService> CartServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CartServiceServer<T>
+    where
+        T: CartService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.CartService/CreateCart" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateCartSvc<T: CartService>(pub Arc<T>);
+                    impl<
+                        T: CartService,
+                    > tonic::server::UnaryService<super::CreateCartRequest>
+                    for CreateCartSvc<T> {
+                        type Response = super::CreateCartResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateCartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).create_cart(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateCartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CartService/GetCart" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCartSvc<T: CartService>(pub Arc<T>);
+                    impl<
+                        T: CartService,
+                    > tonic::server::UnaryService<super::GetCartRequest>
+                    for GetCartSvc<T> {
+                        type Response = super::GetCartResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_cart(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CartService/AddItemToCart" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddItemToCartSvc<T: CartService>(pub Arc<T>);
+                    impl<
+                        T: CartService,
+                    > tonic::server::UnaryService<super::AddItemToCartRequest>
+                    for AddItemToCartSvc<T> {
+                        type Response = super::AddItemToCartResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddItemToCartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_item_to_cart(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddItemToCartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CartService/UpdateCartItem" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateCartItemSvc<T: CartService>(pub Arc<T>);
+                    impl<
+                        T: CartService,
+                    > tonic::server::UnaryService<super::UpdateCartItemRequest>
+                    for UpdateCartItemSvc<T> {
+                        type Response = super::UpdateCartItemResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateCartItemRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_cart_item(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateCartItemSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CartService/RemoveItemFromCart" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveItemFromCartSvc<T: CartService>(pub Arc<T>);
+                    impl<
+                        T: CartService,
+                    > tonic::server::UnaryService<super::RemoveItemFromCartRequest>
+                    for RemoveItemFromCartSvc<T> {
+                        type Response = super::RemoveItemFromCartResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveItemFromCartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_item_from_cart(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveItemFromCartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CartService/MergeCart" => {
+                    #[allow(non_camel_case_types)]
+                    struct MergeCartSvc<T: CartService>(pub Arc<T>);
+                    impl<
+                        T: CartService,
+                    > tonic::server::UnaryService<super::MergeCartRequest>
+                    for MergeCartSvc<T> {
+                        type Response = super::MergeCartResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MergeCartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).merge_cart(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = MergeCartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CartService/DeleteCart" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteCartSvc<T: CartService>(pub Arc<T>);
+                    impl<
+                        T: CartService,
+                    > tonic::server::UnaryService<super::DeleteCartRequest>
+                    for DeleteCartSvc<T> {
+                        type Response = super::DeleteCartResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteCartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).delete_cart(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteCartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CartService> Clone for CartServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CartService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: CartService> tonic::server::NamedService for CartServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.CartService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiscountResponse {
+    #[prost(string, tag = "1")]
+    pub discount_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub created_at: i64,
+    #[prost(int64, tag = "5")]
+    pub updated_at: i64,
+    #[prost(string, optional, tag = "6")]
+    pub code: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "DiscountType", tag = "7")]
+    pub r#type: i32,
+    #[prost(uint32, optional, tag = "8")]
+    pub percent: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "9")]
+    pub amount: ::core::option::Option<u32>,
+    #[prost(enumeration = "Currency", optional, tag = "10")]
+    pub currency: ::core::option::Option<i32>,
+    #[prost(int64, optional, tag = "11")]
+    pub starts_at: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "12")]
+    pub ends_at: ::core::option::Option<i64>,
+    #[prost(uint32, optional, tag = "13")]
+    pub max_redemptions: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "14")]
+    pub max_redemptions_per_user: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "15")]
+    pub minimum_order_amount: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "16")]
+    pub scope: ::core::option::Option<DiscountScope>,
+    #[prost(uint32, tag = "17")]
+    pub redemption_count: u32,
+    #[prost(bool, tag = "18")]
+    pub is_active: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiscountScope {
+    #[prost(string, repeated, tag = "1")]
+    pub offer_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration = "OfferType", repeated, tag = "2")]
+    pub offer_types: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateDiscountRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub code: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "DiscountType", tag = "3")]
+    pub r#type: i32,
+    #[prost(uint32, optional, tag = "4")]
+    pub percent: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "5")]
+    pub amount: ::core::option::Option<u32>,
+    #[prost(enumeration = "Currency", optional, tag = "6")]
+    pub currency: ::core::option::Option<i32>,
+    #[prost(int64, optional, tag = "7")]
+    pub starts_at: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "8")]
+    pub ends_at: ::core::option::Option<i64>,
+    #[prost(uint32, optional, tag = "9")]
+    pub max_redemptions: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "10")]
+    pub max_redemptions_per_user: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "11")]
+    pub minimum_order_amount: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "12")]
+    pub scope: ::core::option::Option<DiscountScope>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateDiscountResponse {
+    #[prost(message, optional, tag = "1")]
+    pub discount: ::core::option::Option<DiscountResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDiscountRequest {
+    #[prost(string, tag = "1")]
+    pub discount_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDiscountResponse {
+    #[prost(message, optional, tag = "1")]
+    pub discount: ::core::option::Option<DiscountResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDiscountsRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::types::v1::PaginationRequest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDiscountsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub discounts: ::prost::alloc::vec::Vec<DiscountResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::types::v1::PaginationResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateDiscountRequest {
+    #[prost(string, tag = "1")]
+    pub discount_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub code: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "3")]
+    pub percent: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "4")]
+    pub amount: ::core::option::Option<u32>,
+    #[prost(int64, optional, tag = "5")]
+    pub starts_at: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "6")]
+    pub ends_at: ::core::option::Option<i64>,
+    #[prost(uint32, optional, tag = "7")]
+    pub max_redemptions: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "8")]
+    pub max_redemptions_per_user: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "9")]
+    pub minimum_order_amount: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "10")]
+    pub scope: ::core::option::Option<DiscountScope>,
+    #[prost(bool, optional, tag = "11")]
+    pub is_active: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateDiscountResponse {
+    #[prost(message, optional, tag = "1")]
+    pub discount: ::core::option::Option<DiscountResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteDiscountRequest {
+    #[prost(string, tag = "1")]
+    pub discount_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteDiscountResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateDiscountRequest {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub code: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateDiscountResponse {
+    #[prost(message, optional, tag = "1")]
+    pub discount: ::core::option::Option<DiscountResponse>,
+    #[prost(uint32, tag = "2")]
+    pub discount_amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApplyDiscountRequest {
+    #[prost(string, tag = "1")]
+    pub cart_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub code: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApplyDiscountResponse {
+    #[prost(message, optional, tag = "1")]
+    pub discount: ::core::option::Option<DiscountResponse>,
+    #[prost(uint32, tag = "2")]
+    pub discount_amount: u32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DiscountType {
+    Unspecified = 0,
+    Percentage = 1,
+    FixedAmount = 2,
+}
+impl DiscountType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DiscountType::Unspecified => "DISCOUNT_TYPE_UNSPECIFIED",
+            DiscountType::Percentage => "DISCOUNT_TYPE_PERCENTAGE",
+            DiscountType::FixedAmount => "DISCOUNT_TYPE_FIXED_AMOUNT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DISCOUNT_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "DISCOUNT_TYPE_PERCENTAGE" => Some(Self::Percentage),
+            "DISCOUNT_TYPE_FIXED_AMOUNT" => Some(Self::FixedAmount),
+            _ => None,
+        }
+    }
+}
+/// Generated server implementations.
+pub mod discount_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DiscountServiceServer.
+    #[async_trait]
+    pub trait DiscountService: Send + Sync + 'static {
+        async fn create_discount(
+            &self,
+            request: tonic::Request<super::CreateDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateDiscountResponse>,
+            tonic::Status,
+        >;
+        async fn get_discount(
+            &self,
+            request: tonic::Request<super::GetDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDiscountResponse>,
+            tonic::Status,
+        >;
+        async fn list_discounts(
+            &self,
+            request: tonic::Request<super::ListDiscountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListDiscountsResponse>,
+            tonic::Status,
+        >;
+        async fn update_discount(
+            &self,
+            request: tonic::Request<super::UpdateDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateDiscountResponse>,
+            tonic::Status,
+        >;
+        async fn delete_discount(
+            &self,
+            request: tonic::Request<super::DeleteDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteDiscountResponse>,
+            tonic::Status,
+        >;
+        async fn validate_discount(
+            &self,
+            request: tonic::Request<super::ValidateDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidateDiscountResponse>,
+            tonic::Status,
+        >;
+        async fn apply_discount(
+            &self,
+            request: tonic::Request<super::ApplyDiscountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApplyDiscountResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct DiscountServiceServer<T: DiscountService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: DiscountService> DiscountServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DiscountServiceServer<T>
+    where
+        T: DiscountService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.DiscountService/CreateDiscount" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateDiscountSvc<T: DiscountService>(pub Arc<T>);
+                    impl<
+                        T: DiscountService,
+                    > tonic::server::UnaryService<super::CreateDiscountRequest>
+                    for CreateDiscountSvc<T> {
+                        type Response = super::CreateDiscountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateDiscountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).create_discount(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateDiscountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.DiscountService/GetDiscount" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDiscountSvc<T: DiscountService>(pub Arc<T>);
+                    impl<
+                        T: DiscountService,
+                    > tonic::server::UnaryService<super::GetDiscountRequest>
+                    for GetDiscountSvc<T> {
+                        type Response = super::GetDiscountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetDiscountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_discount(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetDiscountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.DiscountService/ListDiscounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListDiscountsSvc<T: DiscountService>(pub Arc<T>);
+                    impl<
+                        T: DiscountService,
+                    > tonic::server::UnaryService<super::ListDiscountsRequest>
+                    for ListDiscountsSvc<T> {
+                        type Response = super::ListDiscountsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListDiscountsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).list_discounts(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListDiscountsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.DiscountService/UpdateDiscount" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateDiscountSvc<T: DiscountService>(pub Arc<T>);
+                    impl<
+                        T: DiscountService,
+                    > tonic::server::UnaryService<super::UpdateDiscountRequest>
+                    for UpdateDiscountSvc<T> {
+                        type Response = super::UpdateDiscountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateDiscountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_discount(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateDiscountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.DiscountService/DeleteDiscount" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteDiscountSvc<T: DiscountService>(pub Arc<T>);
+                    impl<
+                        T: DiscountService,
+                    > tonic::server::UnaryService<super::DeleteDiscountRequest>
+                    for DeleteDiscountSvc<T> {
+                        type Response = super::DeleteDiscountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteDiscountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_discount(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteDiscountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.DiscountService/ValidateDiscount" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidateDiscountSvc<T: DiscountService>(pub Arc<T>);
+                    impl<
+                        T: DiscountService,
+                    > tonic::server::UnaryService<super::ValidateDiscountRequest>
+                    for ValidateDiscountSvc<T> {
+                        type Response = super::ValidateDiscountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidateDiscountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).validate_discount(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidateDiscountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.DiscountService/ApplyDiscount" => {
+                    #[allow(non_camel_case_types)]
+                    struct ApplyDiscountSvc<T: DiscountService>(pub Arc<T>);
+                    impl<
+                        T: DiscountService,
+                    > tonic::server::UnaryService<super::ApplyDiscountRequest>
+                    for ApplyDiscountSvc<T> {
+                        type Response = super::ApplyDiscountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ApplyDiscountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).apply_discount(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ApplyDiscountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: DiscountService> Clone for DiscountServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: DiscountService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: DiscountService> tonic::server::NamedService for DiscountServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.DiscountService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderResponse {
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub shop_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub buyer_user_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub created_at: i64,
+    #[prost(int64, tag = "7")]
+    pub updated_at: i64,
+    #[prost(enumeration = "OrderStatus", tag = "8")]
+    pub status: i32,
+    #[prost(enumeration = "Currency", tag = "9")]
+    pub currency: i32,
+    #[prost(uint32, tag = "10")]
+    pub subtotal_amount: u32,
+    #[prost(uint32, tag = "11")]
+    pub shipping_amount: u32,
+    #[prost(uint32, tag = "12")]
+    pub total_amount: u32,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "13")]
+    pub shipping_country: ::core::option::Option<i32>,
+    #[prost(uint32, tag = "14")]
+    pub platform_fee_percent: u32,
+    #[prost(uint32, tag = "15")]
+    pub minimum_platform_fee_cent: u32,
+    #[prost(message, repeated, tag = "16")]
+    pub items: ::prost::alloc::vec::Vec<OrderItemResponse>,
+    #[prost(message, repeated, tag = "17")]
+    pub transitions: ::prost::alloc::vec::Vec<OrderStatusTransitionResponse>,
+    #[prost(uint32, tag = "18")]
+    pub platform_fee_amount: u32,
+    #[prost(uint32, tag = "19")]
+    pub net_amount: u32,
+    #[prost(string, optional, tag = "20")]
+    pub discount_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "21")]
+    pub discount_amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderItemResponse {
+    #[prost(string, tag = "1")]
+    pub order_item_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "4")]
+    pub offer_name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "5")]
+    pub offer_variant_sku: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "6")]
+    pub quantity: u32,
+    #[prost(uint32, tag = "7")]
+    pub unit_amount: u32,
+    #[prost(enumeration = "Currency", tag = "8")]
+    pub currency: i32,
+    #[prost(uint32, tag = "9")]
+    pub amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderStatusTransitionResponse {
+    #[prost(enumeration = "OrderStatus", optional, tag = "1")]
+    pub from_status: ::core::option::Option<i32>,
+    #[prost(enumeration = "OrderStatus", tag = "2")]
+    pub to_status: i32,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub created_at: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderDirectPurchase {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+    #[prost(enumeration = "Currency", optional, tag = "4")]
+    pub currency: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateOrderRequest {
+    #[prost(string, optional, tag = "1")]
+    pub cart_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "2")]
+    pub direct_purchase: ::core::option::Option<OrderDirectPurchase>,
+    #[prost(enumeration = "ShippingCountry", optional, tag = "3")]
+    pub shipping_country: ::core::option::Option<i32>,
+    #[prost(string, optional, tag = "4")]
+    pub discount_code: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateOrderResponse {
+    #[prost(message, optional, tag = "1")]
+    pub order: ::core::option::Option<OrderResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOrderRequest {
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOrderResponse {
+    #[prost(message, optional, tag = "1")]
+    pub order: ::core::option::Option<OrderResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOrdersRequest {
+    #[prost(string, optional, tag = "1")]
+    pub shop_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "OrderStatus", optional, tag = "2")]
+    pub status: ::core::option::Option<i32>,
+    #[prost(message, optional, tag = "3")]
+    pub pagination: ::core::option::Option<super::super::types::v1::PaginationRequest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListOrdersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub orders: ::prost::alloc::vec::Vec<OrderResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: ::core::option::Option<super::super::types::v1::PaginationResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOrderStatusRequest {
+    #[prost(string, tag = "1")]
+    pub order_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "OrderStatus", tag = "2")]
+    pub status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateOrderStatusResponse {
+    #[prost(message, optional, tag = "1")]
+    pub order: ::core::option::Option<OrderResponse>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OrderStatus {
+    Unspecified = 0,
+    Pending = 1,
+    Paid = 2,
+    Fulfilled = 3,
+    Shipped = 4,
+    Cancelled = 5,
+    Refunded = 6,
+}
+impl OrderStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OrderStatus::Unspecified => "ORDER_STATUS_UNSPECIFIED",
+            OrderStatus::Pending => "ORDER_STATUS_PENDING",
+            OrderStatus::Paid => "ORDER_STATUS_PAID",
+            OrderStatus::Fulfilled => "ORDER_STATUS_FULFILLED",
+            OrderStatus::Shipped => "ORDER_STATUS_SHIPPED",
+            OrderStatus::Cancelled => "ORDER_STATUS_CANCELLED",
+            OrderStatus::Refunded => "ORDER_STATUS_REFUNDED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ORDER_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "ORDER_STATUS_PENDING" => Some(Self::Pending),
+            "ORDER_STATUS_PAID" => Some(Self::Paid),
+            "ORDER_STATUS_FULFILLED" => Some(Self::Fulfilled),
+            "ORDER_STATUS_SHIPPED" => Some(Self::Shipped),
+            "ORDER_STATUS_CANCELLED" => Some(Self::Cancelled),
+            "ORDER_STATUS_REFUNDED" => Some(Self::Refunded),
+            _ => None,
+        }
+    }
+}
+/// Generated server implementations.
+pub mod order_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with OrderServiceServer.
+    #[async_trait]
+    pub trait OrderService: Send + Sync + 'static {
+        async fn create_order(
+            &self,
+            request: tonic::Request<super::CreateOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateOrderResponse>,
+            tonic::Status,
+        >;
+        async fn get_order(
+            &self,
+            request: tonic::Request<super::GetOrderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOrderResponse>,
+            tonic::Status,
+        >;
+        async fn list_orders(
+            &self,
+            request: tonic::Request<super::ListOrdersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListOrdersResponse>,
+            tonic::Status,
+        >;
+        async fn update_order_status(
+            &self,
+            request: tonic::Request<super::UpdateOrderStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateOrderStatusResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct OrderServiceServer<T: OrderService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: OrderService> OrderServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for OrderServiceServer<T>
+    where
+        T: OrderService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.OrderService/CreateOrder" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateOrderSvc<T: OrderService>(pub Arc<T>);
+                    impl<
+                        T: OrderService,
+                    > tonic::server::UnaryService<super::CreateOrderRequest>
+                    for CreateOrderSvc<T> {
+                        type Response = super::CreateOrderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateOrderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).create_order(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateOrderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OrderService/GetOrder" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOrderSvc<T: OrderService>(pub Arc<T>);
+                    impl<
+                        T: OrderService,
+                    > tonic::server::UnaryService<super::GetOrderRequest>
+                    for GetOrderSvc<T> {
+                        type Response = super::GetOrderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOrderRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_order(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetOrderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OrderService/ListOrders" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListOrdersSvc<T: OrderService>(pub Arc<T>);
+                    impl<
+                        T: OrderService,
+                    > tonic::server::UnaryService<super::ListOrdersRequest>
+                    for ListOrdersSvc<T> {
+                        type Response = super::ListOrdersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListOrdersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).list_orders(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListOrdersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.OrderService/UpdateOrderStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateOrderStatusSvc<T: OrderService>(pub Arc<T>);
+                    impl<
+                        T: OrderService,
+                    > tonic::server::UnaryService<super::UpdateOrderStatusRequest>
+                    for UpdateOrderStatusSvc<T> {
+                        type Response = super::UpdateOrderStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateOrderStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_order_status(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateOrderStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: OrderService> Clone for OrderServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: OrderService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: OrderService> tonic::server::NamedService for OrderServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.OrderService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxRateResponse {
+    #[prost(enumeration = "ShippingCountry", tag = "1")]
+    pub country: i32,
+    #[prost(enumeration = "TaxCategory", tag = "2")]
+    pub tax_category: i32,
+    #[prost(uint32, tag = "3")]
+    pub rate_basis_points: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutTaxRateRequest {
+    #[prost(enumeration = "ShippingCountry", tag = "1")]
+    pub country: i32,
+    #[prost(enumeration = "TaxCategory", tag = "2")]
+    pub tax_category: i32,
+    #[prost(uint32, tag = "3")]
+    pub rate_basis_points: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutTaxRateResponse {
+    #[prost(message, optional, tag = "1")]
+    pub tax_rate: ::core::option::Option<TaxRateResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTaxRatesRequest {
+    #[prost(enumeration = "ShippingCountry", optional, tag = "1")]
+    pub country: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTaxRatesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub tax_rates: ::prost::alloc::vec::Vec<TaxRateResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxQuoteItem {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaxQuoteRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<TaxQuoteItem>,
+    #[prost(enumeration = "ShippingCountry", tag = "2")]
+    pub shipping_country: i32,
+    #[prost(enumeration = "Currency", optional, tag = "3")]
+    pub currency: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaxLineResponse {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub offer_variant_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "3")]
+    pub quantity: u32,
+    #[prost(enumeration = "TaxCategory", tag = "4")]
+    pub tax_category: i32,
+    #[prost(uint32, tag = "5")]
+    pub rate_basis_points: u32,
+    #[prost(bool, tag = "6")]
+    pub prices_include_tax: bool,
+    #[prost(uint32, tag = "7")]
+    pub net_amount: u32,
+    #[prost(uint32, tag = "8")]
+    pub tax_amount: u32,
+    #[prost(uint32, tag = "9")]
+    pub gross_amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaxQuoteResponse {
+    #[prost(enumeration = "Currency", tag = "1")]
+    pub currency: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub lines: ::prost::alloc::vec::Vec<TaxLineResponse>,
+    #[prost(uint32, tag = "3")]
+    pub net_amount: u32,
+    #[prost(uint32, tag = "4")]
+    pub tax_amount: u32,
+    #[prost(uint32, tag = "5")]
+    pub gross_amount: u32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaxCategory {
+    Unspecified = 0,
+    Standard = 1,
+    Reduced = 2,
+    DigitalServices = 3,
+}
+impl TaxCategory {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaxCategory::Unspecified => "TAX_CATEGORY_UNSPECIFIED",
+            TaxCategory::Standard => "TAX_CATEGORY_STANDARD",
+            TaxCategory::Reduced => "TAX_CATEGORY_REDUCED",
+            TaxCategory::DigitalServices => "TAX_CATEGORY_DIGITAL_SERVICES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TAX_CATEGORY_UNSPECIFIED" => Some(Self::Unspecified),
+            "TAX_CATEGORY_STANDARD" => Some(Self::Standard),
+            "TAX_CATEGORY_REDUCED" => Some(Self::Reduced),
+            "TAX_CATEGORY_DIGITAL_SERVICES" => Some(Self::DigitalServices),
+            _ => None,
+        }
+    }
+}
+/// Generated server implementations.
+pub mod tax_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TaxServiceServer.
+    #[async_trait]
+    pub trait TaxService: Send + Sync + 'static {
+        async fn put_tax_rate(
+            &self,
+            request: tonic::Request<super::PutTaxRateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutTaxRateResponse>,
+            tonic::Status,
+        >;
+        async fn list_tax_rates(
+            &self,
+            request: tonic::Request<super::ListTaxRatesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTaxRatesResponse>,
+            tonic::Status,
+        >;
+        async fn get_tax_quote(
+            &self,
+            request: tonic::Request<super::GetTaxQuoteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTaxQuoteResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct TaxServiceServer<T: TaxService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: TaxService> TaxServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TaxServiceServer<T>
+    where
+        T: TaxService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.TaxService/PutTaxRate" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutTaxRateSvc<T: TaxService>(pub Arc<T>);
+                    impl<
+                        T: TaxService,
+                    > tonic::server::UnaryService<super::PutTaxRateRequest>
+                    for PutTaxRateSvc<T> {
+                        type Response = super::PutTaxRateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutTaxRateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_tax_rate(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutTaxRateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.TaxService/ListTaxRates" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTaxRatesSvc<T: TaxService>(pub Arc<T>);
+                    impl<
+                        T: TaxService,
+                    > tonic::server::UnaryService<super::ListTaxRatesRequest>
+                    for ListTaxRatesSvc<T> {
+                        type Response = super::ListTaxRatesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTaxRatesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).list_tax_rates(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListTaxRatesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.TaxService/GetTaxQuote" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTaxQuoteSvc<T: TaxService>(pub Arc<T>);
+                    impl<
+                        T: TaxService,
+                    > tonic::server::UnaryService<super::GetTaxQuoteRequest>
+                    for GetTaxQuoteSvc<T> {
+                        type Response = super::GetTaxQuoteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTaxQuoteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_tax_quote(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTaxQuoteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: TaxService> Clone for TaxServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: TaxService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: TaxService> tonic::server::NamedService for TaxServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.TaxService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShippingRule {
+    #[prost(enumeration = "ShippingRuleType", tag = "1")]
+    pub rule_type: i32,
+    #[prost(uint32, tag = "2")]
+    pub min_value: u32,
+    #[prost(uint32, optional, tag = "3")]
+    pub max_value: ::core::option::Option<u32>,
+    #[prost(uint32, tag = "4")]
+    pub amount: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShippingZoneResponse {
+    #[prost(string, tag = "1")]
+    pub shipping_zone_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "ShippingCountry", repeated, tag = "3")]
+    pub countries: ::prost::alloc::vec::Vec<i32>,
+    #[prost(uint32, optional, tag = "4")]
+    pub free_shipping_threshold: ::core::option::Option<u32>,
+    #[prost(message, repeated, tag = "5")]
+    pub rules: ::prost::alloc::vec::Vec<ShippingRule>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShippingProfileResponse {
+    #[prost(string, tag = "1")]
+    pub shipping_profile_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub created_at: i64,
+    #[prost(int64, tag = "5")]
+    pub updated_at: i64,
+    #[prost(string, tag = "6")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "Currency", tag = "7")]
+    pub currency: i32,
+    #[prost(message, repeated, tag = "8")]
+    pub zones: ::prost::alloc::vec::Vec<ShippingZoneResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateShippingProfileRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "Currency", tag = "3")]
+    pub currency: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateShippingProfileResponse {
+    #[prost(message, optional, tag = "1")]
+    pub shipping_profile: ::core::option::Option<ShippingProfileResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetShippingProfileRequest {
+    #[prost(string, tag = "1")]
+    pub shipping_profile_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetShippingProfileResponse {
+    #[prost(message, optional, tag = "1")]
+    pub shipping_profile: ::core::option::Option<ShippingProfileResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListShippingProfilesRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListShippingProfilesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub shipping_profiles: ::prost::alloc::vec::Vec<ShippingProfileResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateShippingProfileRequest {
+    #[prost(string, tag = "1")]
+    pub shipping_profile_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "Currency", optional, tag = "3")]
+    pub currency: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateShippingProfileResponse {
+    #[prost(message, optional, tag = "1")]
+    pub shipping_profile: ::core::option::Option<ShippingProfileResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteShippingProfileRequest {
+    #[prost(string, tag = "1")]
+    pub shipping_profile_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteShippingProfileResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutShippingZoneRequest {
+    #[prost(string, tag = "1")]
+    pub shipping_profile_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub shipping_zone_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "ShippingCountry", repeated, tag = "4")]
+    pub countries: ::prost::alloc::vec::Vec<i32>,
+    #[prost(uint32, optional, tag = "5")]
+    pub free_shipping_threshold: ::core::option::Option<u32>,
+    #[prost(message, repeated, tag = "6")]
+    pub rules: ::prost::alloc::vec::Vec<ShippingRule>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutShippingZoneResponse {
+    #[prost(message, optional, tag = "1")]
+    pub shipping_profile: ::core::option::Option<ShippingProfileResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteShippingZoneRequest {
+    #[prost(string, tag = "1")]
+    pub shipping_zone_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteShippingZoneResponse {}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ShippingRuleType {
+    Unspecified = 0,
+    Weight = 1,
+    OrderValue = 2,
+    ItemCount = 3,
+}
+impl ShippingRuleType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ShippingRuleType::Unspecified => "SHIPPING_RULE_TYPE_UNSPECIFIED",
+            ShippingRuleType::Weight => "SHIPPING_RULE_TYPE_WEIGHT",
+            ShippingRuleType::OrderValue => "SHIPPING_RULE_TYPE_ORDER_VALUE",
+            ShippingRuleType::ItemCount => "SHIPPING_RULE_TYPE_ITEM_COUNT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SHIPPING_RULE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SHIPPING_RULE_TYPE_WEIGHT" => Some(Self::Weight),
+            "SHIPPING_RULE_TYPE_ORDER_VALUE" => Some(Self::OrderValue),
+            "SHIPPING_RULE_TYPE_ITEM_COUNT" => Some(Self::ItemCount),
+            _ => None,
+        }
+    }
+}
+/// Generated server implementations.
+pub mod shipping_profile_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ShippingProfileServiceServer.
+    #[async_trait]
+    pub trait ShippingProfileService: Send + Sync + 'static {
+        async fn create_shipping_profile(
+            &self,
+            request: tonic::Request<super::CreateShippingProfileRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateShippingProfileResponse>,
+            tonic::Status,
+        >;
+        async fn get_shipping_profile(
+            &self,
+            request: tonic::Request<super::GetShippingProfileRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetShippingProfileResponse>,
+            tonic::Status,
+        >;
+        async fn list_shipping_profiles(
+            &self,
+            request: tonic::Request<super::ListShippingProfilesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListShippingProfilesResponse>,
+            tonic::Status,
+        >;
+        async fn update_shipping_profile(
+            &self,
+            request: tonic::Request<super::UpdateShippingProfileRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateShippingProfileResponse>,
+            tonic::Status,
+        >;
+        async fn delete_shipping_profile(
+            &self,
+            request: tonic::Request<super::DeleteShippingProfileRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteShippingProfileResponse>,
+            tonic::Status,
+        >;
+        async fn put_shipping_zone(
+            &self,
+            request: tonic::Request<super::PutShippingZoneRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutShippingZoneResponse>,
+            tonic::Status,
+        >;
+        async fn delete_shipping_zone(
+            &self,
+            request: tonic::Request<super::DeleteShippingZoneRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteShippingZoneResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct ShippingProfileServiceServer<T: ShippingProfileService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ShippingProfileService> ShippingProfileServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for ShippingProfileServiceServer<T>
+    where
+        T: ShippingProfileService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.ShippingProfileService/CreateShippingProfile" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateShippingProfileSvc<T: ShippingProfileService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ShippingProfileService,
+                    > tonic::server::UnaryService<super::CreateShippingProfileRequest>
+                    for CreateShippingProfileSvc<T> {
+                        type Response = super::CreateShippingProfileResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateShippingProfileRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).create_shipping_profile(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateShippingProfileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingProfileService/GetShippingProfile" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetShippingProfileSvc<T: ShippingProfileService>(pub Arc<T>);
+                    impl<
+                        T: ShippingProfileService,
+                    > tonic::server::UnaryService<super::GetShippingProfileRequest>
+                    for GetShippingProfileSvc<T> {
+                        type Response = super::GetShippingProfileResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetShippingProfileRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_shipping_profile(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetShippingProfileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingProfileService/ListShippingProfiles" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListShippingProfilesSvc<T: ShippingProfileService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ShippingProfileService,
+                    > tonic::server::UnaryService<super::ListShippingProfilesRequest>
+                    for ListShippingProfilesSvc<T> {
+                        type Response = super::ListShippingProfilesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListShippingProfilesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).list_shipping_profiles(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListShippingProfilesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingProfileService/UpdateShippingProfile" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateShippingProfileSvc<T: ShippingProfileService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ShippingProfileService,
+                    > tonic::server::UnaryService<super::UpdateShippingProfileRequest>
+                    for UpdateShippingProfileSvc<T> {
+                        type Response = super::UpdateShippingProfileResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateShippingProfileRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_shipping_profile(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateShippingProfileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingProfileService/DeleteShippingProfile" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteShippingProfileSvc<T: ShippingProfileService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ShippingProfileService,
+                    > tonic::server::UnaryService<super::DeleteShippingProfileRequest>
+                    for DeleteShippingProfileSvc<T> {
+                        type Response = super::DeleteShippingProfileResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteShippingProfileRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_shipping_profile(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteShippingProfileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingProfileService/PutShippingZone" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutShippingZoneSvc<T: ShippingProfileService>(pub Arc<T>);
+                    impl<
+                        T: ShippingProfileService,
+                    > tonic::server::UnaryService<super::PutShippingZoneRequest>
+                    for PutShippingZoneSvc<T> {
+                        type Response = super::PutShippingZoneResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutShippingZoneRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_shipping_zone(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutShippingZoneSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.ShippingProfileService/DeleteShippingZone" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteShippingZoneSvc<T: ShippingProfileService>(pub Arc<T>);
+                    impl<
+                        T: ShippingProfileService,
+                    > tonic::server::UnaryService<super::DeleteShippingZoneRequest>
+                    for DeleteShippingZoneSvc<T> {
+                        type Response = super::DeleteShippingZoneResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteShippingZoneRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_shipping_zone(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteShippingZoneSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ShippingProfileService> Clone for ShippingProfileServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: ShippingProfileService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ShippingProfileService> tonic::server::NamedService
+    for ShippingProfileServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.ShippingProfileService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CategoryResponse {
+    #[prost(string, tag = "1")]
+    pub category_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "3")]
+    pub ancestor_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(int64, tag = "4")]
+    pub created_at: i64,
+    #[prost(int64, tag = "5")]
+    pub updated_at: i64,
+    #[prost(string, tag = "6")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(int64, tag = "8")]
+    pub ordering: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCategoryRequest {
+    #[prost(string, optional, tag = "1")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub ordering: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCategoryResponse {
+    #[prost(message, optional, tag = "1")]
+    pub category: ::core::option::Option<CategoryResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCategoryRequest {
+    #[prost(string, optional, tag = "1")]
+    pub category_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub slug: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCategoryResponse {
+    #[prost(message, optional, tag = "1")]
+    pub category: ::core::option::Option<CategoryResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCategoriesRequest {
+    #[prost(string, optional, tag = "1")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCategoriesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub categories: ::prost::alloc::vec::Vec<CategoryResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCategoryRequest {
+    #[prost(string, tag = "1")]
+    pub category_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub slug: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "4")]
+    pub ordering: ::core::option::Option<i64>,
+    #[prost(string, optional, tag = "5")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "6")]
+    pub is_root: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCategoryResponse {
+    #[prost(message, optional, tag = "1")]
+    pub category: ::core::option::Option<CategoryResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCategoryRequest {
+    #[prost(string, tag = "1")]
+    pub category_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCategoryResponse {}
+/// Generated server implementations.
+pub mod category_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CategoryServiceServer.
+    #[async_trait]
+    pub trait CategoryService: Send + Sync + 'static {
+        async fn create_category(
+            &self,
+            request: tonic::Request<super::CreateCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateCategoryResponse>,
+            tonic::Status,
+        >;
+        async fn get_category(
+            &self,
+            request: tonic::Request<super::GetCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCategoryResponse>,
+            tonic::Status,
+        >;
+        async fn list_categories(
+            &self,
+            request: tonic::Request<super::ListCategoriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCategoriesResponse>,
+            tonic::Status,
+        >;
+        async fn update_category(
+            &self,
+            request: tonic::Request<super::UpdateCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateCategoryResponse>,
+            tonic::Status,
+        >;
+        async fn delete_category(
+            &self,
+            request: tonic::Request<super::DeleteCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteCategoryResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct CategoryServiceServer<T: CategoryService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: CategoryService> CategoryServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CategoryServiceServer<T>
+    where
+        T: CategoryService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/sited_io.commerce.v1.CategoryService/CreateCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::CreateCategoryRequest>
+                    for CreateCategorySvc<T> {
+                        type Response = super::CreateCategoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).create_category(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CategoryService/GetCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::GetCategoryRequest>
+                    for GetCategorySvc<T> {
+                        type Response = super::GetCategoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_category(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CategoryService/ListCategories" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCategoriesSvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::ListCategoriesRequest>
+                    for ListCategoriesSvc<T> {
+                        type Response = super::ListCategoriesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCategoriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).list_categories(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListCategoriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CategoryService/UpdateCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::UpdateCategoryRequest>
+                    for UpdateCategorySvc<T> {
+                        type Response = super::UpdateCategoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_category(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CategoryService/DeleteCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::DeleteCategoryRequest>
+                    for DeleteCategorySvc<T> {
+                        type Response = super::DeleteCategoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_category(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CategoryService> Clone for CategoryServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CategoryService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: CategoryService> tonic::server::NamedService for CategoryServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.CategoryService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectionRule {
+    #[prost(enumeration = "OfferType", optional, tag = "1")]
+    pub offer_type: ::core::option::Option<i32>,
+    #[prost(string, optional, tag = "2")]
+    pub category_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "3")]
+    pub is_featured: ::core::option::Option<bool>,
+    #[prost(enumeration = "Currency", optional, tag = "4")]
+    pub currency: ::core::option::Option<i32>,
+    #[prost(uint32, optional, tag = "5")]
+    pub min_price: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "6")]
+    pub max_price: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectionOfferResponse {
+    #[prost(string, tag = "1")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub ordering: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectionResponse {
+    #[prost(string, tag = "1")]
+    pub collection_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub created_at: i64,
+    #[prost(int64, tag = "5")]
+    pub updated_at: i64,
+    #[prost(string, tag = "6")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(int64, tag = "8")]
+    pub ordering: i64,
+    #[prost(enumeration = "CollectionType", tag = "9")]
+    pub collection_type: i32,
+    #[prost(message, optional, tag = "10")]
+    pub rule: ::core::option::Option<CollectionRule>,
+    #[prost(message, repeated, tag = "11")]
+    pub offers: ::prost::alloc::vec::Vec<CollectionOfferResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCollectionRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub ordering: i64,
+    #[prost(enumeration = "CollectionType", tag = "5")]
+    pub collection_type: i32,
+    #[prost(message, optional, tag = "6")]
+    pub rule: ::core::option::Option<CollectionRule>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCollectionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<CollectionResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCollectionRequest {
+    #[prost(string, optional, tag = "1")]
+    pub collection_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub shop_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub slug: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCollectionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<CollectionResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCollectionsRequest {
+    #[prost(string, tag = "1")]
+    pub shop_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCollectionsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub collections: ::prost::alloc::vec::Vec<CollectionResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCollectionRequest {
+    #[prost(string, tag = "1")]
+    pub collection_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub slug: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "4")]
+    pub ordering: ::core::option::Option<i64>,
+    #[prost(message, optional, tag = "5")]
+    pub rule: ::core::option::Option<CollectionRule>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCollectionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<CollectionResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCollectionRequest {
+    #[prost(string, tag = "1")]
+    pub collection_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCollectionResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutOfferToCollectionRequest {
+    #[prost(string, tag = "1")]
+    pub collection_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub offer_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub ordering: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PutOfferToCollectionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<CollectionResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveOfferFromCollectionRequest {
+    #[prost(string, tag = "1")]
+    pub collection_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub offer_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveOfferFromCollectionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub collection: ::core::option::Option<CollectionResponse>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CollectionType {
+    Unspecified = 0,
+    Manual = 1,
+    Rule = 2,
+}
+impl CollectionType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CollectionType::Unspecified => "COLLECTION_TYPE_UNSPECIFIED",
+            CollectionType::Manual => "COLLECTION_TYPE_MANUAL",
+            CollectionType::Rule => "COLLECTION_TYPE_RULE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COLLECTION_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "COLLECTION_TYPE_MANUAL" => Some(Self::Manual),
+            "COLLECTION_TYPE_RULE" => Some(Self::Rule),
             _ => None,
         }
     }
 }
 /// Generated server implementations.
-pub mod shipping_rate_service_server {
+pub mod collection_service_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ShippingRateServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with CollectionServiceServer.
     #[async_trait]
-    pub trait ShippingRateService: Send + Sync + 'static {
-        async fn put_shipping_rate(
+    pub trait CollectionService: Send + Sync + 'static {
+        async fn create_collection(
             &self,
-            request: tonic::Request<super::PutShippingRateRequest>,
+            request: tonic::Request<super::CreateCollectionRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::PutShippingRateResponse>,
+            tonic::Response<super::CreateCollectionResponse>,
             tonic::Status,
         >;
-        async fn get_shipping_rate(
+        async fn get_collection(
             &self,
-            request: tonic::Request<super::GetShippingRateRequest>,
+            request: tonic::Request<super::GetCollectionRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetShippingRateResponse>,
+            tonic::Response<super::GetCollectionResponse>,
             tonic::Status,
         >;
-        async fn delete_shipping_rate(
+        async fn list_collections(
             &self,
-            request: tonic::Request<super::DeleteShippingRateRequest>,
+            request: tonic::Request<super::ListCollectionsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteShippingRateResponse>,
+            tonic::Response<super::ListCollectionsResponse>,
+            tonic::Status,
+        >;
+        async fn update_collection(
+            &self,
+            request: tonic::Request<super::UpdateCollectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateCollectionResponse>,
+            tonic::Status,
+        >;
+        async fn delete_collection(
+            &self,
+            request: tonic::Request<super::DeleteCollectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteCollectionResponse>,
+            tonic::Status,
+        >;
+        async fn put_offer_to_collection(
+            &self,
+            request: tonic::Request<super::PutOfferToCollectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PutOfferToCollectionResponse>,
+            tonic::Status,
+        >;
+        async fn remove_offer_from_collection(
+            &self,
+            request: tonic::Request<super::RemoveOfferFromCollectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveOfferFromCollectionResponse>,
             tonic::Status,
         >;
     }
     #[derive(Debug)]
-    pub struct ShippingRateServiceServer<T: ShippingRateService> {
+    pub struct CollectionServiceServer<T: CollectionService> {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
@@ -3677,7 +10335,7 @@ pub mod shipping_rate_service_server {
         max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: ShippingRateService> ShippingRateServiceServer<T> {
+    impl<T: CollectionService> CollectionServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -3729,9 +10387,9 @@ pub mod shipping_rate_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for ShippingRateServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CollectionServiceServer<T>
     where
-        T: ShippingRateService,
+        T: CollectionService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -3747,25 +10405,25 @@ pub mod shipping_rate_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/sited_io.commerce.v1.ShippingRateService/PutShippingRate" => {
+                "/sited_io.commerce.v1.CollectionService/CreateCollection" => {
                     #[allow(non_camel_case_types)]
-                    struct PutShippingRateSvc<T: ShippingRateService>(pub Arc<T>);
+                    struct CreateCollectionSvc<T: CollectionService>(pub Arc<T>);
                     impl<
-                        T: ShippingRateService,
-                    > tonic::server::UnaryService<super::PutShippingRateRequest>
-                    for PutShippingRateSvc<T> {
-                        type Response = super::PutShippingRateResponse;
+                        T: CollectionService,
+                    > tonic::server::UnaryService<super::CreateCollectionRequest>
+                    for CreateCollectionSvc<T> {
+                        type Response = super::CreateCollectionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PutShippingRateRequest>,
+                            request: tonic::Request<super::CreateCollectionRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).put_shipping_rate(request).await
+                                (*inner).create_collection(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3777,7 +10435,7 @@ pub mod shipping_rate_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = PutShippingRateSvc(inner);
+                        let method = CreateCollectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3793,25 +10451,25 @@ pub mod shipping_rate_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.ShippingRateService/GetShippingRate" => {
+                "/sited_io.commerce.v1.CollectionService/GetCollection" => {
                     #[allow(non_camel_case_types)]
-                    struct GetShippingRateSvc<T: ShippingRateService>(pub Arc<T>);
+                    struct GetCollectionSvc<T: CollectionService>(pub Arc<T>);
                     impl<
-                        T: ShippingRateService,
-                    > tonic::server::UnaryService<super::GetShippingRateRequest>
-                    for GetShippingRateSvc<T> {
-                        type Response = super::GetShippingRateResponse;
+                        T: CollectionService,
+                    > tonic::server::UnaryService<super::GetCollectionRequest>
+                    for GetCollectionSvc<T> {
+                        type Response = super::GetCollectionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetShippingRateRequest>,
+                            request: tonic::Request<super::GetCollectionRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_shipping_rate(request).await
+                                (*inner).get_collection(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3823,7 +10481,7 @@ pub mod shipping_rate_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetShippingRateSvc(inner);
+                        let method = GetCollectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3839,25 +10497,25 @@ pub mod shipping_rate_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/sited_io.commerce.v1.ShippingRateService/DeleteShippingRate" => {
+                "/sited_io.commerce.v1.CollectionService/ListCollections" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteShippingRateSvc<T: ShippingRateService>(pub Arc<T>);
+                    struct ListCollectionsSvc<T: CollectionService>(pub Arc<T>);
                     impl<
-                        T: ShippingRateService,
-                    > tonic::server::UnaryService<super::DeleteShippingRateRequest>
-                    for DeleteShippingRateSvc<T> {
-                        type Response = super::DeleteShippingRateResponse;
+                        T: CollectionService,
+                    > tonic::server::UnaryService<super::ListCollectionsRequest>
+                    for ListCollectionsSvc<T> {
+                        type Response = super::ListCollectionsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteShippingRateRequest>,
+                            request: tonic::Request<super::ListCollectionsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).delete_shipping_rate(request).await
+                                (*inner).list_collections(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3869,7 +10527,196 @@ pub mod shipping_rate_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DeleteShippingRateSvc(inner);
+                        let method = ListCollectionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CollectionService/UpdateCollection" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateCollectionSvc<T: CollectionService>(pub Arc<T>);
+                    impl<
+                        T: CollectionService,
+                    > tonic::server::UnaryService<super::UpdateCollectionRequest>
+                    for UpdateCollectionSvc<T> {
+                        type Response = super::UpdateCollectionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateCollectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).update_collection(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateCollectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CollectionService/DeleteCollection" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteCollectionSvc<T: CollectionService>(pub Arc<T>);
+                    impl<
+                        T: CollectionService,
+                    > tonic::server::UnaryService<super::DeleteCollectionRequest>
+                    for DeleteCollectionSvc<T> {
+                        type Response = super::DeleteCollectionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteCollectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).delete_collection(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DeleteCollectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CollectionService/PutOfferToCollection" => {
+                    #[allow(non_camel_case_types)]
+                    struct PutOfferToCollectionSvc<T: CollectionService>(pub Arc<T>);
+                    impl<
+                        T: CollectionService,
+                    > tonic::server::UnaryService<super::PutOfferToCollectionRequest>
+                    for PutOfferToCollectionSvc<T> {
+                        type Response = super::PutOfferToCollectionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PutOfferToCollectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).put_offer_to_collection(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PutOfferToCollectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/sited_io.commerce.v1.CollectionService/RemoveOfferFromCollection" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveOfferFromCollectionSvc<T: CollectionService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: CollectionService,
+                    > tonic::server::UnaryService<
+                        super::RemoveOfferFromCollectionRequest,
+                    > for RemoveOfferFromCollectionSvc<T> {
+                        type Response = super::RemoveOfferFromCollectionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::RemoveOfferFromCollectionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_offer_from_collection(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveOfferFromCollectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3900,7 +10747,7 @@ pub mod shipping_rate_service_server {
             }
         }
     }
-    impl<T: ShippingRateService> Clone for ShippingRateServiceServer<T> {
+    impl<T: CollectionService> Clone for CollectionServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -3912,7 +10759,7 @@ pub mod shipping_rate_service_server {
             }
         }
     }
-    impl<T: ShippingRateService> Clone for _Inner<T> {
+    impl<T: CollectionService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(Arc::clone(&self.0))
         }
@@ -3922,8 +10769,8 @@ pub mod shipping_rate_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: ShippingRateService> tonic::server::NamedService
-    for ShippingRateServiceServer<T> {
-        const NAME: &'static str = "sited_io.commerce.v1.ShippingRateService";
+    impl<T: CollectionService> tonic::server::NamedService
+    for CollectionServiceServer<T> {
+        const NAME: &'static str = "sited_io.commerce.v1.CollectionService";
     }
 }
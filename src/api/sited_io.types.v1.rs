@@ -34,6 +34,8 @@ pub struct PaginationRequest {
     pub page: u32,
     #[prost(uint32, tag = "2")]
     pub size: u32,
+    #[prost(string, optional, tag = "3")]
+    pub cursor: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -44,4 +46,6 @@ pub struct PaginationResponse {
     pub size: u32,
     #[prost(uint32, tag = "3")]
     pub total_elements: u32,
+    #[prost(string, optional, tag = "4")]
+    pub next_cursor: ::core::option::Option<::prost::alloc::string::String>,
 }
@@ -0,0 +1,30 @@
+use crate::api::sited_io::commerce::v1::Currency;
+
+/// Number of decimal places of the minor unit of a currency. All amounts are
+/// stored as integers in the minor unit, so 1000 means 10.00 EUR but
+/// 1000 JPY.
+pub fn get_minor_unit_exponent(currency: Currency) -> u32 {
+    match currency {
+        Currency::Jpy => 0,
+        Currency::Unspecified
+        | Currency::Eur
+        | Currency::Usd
+        | Currency::Gbp
+        | Currency::Chf
+        | Currency::Sek
+        | Currency::Pln => 2,
+    }
+}
+
+/// Converts an amount given in hundredths of the major unit, like the minimum
+/// platform fee of a shop, to the minor unit of the currency. Fractions of a
+/// minor unit are rounded up, so 50 cents become 1 JPY.
+pub fn cents_to_minor_units(cents: u32, currency: Currency) -> u32 {
+    let exponent = get_minor_unit_exponent(currency);
+
+    if exponent >= 2 {
+        cents.saturating_mul(10u32.pow(exponent - 2))
+    } else {
+        cents.div_ceil(10u32.pow(2 - exponent))
+    }
+}
@@ -1,5 +1,8 @@
 use std::ops::DerefMut;
 
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, TimeZone, Utc};
 use deadpool_postgres::tokio_postgres::error::SqlState;
 use deadpool_postgres::tokio_postgres::types::{FromSql, Type, WrongType};
 use deadpool_postgres::tokio_postgres::Row;
@@ -10,8 +13,17 @@ use deadpool_postgres::{
 use openssl::ssl::{SslConnector, SslMethod};
 use postgres_openssl::MakeTlsConnector;
 use refinery::Target;
-use sea_query::{Expr, Iden, PgFunc, SimpleExpr};
+use sea_query::extension::postgres::PgExpr;
+use sea_query::{
+    any, Alias, Asterisk, BinOper, CaseStatement, Condition, Expr, Func, Iden,
+    IntoColumnRef, IntoCondition, NullOrdering, Order, PgFunc, Query,
+    SelectStatement, SimpleExpr, Value,
+};
+use serde::{Deserialize, Serialize};
 use tonic::Status;
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::{ContentLanguage, FilterOperator};
 
 mod embedded {
     use refinery::embed_migrations;
@@ -152,11 +164,138 @@ pub async fn migrate(pool: &Pool) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-pub fn build_simple_plain_ts_query(query: &String) -> Expr {
-    Expr::expr(
+/// Text search configurations of the content languages. Content without a
+/// language uses `simple`, which neither stems words nor removes stop words.
+/// The generated `*_ts` columns have to use the same configurations.
+const TS_CONFIGS: [(ContentLanguage, &str); 2] = [
+    (ContentLanguage::German, "german"),
+    (ContentLanguage::English, "english"),
+];
+const DEFAULT_TS_CONFIG: &str = "simple";
+
+/// Builds the query with the text search configuration of the content
+/// language in the given column of each row.
+fn build_ts_query_per_language<C>(
+    language_column: C,
+    build_ts_query: impl Fn(&'static str) -> SimpleExpr,
+) -> Expr
+where
+    C: IntoColumnRef + Copy,
+{
+    let mut ts_query = CaseStatement::new();
+
+    for (language, config) in TS_CONFIGS {
+        ts_query = ts_query.case(
+            Expr::col(language_column).eq(language.as_str_name()),
+            build_ts_query(config),
+        );
+    }
+
+    Expr::expr(ts_query.finally(build_ts_query(DEFAULT_TS_CONFIG)))
+}
+
+pub fn build_plain_ts_query<C>(language_column: C, query: &str) -> Expr
+where
+    C: IntoColumnRef + Copy,
+{
+    build_ts_query_per_language(language_column, |config| {
         PgFunc::plainto_tsquery("", None)
-            .args([SimpleExpr::Value("simple".into()), query.into()]),
-    )
+            .args([SimpleExpr::Value(config.into()), query.into()])
+            .into()
+    })
+}
+
+/// Builds a query matching words that start with each word of the query, so
+/// that `yog` matches `yoga`. Returns `None` for queries without words.
+pub fn build_prefix_ts_query<C>(language_column: C, query: &str) -> Option<Expr>
+where
+    C: IntoColumnRef + Copy,
+{
+    let words: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| format!("{w}:*"))
+        .collect();
+
+    if words.is_empty() {
+        return None;
+    }
+
+    let words = words.join(" & ");
+
+    Some(build_ts_query_per_language(language_column, |config| {
+        PgFunc::to_tsquery("", None)
+            .args([SimpleExpr::Value(config.into()), words.as_str().into()])
+            .into()
+    }))
+}
+
+/// Condition matching the words or word prefixes of the query in the text
+/// search column, and the rank of the match.
+pub fn build_ts_search<C>(
+    ts_column: C,
+    language_column: C,
+    query: &str,
+) -> (Condition, SimpleExpr)
+where
+    C: IntoColumnRef + Copy,
+{
+    let tsquery = build_plain_ts_query(language_column, query);
+
+    let mut condition =
+        Condition::any().add(Expr::col(ts_column).matches(tsquery.clone()));
+    let mut rank: SimpleExpr =
+        PgFunc::ts_rank(Expr::col(ts_column), tsquery).into();
+
+    if let Some(prefix_tsquery) = build_prefix_ts_query(language_column, query)
+    {
+        condition =
+            condition.add(Expr::col(ts_column).matches(prefix_tsquery.clone()));
+        rank = rank.add(PgFunc::ts_rank(Expr::col(ts_column), prefix_tsquery));
+    }
+
+    (condition, rank)
+}
+
+/// Like `build_ts_search`, but also matches names that are similar to the
+/// query by trigrams to tolerate typos, which adds to the rank.
+pub fn build_name_search<C>(
+    name_column: C,
+    name_ts_column: C,
+    language_column: C,
+    query: &str,
+) -> (Condition, SimpleExpr)
+where
+    C: IntoColumnRef + Copy,
+{
+    let (condition, rank) =
+        build_ts_search(name_ts_column, language_column, query);
+
+    let condition = condition.add(
+        Expr::col(name_column).binary(BinOper::Custom("%"), Expr::val(query)),
+    );
+    let rank = rank.add(
+        Func::cust(Similarity)
+            .args([Expr::col(name_column).into(), query.into()]),
+    );
+
+    (condition, rank)
+}
+
+pub struct Similarity;
+
+impl Iden for Similarity {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "SIMILARITY").unwrap()
+    }
+}
+
+pub struct Md5;
+
+impl Iden for Md5 {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "MD5").unwrap()
+    }
 }
 
 pub struct ArrayAgg;
@@ -167,6 +306,361 @@ impl Iden for ArrayAgg {
     }
 }
 
+pub struct Any;
+
+impl Iden for Any {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "ANY").unwrap()
+    }
+}
+
+pub struct ArrayAppend;
+
+impl Iden for ArrayAppend {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "ARRAY_APPEND").unwrap()
+    }
+}
+
+/// Builds an array of the given SQL array type from the values by appending
+/// them one by one, as the query builder cannot bind arrays.
+pub fn get_array_expr<V>(
+    values: impl IntoIterator<Item = V>,
+    array_type: &str,
+) -> SimpleExpr
+where
+    V: Into<Value>,
+{
+    values.into_iter().fold(
+        Expr::cust(format!("ARRAY[]::{array_type}")),
+        |array, value| {
+            Func::cust(ArrayAppend)
+                .args([array, SimpleExpr::Value(value.into())])
+                .into()
+        },
+    )
+}
+
+/// Filters of a list that are joined by `AND` or `OR`, together with nested
+/// groups of filters.
+#[derive(Debug, Clone)]
+pub struct FilterGroup<F> {
+    pub operator: FilterOperator,
+    pub filters: Vec<(F, String)>,
+    pub groups: Vec<FilterGroup<F>>,
+}
+
+impl<F: Copy> FilterGroup<F> {
+    /// Group of filters that all have to match.
+    pub fn all(filters: Vec<(F, String)>) -> Self {
+        Self {
+            operator: FilterOperator::And,
+            filters,
+            groups: Vec::new(),
+        }
+    }
+
+    /// Queries of the filters of the field in the group and all of its
+    /// nested groups.
+    pub fn queries(&self, field: F) -> Vec<&str>
+    where
+        F: PartialEq,
+    {
+        let mut queries: Vec<&str> = self
+            .filters
+            .iter()
+            .filter(|(f, _)| *f == field)
+            .map(|(_, q)| q.as_str())
+            .collect();
+        for group in self.groups.iter() {
+            queries.extend(group.queries(field));
+        }
+        queries
+    }
+
+    /// Fields of the filters of the group and all of its nested groups.
+    pub fn fields(&self) -> Vec<F> {
+        let mut fields: Vec<F> = self.filters.iter().map(|(f, _)| *f).collect();
+        for group in self.groups.iter() {
+            fields.extend(group.fields());
+        }
+        fields
+    }
+
+    /// Joins the conditions returned by `get_condition` for each filter.
+    /// Returns `None` if neither the group nor its nested groups have a
+    /// condition, so empty groups do not restrict the list.
+    pub fn build_condition<E>(
+        &self,
+        get_condition: &mut impl FnMut(F, &str) -> Result<Option<Condition>, E>,
+    ) -> Result<Option<Condition>, E> {
+        let mut condition = match self.operator {
+            FilterOperator::Or => Condition::any(),
+            FilterOperator::Unspecified | FilterOperator::And => {
+                Condition::all()
+            }
+        };
+        let mut is_empty = true;
+
+        for (field, query) in self.filters.iter() {
+            if let Some(filter_condition) = get_condition(*field, query)? {
+                condition = condition.add(filter_condition);
+                is_empty = false;
+            }
+        }
+
+        for group in self.groups.iter() {
+            if let Some(group_condition) =
+                group.build_condition(get_condition)?
+            {
+                condition = condition.add(group_condition);
+                is_empty = false;
+            }
+        }
+
+        Ok((!is_empty).then_some(condition))
+    }
+}
+
+/// Type of the value of a sort key, which is needed to read it from a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKeyKind {
+    Int,
+    Float,
+    Text,
+    Timestamp,
+    Uuid,
+}
+
+/// Expression a list is ordered by. Lists are ordered by a unique key last,
+/// so that their order is stable and can be continued from a cursor.
+#[derive(Debug, Clone)]
+pub struct SortKey {
+    expr: SimpleExpr,
+    order: Order,
+    kind: SortKeyKind,
+    nullable: bool,
+}
+
+impl SortKey {
+    const ALIAS_PREFIX: &'static str = "sort_key_";
+
+    pub fn new(
+        expr: impl Into<SimpleExpr>,
+        order: Order,
+        kind: SortKeyKind,
+    ) -> Self {
+        let expr = expr.into();
+        // floats are compared with double precision, so that the value in
+        // the cursor is exactly the one that is compared
+        let expr = match kind {
+            SortKeyKind::Float => expr.cast_as(Alias::new("FLOAT8")),
+            _ => expr,
+        };
+
+        Self {
+            expr,
+            order,
+            kind,
+            nullable: false,
+        }
+    }
+
+    /// Rows without a value are listed last, in either direction.
+    pub fn nullable(mut self) -> Self {
+        self.nullable = true;
+        self
+    }
+
+    /// Key in a random order that stays the same for the same seed.
+    pub fn random<C>(seed: &str, id_column: C) -> Self
+    where
+        C: IntoColumnRef,
+    {
+        Self::new(
+            Func::cust(Md5).arg(
+                Expr::val(seed).concatenate(
+                    Expr::col(id_column).cast_as(Alias::new("TEXT")),
+                ),
+            ),
+            Order::Asc,
+            SortKeyKind::Text,
+        )
+    }
+
+    pub fn kind(&self) -> SortKeyKind {
+        self.kind
+    }
+
+    fn get_alias(index: usize) -> String {
+        format!("{}{index}", Self::ALIAS_PREFIX)
+    }
+
+    /// Orders the query by the keys and selects their values, which the
+    /// cursor of the next page is read from.
+    pub fn add_to_query(query: &mut SelectStatement, sort_keys: &[Self]) {
+        for (i, key) in sort_keys.iter().enumerate() {
+            query.expr_as(key.expr.clone(), Alias::new(Self::get_alias(i)));
+            if key.nullable {
+                query.order_by_expr_with_nulls(
+                    key.expr.clone(),
+                    key.order.clone(),
+                    NullOrdering::Last,
+                );
+            } else {
+                query.order_by_expr(key.expr.clone(), key.order.clone());
+            }
+        }
+    }
+}
+
+/// Value of a sort key in a cursor. Timestamps are stored in microseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum CursorValue {
+    Null,
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Timestamp(i64),
+    Uuid(String),
+}
+
+impl CursorValue {
+    fn from_row(row: &Row, alias: &str, kind: SortKeyKind) -> Self {
+        match kind {
+            SortKeyKind::Int => row.get::<_, Option<i64>>(alias).map(Self::Int),
+            SortKeyKind::Float => {
+                row.get::<_, Option<f64>>(alias).map(Self::Float)
+            }
+            SortKeyKind::Text => {
+                row.get::<_, Option<String>>(alias).map(Self::Text)
+            }
+            SortKeyKind::Timestamp => row
+                .get::<_, Option<DateTime<Utc>>>(alias)
+                .map(|t| Self::Timestamp(t.timestamp_micros())),
+            SortKeyKind::Uuid => row
+                .get::<_, Option<Uuid>>(alias)
+                .map(|u| Self::Uuid(u.to_string())),
+        }
+        .unwrap_or(Self::Null)
+    }
+
+    /// Returns the value as expression, or `None` for null values of
+    /// nullable keys.
+    fn to_expr(&self, key: &SortKey) -> Result<Option<SimpleExpr>, DbError> {
+        let err = DbError::Argument("pagination.cursor");
+
+        let value = match (self, key.kind) {
+            (Self::Null, _) if key.nullable => return Ok(None),
+            (Self::Int(v), SortKeyKind::Int) => Expr::val(*v),
+            (Self::Float(v), SortKeyKind::Float) => Expr::val(*v),
+            (Self::Text(v), SortKeyKind::Text) => Expr::val(v.as_str()),
+            (Self::Timestamp(v), SortKeyKind::Timestamp) => {
+                let nanos = u32::try_from(v.rem_euclid(1_000_000) * 1000)
+                    .map_err(|_| DbError::Argument("pagination.cursor"))?;
+                Expr::val(
+                    Utc.timestamp_opt(v.div_euclid(1_000_000), nanos)
+                        .single()
+                        .ok_or(err)?,
+                )
+            }
+            (Self::Uuid(v), SortKeyKind::Uuid) => {
+                Expr::val(v.parse::<Uuid>().map_err(|_| err)?)
+            }
+            _ => return Err(err),
+        };
+
+        Ok(Some(value.into()))
+    }
+}
+
+/// Position after the last row of a page, which the next page starts from.
+/// Clients only see the encoded cursor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cursor {
+    keys: Vec<CursorValue>,
+    seed: Option<String>,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, DbError> {
+        URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()
+            .and_then(|c| serde_json::from_slice(&c).ok())
+            .ok_or(DbError::Argument("pagination.cursor"))
+    }
+
+    /// Seed of the random order of the list the cursor belongs to.
+    pub fn seed(&self) -> Option<&str> {
+        self.seed.as_deref()
+    }
+
+    /// Returns the cursor after the row, whose sort keys of the given kinds
+    /// were selected by `SortKey::add_to_query`.
+    pub fn from_row(
+        row: &Row,
+        sort_key_kinds: &[SortKeyKind],
+        seed: Option<String>,
+    ) -> Self {
+        Self {
+            keys: sort_key_kinds
+                .iter()
+                .enumerate()
+                .map(|(i, kind)| {
+                    CursorValue::from_row(row, &SortKey::get_alias(i), *kind)
+                })
+                .collect(),
+            seed,
+        }
+    }
+
+    /// Condition matching the rows after the cursor in the order of the sort
+    /// keys, which have to be the keys the cursor was created with.
+    pub fn get_condition(
+        &self,
+        sort_keys: &[SortKey],
+    ) -> Result<Condition, DbError> {
+        if self.keys.len() != sort_keys.len() {
+            return Err(DbError::Argument("pagination.cursor"));
+        }
+
+        // rows are after the cursor if all keys before are equal and the
+        // key is after the value of the cursor
+        let mut condition = Condition::any();
+        let mut equal = Condition::all();
+
+        for (value, key) in self.keys.iter().zip(sort_keys) {
+            let expr = Expr::expr(key.expr.clone());
+
+            match value.to_expr(key)? {
+                Some(value) => {
+                    let after = match key.order {
+                        Order::Desc => expr.clone().lt(value.clone()),
+                        _ => expr.clone().gt(value.clone()),
+                    };
+                    let after = if key.nullable {
+                        any![after, expr.clone().is_null()]
+                    } else {
+                        after.into_condition()
+                    };
+                    condition = condition.add(equal.clone().add(after));
+                    equal = equal.add(expr.eq(value));
+                }
+                // rows without a value are last, so only rows without a
+                // value can follow
+                None => equal = equal.add(expr.is_null()),
+            }
+        }
+
+        Ok(condition)
+    }
+}
+
 pub fn get_type_from_oid<'a, T>(
     oid: i32,
 ) -> Result<Type, Box<dyn std::error::Error + Sync + Send>>
@@ -189,3 +683,28 @@ where
 pub fn get_count_from_rows(rows: &[Row]) -> i64 {
     rows.first().map(|row| row.get("count")).unwrap_or(0)
 }
+
+const TOTAL_COUNT_ALIAS: &str = "total_count";
+
+/// Selects the count of all rows of the query on every row, regardless of
+/// limit and offset.
+pub fn add_total_count(query: &mut SelectStatement) {
+    query.expr_as(
+        Expr::cust("COUNT(*) OVER ()"),
+        Alias::new(TOTAL_COUNT_ALIAS),
+    );
+}
+
+/// Returns the count selected by `add_total_count`, which is missing if the
+/// query returned no rows.
+pub fn get_total_count_from_rows(rows: &[Row]) -> Option<i64> {
+    rows.first().map(|row| row.get(TOTAL_COUNT_ALIAS))
+}
+
+/// Counts the rows of the query as `count`.
+pub fn select_count(query: SelectStatement) -> SelectStatement {
+    Query::select()
+        .expr_as(Expr::col(Asterisk).count(), Alias::new("count"))
+        .from_subquery(query, Alias::new("list"))
+        .to_owned()
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use crate::api::sited_io::commerce::v1::Currency;
+use crate::currency::cents_to_minor_units;
+
+/// Platform fee breakdown of a payment in the minor unit of its currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    pub gross_amount: u32,
+    pub platform_fee_amount: u32,
+    pub net_amount: u32,
+}
+
+impl FeeBreakdown {
+    /// Calculates the fees for `quantity` units of `unit_amount` plus the
+    /// shipping amount. Returns `None` if the gross amount does not fit into
+    /// an `u32`.
+    pub fn calculate(
+        unit_amount: u32,
+        quantity: u32,
+        shipping_amount: u32,
+        platform_fee_percent: u32,
+        minimum_platform_fee: u32,
+    ) -> Option<Self> {
+        let gross_amount = u64::from(unit_amount) * u64::from(quantity)
+            + u64::from(shipping_amount);
+
+        Some(Self::from_gross_amount(
+            u32::try_from(gross_amount).ok()?,
+            platform_fee_percent,
+            minimum_platform_fee,
+        ))
+    }
+
+    /// The platform fee is the larger of `platform_fee_percent` of the gross
+    /// amount and `minimum_platform_fee`, both in the minor unit of the
+    /// currency of the payment. The percentage is rounded half up to whole
+    /// minor units, so 2.5 cents become 3 cents and 2.4 cents become 2 cents.
+    /// The fee never exceeds the gross amount, so the seller's net amount
+    /// cannot become negative.
+    pub fn from_gross_amount(
+        gross_amount: u32,
+        platform_fee_percent: u32,
+        minimum_platform_fee: u32,
+    ) -> Self {
+        let percent_fee =
+            (u64::from(gross_amount) * u64::from(platform_fee_percent) + 50)
+                / 100;
+
+        let platform_fee_amount = percent_fee
+            .max(u64::from(minimum_platform_fee))
+            .min(u64::from(gross_amount));
+
+        // cannot fail because the fee is capped at the gross amount
+        let platform_fee_amount = u32::try_from(platform_fee_amount)
+            .expect("Should not be greater than gross amount");
+
+        Self {
+            gross_amount,
+            platform_fee_amount,
+            net_amount: gross_amount - platform_fee_amount,
+        }
+    }
+}
+
+/// Minimum platform fees the platform requires shops to charge, in the minor
+/// unit of each currency. Currencies without a configured minimum require the
+/// EUR minimum converted to their minor unit.
+#[derive(Debug, Clone)]
+pub struct AllowedMinimumPlatformFees {
+    eur_cent: u32,
+    per_currency: HashMap<Currency, u32>,
+}
+
+impl AllowedMinimumPlatformFees {
+    pub fn new(eur_cent: u32, per_currency: HashMap<Currency, u32>) -> Self {
+        Self {
+            eur_cent,
+            per_currency,
+        }
+    }
+
+    /// Parses comma separated `<code>:<amount>` pairs like `USD:50,JPY:80`,
+    /// where the amount is given in the minor unit of the currency.
+    pub fn parse_per_currency(
+        s: &str,
+    ) -> Result<HashMap<Currency, u32>, String> {
+        s.split(',')
+            .filter(|pair| !pair.trim().is_empty())
+            .map(|pair| {
+                let (code, amount) = pair
+                    .split_once(':')
+                    .ok_or_else(|| format!("invalid minimum fee '{pair}'"))?;
+                let currency = Currency::from_str_name(&format!(
+                    "CURRENCY_{}",
+                    code.trim().to_uppercase()
+                ))
+                .filter(|c| !matches!(c, Currency::Unspecified | Currency::Eur))
+                .ok_or_else(|| format!("invalid currency '{code}'"))?;
+                let amount = amount
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid amount '{amount}'"))?;
+
+                Ok((currency, amount))
+            })
+            .collect()
+    }
+
+    pub fn get(&self, currency: Currency) -> u32 {
+        match currency {
+            Currency::Unspecified | Currency::Eur => self.eur_cent,
+            currency => self
+                .per_currency
+                .get(&currency)
+                .copied()
+                .unwrap_or_else(|| {
+                    cents_to_minor_units(self.eur_cent, currency)
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_percentage_of_gross_amount() {
+        assert_eq!(
+            FeeBreakdown::from_gross_amount(1000, 5, 0),
+            FeeBreakdown {
+                gross_amount: 1000,
+                platform_fee_amount: 50,
+                net_amount: 950,
+            }
+        );
+    }
+
+    #[test]
+    fn rounds_percentage_half_up() {
+        // 2.5 cents
+        assert_eq!(
+            FeeBreakdown::from_gross_amount(50, 5, 0).platform_fee_amount,
+            3
+        );
+        // 2.45 cents
+        assert_eq!(
+            FeeBreakdown::from_gross_amount(49, 5, 0).platform_fee_amount,
+            2
+        );
+    }
+
+    #[test]
+    fn charges_at_least_minimum_fee() {
+        assert_eq!(
+            FeeBreakdown::from_gross_amount(1000, 5, 100).platform_fee_amount,
+            100
+        );
+    }
+
+    #[test]
+    fn caps_fee_at_gross_amount() {
+        assert_eq!(
+            FeeBreakdown::from_gross_amount(80, 5, 100),
+            FeeBreakdown {
+                gross_amount: 80,
+                platform_fee_amount: 80,
+                net_amount: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn calculates_gross_amount_with_shipping() {
+        assert_eq!(
+            FeeBreakdown::calculate(500, 2, 300, 5, 0),
+            Some(FeeBreakdown {
+                gross_amount: 1300,
+                platform_fee_amount: 65,
+                net_amount: 1235,
+            })
+        );
+    }
+
+    #[test]
+    fn fails_if_gross_amount_overflows() {
+        assert_eq!(FeeBreakdown::calculate(u32::MAX, 2, 0, 5, 0), None);
+    }
+
+    #[test]
+    fn parses_minimum_fees_per_currency() {
+        let fees =
+            AllowedMinimumPlatformFees::parse_per_currency("USD:50, jpy:80,")
+                .unwrap();
+
+        assert_eq!(fees.len(), 2);
+        assert_eq!(fees.get(&Currency::Usd), Some(&50));
+        assert_eq!(fees.get(&Currency::Jpy), Some(&80));
+    }
+
+    #[test]
+    fn rejects_invalid_minimum_fees() {
+        for s in ["USD", "EUR:50", "XYZ:50", "USD:-1"] {
+            assert!(AllowedMinimumPlatformFees::parse_per_currency(s).is_err());
+        }
+    }
+
+    #[test]
+    fn converts_eur_minimum_fee_for_unconfigured_currencies() {
+        let fees = AllowedMinimumPlatformFees::new(
+            50,
+            HashMap::from([(Currency::Usd, 60)]),
+        );
+
+        assert_eq!(fees.get(Currency::Eur), 50);
+        assert_eq!(fees.get(Currency::Usd), 60);
+        assert_eq!(fees.get(Currency::Jpy), 1);
+    }
+}
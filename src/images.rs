@@ -1,43 +1,177 @@
+use std::str::FromStr;
+
 use aws_credential_types::Credentials;
 use aws_sdk_s3::config::Region;
 use aws_sdk_s3::primitives::ByteStream;
 use aws_sdk_s3::Client;
+use image::imageops::FilterType;
+use image::DynamicImage;
+use sea_query::SimpleExpr;
 use tonic::Status;
 
+use crate::api::sited_io::commerce::v1::ImageVariantResponse;
+use crate::db::get_array_expr;
+
+/// Widths in pixels of the resized variants stored next to every image.
+pub const DEFAULT_VARIANT_WIDTHS: [u32; 4] = [160, 480, 1024, 2048];
+
+const WEBP_CONTENT_TYPE: &str = "image/webp";
+const AVIF_CONTENT_TYPE: &str = "image/avif";
+
+/// Speed of the AVIF encoder from 1 to 10, trading size for encoding time.
+const AVIF_SPEED: u8 = 8;
+
+/// Key, content type and data of an object stored in the bucket.
+type ImageObject = (String, &'static str, Vec<u8>);
+
+/// Kinds of images, which can be encoded differently.
+#[derive(Debug, Clone, Copy)]
+pub enum ImageKind {
+    Offer,
+    Logo,
+    Banner,
+}
+
+/// Encoding of images. Lossy images are encoded at a quality from 1 to 100.
+#[derive(Debug, Clone, Copy)]
+pub enum ImageEncoding {
+    Lossless,
+    Lossy(f32),
+}
+
+impl FromStr for ImageEncoding {
+    type Err = String;
+
+    /// Parses `lossless` or `lossy:<quality>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().split_once(':') {
+            None if s.trim() == "lossless" => Ok(Self::Lossless),
+            Some(("lossy", quality)) => quality
+                .trim()
+                .parse()
+                .ok()
+                .filter(|q| (1.0..=100.0).contains(q))
+                .map(Self::Lossy)
+                .ok_or_else(|| format!("invalid image quality '{quality}'")),
+            _ => Err(format!("invalid image encoding '{s}'")),
+        }
+    }
+}
+
+/// How uploaded images are encoded and stored.
+#[derive(Debug, Clone)]
+pub struct ImageEncodingPolicy {
+    pub offer: ImageEncoding,
+    pub logo: ImageEncoding,
+    pub banner: ImageEncoding,
+    /// Whether AVIF variants are stored next to the WebP variants.
+    pub avif: bool,
+    /// Whether uploaded images are kept, so that they can be encoded again
+    /// when the policy changes.
+    pub keep_original: bool,
+}
+
+impl Default for ImageEncodingPolicy {
+    fn default() -> Self {
+        Self {
+            offer: ImageEncoding::Lossy(80.0),
+            logo: ImageEncoding::Lossless,
+            banner: ImageEncoding::Lossy(85.0),
+            avif: true,
+            keep_original: false,
+        }
+    }
+}
+
+impl ImageEncodingPolicy {
+    fn get_encoding(&self, kind: ImageKind) -> ImageEncoding {
+        match kind {
+            ImageKind::Offer => self.offer,
+            ImageKind::Logo => self.logo,
+            ImageKind::Banner => self.banner,
+        }
+    }
+}
+
+/// Widths and content types of the resized variants stored for an image.
+/// Images stored before their variants were recorded have none.
+#[derive(Debug, Clone, Default)]
+pub struct StoredImageVariants {
+    pub widths: Vec<u32>,
+    pub content_types: Vec<String>,
+}
+
+impl StoredImageVariants {
+    /// Reads the variants from the array columns they are recorded in.
+    pub fn from_columns(widths: Vec<i64>, content_types: Vec<String>) -> Self {
+        Self {
+            widths: widths
+                .into_iter()
+                .map(|w| {
+                    u32::try_from(w)
+                        .expect("Should not be greater than 4294967295")
+                })
+                .collect(),
+            content_types,
+        }
+    }
+
+    pub fn get_widths_expr(&self) -> SimpleExpr {
+        get_array_expr(self.widths.iter().map(|w| i64::from(*w)), "INT[]")
+    }
+
+    pub fn get_content_types_expr(&self) -> SimpleExpr {
+        get_array_expr(self.content_types.iter().cloned(), "VARCHAR[]")
+    }
+}
+
+/// Bucket, variant and encoding settings of the image service.
+#[derive(Debug, Clone)]
+pub struct ImageServiceConfig {
+    pub bucket_name: String,
+    pub bucket_endpoint: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub base_url: String,
+    pub max_size: usize,
+    pub variant_widths: Vec<u32>,
+    pub encoding_policy: ImageEncodingPolicy,
+}
+
 #[derive(Debug, Clone)]
 pub struct ImageService {
     client: Client,
     bucket_name: String,
     base_url: String,
     max_size: usize,
+    variant_widths: Vec<u32>,
+    encoding_policy: ImageEncodingPolicy,
 }
 
 impl ImageService {
-    pub async fn new(
-        bucket_name: String,
-        bucket_endpoint: String,
-        access_key_id: String,
-        secret_access_key: String,
-        base_url: String,
-        max_size: usize,
-    ) -> Self {
-        let credentials =
-            Credentials::from_keys(access_key_id, secret_access_key, None);
-
-        let config = aws_config::from_env()
+    pub async fn new(config: ImageServiceConfig) -> Self {
+        let credentials = Credentials::from_keys(
+            config.access_key_id,
+            config.secret_access_key,
+            None,
+        );
+
+        let aws_config = aws_config::from_env()
             .credentials_provider(credentials)
             .region(Region::new("auto"))
-            .endpoint_url(bucket_endpoint)
+            .endpoint_url(config.bucket_endpoint)
             .load()
             .await;
 
-        let client = Client::new(&config);
+        let client = Client::new(&aws_config);
 
         Self {
             client,
-            bucket_name,
-            base_url,
-            max_size,
+            bucket_name: config.bucket_name,
+            base_url: config.base_url,
+            max_size: config.max_size,
+            variant_widths: config.variant_widths,
+            encoding_policy: config.encoding_policy,
         }
     }
 
@@ -52,6 +186,58 @@ impl ImageService {
         image_path.map(|p| self.get_image_url(&p))
     }
 
+    fn get_variant_path(
+        image_path: &str,
+        width: u32,
+        content_type: &str,
+    ) -> String {
+        match content_type {
+            AVIF_CONTENT_TYPE => format!("{image_path}_{width}w.avif"),
+            _ => format!("{image_path}_{width}w"),
+        }
+    }
+
+    fn get_original_path(image_path: &str) -> String {
+        format!("{image_path}_original")
+    }
+
+    /// Returns the URLs of the stored variants of the image, from which
+    /// clients can build a `srcset` per content type.
+    pub fn get_image_variants(
+        &self,
+        image_path: &str,
+        variants: &StoredImageVariants,
+    ) -> Vec<ImageVariantResponse> {
+        variants
+            .content_types
+            .iter()
+            .flat_map(|content_type| {
+                variants
+                    .widths
+                    .iter()
+                    .map(move |width| ImageVariantResponse {
+                        image_url: self.get_image_url(&Self::get_variant_path(
+                            image_path,
+                            *width,
+                            content_type,
+                        )),
+                        width: *width,
+                        content_type: content_type.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    pub fn get_opt_image_variants(
+        &self,
+        image_path: Option<&String>,
+        variants: &StoredImageVariants,
+    ) -> Vec<ImageVariantResponse> {
+        image_path
+            .map(|p| self.get_image_variants(p, variants))
+            .unwrap_or_default()
+    }
+
     pub fn validate_image(&self, image_data: &[u8]) -> Result<(), Status> {
         if image_data.len() > self.max_size {
             return Err(Status::resource_exhausted(format!(
@@ -73,29 +259,73 @@ impl ImageService {
         Ok(())
     }
 
-    pub async fn put_image(
-        &self,
-        image_path: &String,
-        image_data: &[u8],
-    ) -> Result<(), Status> {
-        let img = image::load_from_memory(image_data).map_err(|err| {
-            tracing::log::error!("[ImageService.put_image]: {err}");
-            Status::internal("image")
-        })?;
-        let encoder = webp::Encoder::from_image(&img).map_err(|err| {
+    fn encode_webp(
+        img: &DynamicImage,
+        encoding: ImageEncoding,
+    ) -> Result<Vec<u8>, Status> {
+        let encoder = webp::Encoder::from_image(img).map_err(|err| {
             tracing::log::error!("[ImageService.put_image]: {err}");
             Status::invalid_argument(format!(
                 "Could not convert to 'webp': {err}"
             ))
         })?;
-        let img_webp = encoder.encode_lossless().to_owned();
 
+        let img_webp = match encoding {
+            ImageEncoding::Lossless => encoder.encode_lossless(),
+            ImageEncoding::Lossy(quality) => encoder.encode(quality),
+        };
+
+        Ok(img_webp.to_owned())
+    }
+
+    /// Encodes the image as AVIF, which has no lossless mode, so lossless
+    /// images are encoded at the highest quality.
+    fn encode_avif(
+        img: &DynamicImage,
+        encoding: ImageEncoding,
+    ) -> Result<Vec<u8>, Status> {
+        let quality = match encoding {
+            ImageEncoding::Lossless => 100.0,
+            ImageEncoding::Lossy(quality) => quality,
+        };
+
+        let img = img.to_rgba8();
+        let pixels: Vec<ravif::RGBA8> = img
+            .pixels()
+            .map(|p| ravif::RGBA8::new(p[0], p[1], p[2], p[3]))
+            .collect();
+
+        let encoded = ravif::Encoder::new()
+            .with_quality(quality)
+            .with_alpha_quality(quality)
+            .with_speed(AVIF_SPEED)
+            .encode_rgba(ravif::Img::new(
+                pixels.as_slice(),
+                usize::try_from(img.width()).unwrap_or_default(),
+                usize::try_from(img.height()).unwrap_or_default(),
+            ))
+            .map_err(|err| {
+                tracing::log::error!("[ImageService.put_image]: {err}");
+                Status::invalid_argument(format!(
+                    "Could not convert to 'avif': {err}"
+                ))
+            })?;
+
+        Ok(encoded.avif_file)
+    }
+
+    async fn put_object(
+        &self,
+        key: &String,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Status> {
         self.client
             .put_object()
             .bucket(&self.bucket_name)
-            .key(image_path)
-            .content_type("image/webp")
-            .body(ByteStream::from(img_webp))
+            .key(key)
+            .content_type(content_type)
+            .body(ByteStream::from(data))
             .send()
             .await
             .map_err(|err| {
@@ -106,14 +336,131 @@ impl ImageService {
         Ok(())
     }
 
-    pub async fn remove_image(
+    /// Decodes the image and encodes it together with its resized variants
+    /// by the policy of the kind of image, returning the objects to store.
+    fn encode_objects(
+        image_path: &str,
+        image_data: &[u8],
+        kind: ImageKind,
+        variant_widths: &[u32],
+        policy: &ImageEncodingPolicy,
+    ) -> Result<Vec<ImageObject>, Status> {
+        let img = image::load_from_memory(image_data).map_err(|err| {
+            tracing::log::error!("[ImageService.put_image]: {err}");
+            Status::internal("image")
+        })?;
+        let encoding = policy.get_encoding(kind);
+        let img_webp = Self::encode_webp(&img, encoding)?;
+        let mut img_avif = None;
+
+        let mut objects = Vec::with_capacity(variant_widths.len() * 2 + 2);
+
+        if policy.keep_original {
+            let content_type = infer::get(image_data)
+                .map(|t| t.mime_type())
+                .unwrap_or("application/octet-stream");
+            objects.push((
+                Self::get_original_path(image_path),
+                content_type,
+                image_data.to_vec(),
+            ));
+        }
+
+        for width in variant_widths.iter() {
+            // images are not scaled up, so variants at least as wide as the
+            // image are the image itself
+            let variant = (*width < img.width())
+                .then(|| img.resize(*width, u32::MAX, FilterType::CatmullRom));
+
+            let variant_webp = match variant.as_ref() {
+                Some(variant) => Self::encode_webp(variant, encoding)?,
+                None => img_webp.clone(),
+            };
+            objects.push((
+                Self::get_variant_path(image_path, *width, WEBP_CONTENT_TYPE),
+                WEBP_CONTENT_TYPE,
+                variant_webp,
+            ));
+
+            if policy.avif {
+                let variant_avif = match variant.as_ref() {
+                    Some(variant) => Self::encode_avif(variant, encoding)?,
+                    // the image itself is only encoded once
+                    None => {
+                        if img_avif.is_none() {
+                            img_avif = Some(Self::encode_avif(&img, encoding)?);
+                        }
+                        img_avif.clone().unwrap_or_default()
+                    }
+                };
+                objects.push((
+                    Self::get_variant_path(
+                        image_path,
+                        *width,
+                        AVIF_CONTENT_TYPE,
+                    ),
+                    AVIF_CONTENT_TYPE,
+                    variant_avif,
+                ));
+            }
+        }
+        objects.push((image_path.to_string(), WEBP_CONTENT_TYPE, img_webp));
+
+        Ok(objects)
+    }
+
+    /// Stores the image together with its resized variants, encoded by the
+    /// policy of the kind of image, and returns the stored variants. Images
+    /// are encoded on the blocking thread pool, so that encoding does not
+    /// stall other requests.
+    pub async fn put_image(
         &self,
-        image_path: &String,
-    ) -> Result<(), Status> {
+        image_path: &str,
+        image_data: &[u8],
+        kind: ImageKind,
+    ) -> Result<StoredImageVariants, Status> {
+        let objects = {
+            let image_path = image_path.to_owned();
+            let image_data = image_data.to_vec();
+            let variant_widths = self.variant_widths.clone();
+            let policy = self.encoding_policy.clone();
+
+            tokio::task::spawn_blocking(move || {
+                Self::encode_objects(
+                    &image_path,
+                    &image_data,
+                    kind,
+                    &variant_widths,
+                    &policy,
+                )
+            })
+            .await
+            .map_err(|err| {
+                tracing::log::error!("[ImageService.put_image]: {err}");
+                Status::internal("image")
+            })??
+        };
+
+        for (key, content_type, data) in objects {
+            self.put_object(&key, content_type, data).await?;
+        }
+
+        let mut content_types = vec![WEBP_CONTENT_TYPE.to_string()];
+        if self.encoding_policy.avif {
+            content_types.push(AVIF_CONTENT_TYPE.to_string());
+        }
+
+        Ok(StoredImageVariants {
+            widths: self.variant_widths.clone(),
+            content_types,
+        })
+    }
+
+    async fn delete_object(&self, key: &String) -> Result<(), Status> {
         self.client
             .delete_object()
             .bucket(&self.bucket_name)
-            .key(image_path)
+            .key(key)
             .send()
             .await
             .map_err(|err| {
@@ -123,4 +470,29 @@ impl ImageService {
 
         Ok(())
     }
+
+    /// Removes the image together with its stored variants and the kept
+    /// upload, which is ignored by the bucket if it was not kept.
+    pub async fn remove_image(
+        &self,
+        image_path: &String,
+        variants: &StoredImageVariants,
+    ) -> Result<(), Status> {
+        self.delete_object(image_path).await?;
+        self.delete_object(&Self::get_original_path(image_path))
+            .await?;
+
+        for content_type in variants.content_types.iter() {
+            for width in variants.widths.iter() {
+                self.delete_object(&Self::get_variant_path(
+                    image_path,
+                    *width,
+                    content_type,
+                ))
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
 }
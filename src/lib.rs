@@ -1,14 +1,20 @@
 pub mod api;
 mod auth;
+mod currency;
 pub mod db;
+mod fees;
 pub mod images;
 pub mod logging;
 mod model;
+mod pricing;
 mod publisher;
+pub mod schedulers;
 mod services;
 pub mod subscribers;
+mod tax;
 
 pub use auth::init_jwks_verifier;
+pub use fees::AllowedMinimumPlatformFees;
 pub use publisher::Publisher;
 pub use services::*;
 
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use http::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 use http::{HeaderName, Method};
 use tonic::transport::Server;
@@ -7,13 +9,19 @@ use tower_http::trace::TraceLayer;
 use commerce::api::sited_io::commerce::v1::offer_service_server::OfferServiceServer;
 use commerce::api::sited_io::commerce::v1::shop_service_server::ShopServiceServer;
 use commerce::db::{init_db_pool, migrate};
-use commerce::images::ImageService;
+use commerce::images::{
+    ImageEncodingPolicy, ImageService, ImageServiceConfig,
+    DEFAULT_VARIANT_WIDTHS,
+};
 use commerce::logging::{LogOnFailure, LogOnRequest, LogOnResponse};
+use commerce::schedulers::SalesScheduler;
 use commerce::subscribers::WebsitesSubscriber;
 use commerce::{
-    get_env_var, init_jwks_verifier, OfferService, Publisher,
+    get_env_var, init_jwks_verifier, AllowedMinimumPlatformFees, CartService,
+    CategoryService, CollectionService, DiscountService, InventoryService,
+    OfferService, OrderService, Publisher, ShippingProfileService,
     ShippingRateService, ShopCustomizationService, ShopDomainService,
-    ShopService,
+    ShopService, TaxService,
 };
 
 #[tokio::main(flavor = "current_thread")]
@@ -35,6 +43,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         get_env_var("ALLOWED_MIN_MINIMUM_PLATFORM_FEE_CENT")
             .parse()
             .unwrap();
+    // minimum platform fees of currencies other than EUR, defaulting to the
+    // EUR minimum in the minor unit of the currency
+    let allowed_minimum_platform_fees = AllowedMinimumPlatformFees::new(
+        allowed_min_minimum_platform_fee_cent,
+        std::env::var("ALLOWED_MIN_MINIMUM_PLATFORM_FEES")
+            .map(|fees| {
+                AllowedMinimumPlatformFees::parse_per_currency(&fees).unwrap()
+            })
+            .unwrap_or_default(),
+    );
 
     // initialize database connection and migrate
     let db_pool = init_db_pool(
@@ -47,15 +65,44 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     )?;
     migrate(&db_pool).await?;
 
+    // images are encoded by the default policy unless configured otherwise
+    let default_policy = ImageEncodingPolicy::default();
+    let image_encoding_policy = ImageEncodingPolicy {
+        offer: std::env::var("IMAGE_ENCODING_OFFER")
+            .map(|e| e.parse().unwrap())
+            .unwrap_or(default_policy.offer),
+        logo: std::env::var("IMAGE_ENCODING_LOGO")
+            .map(|e| e.parse().unwrap())
+            .unwrap_or(default_policy.logo),
+        banner: std::env::var("IMAGE_ENCODING_BANNER")
+            .map(|e| e.parse().unwrap())
+            .unwrap_or(default_policy.banner),
+        avif: std::env::var("IMAGE_AVIF")
+            .map(|a| a.parse().unwrap())
+            .unwrap_or(default_policy.avif),
+        keep_original: std::env::var("IMAGE_KEEP_ORIGINAL")
+            .map(|k| k.parse().unwrap())
+            .unwrap_or(default_policy.keep_original),
+    };
+
     // initialize s3 bucket
-    let image_service = ImageService::new(
-        get_env_var("BUCKET_NAME"),
-        get_env_var("BUCKET_ENDPOINT"),
-        get_env_var("BUCKET_ACCESS_KEY_ID"),
-        get_env_var("BUCKET_SECRET_ACCESS_KEY"),
-        get_env_var("BUCKET_URL"),
-        get_env_var("IMAGE_MAX_SIZE").parse().unwrap(),
-    )
+    let image_service = ImageService::new(ImageServiceConfig {
+        bucket_name: get_env_var("BUCKET_NAME"),
+        bucket_endpoint: get_env_var("BUCKET_ENDPOINT"),
+        access_key_id: get_env_var("BUCKET_ACCESS_KEY_ID"),
+        secret_access_key: get_env_var("BUCKET_SECRET_ACCESS_KEY"),
+        base_url: get_env_var("BUCKET_URL"),
+        max_size: get_env_var("IMAGE_MAX_SIZE").parse().unwrap(),
+        variant_widths: std::env::var("IMAGE_VARIANT_WIDTHS")
+            .map(|widths| {
+                widths
+                    .split(',')
+                    .map(|w| w.trim().parse().unwrap())
+                    .collect()
+            })
+            .unwrap_or_else(|_| DEFAULT_VARIANT_WIDTHS.to_vec()),
+        encoding_policy: image_encoding_policy,
+    })
     .await;
 
     // initialize NATS client
@@ -78,13 +125,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // initialize publisher
     let publisher = Publisher::new(nats_client);
 
+    // initialize scheduler publishing offers when their sales start or end
+    let sales_scheduler = SalesScheduler::new(
+        db_pool.clone(),
+        publisher.clone(),
+        image_service.clone(),
+        Duration::from_secs(60),
+    );
+
     let shop_service = ShopService::build(
         db_pool.clone(),
         init_jwks_verifier(&jwks_host, &jwks_url)?,
         publisher.clone(),
         image_service.clone(),
         allowed_min_platform_fee_percent,
-        allowed_min_minimum_platform_fee_cent,
+        allowed_minimum_platform_fees.clone(),
     );
 
     let shop_customization_service = ShopCustomizationService::build(
@@ -102,13 +157,58 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         db_pool.clone(),
         init_jwks_verifier(&jwks_host, &jwks_url)?,
         publisher.clone(),
-        image_service,
+        image_service.clone(),
+    );
+
+    let category_service = CategoryService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
+    );
+
+    let collection_service = CollectionService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
     );
 
     let shipping_rate_service = ShippingRateService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
+        publisher.clone(),
+    );
+
+    let shipping_profile_service = ShippingProfileService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
+    );
+
+    let inventory_service = InventoryService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
+        publisher.clone(),
+        image_service.clone(),
+    );
+
+    let cart_service = CartService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
+    );
+
+    let discount_service = DiscountService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
+    );
+
+    let tax_service = TaxService::build(
+        db_pool.clone(),
+        init_jwks_verifier(&jwks_host, &jwks_url)?,
+    );
+
+    let order_service = OrderService::build(
         db_pool,
         init_jwks_verifier(&jwks_host, &jwks_url)?,
         publisher.clone(),
+        image_service,
+        allowed_minimum_platform_fees,
     );
 
     // configure gRPC health reporter
@@ -138,6 +238,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         tokio::spawn(async move {
             website_subscriber.subscribe().await;
         }),
+        tokio::spawn(async move {
+            sales_scheduler.run().await;
+        }),
         Server::builder()
             .layer(
                 TraceLayer::new_for_grpc()
@@ -167,12 +270,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .add_service(tonic_web::enable(shop_customization_service))
             .add_service(tonic_web::enable(shop_domain_service))
             .add_service(tonic_web::enable(offer_service))
+            .add_service(tonic_web::enable(category_service))
+            .add_service(tonic_web::enable(collection_service))
             .add_service(tonic_web::enable(shipping_rate_service))
+            .add_service(tonic_web::enable(shipping_profile_service))
+            .add_service(tonic_web::enable(inventory_service))
+            .add_service(tonic_web::enable(cart_service))
+            .add_service(tonic_web::enable(discount_service))
+            .add_service(tonic_web::enable(tax_service))
+            .add_service(tonic_web::enable(order_service))
             .serve(host.parse().unwrap())
     );
 
     res.0?;
     res.1?;
+    res.2?;
 
     publisher.flush().await?;
 
@@ -0,0 +1,255 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{Asterisk, Expr, Iden, LockType, PostgresQueryBuilder, Query};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::DbError;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "carts")]
+pub enum CartIden {
+    Table,
+    CartId,
+    ShopId,
+    UserId,
+    CreatedAt,
+    UpdatedAt,
+    ExpiresAt,
+    DiscountId,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cart {
+    pub cart_id: Uuid,
+    pub shop_id: Uuid,
+    pub user_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub discount_id: Option<Uuid>,
+}
+
+impl Cart {
+    /// `expires_at` is set by the clock of the service, so carts read from
+    /// the database are checked against it as well. Expired carts are
+    /// treated as not found until `delete_expired` removes them.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub async fn create(
+        pool: &Pool,
+        shop_id: &Uuid,
+        user_id: Option<&String>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(CartIden::Table)
+            .columns([CartIden::ShopId, CartIden::UserId, CartIden::ExpiresAt])
+            .values([
+                (*shop_id).into(),
+                user_id.cloned().into(),
+                expires_at.into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    /// Returns the cart if it has not expired yet.
+    pub async fn get(
+        pool: &Pool,
+        cart_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CartIden::Table)
+            .and_where(Expr::col(CartIden::CartId).eq(*cart_id))
+            .and_where(
+                Expr::col(CartIden::ExpiresAt).gt(Expr::current_timestamp()),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from).filter(|c| !c.is_expired(Utc::now())))
+    }
+
+    /// Returns the unexpired cart of the user for the given shop.
+    pub async fn get_for_user(
+        pool: &Pool,
+        shop_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CartIden::Table)
+            .and_where(Expr::col(CartIden::ShopId).eq(*shop_id))
+            .and_where(Expr::col(CartIden::UserId).eq(user_id))
+            .and_where(
+                Expr::col(CartIden::ExpiresAt).gt(Expr::current_timestamp()),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from).filter(|c| !c.is_expired(Utc::now())))
+    }
+
+    /// Locks the unexpired cart row until the transaction ends, so
+    /// concurrent changes to its items are serialized.
+    pub async fn get_for_update<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CartIden::Table)
+            .and_where(Expr::col(CartIden::CartId).eq(*cart_id))
+            .and_where(
+                Expr::col(CartIden::ExpiresAt).gt(Expr::current_timestamp()),
+            )
+            .lock(LockType::Update)
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(Self::from).filter(|c| !c.is_expired(Utc::now())))
+    }
+
+    /// Extends the cart if it has not expired yet, an expired cart is not
+    /// revived by writes to it.
+    pub async fn update_expires_at<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<Self>, DbError> {
+        let (sql, values) = Query::update()
+            .table(CartIden::Table)
+            .value(CartIden::ExpiresAt, expires_at)
+            .and_where(Expr::col(CartIden::CartId).eq(*cart_id))
+            .and_where(
+                Expr::col(CartIden::ExpiresAt).gt(Expr::current_timestamp()),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Assigns an anonymous cart to a user.
+    pub async fn update_user_id<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+        user_id: &String,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::update()
+            .table(CartIden::Table)
+            .value(CartIden::UserId, user_id)
+            .value(CartIden::ExpiresAt, expires_at)
+            .and_where(Expr::col(CartIden::CartId).eq(*cart_id))
+            .and_where(Expr::col(CartIden::UserId).is_null())
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    /// Sets the discount code entered for the cart, `None` removes it.
+    pub async fn update_discount_id<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+        discount_id: Option<&Uuid>,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::update()
+            .table(CartIden::Table)
+            .value(CartIden::DiscountId, discount_id.copied())
+            .and_where(Expr::col(CartIden::CartId).eq(*cart_id))
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn delete<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(CartIden::Table)
+            .and_where(Expr::col(CartIden::CartId).eq(*cart_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes all expired carts of the shop together with their items.
+    pub async fn delete_expired(
+        pool: &Pool,
+        shop_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(CartIden::Table)
+            .and_where(Expr::col(CartIden::ShopId).eq(*shop_id))
+            .and_where(
+                Expr::col(CartIden::ExpiresAt).lte(Expr::current_timestamp()),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(())
+    }
+}
+
+impl From<&Row> for Cart {
+    fn from(row: &Row) -> Self {
+        Self {
+            cart_id: row.get(CartIden::CartId.to_string().as_str()),
+            shop_id: row.get(CartIden::ShopId.to_string().as_str()),
+            user_id: row.get(CartIden::UserId.to_string().as_str()),
+            created_at: row.get(CartIden::CreatedAt.to_string().as_str()),
+            updated_at: row.get(CartIden::UpdatedAt.to_string().as_str()),
+            expires_at: row.get(CartIden::ExpiresAt.to_string().as_str()),
+            discount_id: row.get(CartIden::DiscountId.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for Cart {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
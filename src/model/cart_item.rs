@@ -0,0 +1,207 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{
+    Asterisk, Expr, Iden, LockType, Order, PostgresQueryBuilder, Query,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::DbError;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "cart_items")]
+pub enum CartItemIden {
+    Table,
+    CartItemId,
+    CartId,
+    OfferId,
+    OfferVariantId,
+    CreatedAt,
+    Quantity,
+    Currency,
+    UnitAmount,
+    Amount,
+}
+
+#[derive(Debug, Clone)]
+pub struct CartItem {
+    pub cart_item_id: Uuid,
+    pub offer_id: Uuid,
+    pub offer_variant_id: Option<Uuid>,
+    pub quantity: u32,
+    pub currency: String,
+    pub unit_amount: u32,
+    pub amount: u32,
+}
+
+impl CartItem {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        quantity: u32,
+        currency: &str,
+        unit_amount: u32,
+        amount: u32,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(CartItemIden::Table)
+            .columns([
+                CartItemIden::CartId,
+                CartItemIden::OfferId,
+                CartItemIden::OfferVariantId,
+                CartItemIden::Quantity,
+                CartItemIden::Currency,
+                CartItemIden::UnitAmount,
+                CartItemIden::Amount,
+            ])
+            .values([
+                (*cart_id).into(),
+                (*offer_id).into(),
+                offer_variant_id.copied().into(),
+                i64::from(quantity).into(),
+                currency.into(),
+                i64::from(unit_amount).into(),
+                i64::from(amount).into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn list(
+        pool: &Pool,
+        cart_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CartItemIden::Table)
+            .and_where(Expr::col(CartItemIden::CartId).eq(*cart_id))
+            .order_by(CartItemIden::CreatedAt, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    pub async fn list_for_update<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CartItemIden::Table)
+            .and_where(Expr::col(CartItemIden::CartId).eq(*cart_id))
+            .lock(LockType::Update)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    pub async fn update_quantity<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+        cart_item_id: &Uuid,
+        quantity: u32,
+        unit_amount: u32,
+        amount: u32,
+    ) -> Result<Option<Self>, DbError> {
+        let (sql, values) = Query::update()
+            .table(CartItemIden::Table)
+            .value(CartItemIden::Quantity, i64::from(quantity))
+            .value(CartItemIden::UnitAmount, i64::from(unit_amount))
+            .value(CartItemIden::Amount, i64::from(amount))
+            .and_where(Expr::col(CartItemIden::CartId).eq(*cart_id))
+            .and_where(Expr::col(CartItemIden::CartItemId).eq(*cart_item_id))
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Moves an item from one cart to another, used when merging carts.
+    pub async fn update_cart_id<'a>(
+        transaction: &Transaction<'a>,
+        cart_item_id: &Uuid,
+        cart_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::update()
+            .table(CartItemIden::Table)
+            .value(CartItemIden::CartId, *cart_id)
+            .and_where(Expr::col(CartItemIden::CartItemId).eq(*cart_item_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete<'a>(
+        transaction: &Transaction<'a>,
+        cart_id: &Uuid,
+        cart_item_id: &Uuid,
+    ) -> Result<bool, DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(CartItemIden::Table)
+            .and_where(Expr::col(CartItemIden::CartId).eq(*cart_id))
+            .and_where(Expr::col(CartItemIden::CartItemId).eq(*cart_item_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let deleted = transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(deleted > 0)
+    }
+}
+
+impl From<&Row> for CartItem {
+    fn from(row: &Row) -> Self {
+        Self {
+            cart_item_id: row
+                .get(CartItemIden::CartItemId.to_string().as_str()),
+            offer_id: row.get(CartItemIden::OfferId.to_string().as_str()),
+            offer_variant_id: row
+                .get(CartItemIden::OfferVariantId.to_string().as_str()),
+            quantity: u32::try_from(
+                row.get::<&str, i64>(
+                    CartItemIden::Quantity.to_string().as_str(),
+                ),
+            )
+            .expect("Should not be greater than 4294967295"),
+            currency: row.get(CartItemIden::Currency.to_string().as_str()),
+            unit_amount: u32::try_from(row.get::<&str, i64>(
+                CartItemIden::UnitAmount.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            amount: u32::try_from(
+                row.get::<&str, i64>(CartItemIden::Amount.to_string().as_str()),
+            )
+            .expect("Should not be greater than 4294967295"),
+        }
+    }
+}
+
+impl From<Row> for CartItem {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
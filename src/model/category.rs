@@ -0,0 +1,450 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Pool;
+use sea_query::{
+    any, Alias, Asterisk, Expr, Func, Iden, OnConflict, Order,
+    PostgresQueryBuilder, Query, SimpleExpr,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::{Any, ArrayAgg, ArrayAppend, DbError};
+
+use super::offer::OfferIden;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "categories")]
+pub enum CategoryIden {
+    Table,
+    CategoryId,
+    ParentId,
+    AncestorIds,
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    Slug,
+    Ordering,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_categories")]
+pub enum OfferCategoryIden {
+    Table,
+    OfferId,
+    CategoryId,
+}
+
+#[derive(Debug, Clone)]
+pub struct Category {
+    pub category_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub ancestor_ids: Vec<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub name: String,
+    pub slug: String,
+    pub ordering: i64,
+}
+
+impl Category {
+    const PARENT_ALIAS: &'static str = "parent";
+
+    fn get_parent_alias() -> Alias {
+        Alias::new(Self::PARENT_ALIAS)
+    }
+
+    /// Selects the ancestors of the parent followed by the parent itself,
+    /// which are the ancestors of a child of it.
+    fn get_child_ancestor_ids_expr(parent_id: SimpleExpr) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAppend)
+                    .arg(Expr::col((
+                        Self::get_parent_alias(),
+                        CategoryIden::AncestorIds,
+                    )))
+                    .arg(Expr::col((
+                        Self::get_parent_alias(),
+                        CategoryIden::CategoryId,
+                    ))),
+            )
+            .from_as(CategoryIden::Table, Self::get_parent_alias())
+            .and_where(
+                Expr::col((Self::get_parent_alias(), CategoryIden::CategoryId))
+                    .eq(parent_id),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    /// Selects the categories of the offer in the given table.
+    pub fn get_offer_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg)
+                    .arg(Expr::col(OfferCategoryIden::CategoryId)),
+            )
+            .from(OfferCategoryIden::Table)
+            .and_where(
+                Expr::col((
+                    OfferCategoryIden::Table,
+                    OfferCategoryIden::OfferId,
+                ))
+                .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    /// Condition on offers that are in the given category or in one of its
+    /// descendants.
+    pub fn get_in_category_expr(category_id: SimpleExpr) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(Expr::val(1))
+            .from(OfferCategoryIden::Table)
+            .inner_join(
+                CategoryIden::Table,
+                Expr::col((CategoryIden::Table, CategoryIden::CategoryId))
+                    .equals((
+                        OfferCategoryIden::Table,
+                        OfferCategoryIden::CategoryId,
+                    )),
+            )
+            .and_where(
+                Expr::col((
+                    OfferCategoryIden::Table,
+                    OfferCategoryIden::OfferId,
+                ))
+                .equals((OfferIden::Table, OfferIden::OfferId)),
+            )
+            .cond_where(any![
+                Expr::col((CategoryIden::Table, CategoryIden::CategoryId))
+                    .eq(category_id.clone()),
+                Expr::expr(category_id).eq(Func::cust(Any).arg(Expr::col((
+                    CategoryIden::Table,
+                    CategoryIden::AncestorIds,
+                )))),
+            ]);
+
+        Expr::exists(query)
+    }
+
+    pub async fn create(
+        pool: &Pool,
+        parent_id: Option<&Uuid>,
+        name: &String,
+        slug: &String,
+        ordering: i64,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = {
+            let mut query = Query::insert();
+
+            query.into_table(CategoryIden::Table);
+
+            if let Some(parent_id) = parent_id {
+                query
+                    .columns([
+                        CategoryIden::ParentId,
+                        CategoryIden::AncestorIds,
+                        CategoryIden::Name,
+                        CategoryIden::Slug,
+                        CategoryIden::Ordering,
+                    ])
+                    .values([
+                        (*parent_id).into(),
+                        Self::get_child_ancestor_ids_expr((*parent_id).into()),
+                        name.into(),
+                        slug.into(),
+                        ordering.into(),
+                    ])?;
+            } else {
+                query
+                    .columns([
+                        CategoryIden::Name,
+                        CategoryIden::Slug,
+                        CategoryIden::Ordering,
+                    ])
+                    .values([name.into(), slug.into(), ordering.into()])?;
+            }
+
+            query.returning_all().build_postgres(PostgresQueryBuilder)
+        };
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        category_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CategoryIden::Table)
+            .and_where(Expr::col(CategoryIden::CategoryId).eq(*category_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn get_by_slug(
+        pool: &Pool,
+        slug: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CategoryIden::Table)
+            .and_where(Expr::col(CategoryIden::Slug).eq(slug))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Returns the children of the parent or, without a parent, all
+    /// categories.
+    pub async fn list(
+        pool: &Pool,
+        parent_id: Option<&Uuid>,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = {
+            let mut query = Query::select();
+
+            query.column(Asterisk).from(CategoryIden::Table);
+
+            if let Some(parent_id) = parent_id {
+                query.and_where(
+                    Expr::col(CategoryIden::ParentId).eq(*parent_id),
+                );
+            }
+
+            query
+                .order_by(CategoryIden::Ordering, Order::Asc)
+                .order_by(CategoryIden::Name, Order::Asc)
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    /// Updates the category. With `parent_id` set the category is moved
+    /// below the given parent, or to the root if that is `None`, and the
+    /// ancestors of its descendants are updated accordingly.
+    pub async fn update(
+        pool: &Pool,
+        category_id: &Uuid,
+        name: Option<String>,
+        slug: Option<String>,
+        ordering: Option<i64>,
+        parent_id: Option<Option<Uuid>>,
+    ) -> Result<Option<Self>, DbError> {
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
+
+        let (sql, values) = {
+            let mut query = Query::update();
+
+            query.table(CategoryIden::Table);
+
+            if let Some(name) = name {
+                query.value(CategoryIden::Name, name);
+            }
+
+            if let Some(slug) = slug {
+                query.value(CategoryIden::Slug, slug);
+            }
+
+            if let Some(ordering) = ordering {
+                query.value(CategoryIden::Ordering, ordering);
+            }
+
+            if let Some(parent_id) = parent_id {
+                query.value(CategoryIden::ParentId, parent_id);
+            }
+
+            query
+                .and_where(Expr::col(CategoryIden::CategoryId).eq(*category_id))
+                .returning_all()
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let Some(row) = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if parent_id.is_none() {
+            transaction.commit().await?;
+            return Ok(Some(Self::from(row)));
+        }
+
+        // descendants are updated after their parents, ordered by depth
+        let (sql, values) = Query::select()
+            .column(CategoryIden::CategoryId)
+            .from(CategoryIden::Table)
+            .and_where(
+                Expr::val(*category_id)
+                    .eq(Func::cust(Any)
+                        .arg(Expr::col(CategoryIden::AncestorIds))),
+            )
+            .order_by_expr(Expr::cust("CARDINALITY(ancestor_ids)"), Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        let mut category_ids = vec![*category_id];
+        category_ids.extend(rows.iter().map(|row| {
+            row.get::<&str, Uuid>(CategoryIden::CategoryId.to_string().as_str())
+        }));
+
+        for id in category_ids {
+            let (sql, values) = Query::update()
+                .table(CategoryIden::Table)
+                .value(
+                    CategoryIden::AncestorIds,
+                    Func::coalesce([
+                        Self::get_child_ancestor_ids_expr(
+                            Expr::col((
+                                CategoryIden::Table,
+                                CategoryIden::ParentId,
+                            ))
+                            .into(),
+                        ),
+                        Expr::cust("ARRAY[]::UUID[]"),
+                    ]),
+                )
+                .and_where(Expr::col(CategoryIden::CategoryId).eq(id))
+                .build_postgres(PostgresQueryBuilder);
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CategoryIden::Table)
+            .and_where(Expr::col(CategoryIden::CategoryId).eq(*category_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(Some(Self::from(row)))
+    }
+
+    /// Deletes the category together with its descendants.
+    pub async fn delete(
+        pool: &Pool,
+        category_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(CategoryIden::Table)
+            .and_where(Expr::col(CategoryIden::CategoryId).eq(*category_id))
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn add_offer(
+        pool: &Pool,
+        offer_id: &Uuid,
+        category_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(OfferCategoryIden::Table)
+            .columns([
+                OfferCategoryIden::OfferId,
+                OfferCategoryIden::CategoryId,
+            ])
+            .values([(*offer_id).into(), (*category_id).into()])?
+            .on_conflict(
+                OnConflict::columns([
+                    OfferCategoryIden::OfferId,
+                    OfferCategoryIden::CategoryId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(())
+    }
+
+    /// Returns `false` if the offer was not in the category.
+    pub async fn remove_offer(
+        pool: &Pool,
+        offer_id: &Uuid,
+        category_id: &Uuid,
+    ) -> Result<bool, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(OfferCategoryIden::Table)
+            .and_where(Expr::col(OfferCategoryIden::OfferId).eq(*offer_id))
+            .and_where(
+                Expr::col(OfferCategoryIden::CategoryId).eq(*category_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let count = conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(count > 0)
+    }
+}
+
+impl From<&Row> for Category {
+    fn from(row: &Row) -> Self {
+        Self {
+            category_id: row.get(CategoryIden::CategoryId.to_string().as_str()),
+            parent_id: row.get(CategoryIden::ParentId.to_string().as_str()),
+            ancestor_ids: row
+                .get(CategoryIden::AncestorIds.to_string().as_str()),
+            created_at: row.get(CategoryIden::CreatedAt.to_string().as_str()),
+            updated_at: row.get(CategoryIden::UpdatedAt.to_string().as_str()),
+            name: row.get(CategoryIden::Name.to_string().as_str()),
+            slug: row.get(CategoryIden::Slug.to_string().as_str()),
+            ordering: row.get(CategoryIden::Ordering.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for Category {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
@@ -0,0 +1,548 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Pool;
+use sea_query::{
+    all, any, Asterisk, Expr, Iden, OnConflict, Order, PostgresQueryBuilder,
+    Query, SimpleExpr,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::CollectionType;
+use crate::db::DbError;
+
+use super::category::Category;
+use super::offer::OfferIden;
+use super::offer_price::{OfferPriceAsRel, OfferPriceIden};
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "collections")]
+pub enum CollectionIden {
+    Table,
+    CollectionId,
+    ShopId,
+    UserId,
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    Slug,
+    Ordering,
+    CollectionType,
+    RuleOfferType,
+    RuleCategoryId,
+    RuleIsFeatured,
+    RuleCurrency,
+    RuleMinPrice,
+    RuleMaxPrice,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "collection_offers")]
+pub enum CollectionOfferIden {
+    Table,
+    CollectionId,
+    OfferId,
+    Ordering,
+}
+
+/// Conditions an offer has to meet to be in a rule-based collection. Unset
+/// conditions are ignored. Prices are in the given currency, with
+/// `min_price` inclusive and `max_price` exclusive.
+#[derive(Debug, Clone, Default)]
+pub struct CollectionRuleValues {
+    pub offer_type: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub is_featured: Option<bool>,
+    pub currency: Option<String>,
+    pub min_price: Option<u32>,
+    pub max_price: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Collection {
+    pub collection_id: Uuid,
+    pub shop_id: Uuid,
+    pub user_id: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub name: String,
+    pub slug: String,
+    pub ordering: i64,
+    pub collection_type: String,
+    pub rule: CollectionRuleValues,
+}
+
+/// Offer of a manual collection.
+#[derive(Debug, Clone)]
+pub struct CollectionOffer {
+    pub collection_id: Uuid,
+    pub offer_id: Uuid,
+    pub ordering: i64,
+}
+
+impl Collection {
+    /// Condition on offers that are in the collection, either added to it
+    /// if it is manual or meeting its rule otherwise.
+    pub fn get_in_collection_expr(collection_id: &Uuid) -> SimpleExpr {
+        let col = |c: CollectionIden| Expr::col((CollectionIden::Table, c));
+
+        let mut offers_query = Query::select();
+
+        offers_query
+            .expr(Expr::val(1))
+            .from(CollectionOfferIden::Table)
+            .and_where(
+                Expr::col((
+                    CollectionOfferIden::Table,
+                    CollectionOfferIden::CollectionId,
+                ))
+                .equals((CollectionIden::Table, CollectionIden::CollectionId)),
+            )
+            .and_where(
+                Expr::col((
+                    CollectionOfferIden::Table,
+                    CollectionOfferIden::OfferId,
+                ))
+                .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        let unit_amount =
+            || Expr::expr(OfferPriceAsRel::get_charged_unit_amount_expr());
+
+        let mut prices_query = Query::select();
+
+        prices_query
+            .expr(Expr::val(1))
+            .from(OfferPriceIden::Table)
+            .and_where(
+                Expr::col((OfferPriceIden::Table, OfferPriceIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            )
+            .and_where(
+                Expr::col((OfferPriceIden::Table, OfferPriceIden::Currency))
+                    .equals((
+                        CollectionIden::Table,
+                        CollectionIden::RuleCurrency,
+                    )),
+            )
+            .cond_where(any![
+                col(CollectionIden::RuleMinPrice).is_null(),
+                unit_amount().gte(col(CollectionIden::RuleMinPrice)),
+            ])
+            .cond_where(any![
+                col(CollectionIden::RuleMaxPrice).is_null(),
+                unit_amount().lt(col(CollectionIden::RuleMaxPrice)),
+            ]);
+
+        let mut query = Query::select();
+
+        query
+            .expr(Expr::val(1))
+            .from(CollectionIden::Table)
+            .and_where(col(CollectionIden::CollectionId).eq(*collection_id))
+            .and_where(
+                col(CollectionIden::ShopId)
+                    .equals((OfferIden::Table, OfferIden::ShopId)),
+            )
+            .cond_where(any![
+                all![
+                    col(CollectionIden::CollectionType)
+                        .eq(CollectionType::Manual.as_str_name()),
+                    Expr::exists(offers_query),
+                ],
+                all![
+                    col(CollectionIden::CollectionType)
+                        .eq(CollectionType::Rule.as_str_name()),
+                    any![
+                        col(CollectionIden::RuleOfferType).is_null(),
+                        col(CollectionIden::RuleOfferType)
+                            .equals((OfferIden::Table, OfferIden::Type)),
+                    ],
+                    any![
+                        col(CollectionIden::RuleIsFeatured).is_null(),
+                        col(CollectionIden::RuleIsFeatured)
+                            .equals((OfferIden::Table, OfferIden::IsFeatured)),
+                    ],
+                    any![
+                        col(CollectionIden::RuleCategoryId).is_null(),
+                        Category::get_in_category_expr(
+                            col(CollectionIden::RuleCategoryId).into(),
+                        ),
+                    ],
+                    any![
+                        all![
+                            col(CollectionIden::RuleMinPrice).is_null(),
+                            col(CollectionIden::RuleMaxPrice).is_null(),
+                        ],
+                        Expr::exists(prices_query),
+                    ],
+                ],
+            ]);
+
+        Expr::exists(query)
+    }
+
+    /// Selects the ordering of the offer in the manual collection, which is
+    /// `NULL` for rule-based collections.
+    pub fn get_offer_ordering_expr(collection_id: &Uuid) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .column(CollectionOfferIden::Ordering)
+            .from(CollectionOfferIden::Table)
+            .and_where(
+                Expr::col((
+                    CollectionOfferIden::Table,
+                    CollectionOfferIden::CollectionId,
+                ))
+                .eq(*collection_id),
+            )
+            .and_where(
+                Expr::col((
+                    CollectionOfferIden::Table,
+                    CollectionOfferIden::OfferId,
+                ))
+                .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        pool: &Pool,
+        shop_id: &Uuid,
+        user_id: &String,
+        name: &String,
+        slug: &String,
+        ordering: i64,
+        collection_type: &str,
+        rule: &CollectionRuleValues,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(CollectionIden::Table)
+            .columns([
+                CollectionIden::ShopId,
+                CollectionIden::UserId,
+                CollectionIden::Name,
+                CollectionIden::Slug,
+                CollectionIden::Ordering,
+                CollectionIden::CollectionType,
+                CollectionIden::RuleOfferType,
+                CollectionIden::RuleCategoryId,
+                CollectionIden::RuleIsFeatured,
+                CollectionIden::RuleCurrency,
+                CollectionIden::RuleMinPrice,
+                CollectionIden::RuleMaxPrice,
+            ])
+            .values([
+                (*shop_id).into(),
+                user_id.into(),
+                name.into(),
+                slug.into(),
+                ordering.into(),
+                collection_type.into(),
+                rule.offer_type.clone().into(),
+                rule.category_id.into(),
+                rule.is_featured.into(),
+                rule.currency.clone().into(),
+                rule.min_price.map(i64::from).into(),
+                rule.max_price.map(i64::from).into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        collection_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CollectionIden::Table)
+            .and_where(
+                Expr::col(CollectionIden::CollectionId).eq(*collection_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn get_by_slug(
+        pool: &Pool,
+        shop_id: &Uuid,
+        slug: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CollectionIden::Table)
+            .and_where(Expr::col(CollectionIden::ShopId).eq(*shop_id))
+            .and_where(Expr::col(CollectionIden::Slug).eq(slug))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn list(
+        pool: &Pool,
+        shop_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CollectionIden::Table)
+            .and_where(Expr::col(CollectionIden::ShopId).eq(*shop_id))
+            .order_by(CollectionIden::Ordering, Order::Asc)
+            .order_by(CollectionIden::Name, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    /// Updates the collection. A given rule replaces the current one.
+    pub async fn update(
+        pool: &Pool,
+        collection_id: &Uuid,
+        user_id: &String,
+        name: Option<String>,
+        slug: Option<String>,
+        ordering: Option<i64>,
+        rule: Option<&CollectionRuleValues>,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = {
+            let mut query = Query::update();
+
+            query.table(CollectionIden::Table);
+
+            if let Some(name) = name {
+                query.value(CollectionIden::Name, name);
+            }
+
+            if let Some(slug) = slug {
+                query.value(CollectionIden::Slug, slug);
+            }
+
+            if let Some(ordering) = ordering {
+                query.value(CollectionIden::Ordering, ordering);
+            }
+
+            if let Some(rule) = rule {
+                query.values([
+                    (
+                        CollectionIden::RuleOfferType,
+                        rule.offer_type.clone().into(),
+                    ),
+                    (CollectionIden::RuleCategoryId, rule.category_id.into()),
+                    (CollectionIden::RuleIsFeatured, rule.is_featured.into()),
+                    (
+                        CollectionIden::RuleCurrency,
+                        rule.currency.clone().into(),
+                    ),
+                    (
+                        CollectionIden::RuleMinPrice,
+                        rule.min_price.map(i64::from).into(),
+                    ),
+                    (
+                        CollectionIden::RuleMaxPrice,
+                        rule.max_price.map(i64::from).into(),
+                    ),
+                ]);
+            }
+
+            query
+                .and_where(
+                    Expr::col(CollectionIden::CollectionId).eq(*collection_id),
+                )
+                .and_where(Expr::col(CollectionIden::UserId).eq(user_id))
+                .returning_all()
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn delete(
+        pool: &Pool,
+        collection_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(CollectionIden::Table)
+            .and_where(
+                Expr::col(CollectionIden::CollectionId).eq(*collection_id),
+            )
+            .and_where(Expr::col(CollectionIden::UserId).eq(user_id))
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Adds the offer to the collection or, if it is already in it, updates
+    /// its ordering.
+    pub async fn put_offer(
+        pool: &Pool,
+        collection_id: &Uuid,
+        offer_id: &Uuid,
+        ordering: i64,
+    ) -> Result<(), DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(CollectionOfferIden::Table)
+            .columns([
+                CollectionOfferIden::CollectionId,
+                CollectionOfferIden::OfferId,
+                CollectionOfferIden::Ordering,
+            ])
+            .values([
+                (*collection_id).into(),
+                (*offer_id).into(),
+                ordering.into(),
+            ])?
+            .on_conflict(
+                OnConflict::columns([
+                    CollectionOfferIden::CollectionId,
+                    CollectionOfferIden::OfferId,
+                ])
+                .update_column(CollectionOfferIden::Ordering)
+                .to_owned(),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(())
+    }
+
+    /// Returns `false` if the offer was not in the collection.
+    pub async fn remove_offer(
+        pool: &Pool,
+        collection_id: &Uuid,
+        offer_id: &Uuid,
+    ) -> Result<bool, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(CollectionOfferIden::Table)
+            .and_where(
+                Expr::col(CollectionOfferIden::CollectionId).eq(*collection_id),
+            )
+            .and_where(Expr::col(CollectionOfferIden::OfferId).eq(*offer_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let count = conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(count > 0)
+    }
+
+    /// Returns the offers of the given manual collections in their order.
+    pub async fn list_offers(
+        pool: &Pool,
+        collection_ids: &[Uuid],
+    ) -> Result<Vec<CollectionOffer>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(CollectionOfferIden::Table)
+            .and_where(
+                Expr::col(CollectionOfferIden::CollectionId)
+                    .is_in(collection_ids.to_vec()),
+            )
+            .order_by(CollectionOfferIden::Ordering, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(CollectionOffer::from).collect())
+    }
+}
+
+impl From<&Row> for Collection {
+    fn from(row: &Row) -> Self {
+        Self {
+            collection_id: row
+                .get(CollectionIden::CollectionId.to_string().as_str()),
+            shop_id: row.get(CollectionIden::ShopId.to_string().as_str()),
+            user_id: row.get(CollectionIden::UserId.to_string().as_str()),
+            created_at: row.get(CollectionIden::CreatedAt.to_string().as_str()),
+            updated_at: row.get(CollectionIden::UpdatedAt.to_string().as_str()),
+            name: row.get(CollectionIden::Name.to_string().as_str()),
+            slug: row.get(CollectionIden::Slug.to_string().as_str()),
+            ordering: row.get(CollectionIden::Ordering.to_string().as_str()),
+            collection_type: row
+                .get(CollectionIden::CollectionType.to_string().as_str()),
+            rule: CollectionRuleValues {
+                offer_type: row
+                    .get(CollectionIden::RuleOfferType.to_string().as_str()),
+                category_id: row
+                    .get(CollectionIden::RuleCategoryId.to_string().as_str()),
+                is_featured: row
+                    .get(CollectionIden::RuleIsFeatured.to_string().as_str()),
+                currency: row
+                    .get(CollectionIden::RuleCurrency.to_string().as_str()),
+                min_price: row
+                    .get::<&str, Option<i64>>(
+                        CollectionIden::RuleMinPrice.to_string().as_str(),
+                    )
+                    .map(|p| {
+                        u32::try_from(p)
+                            .expect("Should not be greater than 4294967295")
+                    }),
+                max_price: row
+                    .get::<&str, Option<i64>>(
+                        CollectionIden::RuleMaxPrice.to_string().as_str(),
+                    )
+                    .map(|p| {
+                        u32::try_from(p)
+                            .expect("Should not be greater than 4294967295")
+                    }),
+            },
+        }
+    }
+}
+
+impl From<Row> for Collection {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
+
+impl From<&Row> for CollectionOffer {
+    fn from(row: &Row) -> Self {
+        Self {
+            collection_id: row
+                .get(CollectionOfferIden::CollectionId.to_string().as_str()),
+            offer_id: row
+                .get(CollectionOfferIden::OfferId.to_string().as_str()),
+            ordering: row
+                .get(CollectionOfferIden::Ordering.to_string().as_str()),
+        }
+    }
+}
@@ -0,0 +1,684 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{
+    Alias, Asterisk, Expr, Func, Iden, Order, PostgresQueryBuilder, Query,
+    SelectStatement, SimpleExpr,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::{get_count_from_rows, ArrayAgg, DbError};
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "discounts")]
+pub enum DiscountIden {
+    Table,
+    DiscountId,
+    ShopId,
+    UserId,
+    CreatedAt,
+    UpdatedAt,
+    Code,
+    DiscountType,
+    Percent,
+    Amount,
+    Currency,
+    StartsAt,
+    EndsAt,
+    MaxRedemptions,
+    MaxRedemptionsPerUser,
+    MinimumOrderAmount,
+    RedemptionCount,
+    IsActive,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "discount_offers")]
+pub enum DiscountOfferIden {
+    Table,
+    DiscountId,
+    OfferId,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "discount_offer_types")]
+pub enum DiscountOfferTypeIden {
+    Table,
+    DiscountId,
+    OfferType,
+}
+
+#[derive(Debug, Clone)]
+pub struct Discount {
+    pub discount_id: Uuid,
+    pub shop_id: Uuid,
+    pub user_id: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub code: Option<String>,
+    pub discount_type: String,
+    pub percent: Option<u32>,
+    pub amount: Option<u32>,
+    pub currency: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub max_redemptions: Option<u32>,
+    pub max_redemptions_per_user: Option<u32>,
+    pub minimum_order_amount: Option<u32>,
+    pub redemption_count: u32,
+    pub is_active: bool,
+    pub offer_ids: Vec<Uuid>,
+    pub offer_types: Vec<String>,
+}
+
+/// Values of a discount that are set on create and update.
+#[derive(Debug, Clone)]
+pub struct DiscountValues {
+    pub code: Option<String>,
+    pub percent: Option<u32>,
+    pub amount: Option<u32>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub max_redemptions: Option<u32>,
+    pub max_redemptions_per_user: Option<u32>,
+    pub minimum_order_amount: Option<u32>,
+}
+
+impl Discount {
+    const OFFER_IDS_ALIAS: &'static str = "offer_ids";
+    const OFFER_TYPES_ALIAS: &'static str = "offer_types";
+
+    fn get_offer_ids_alias() -> Alias {
+        Alias::new(Self::OFFER_IDS_ALIAS)
+    }
+
+    fn get_offer_types_alias() -> Alias {
+        Alias::new(Self::OFFER_TYPES_ALIAS)
+    }
+
+    fn get_offer_ids_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg).arg(Expr::col(DiscountOfferIden::OfferId)),
+            )
+            .from(DiscountOfferIden::Table)
+            .and_where(
+                Expr::col((
+                    DiscountOfferIden::Table,
+                    DiscountOfferIden::DiscountId,
+                ))
+                .equals((DiscountIden::Table, DiscountIden::DiscountId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    fn get_offer_types_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg)
+                    .arg(Expr::col(DiscountOfferTypeIden::OfferType)),
+            )
+            .from(DiscountOfferTypeIden::Table)
+            .and_where(
+                Expr::col((
+                    DiscountOfferTypeIden::Table,
+                    DiscountOfferTypeIden::DiscountId,
+                ))
+                .equals((DiscountIden::Table, DiscountIden::DiscountId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    fn select_with_scope() -> SelectStatement {
+        let mut query = Query::select();
+
+        query
+            .column((DiscountIden::Table, Asterisk))
+            .expr_as(Self::get_offer_ids_agg(), Self::get_offer_ids_alias())
+            .expr_as(Self::get_offer_types_agg(), Self::get_offer_types_alias())
+            .from(DiscountIden::Table);
+
+        query
+    }
+
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        shop_id: &Uuid,
+        user_id: &String,
+        discount_type: &str,
+        currency: Option<&str>,
+        values: &DiscountValues,
+    ) -> Result<Uuid, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(DiscountIden::Table)
+            .columns([
+                DiscountIden::ShopId,
+                DiscountIden::UserId,
+                DiscountIden::DiscountType,
+                DiscountIden::Currency,
+                DiscountIden::Code,
+                DiscountIden::Percent,
+                DiscountIden::Amount,
+                DiscountIden::StartsAt,
+                DiscountIden::EndsAt,
+                DiscountIden::MaxRedemptions,
+                DiscountIden::MaxRedemptionsPerUser,
+                DiscountIden::MinimumOrderAmount,
+            ])
+            .values([
+                (*shop_id).into(),
+                user_id.into(),
+                discount_type.into(),
+                currency.into(),
+                values.code.clone().into(),
+                values.percent.map(i64::from).into(),
+                values.amount.map(i64::from).into(),
+                values.starts_at.into(),
+                values.ends_at.into(),
+                values.max_redemptions.map(i64::from).into(),
+                values.max_redemptions_per_user.map(i64::from).into(),
+                values.minimum_order_amount.map(i64::from).into(),
+            ])?
+            .returning_col(DiscountIden::DiscountId)
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.get(DiscountIden::DiscountId.to_string().as_str()))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        discount_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Self::select_with_scope()
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::DiscountId))
+                    .eq(*discount_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn get_for_user<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let (sql, values) = Self::select_with_scope()
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::DiscountId))
+                    .eq(*discount_id),
+            )
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::UserId))
+                    .eq(user_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Returns the active discount of the shop with the given code.
+    pub async fn get_by_code(
+        pool: &Pool,
+        shop_id: &Uuid,
+        code: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Self::select_with_scope()
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::ShopId))
+                    .eq(*shop_id),
+            )
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::Code)).eq(code),
+            )
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::IsActive))
+                    .eq(true),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Returns the active discounts of the shop without a code, which apply
+    /// without being entered by the buyer.
+    pub async fn list_automatic(
+        pool: &Pool,
+        shop_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Self::select_with_scope()
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::ShopId))
+                    .eq(*shop_id),
+            )
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::Code)).is_null(),
+            )
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::IsActive))
+                    .eq(true),
+            )
+            .order_by(
+                (DiscountIden::Table, DiscountIden::CreatedAt),
+                Order::Asc,
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    pub async fn list(
+        pool: &Pool,
+        shop_id: &Uuid,
+        user_id: &String,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<Self>, i64), DbError> {
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
+
+        let (sql, values) = Self::select_with_scope()
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::ShopId))
+                    .eq(*shop_id),
+            )
+            .and_where(
+                Expr::col((DiscountIden::Table, DiscountIden::UserId))
+                    .eq(user_id),
+            )
+            .order_by(
+                (DiscountIden::Table, DiscountIden::CreatedAt),
+                Order::Desc,
+            )
+            .limit(limit)
+            .offset(offset)
+            .build_postgres(PostgresQueryBuilder);
+
+        let (count_sql, count_values) = Query::select()
+            .expr(Expr::col(Asterisk).count())
+            .from(DiscountIden::Table)
+            .and_where(Expr::col(DiscountIden::ShopId).eq(*shop_id))
+            .and_where(Expr::col(DiscountIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        let count_rows = transaction
+            .query(count_sql.as_str(), &count_values.as_params())
+            .await?;
+
+        let count = get_count_from_rows(&count_rows);
+
+        transaction.commit().await?;
+
+        Ok((rows.iter().map(Self::from).collect(), count))
+    }
+
+    pub async fn update<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+        user_id: &String,
+        values: &DiscountValues,
+        is_active: bool,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::update()
+            .table(DiscountIden::Table)
+            .values([
+                (DiscountIden::Code, values.code.clone().into()),
+                (DiscountIden::Percent, values.percent.map(i64::from).into()),
+                (DiscountIden::Amount, values.amount.map(i64::from).into()),
+                (DiscountIden::StartsAt, values.starts_at.into()),
+                (DiscountIden::EndsAt, values.ends_at.into()),
+                (
+                    DiscountIden::MaxRedemptions,
+                    values.max_redemptions.map(i64::from).into(),
+                ),
+                (
+                    DiscountIden::MaxRedemptionsPerUser,
+                    values.max_redemptions_per_user.map(i64::from).into(),
+                ),
+                (
+                    DiscountIden::MinimumOrderAmount,
+                    values.minimum_order_amount.map(i64::from).into(),
+                ),
+                (DiscountIden::IsActive, is_active.into()),
+            ])
+            .and_where(Expr::col(DiscountIden::DiscountId).eq(*discount_id))
+            .and_where(Expr::col(DiscountIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Replaces the offers and offer types the discount is limited to. A
+    /// discount without any of them applies to all offers of the shop.
+    pub async fn put_scope<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+        offer_ids: &[Uuid],
+        offer_types: &[&str],
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(DiscountOfferIden::Table)
+            .and_where(
+                Expr::col(DiscountOfferIden::DiscountId).eq(*discount_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(DiscountOfferTypeIden::Table)
+            .and_where(
+                Expr::col(DiscountOfferTypeIden::DiscountId).eq(*discount_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        if !offer_ids.is_empty() {
+            let (sql, values) = {
+                let mut query = Query::insert();
+
+                query.into_table(DiscountOfferIden::Table).columns([
+                    DiscountOfferIden::DiscountId,
+                    DiscountOfferIden::OfferId,
+                ]);
+
+                for offer_id in offer_ids {
+                    query
+                        .values([(*discount_id).into(), (*offer_id).into()])?;
+                }
+
+                query.build_postgres(PostgresQueryBuilder)
+            };
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        if !offer_types.is_empty() {
+            let (sql, values) = {
+                let mut query = Query::insert();
+
+                query.into_table(DiscountOfferTypeIden::Table).columns([
+                    DiscountOfferTypeIden::DiscountId,
+                    DiscountOfferTypeIden::OfferType,
+                ]);
+
+                for offer_type in offer_types {
+                    query.values([
+                        (*discount_id).into(),
+                        (*offer_type).into(),
+                    ])?;
+                }
+
+                query.build_postgres(PostgresQueryBuilder)
+            };
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Counts a redemption of the discount. The update locks the discount
+    /// row until the transaction ends, so concurrent redemptions cannot
+    /// exceed `max_redemptions`. Returns `false` if the discount is inactive
+    /// or fully redeemed.
+    pub async fn increment_redemption_count<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+    ) -> Result<bool, DbError> {
+        let (sql, values) = Query::update()
+            .table(DiscountIden::Table)
+            .value(
+                DiscountIden::RedemptionCount,
+                Expr::col(DiscountIden::RedemptionCount).add(1),
+            )
+            .and_where(Expr::col(DiscountIden::DiscountId).eq(*discount_id))
+            .and_where(Expr::col(DiscountIden::IsActive).eq(true))
+            .and_where(
+                Expr::col(DiscountIden::MaxRedemptions)
+                    .is_null()
+                    .or(Expr::col(DiscountIden::RedemptionCount)
+                        .lt(Expr::col(DiscountIden::MaxRedemptions))),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let count = transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    pub async fn decrement_redemption_count<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::update()
+            .table(DiscountIden::Table)
+            .value(
+                DiscountIden::RedemptionCount,
+                Expr::col(DiscountIden::RedemptionCount).sub(1),
+            )
+            .and_where(Expr::col(DiscountIden::DiscountId).eq(*discount_id))
+            .and_where(Expr::col(DiscountIden::RedemptionCount).gt(0))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+        user_id: &String,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(DiscountIden::Table)
+            .and_where(Expr::col(DiscountIden::DiscountId).eq(*discount_id))
+            .and_where(Expr::col(DiscountIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns whether a line of the given offer counts towards the
+    /// discount.
+    pub fn applies_to(
+        &self,
+        offer_id: &Uuid,
+        offer_type: Option<&str>,
+    ) -> bool {
+        if self.offer_ids.is_empty() && self.offer_types.is_empty() {
+            return true;
+        }
+
+        self.offer_ids.contains(offer_id)
+            || offer_type
+                .is_some_and(|t| self.offer_types.iter().any(|o| o == t))
+    }
+
+    /// Calculates the discount on the sum of all lines it applies to. A
+    /// percentage is rounded half up to whole minor units, a fixed amount is
+    /// capped at the sum, so the discount never exceeds what it applies to.
+    pub fn calculate_amount(&self, eligible_amount: u64) -> u64 {
+        let amount = match (self.percent, self.amount) {
+            (Some(percent), _) => {
+                (eligible_amount * u64::from(percent) + 50) / 100
+            }
+            (None, Some(amount)) => u64::from(amount),
+            (None, None) => 0,
+        };
+
+        amount.min(eligible_amount)
+    }
+}
+
+fn get_u32(row: &Row, iden: DiscountIden) -> u32 {
+    u32::try_from(row.get::<&str, i64>(iden.to_string().as_str()))
+        .expect("Should not be greater than 4294967295")
+}
+
+fn get_optional_u32(row: &Row, iden: DiscountIden) -> Option<u32> {
+    row.get::<&str, Option<i64>>(iden.to_string().as_str())
+        .map(|v| {
+            u32::try_from(v).expect("Should not be greater than 4294967295")
+        })
+}
+
+impl From<&Row> for Discount {
+    fn from(row: &Row) -> Self {
+        Self {
+            discount_id: row.get(DiscountIden::DiscountId.to_string().as_str()),
+            shop_id: row.get(DiscountIden::ShopId.to_string().as_str()),
+            user_id: row.get(DiscountIden::UserId.to_string().as_str()),
+            created_at: row.get(DiscountIden::CreatedAt.to_string().as_str()),
+            updated_at: row.get(DiscountIden::UpdatedAt.to_string().as_str()),
+            code: row.get(DiscountIden::Code.to_string().as_str()),
+            discount_type: row
+                .get(DiscountIden::DiscountType.to_string().as_str()),
+            percent: get_optional_u32(row, DiscountIden::Percent),
+            amount: get_optional_u32(row, DiscountIden::Amount),
+            currency: row.get(DiscountIden::Currency.to_string().as_str()),
+            starts_at: row.get(DiscountIden::StartsAt.to_string().as_str()),
+            ends_at: row.get(DiscountIden::EndsAt.to_string().as_str()),
+            max_redemptions: get_optional_u32(
+                row,
+                DiscountIden::MaxRedemptions,
+            ),
+            max_redemptions_per_user: get_optional_u32(
+                row,
+                DiscountIden::MaxRedemptionsPerUser,
+            ),
+            minimum_order_amount: get_optional_u32(
+                row,
+                DiscountIden::MinimumOrderAmount,
+            ),
+            redemption_count: get_u32(row, DiscountIden::RedemptionCount),
+            is_active: row.get(DiscountIden::IsActive.to_string().as_str()),
+            offer_ids: row
+                .get::<&str, Option<Vec<Uuid>>>(Self::OFFER_IDS_ALIAS)
+                .unwrap_or_default(),
+            offer_types: row
+                .get::<&str, Option<Vec<String>>>(Self::OFFER_TYPES_ALIAS)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Row> for Discount {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_discount(percent: Option<u32>, amount: Option<u32>) -> Discount {
+        Discount {
+            discount_id: Uuid::nil(),
+            shop_id: Uuid::nil(),
+            user_id: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            code: None,
+            discount_type: String::new(),
+            percent,
+            amount,
+            currency: None,
+            starts_at: None,
+            ends_at: None,
+            max_redemptions: None,
+            max_redemptions_per_user: None,
+            minimum_order_amount: None,
+            redemption_count: 0,
+            is_active: true,
+            offer_ids: Vec::new(),
+            offer_types: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn takes_percentage_of_eligible_amount() {
+        assert_eq!(get_discount(Some(10), None).calculate_amount(1000), 100);
+    }
+
+    #[test]
+    fn rounds_percentage_half_up() {
+        // 2.5 cents
+        assert_eq!(get_discount(Some(10), None).calculate_amount(25), 3);
+        // 2.4 cents
+        assert_eq!(get_discount(Some(10), None).calculate_amount(24), 2);
+    }
+
+    #[test]
+    fn takes_fixed_amount() {
+        assert_eq!(get_discount(None, Some(500)).calculate_amount(1000), 500);
+    }
+
+    #[test]
+    fn caps_fixed_amount_at_eligible_amount() {
+        assert_eq!(get_discount(None, Some(500)).calculate_amount(300), 300);
+    }
+
+    #[test]
+    fn caps_percentage_at_eligible_amount() {
+        assert_eq!(get_discount(Some(150), None).calculate_amount(100), 100);
+    }
+
+    #[test]
+    fn has_no_amount_without_percent_or_amount() {
+        assert_eq!(get_discount(None, None).calculate_amount(1000), 0);
+    }
+}
@@ -0,0 +1,129 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{Asterisk, Expr, Iden, PostgresQueryBuilder, Query};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::{get_count_from_rows, DbError};
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "discount_redemptions")]
+pub enum DiscountRedemptionIden {
+    Table,
+    DiscountId,
+    OrderId,
+    UserId,
+    Amount,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiscountRedemption {
+    pub discount_id: Uuid,
+}
+
+impl DiscountRedemption {
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+        order_id: &Uuid,
+        user_id: &String,
+        amount: u32,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(DiscountRedemptionIden::Table)
+            .columns([
+                DiscountRedemptionIden::DiscountId,
+                DiscountRedemptionIden::OrderId,
+                DiscountRedemptionIden::UserId,
+                DiscountRedemptionIden::Amount,
+            ])
+            .values([
+                (*discount_id).into(),
+                (*order_id).into(),
+                user_id.into(),
+                i64::from(amount).into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn count_for_user(
+        pool: &Pool,
+        discount_id: &Uuid,
+        user_id: &String,
+    ) -> Result<i64, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .expr(Expr::col(Asterisk).count())
+            .from(DiscountRedemptionIden::Table)
+            .and_where(
+                Expr::col(DiscountRedemptionIden::DiscountId).eq(*discount_id),
+            )
+            .and_where(Expr::col(DiscountRedemptionIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(get_count_from_rows(&rows))
+    }
+
+    /// Counts within the transaction, so redemptions made earlier in it are
+    /// included.
+    pub async fn count_for_user_in_transaction<'a>(
+        transaction: &Transaction<'a>,
+        discount_id: &Uuid,
+        user_id: &String,
+    ) -> Result<i64, DbError> {
+        let (sql, values) = Query::select()
+            .expr(Expr::col(Asterisk).count())
+            .from(DiscountRedemptionIden::Table)
+            .and_where(
+                Expr::col(DiscountRedemptionIden::DiscountId).eq(*discount_id),
+            )
+            .and_where(Expr::col(DiscountRedemptionIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(get_count_from_rows(&rows))
+    }
+
+    /// Removes the redemptions of the order and returns them, so their
+    /// discounts can be made available again.
+    pub async fn delete_for_order<'a>(
+        transaction: &Transaction<'a>,
+        order_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(DiscountRedemptionIden::Table)
+            .and_where(Expr::col(DiscountRedemptionIden::OrderId).eq(*order_id))
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+}
+
+impl From<&Row> for DiscountRedemption {
+    fn from(row: &Row) -> Self {
+        Self {
+            discount_id: row
+                .get(DiscountRedemptionIden::DiscountId.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for DiscountRedemption {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
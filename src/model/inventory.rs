@@ -0,0 +1,389 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{
+    Alias, Asterisk, Expr, Func, Iden, IntoColumnRef, LockType,
+    PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::{
+    InventoryPolicy, InventoryReservationStatus,
+};
+use crate::db::DbError;
+
+use super::inventory_reservation::InventoryReservationIden;
+use super::offer::OfferIden;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "inventories")]
+pub enum InventoryIden {
+    Table,
+    InventoryId,
+    OfferId,
+    OfferVariantId,
+    UserId,
+    OnHand,
+}
+
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    pub inventory_id: Uuid,
+    pub offer_id: Uuid,
+    pub offer_variant_id: Option<Uuid>,
+    pub user_id: String,
+    pub on_hand: i64,
+    pub reserved: i64,
+}
+
+impl Inventory {
+    const RESERVED_ALIAS: &'static str = "reserved";
+
+    fn get_reserved_alias() -> Alias {
+        Alias::new(Self::RESERVED_ALIAS)
+    }
+
+    /// Sum of the quantities of all reservations of the outer inventory row
+    /// that are neither committed, released nor expired.
+    fn get_reserved_expr() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Expr::col((
+                    InventoryReservationIden::Table,
+                    InventoryReservationIden::Quantity,
+                ))
+                .sum(),
+            )
+            .from(InventoryReservationIden::Table)
+            .and_where(
+                Expr::col((
+                    InventoryReservationIden::Table,
+                    InventoryReservationIden::InventoryId,
+                ))
+                .equals((InventoryIden::Table, InventoryIden::InventoryId)),
+            )
+            .and_where(
+                Expr::col((
+                    InventoryReservationIden::Table,
+                    InventoryReservationIden::Status,
+                ))
+                .eq(InventoryReservationStatus::Reserved.as_str_name()),
+            )
+            .and_where(
+                Expr::col((
+                    InventoryReservationIden::Table,
+                    InventoryReservationIden::ExpiresAt,
+                ))
+                .gt(Expr::current_timestamp()),
+            );
+
+        Expr::expr(Func::coalesce([
+            SimpleExpr::SubQuery(
+                None,
+                Box::new(query.into_sub_query_statement()),
+            ),
+            Expr::val(0).into(),
+        ]))
+        .cast_as(Alias::new("INT8"))
+    }
+
+    /// Quantity of the outer inventory row that can still be reserved.
+    pub fn get_available_expr() -> SimpleExpr {
+        Expr::col((InventoryIden::Table, InventoryIden::OnHand))
+            .sub(Self::get_reserved_expr())
+    }
+
+    /// Available quantity of the outer offer variant row, NULL if the variant
+    /// is not tracked.
+    pub fn get_variant_available_expr(
+        offer_variant_id_col: impl IntoColumnRef,
+    ) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(Self::get_available_expr())
+            .from(InventoryIden::Table)
+            .and_where(
+                Expr::col((
+                    InventoryIden::Table,
+                    InventoryIden::OfferVariantId,
+                ))
+                .equals(offer_variant_id_col),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    /// An offer is sold out if backorders are denied, its stock is tracked
+    /// and none of its inventories has anything available.
+    pub fn get_sold_out_expr() -> SimpleExpr {
+        let mut tracked = Query::select();
+        tracked
+            .expr(Expr::val(1))
+            .from(InventoryIden::Table)
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        let mut available = tracked.clone();
+        available.and_where(Expr::expr(Self::get_available_expr()).gt(0));
+
+        Expr::col((OfferIden::Table, OfferIden::InventoryPolicy))
+            .ne(InventoryPolicy::AllowBackorder.as_str_name())
+            .and(Expr::exists(tracked))
+            .and(Expr::exists(available).not())
+    }
+
+    fn select_with_reserved() -> SelectStatement {
+        let mut query = Query::select();
+
+        query
+            .column((InventoryIden::Table, Asterisk))
+            .expr_as(Self::get_reserved_expr(), Self::get_reserved_alias())
+            .from(InventoryIden::Table);
+
+        query
+    }
+
+    fn offer_variant_cond(offer_variant_id: Option<&Uuid>) -> SimpleExpr {
+        let col = (InventoryIden::Table, InventoryIden::OfferVariantId);
+        match offer_variant_id {
+            Some(offer_variant_id) => Expr::col(col).eq(*offer_variant_id),
+            None => Expr::col(col).is_null(),
+        }
+    }
+
+    pub fn available(&self) -> i64 {
+        self.on_hand - self.reserved
+    }
+
+    pub async fn create(
+        pool: &Pool,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        user_id: &String,
+        on_hand: i64,
+    ) -> Result<(), DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(InventoryIden::Table)
+            .columns([
+                InventoryIden::OfferId,
+                InventoryIden::OfferVariantId,
+                InventoryIden::UserId,
+                InventoryIden::OnHand,
+            ])
+            .values([
+                (*offer_id).into(),
+                offer_variant_id.copied().into(),
+                user_id.into(),
+                on_hand.into(),
+            ])?
+            .build_postgres(PostgresQueryBuilder);
+
+        conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(())
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Self::select_with_reserved()
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::OfferId))
+                    .eq(*offer_id),
+            )
+            .and_where(Self::offer_variant_cond(offer_variant_id))
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::UserId))
+                    .eq(user_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn get_by_id(
+        pool: &Pool,
+        inventory_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Self::select_with_reserved()
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::InventoryId))
+                    .eq(*inventory_id),
+            )
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::UserId))
+                    .eq(user_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Locks the inventory row until the transaction ends, so concurrent
+    /// reservations cannot oversell.
+    pub async fn get_for_update<'a>(
+        transaction: &Transaction<'a>,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+    ) -> Result<Option<Self>, DbError> {
+        let (sql, values) = Self::select_with_reserved()
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::OfferId))
+                    .eq(*offer_id),
+            )
+            .and_where(Self::offer_variant_cond(offer_variant_id))
+            .lock(LockType::Update)
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn list(
+        pool: &Pool,
+        offer_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Self::select_with_reserved()
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::OfferId))
+                    .eq(*offer_id),
+            )
+            .and_where(
+                Expr::col((InventoryIden::Table, InventoryIden::UserId))
+                    .eq(user_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    pub async fn update_on_hand(
+        pool: &Pool,
+        user_id: &String,
+        inventory_id: &Uuid,
+        on_hand: i64,
+    ) -> Result<(), DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::update()
+            .table(InventoryIden::Table)
+            .value(InventoryIden::OnHand, on_hand)
+            .and_where(Expr::col(InventoryIden::UserId).eq(user_id))
+            .and_where(Expr::col(InventoryIden::InventoryId).eq(*inventory_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(())
+    }
+
+    /// Removes committed or ordered stock from the on-hand quantity.
+    pub async fn decrement_on_hand<'a>(
+        transaction: &Transaction<'a>,
+        inventory_id: &Uuid,
+        quantity: u32,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::update()
+            .table(InventoryIden::Table)
+            .value(
+                InventoryIden::OnHand,
+                Expr::col(InventoryIden::OnHand).sub(i64::from(quantity)),
+            )
+            .and_where(Expr::col(InventoryIden::InventoryId).eq(*inventory_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Puts stock of a cancelled order back to the on-hand quantity.
+    pub async fn increment_on_hand<'a>(
+        transaction: &Transaction<'a>,
+        inventory_id: &Uuid,
+        quantity: u32,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::update()
+            .table(InventoryIden::Table)
+            .value(
+                InventoryIden::OnHand,
+                Expr::col(InventoryIden::OnHand).add(i64::from(quantity)),
+            )
+            .and_where(Expr::col(InventoryIden::InventoryId).eq(*inventory_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(
+        pool: &Pool,
+        user_id: &String,
+        inventory_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(InventoryIden::Table)
+            .and_where(Expr::col(InventoryIden::UserId).eq(user_id))
+            .and_where(Expr::col(InventoryIden::InventoryId).eq(*inventory_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(())
+    }
+}
+
+impl From<&Row> for Inventory {
+    fn from(row: &Row) -> Self {
+        Self {
+            inventory_id: row
+                .get(InventoryIden::InventoryId.to_string().as_str()),
+            offer_id: row.get(InventoryIden::OfferId.to_string().as_str()),
+            offer_variant_id: row
+                .get(InventoryIden::OfferVariantId.to_string().as_str()),
+            user_id: row.get(InventoryIden::UserId.to_string().as_str()),
+            on_hand: row.get(InventoryIden::OnHand.to_string().as_str()),
+            reserved: row.get(Self::RESERVED_ALIAS),
+        }
+    }
+}
+
+impl From<Row> for Inventory {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
@@ -0,0 +1,217 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Transaction;
+use sea_query::{
+    Asterisk, Expr, Func, Iden, LockType, PostgresQueryBuilder, Query,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::InventoryReservationStatus;
+use crate::db::DbError;
+
+use super::inventory::Inventory;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "inventory_reservations")]
+pub enum InventoryReservationIden {
+    Table,
+    InventoryReservationId,
+    InventoryId,
+    OfferId,
+    OfferVariantId,
+    UserId,
+    Quantity,
+    Status,
+    ExpiresAt,
+}
+
+#[derive(Debug, Clone)]
+pub struct InventoryReservation {
+    pub inventory_reservation_id: Uuid,
+    pub inventory_id: Uuid,
+    pub offer_id: Uuid,
+    pub offer_variant_id: Option<Uuid>,
+    pub quantity: u32,
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl InventoryReservation {
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        inventory: &Inventory,
+        user_id: &String,
+        quantity: u32,
+        status: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(InventoryReservationIden::Table)
+            .columns([
+                InventoryReservationIden::InventoryId,
+                InventoryReservationIden::OfferId,
+                InventoryReservationIden::OfferVariantId,
+                InventoryReservationIden::UserId,
+                InventoryReservationIden::Quantity,
+                InventoryReservationIden::Status,
+                InventoryReservationIden::ExpiresAt,
+            ])
+            .values([
+                inventory.inventory_id.into(),
+                inventory.offer_id.into(),
+                inventory.offer_variant_id.into(),
+                user_id.into(),
+                i64::from(quantity).into(),
+                status.into(),
+                expires_at.into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get_for_update<'a>(
+        transaction: &Transaction<'a>,
+        inventory_reservation_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(InventoryReservationIden::Table)
+            .and_where(
+                Expr::col(InventoryReservationIden::InventoryReservationId)
+                    .eq(*inventory_reservation_id),
+            )
+            .and_where(Expr::col(InventoryReservationIden::UserId).eq(user_id))
+            .lock(LockType::Update)
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Lists the reservations of the user for the inventory that are neither
+    /// committed, released nor expired.
+    pub async fn list_active_for_update<'a>(
+        transaction: &Transaction<'a>,
+        inventory_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Vec<Self>, DbError> {
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(InventoryReservationIden::Table)
+            .and_where(
+                Expr::col(InventoryReservationIden::InventoryId)
+                    .eq(*inventory_id),
+            )
+            .and_where(Expr::col(InventoryReservationIden::UserId).eq(user_id))
+            .and_where(
+                Expr::col(InventoryReservationIden::Status)
+                    .eq(InventoryReservationStatus::Reserved.as_str_name()),
+            )
+            .and_where(
+                Expr::col(InventoryReservationIden::ExpiresAt)
+                    .gt(Expr::current_timestamp()),
+            )
+            .lock(LockType::Update)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    /// Counts the reservations of the user across all inventories that are
+    /// neither committed, released nor expired.
+    pub async fn count_active_for_user<'a>(
+        transaction: &Transaction<'a>,
+        user_id: &String,
+    ) -> Result<i64, DbError> {
+        let (sql, values) = Query::select()
+            .expr(Func::count(Expr::col(Asterisk)))
+            .from(InventoryReservationIden::Table)
+            .and_where(Expr::col(InventoryReservationIden::UserId).eq(user_id))
+            .and_where(
+                Expr::col(InventoryReservationIden::Status)
+                    .eq(InventoryReservationStatus::Reserved.as_str_name()),
+            )
+            .and_where(
+                Expr::col(InventoryReservationIden::ExpiresAt)
+                    .gt(Expr::current_timestamp()),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.get(0))
+    }
+
+    pub async fn update_status<'a>(
+        transaction: &Transaction<'a>,
+        inventory_reservation_id: &Uuid,
+        status: &str,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::update()
+            .table(InventoryReservationIden::Table)
+            .value(InventoryReservationIden::Status, status)
+            .and_where(
+                Expr::col(InventoryReservationIden::InventoryReservationId)
+                    .eq(*inventory_reservation_id),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+}
+
+impl From<&Row> for InventoryReservation {
+    fn from(row: &Row) -> Self {
+        Self {
+            inventory_reservation_id: row.get(
+                InventoryReservationIden::InventoryReservationId
+                    .to_string()
+                    .as_str(),
+            ),
+            inventory_id: row.get(
+                InventoryReservationIden::InventoryId.to_string().as_str(),
+            ),
+            offer_id: row
+                .get(InventoryReservationIden::OfferId.to_string().as_str()),
+            offer_variant_id: row.get(
+                InventoryReservationIden::OfferVariantId
+                    .to_string()
+                    .as_str(),
+            ),
+            quantity: u32::try_from(row.get::<&str, i64>(
+                InventoryReservationIden::Quantity.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            status: row
+                .get(InventoryReservationIden::Status.to_string().as_str()),
+            expires_at: row
+                .get(InventoryReservationIden::ExpiresAt.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for InventoryReservation {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
@@ -1,15 +1,59 @@
+mod cart;
+mod cart_item;
+mod category;
+mod collection;
+mod discount;
+mod discount_redemption;
+mod inventory;
+mod inventory_reservation;
 mod offer;
+mod offer_attribute;
+mod offer_facet;
 mod offer_image;
+mod offer_option;
 mod offer_price;
+mod offer_price_tier;
+mod offer_tag;
+mod offer_variant;
+mod order;
+mod order_item;
+mod order_status_transition;
+mod scheduler_run;
+mod shipping_profile;
 mod shipping_rate;
+mod shipping_zone;
 mod shop;
 mod shop_customization;
 mod shop_domain;
+mod shop_minimum_platform_fee;
+mod tax_rate;
 
+pub use cart::Cart;
+pub use cart_item::CartItem;
+pub use category::Category;
+pub use collection::{Collection, CollectionOffer, CollectionRuleValues};
+pub use discount::{Discount, DiscountValues};
+pub use discount_redemption::DiscountRedemption;
+pub use inventory::Inventory;
+pub use inventory_reservation::InventoryReservation;
 pub use offer::Offer;
+pub use offer_attribute::OfferAttributeAsRel;
+pub use offer_facet::{OfferFacetCount, OfferFacets};
 pub use offer_image::{OfferImage, OfferImageAsRel, OfferImageIden};
+pub use offer_option::{OfferOption, OfferOptionValue};
 pub use offer_price::{OfferPrice, OfferPriceAsRel};
+pub use offer_price_tier::OfferPriceTier;
+pub use offer_tag::OfferTag;
+pub use offer_variant::OfferVariant;
+pub use order::Order;
+pub use order_item::OrderItem;
+pub use order_status_transition::OrderStatusTransition;
+pub use scheduler_run::SchedulerRun;
+pub use shipping_profile::ShippingProfile;
 pub use shipping_rate::ShippingRate;
+pub use shipping_zone::{ShippingZone, ShippingZoneRule};
 pub use shop::{Shop, ShopIden};
 pub use shop_customization::ShopCustomization;
 pub use shop_domain::ShopDomain;
+pub use shop_minimum_platform_fee::ShopMinimumPlatformFee;
+pub use tax_rate::TaxRate;
@@ -1,22 +1,35 @@
 use chrono::{DateTime, Utc};
 use deadpool_postgres::tokio_postgres::Row;
 use deadpool_postgres::{Pool, Transaction};
-use sea_query::extension::postgres::PgExpr;
 use sea_query::{
-    all, any, Alias, Asterisk, Expr, Func, Iden, IntoColumnRef, Order, PgFunc,
-    PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
+    all, any, Alias, Asterisk, Condition, Expr, Iden, IntoColumnRef,
+    IntoCondition, Order, PostgresQueryBuilder, Query, SelectStatement,
+    SimpleExpr,
 };
 use sea_query_postgres::PostgresBinder;
 use uuid::Uuid;
 
 use crate::api::sited_io::commerce::v1::{
-    OffersFilterField, OffersOrderByField,
+    OffersFilterField, OffersOrderByField, ShippingCountry,
 };
 use crate::api::sited_io::types::v1::Direction;
-use crate::db::{build_simple_plain_ts_query, get_count_from_rows, DbError};
+use crate::db::{
+    add_total_count, build_name_search, build_ts_search, get_count_from_rows,
+    get_total_count_from_rows, select_count, Cursor, DbError, FilterGroup,
+    SortKey, SortKeyKind,
+};
 
+use super::category::Category;
+use super::inventory::Inventory;
+use super::offer_attribute::{OfferAttributeAsRel, OfferAttributeAsRelVec};
+use super::offer_facet::OfferFacets;
 use super::offer_image::{OfferImageAsRel, OfferImageAsRelVec};
-use super::offer_price::{OfferPriceAsRel, OfferPriceAsRelVec, OfferPriceIden};
+use super::offer_option::{OfferOptionAsRel, OfferOptionAsRelVec};
+use super::offer_price::{OfferPriceAsRel, OfferPriceAsRelVec};
+use super::offer_tag::OfferTag;
+use super::offer_variant::{OfferVariantAsRel, OfferVariantAsRelVec};
+use super::shipping_rate::ShippingRate;
+use super::shipping_zone::ShippingZone;
 use super::{OfferImageIden, ShopIden};
 
 #[derive(Debug, Clone, Copy, Iden)]
@@ -36,6 +49,11 @@ pub enum OfferIden {
     Type,
     IsActive,
     IsFeatured,
+    InventoryPolicy,
+    TaxCategory,
+    WeightGrams,
+    ShippingProfileId,
+    ContentLanguage,
 }
 
 #[derive(Debug, Clone)]
@@ -55,16 +73,36 @@ pub struct Offer {
     pub type_: Option<String>,
     pub images: Vec<OfferImageAsRel>,
     pub price: Option<OfferPriceAsRel>,
+    pub prices: Vec<OfferPriceAsRel>,
+    pub options: Vec<OfferOptionAsRel>,
+    pub variants: Vec<OfferVariantAsRel>,
+    pub inventory_policy: String,
+    pub is_sold_out: bool,
+    pub tax_category: String,
+    pub weight_grams: Option<u32>,
+    pub shipping_profile_id: Option<Uuid>,
+    pub category_ids: Vec<Uuid>,
+    pub tags: Vec<String>,
+    pub attributes: Vec<OfferAttributeAsRel>,
 }
 
 impl Offer {
     const OFFER_IMAGES_ALIAS: &'static str = "images";
     const OFFER_PRICES_ALIAS: &'static str = "prices";
+    const OFFER_OPTIONS_ALIAS: &'static str = "options";
+    const OFFER_VARIANTS_ALIAS: &'static str = "variants";
+    const OFFER_VARIANT_PRICES_ALIAS: &'static str = "variant_prices";
+    const IS_SOLD_OUT_ALIAS: &'static str = "is_sold_out";
     const SHOP_NAME_ALIAS: &'static str = "shop_name";
     const SHOP_SLUG_ALIAS: &'static str = "shop_slug";
     const SHOP_DOMAIN_ALIAS: &'static str = "shop_domain";
     const NAME_TS_RANK_ALIAS: &'static str = "name_ts_rank";
     const DESCRIPTION_TS_RANK_ALIAS: &'static str = "description_ts_rank";
+    const SEARCH_SCORE_ALIAS: &'static str = "search_score";
+    const CATEGORY_IDS_ALIAS: &'static str = "category_ids";
+    const COLLECTION_ORDERING_ALIAS: &'static str = "collection_ordering";
+    const TAGS_ALIAS: &'static str = "tags";
+    const ATTRIBUTES_ALIAS: &'static str = "attributes";
 
     fn get_offer_images_alias() -> Alias {
         Alias::new(Self::OFFER_IMAGES_ALIAS)
@@ -74,6 +112,22 @@ impl Offer {
         Alias::new(Self::OFFER_PRICES_ALIAS)
     }
 
+    fn get_offer_options_alias() -> Alias {
+        Alias::new(Self::OFFER_OPTIONS_ALIAS)
+    }
+
+    fn get_offer_variants_alias() -> Alias {
+        Alias::new(Self::OFFER_VARIANTS_ALIAS)
+    }
+
+    fn get_offer_variant_prices_alias() -> Alias {
+        Alias::new(Self::OFFER_VARIANT_PRICES_ALIAS)
+    }
+
+    fn get_is_sold_out_alias() -> Alias {
+        Alias::new(Self::IS_SOLD_OUT_ALIAS)
+    }
+
     fn get_shop_name_alias() -> Alias {
         Alias::new(Self::SHOP_NAME_ALIAS)
     }
@@ -94,13 +148,58 @@ impl Offer {
         Alias::new(Self::DESCRIPTION_TS_RANK_ALIAS)
     }
 
+    fn get_search_score_alias() -> Alias {
+        Alias::new(Self::SEARCH_SCORE_ALIAS)
+    }
+
+    fn get_category_ids_alias() -> Alias {
+        Alias::new(Self::CATEGORY_IDS_ALIAS)
+    }
+
+    fn get_collection_ordering_alias() -> Alias {
+        Alias::new(Self::COLLECTION_ORDERING_ALIAS)
+    }
+
+    fn get_tags_alias() -> Alias {
+        Alias::new(Self::TAGS_ALIAS)
+    }
+
+    fn get_attributes_alias() -> Alias {
+        Alias::new(Self::ATTRIBUTES_ALIAS)
+    }
+
     fn select_with_relations() -> SelectStatement {
         let mut query = Query::select();
 
         query
             .column((OfferIden::Table, Asterisk))
             .expr_as(OfferImageAsRel::get_agg(), Self::get_offer_images_alias())
-            .expr_as(OfferPriceAsRel::get_agg(), Self::get_offer_price_alias())
+            .expr_as(
+                OfferPriceAsRel::get_offer_agg(),
+                Self::get_offer_price_alias(),
+            )
+            .expr_as(
+                OfferOptionAsRel::get_agg(),
+                Self::get_offer_options_alias(),
+            )
+            .expr_as(
+                OfferVariantAsRel::get_agg(),
+                Self::get_offer_variants_alias(),
+            )
+            .expr_as(
+                OfferPriceAsRel::get_variant_agg(),
+                Self::get_offer_variant_prices_alias(),
+            )
+            .expr_as(
+                Inventory::get_sold_out_expr(),
+                Self::get_is_sold_out_alias(),
+            )
+            .expr_as(Category::get_offer_agg(), Self::get_category_ids_alias())
+            .expr_as(OfferTag::get_agg(), Self::get_tags_alias())
+            .expr_as(
+                OfferAttributeAsRel::get_agg(),
+                Self::get_attributes_alias(),
+            )
             .expr_as(
                 Expr::col((ShopIden::Table, ShopIden::Name)),
                 Self::get_shop_name_alias(),
@@ -119,11 +218,6 @@ impl Offer {
                 Expr::col((OfferIden::Table, OfferIden::OfferId))
                     .equals((OfferImageIden::Table, OfferImageIden::OfferId)),
             )
-            .left_join(
-                OfferPriceIden::Table,
-                Expr::col((OfferIden::Table, OfferIden::OfferId))
-                    .equals((OfferPriceIden::Table, OfferPriceIden::OfferId)),
-            )
             .left_join(
                 ShopIden::Table,
                 Expr::col((OfferIden::Table, OfferIden::ShopId))
@@ -139,11 +233,16 @@ impl Offer {
         query
     }
 
-    fn add_order_by(
-        query: &mut SelectStatement,
+    /// Returns the keys of the order by the field. Offers in a collection are
+    /// ordered by the collection, and by relevance with the search score.
+    fn get_sort_keys(
         order_by_field: OffersOrderByField,
         order_by_direction: Direction,
-    ) {
+        search_score: Option<&SimpleExpr>,
+        collection_id: Option<&Uuid>,
+        preferred_currency: Option<&str>,
+        seed: &str,
+    ) -> Result<Vec<SortKey>, DbError> {
         use OffersOrderByField::*;
 
         let order = match order_by_direction {
@@ -151,104 +250,317 @@ impl Offer {
             Direction::Desc => Order::Desc,
         };
 
-        match order_by_field {
-            Unspecified | CreatedAt => {
-                query.order_by((OfferIden::Table, OfferIden::CreatedAt), order)
+        let sort_keys = match order_by_field {
+            Unspecified | CreatedAt => vec![SortKey::new(
+                Expr::col((OfferIden::Table, OfferIden::CreatedAt)),
+                order,
+                SortKeyKind::Timestamp,
+            )],
+            UpdatedAt => vec![SortKey::new(
+                Expr::col((OfferIden::Table, OfferIden::UpdatedAt)),
+                order,
+                SortKeyKind::Timestamp,
+            )],
+            Name => vec![SortKey::new(
+                Expr::col((OfferIden::Table, OfferIden::Name)),
+                order,
+                SortKeyKind::Text,
+            )],
+            Random => vec![SortKey::random(
+                seed,
+                (OfferIden::Table, OfferIden::OfferId),
+            )],
+            // offers of rule-based collections have no ordering and are
+            // ordered by creation
+            CollectionOrdering => {
+                let collection_id =
+                    collection_id.ok_or(DbError::Argument("order_by.field"))?;
+                vec![
+                    SortKey::new(
+                        super::Collection::get_offer_ordering_expr(
+                            collection_id,
+                        ),
+                        order,
+                        SortKeyKind::Int,
+                    )
+                    .nullable(),
+                    SortKey::new(
+                        Expr::col((OfferIden::Table, OfferIden::CreatedAt)),
+                        Order::Desc,
+                        SortKeyKind::Timestamp,
+                    ),
+                ]
             }
-            UpdatedAt => {
-                query.order_by((OfferIden::Table, OfferIden::UpdatedAt), order)
+            Relevance => {
+                let search_score =
+                    search_score.ok_or(DbError::Argument("order_by.field"))?;
+                vec![SortKey::new(
+                    search_score.clone(),
+                    order,
+                    SortKeyKind::Float,
+                )]
+            }
+            // offers are ordered by their lowest price in the currency, those
+            // without a price in it are listed last
+            Price => {
+                let currency = preferred_currency
+                    .ok_or(DbError::Argument("preferred_currency"))?;
+                vec![SortKey::new(
+                    OfferPriceAsRel::get_min_unit_amount_expr(currency),
+                    order,
+                    SortKeyKind::Int,
+                )
+                .nullable()]
             }
-            Name => query.order_by((OfferIden::Table, OfferIden::Name), order),
-            Random => query.order_by_expr(
-                SimpleExpr::FunctionCall(Func::random()),
-                Order::Asc,
-            ),
         };
+
+        Ok(sort_keys)
+    }
+
+    fn is_text_filter(filter_field: OffersFilterField) -> bool {
+        matches!(
+            filter_field,
+            OffersFilterField::Name
+                | OffersFilterField::Description
+                | OffersFilterField::NameAndDescription
+        )
     }
 
     fn add_filter(
         query: &mut SelectStatement,
+        filter: &FilterGroup<OffersFilterField>,
+        preferred_currency: Option<&str>,
+    ) -> Result<Option<SimpleExpr>, DbError> {
+        let mut scores = Vec::new();
+
+        if let Some(condition) =
+            filter.build_condition(&mut |filter_field, filter_query| {
+                Self::get_filter_condition(
+                    query,
+                    &mut scores,
+                    filter_field,
+                    filter_query,
+                    preferred_currency,
+                )
+            })?
+        {
+            query.cond_where(condition);
+        }
+
+        // results of text filters are ranked by the sum of their ranks
+        let search_score = scores.into_iter().reduce(|a, b| a.add(b));
+        if let Some(score) = search_score.clone() {
+            query.expr_as(score, Self::get_search_score_alias());
+        }
+
+        Ok(search_score)
+    }
+
+    /// Returns the condition of a single filter. Text filters also select
+    /// their rank on the query and add it to the search scores.
+    fn get_filter_condition(
+        query: &mut SelectStatement,
+        scores: &mut Vec<SimpleExpr>,
         filter_field: OffersFilterField,
-        filter_query: String,
-    ) -> Result<(), DbError> {
+        filter_query: &str,
+        preferred_currency: Option<&str>,
+    ) -> Result<Option<Condition>, DbError> {
         use OffersFilterField::*;
 
-        match filter_field {
-            Unspecified => {}
+        let condition = match filter_field {
+            Unspecified => return Ok(None),
             Name => {
-                let column = (OfferIden::Table, OfferIden::NameTs);
-                let tsquery = build_simple_plain_ts_query(&filter_query);
-                query
-                    .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(column),
-                            tsquery.clone(),
-                        )),
-                        Self::get_name_ts_rank_alias(),
-                    )
-                    .cond_where(Expr::col(column).matches(tsquery));
+                let (condition, rank) = build_name_search(
+                    (OfferIden::Table, OfferIden::Name),
+                    (OfferIden::Table, OfferIden::NameTs),
+                    (OfferIden::Table, OfferIden::ContentLanguage),
+                    filter_query,
+                );
+                query.expr_as(rank.clone(), Self::get_name_ts_rank_alias());
+                scores.push(rank);
+                condition
             }
             Description => {
-                let column = (OfferIden::Table, OfferIden::DescriptionTs);
-                let tsquery = build_simple_plain_ts_query(&filter_query);
-                query
-                    .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(column),
-                            tsquery.clone(),
-                        )),
-                        Self::get_description_ts_rank_alias(),
-                    )
-                    .cond_where(Expr::col(column).matches(tsquery));
+                let (condition, rank) = build_ts_search(
+                    (OfferIden::Table, OfferIden::DescriptionTs),
+                    (OfferIden::Table, OfferIden::ContentLanguage),
+                    filter_query,
+                );
+                query.expr_as(
+                    rank.clone(),
+                    Self::get_description_ts_rank_alias(),
+                );
+                scores.push(rank);
+                condition
             }
             NameAndDescription => {
-                let name_col = (OfferIden::Table, OfferIden::NameTs);
-                let description_col =
-                    (OfferIden::Table, OfferIden::DescriptionTs);
-
-                let tsquery = build_simple_plain_ts_query(&filter_query);
-
+                let (name_condition, name_rank) = build_name_search(
+                    (OfferIden::Table, OfferIden::Name),
+                    (OfferIden::Table, OfferIden::NameTs),
+                    (OfferIden::Table, OfferIden::ContentLanguage),
+                    filter_query,
+                );
+                let (description_condition, description_rank) = build_ts_search(
+                    (OfferIden::Table, OfferIden::DescriptionTs),
+                    (OfferIden::Table, OfferIden::ContentLanguage),
+                    filter_query,
+                );
                 query
+                    .expr_as(name_rank.clone(), Self::get_name_ts_rank_alias())
                     .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(name_col),
-                            tsquery.clone(),
-                        )),
-                        Self::get_name_ts_rank_alias(),
-                    )
-                    .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(description_col),
-                            tsquery.clone(),
-                        )),
+                        description_rank.clone(),
                         Self::get_description_ts_rank_alias(),
-                    )
-                    .cond_where(any![
-                        Expr::col(name_col).matches(tsquery.clone()),
-                        Expr::col(description_col).matches(tsquery),
-                    ]);
-            }
-            Type => {
-                query.cond_where(
-                    Expr::col((OfferIden::Table, OfferIden::Type))
-                        .eq(filter_query),
-                );
+                    );
+                scores.push(name_rank);
+                scores.push(description_rank);
+                any![name_condition, description_condition]
             }
+            Type => Expr::col((OfferIden::Table, OfferIden::Type))
+                .eq(filter_query)
+                .into_condition(),
             IsFeatured => {
                 let filter_query: bool = filter_query
                     .trim()
                     .parse()
                     .map_err(|_| DbError::Argument("filter.query"))?;
-                query.cond_where(
-                    Expr::col((OfferIden::Table, OfferIden::IsFeatured))
-                        .eq(filter_query),
+                Expr::col((OfferIden::Table, OfferIden::IsFeatured))
+                    .eq(filter_query)
+                    .into_condition()
+            }
+            IsSoldOut => {
+                let filter_query: bool = filter_query
+                    .trim()
+                    .parse()
+                    .map_err(|_| DbError::Argument("filter.query"))?;
+                if filter_query {
+                    Inventory::get_sold_out_expr().into_condition()
+                } else {
+                    Inventory::get_sold_out_expr().not().into_condition()
+                }
+            }
+            ShipsToCountry => {
+                let filter_query = filter_query.trim().to_uppercase();
+                let country = ShippingCountry::from_str_name(&filter_query)
+                    .or_else(|| {
+                        ShippingCountry::from_str_name(&format!(
+                            "SHIPPING_COUNTRY_{filter_query}"
+                        ))
+                    })
+                    .filter(|c| *c != ShippingCountry::Unspecified)
+                    .ok_or(DbError::Argument("filter.query"))?
+                    .as_str_name();
+                // offers with rates of their own ship to the countries of
+                // their rates, others to the zones of their shipping profile
+                // or anywhere without a profile
+                any![
+                    ShippingRate::get_ships_to_expr(country),
+                    all![
+                        ShippingRate::get_exists_expr().not(),
+                        any![
+                            Expr::col((
+                                OfferIden::Table,
+                                OfferIden::ShippingProfileId
+                            ))
+                            .is_null(),
+                            ShippingZone::get_ships_to_expr(country),
+                        ]
+                    ]
+                ]
+            }
+            Category => {
+                let category_id: Uuid = filter_query
+                    .trim()
+                    .parse()
+                    .map_err(|_| DbError::Argument("filter.query"))?;
+                super::Category::get_in_category_expr(category_id.into())
+                    .into_condition()
+            }
+            Collection => {
+                let collection_id: Uuid = filter_query
+                    .trim()
+                    .parse()
+                    .map_err(|_| DbError::Argument("filter.query"))?;
+                query.expr_as(
+                    super::Collection::get_offer_ordering_expr(&collection_id),
+                    Self::get_collection_ordering_alias(),
                 );
+                super::Collection::get_in_collection_expr(&collection_id)
+                    .into_condition()
+            }
+            Tag => {
+                let tag = OfferTag::normalize(filter_query);
+                if tag.is_empty() {
+                    return Err(DbError::Argument("filter.query"));
+                }
+                OfferTag::get_has_tag_expr(&tag).into_condition()
+            }
+            Attribute => {
+                // attributes are filtered by `key=value`
+                let (key, value) = filter_query
+                    .split_once('=')
+                    .filter(|(k, v)| {
+                        !k.trim().is_empty() && !v.trim().is_empty()
+                    })
+                    .ok_or(DbError::Argument("filter.query"))?;
+                OfferAttributeAsRel::get_has_attribute_expr(key, value)
+                    .into_condition()
             }
+            // prices are compared in the preferred currency, so offers
+            // without a price in it never match
+            MinPrice | MaxPrice => {
+                let currency = preferred_currency
+                    .ok_or(DbError::Argument("preferred_currency"))?;
+                let amount: u32 = filter_query
+                    .trim()
+                    .parse()
+                    .map_err(|_| DbError::Argument("filter.query"))?;
+                let min_unit_amount = Expr::expr(
+                    OfferPriceAsRel::get_min_unit_amount_expr(currency),
+                );
+                if filter_field == MinPrice {
+                    min_unit_amount.gte(i64::from(amount)).into_condition()
+                } else {
+                    min_unit_amount.lte(i64::from(amount)).into_condition()
+                }
+            }
+        };
+
+        Ok(Some(condition))
+    }
+
+    /// Selects the price in the given currency for the offer and each of its
+    /// variants, falling back to their default price.
+    pub fn set_preferred_currency(&mut self, currency: &str) {
+        self.price =
+            OfferPriceAsRel::select(&self.prices, Some(currency)).cloned();
+        for variant in self.variants.iter_mut() {
+            variant.price =
+                OfferPriceAsRel::select(&variant.prices, Some(currency))
+                    .cloned();
         }
+    }
 
-        Ok(())
+    /// Returns the price of the variant or, if the variant has none, of the
+    /// offer. With a currency only prices in that currency are considered,
+    /// otherwise the selected price is returned.
+    pub fn get_price<'a>(
+        &'a self,
+        variant: Option<&'a OfferVariantAsRel>,
+        currency: Option<&str>,
+    ) -> Option<&'a OfferPriceAsRel> {
+        match currency {
+            Some(currency) => variant
+                .and_then(|v| v.prices.iter().find(|p| p.currency == currency))
+                .or_else(|| {
+                    self.prices.iter().find(|p| p.currency == currency)
+                }),
+            None => variant
+                .and_then(|v| v.price.as_ref())
+                .or(self.price.as_ref()),
+        }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
         pool: &Pool,
         shop_id: Uuid,
@@ -257,6 +569,8 @@ impl Offer {
         description: Option<String>,
         type_: &str,
         is_featured: bool,
+        tax_category: &str,
+        weight_grams: Option<u32>,
     ) -> Result<Self, DbError> {
         let client = pool.get().await?;
 
@@ -269,6 +583,9 @@ impl Offer {
                 OfferIden::Description,
                 OfferIden::Type,
                 OfferIden::IsFeatured,
+                OfferIden::TaxCategory,
+                OfferIden::WeightGrams,
+                OfferIden::ContentLanguage,
             ])
             .values([
                 shop_id.into(),
@@ -277,6 +594,20 @@ impl Offer {
                 description.unwrap_or_default().into(),
                 type_.into(),
                 is_featured.into(),
+                tax_category.into(),
+                weight_grams.map(i64::from).into(),
+                // offers are searched in the language of their shop
+                SimpleExpr::SubQuery(
+                    None,
+                    Box::new(
+                        Query::select()
+                            .column(ShopIden::ContentLanguage)
+                            .from(ShopIden::Table)
+                            .and_where(Expr::col(ShopIden::ShopId).eq(shop_id))
+                            .to_owned()
+                            .into_sub_query_statement(),
+                    ),
+                ),
             ])?
             .returning_all()
             .build_postgres(PostgresQueryBuilder);
@@ -286,6 +617,24 @@ impl Offer {
         Ok(Self::from(row))
     }
 
+    pub async fn update_content_language(
+        transaction: &Transaction<'_>,
+        shop_id: &Uuid,
+        content_language: &str,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::update()
+            .table(OfferIden::Table)
+            .value(OfferIden::ContentLanguage, content_language)
+            .and_where(Expr::col(OfferIden::ShopId).eq(*shop_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn get(
         pool: &Pool,
         offer_id: &Uuid,
@@ -330,6 +679,38 @@ impl Offer {
         Ok(row.map(Self::from))
     }
 
+    /// Restricts the query to the offers of a list that are visible to the
+    /// requesting user and match the filter. Returns the search score of the
+    /// text filters.
+    fn add_list_conditions(
+        query: &mut SelectStatement,
+        shop_id: Option<Uuid>,
+        user_id: Option<&String>,
+        filter: &FilterGroup<OffersFilterField>,
+        request_user_id: Option<&String>,
+        preferred_currency: Option<&str>,
+    ) -> Result<Option<SimpleExpr>, DbError> {
+        if let Some(shop_id) = shop_id {
+            query.cond_where(
+                Expr::col((OfferIden::Table, OfferIden::ShopId)).eq(shop_id),
+            );
+        }
+
+        if let Some(user_id) = user_id {
+            query.cond_where(
+                Expr::col((OfferIden::Table, OfferIden::UserId)).eq(user_id),
+            );
+        }
+
+        query.cond_where(any![
+            Expr::col((OfferIden::Table, OfferIden::IsActive)).eq(true),
+            Expr::col((OfferIden::Table, OfferIden::UserId))
+                .eq(request_user_id.cloned())
+        ]);
+
+        Self::add_filter(query, filter, preferred_currency)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn list(
         pool: &Pool,
@@ -337,100 +718,268 @@ impl Offer {
         user_id: Option<&String>,
         limit: u64,
         offset: u64,
-        filter: Option<(OffersFilterField, String)>,
-        order_by: Option<(OffersOrderByField, Direction)>,
+        cursor: Option<Cursor>,
+        filter: FilterGroup<OffersFilterField>,
+        mut order_by: Vec<(OffersOrderByField, Direction)>,
+        random_seed: Option<&str>,
         request_user_id: Option<&String>,
-    ) -> Result<(Vec<Self>, i64), DbError> {
+        preferred_currency: Option<&str>,
+    ) -> Result<(Vec<Self>, i64, Option<Cursor>), DbError> {
+        // offers of a collection are in its order unless ordered otherwise,
+        // which is only known when filtering by a single collection
+        let filter_fields = filter.fields();
+        let collection_ids = filter.queries(OffersFilterField::Collection);
+        let collection_id = match collection_ids.as_slice() {
+            [collection_id] => Some(
+                collection_id
+                    .trim()
+                    .parse::<Uuid>()
+                    .map_err(|_| DbError::Argument("filter.query"))?,
+            ),
+            _ => None,
+        };
+        let is_collection_filter = collection_id.is_some();
+        // search results are ranked by relevance unless ordered otherwise
+        let is_text_filter =
+            filter_fields.iter().copied().any(Self::is_text_filter);
+        if order_by.is_empty() && is_text_filter {
+            order_by.push((OffersOrderByField::Relevance, Direction::Desc));
+        } else if order_by.is_empty() && is_collection_filter {
+            order_by
+                .push((OffersOrderByField::CollectionOrdering, Direction::Asc));
+        }
+        if order_by.iter().any(|(f, _)| {
+            (*f == OffersOrderByField::CollectionOrdering
+                && !is_collection_filter)
+                || (*f == OffersOrderByField::Relevance && !is_text_filter)
+        }) {
+            return Err(DbError::Argument("order_by.field"));
+        }
+
+        // random orders are the same for the same seed, which is kept in the
+        // cursor for the next pages
+        let is_random = order_by
+            .iter()
+            .any(|(f, _)| *f == OffersOrderByField::Random);
+        let seed = random_seed
+            .or_else(|| cursor.as_ref().and_then(|c| c.seed()))
+            .map(String::from)
+            .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
+
         let mut conn = pool.get().await?;
         let transaction = conn.transaction().await?;
 
-        let (sql, values) = {
+        let (sql, values, sort_key_kinds) = {
             let mut query = Self::select_with_relations();
 
-            if let Some(shop_id) = shop_id {
-                query.cond_where(
-                    Expr::col((OfferIden::Table, OfferIden::ShopId))
-                        .eq(shop_id),
-                );
-            }
-
-            if let Some(user_id) = user_id {
-                query.cond_where(
-                    Expr::col((OfferIden::Table, OfferIden::UserId))
-                        .eq(user_id),
-                );
+            let search_score = Self::add_list_conditions(
+                &mut query,
+                shop_id,
+                user_id,
+                &filter,
+                request_user_id,
+                preferred_currency,
+            )?;
+
+            let mut sort_keys = Vec::new();
+            for (order_by_field, order_by_direction) in order_by {
+                sort_keys.extend(Self::get_sort_keys(
+                    order_by_field,
+                    order_by_direction,
+                    search_score.as_ref(),
+                    collection_id.as_ref(),
+                    preferred_currency,
+                    &seed,
+                )?);
             }
+            sort_keys.push(SortKey::new(
+                Expr::col((OfferIden::Table, OfferIden::OfferId)),
+                Order::Asc,
+                SortKeyKind::Uuid,
+            ));
 
-            query.cond_where(any![
-                Expr::col((OfferIden::Table, OfferIden::IsActive)).eq(true),
-                Expr::col((OfferIden::Table, OfferIden::UserId))
-                    .eq(request_user_id.cloned())
-            ]);
-
-            if let Some((filter_field, filter_query)) = filter.clone() {
-                Self::add_filter(&mut query, filter_field, filter_query)?;
+            if let Some(cursor) = cursor.as_ref() {
+                query.cond_where(cursor.get_condition(&sort_keys)?);
             }
 
-            if let Some((order_by_field, order_by_direction)) = order_by {
-                Self::add_order_by(
-                    &mut query,
-                    order_by_field,
-                    order_by_direction,
-                );
-            }
+            SortKey::add_to_query(&mut query, &sort_keys);
+            add_total_count(&mut query);
 
-            query
+            let (sql, values) = query
                 .limit(limit)
                 .offset(offset)
-                .build_postgres(PostgresQueryBuilder)
+                .build_postgres(PostgresQueryBuilder);
+
+            let sort_key_kinds: Vec<SortKeyKind> =
+                sort_keys.iter().map(SortKey::kind).collect();
+
+            (sql, values, sort_key_kinds)
         };
 
         let (count_sql, count_values) = {
             let mut query = Query::select();
 
             query
-                .expr(Expr::col((OfferIden::Table, Asterisk)).count())
-                .from(OfferIden::Table)
-                .group_by_col((OfferIden::Table, OfferIden::OfferId));
+                .column((OfferIden::Table, OfferIden::OfferId))
+                .from(OfferIden::Table);
+
+            Self::add_list_conditions(
+                &mut query,
+                shop_id,
+                user_id,
+                &filter,
+                request_user_id,
+                preferred_currency,
+            )?;
+
+            select_count(query).build_postgres(PostgresQueryBuilder)
+        };
 
-            if let Some(shop_id) = shop_id {
-                query.cond_where(
-                    Expr::col((OfferIden::Table, OfferIden::ShopId))
-                        .eq(shop_id),
-                );
-            }
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
 
-            if let Some(user_id) = user_id {
-                query.cond_where(
-                    Expr::col((OfferIden::Table, OfferIden::UserId))
-                        .eq(user_id),
-                );
+        // the list is only counted separately if the page does not contain
+        // the count of the whole list
+        let count = match get_total_count_from_rows(&rows) {
+            Some(count) if cursor.is_none() => count,
+            None if cursor.is_none() && offset == 0 => 0,
+            _ => {
+                let count_rows = transaction
+                    .query(count_sql.as_str(), &count_values.as_params())
+                    .await?;
+                get_count_from_rows(&count_rows)
             }
+        };
 
-            query.cond_where(any![
-                Expr::col((OfferIden::Table, OfferIden::IsActive)).eq(true),
-                Expr::col((OfferIden::Table, OfferIden::UserId))
-                    .eq(request_user_id.cloned())
-            ]);
+        transaction.commit().await?;
 
-            if let Some((filter_field, filter_query)) = filter {
-                Self::add_filter(&mut query, filter_field, filter_query)?;
+        // full pages are continued after their last offer
+        let next_cursor = rows
+            .last()
+            .filter(|_| u64::try_from(rows.len()) == Ok(limit))
+            .map(|row| {
+                Cursor::from_row(
+                    row,
+                    &sort_key_kinds,
+                    is_random.then_some(seed),
+                )
+            });
+
+        let mut offers: Vec<Self> = rows.iter().map(Self::from).collect();
+
+        if let Some(preferred_currency) = preferred_currency {
+            for offer in offers.iter_mut() {
+                offer.set_preferred_currency(preferred_currency);
             }
+        }
 
-            query.build_postgres(PostgresQueryBuilder)
+        Ok((offers, count, next_cursor))
+    }
+
+    /// Counts the offers of a list by facet. Price buckets are only counted
+    /// with a preferred currency.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_facets(
+        pool: &Pool,
+        shop_id: Option<Uuid>,
+        user_id: Option<&String>,
+        filter: &FilterGroup<OffersFilterField>,
+        request_user_id: Option<&String>,
+        preferred_currency: Option<&str>,
+        price_bounds: &[u32],
+    ) -> Result<OfferFacets, DbError> {
+        let price_bounds = match preferred_currency {
+            Some(_) => price_bounds,
+            None => &[],
         };
 
-        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+        let queries = {
+            let mut query = Query::select();
 
-        let count_rows = transaction
-            .query(count_sql.as_str(), &count_values.as_params())
-            .await?;
+            query
+                .columns([
+                    (OfferIden::Table, OfferIden::OfferId),
+                    (OfferIden::Table, OfferIden::Type),
+                    (OfferIden::Table, OfferIden::IsFeatured),
+                ])
+                .from(OfferIden::Table);
+
+            if let Some(preferred_currency) = preferred_currency {
+                query.expr_as(
+                    OfferPriceAsRel::get_min_unit_amount_expr(
+                        preferred_currency,
+                    ),
+                    OfferFacets::get_price_alias(),
+                );
+            }
 
-        let count = get_count_from_rows(&count_rows);
+            Self::add_list_conditions(
+                &mut query,
+                shop_id,
+                user_id,
+                filter,
+                request_user_id,
+                preferred_currency,
+            )?;
 
-        transaction.commit().await?;
+            OfferFacets::build_queries(query, price_bounds)
+        };
 
-        Ok((rows.iter().map(Self::from).collect(), count))
+        OfferFacets::get(pool, queries, price_bounds).await
+    }
+
+    /// Returns ids and names of the offers of the shop whose names match the
+    /// query best, also matching names that start with it or are similar.
+    pub async fn autocomplete(
+        pool: &Pool,
+        shop_id: &Uuid,
+        name_query: &str,
+        limit: u64,
+        request_user_id: Option<&String>,
+    ) -> Result<Vec<(Uuid, String)>, DbError> {
+        let client = pool.get().await?;
+
+        let (sql, values) = {
+            let (condition, rank) = build_name_search(
+                (OfferIden::Table, OfferIden::Name),
+                (OfferIden::Table, OfferIden::NameTs),
+                (OfferIden::Table, OfferIden::ContentLanguage),
+                name_query,
+            );
+
+            Query::select()
+                .columns([
+                    (OfferIden::Table, OfferIden::OfferId),
+                    (OfferIden::Table, OfferIden::Name),
+                ])
+                .from(OfferIden::Table)
+                .cond_where(all![
+                    Expr::col((OfferIden::Table, OfferIden::ShopId))
+                        .eq(*shop_id),
+                    any![
+                        Expr::col((OfferIden::Table, OfferIden::IsActive))
+                            .eq(true),
+                        Expr::col((OfferIden::Table, OfferIden::UserId))
+                            .eq(request_user_id.cloned())
+                    ],
+                    condition,
+                ])
+                .order_by_expr(rank, Order::Desc)
+                .order_by((OfferIden::Table, OfferIden::Name), Order::Asc)
+                .limit(limit)
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let rows = client.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    row.get(OfferIden::OfferId.to_string().as_str()),
+                    row.get(OfferIden::Name.to_string().as_str()),
+                )
+            })
+            .collect())
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -443,6 +992,9 @@ impl Offer {
         is_active: Option<bool>,
         type_: Option<&str>,
         is_featured: Option<bool>,
+        inventory_policy: Option<&str>,
+        tax_category: Option<&str>,
+        weight_grams: Option<u32>,
     ) -> Result<Self, DbError> {
         let client = pool.get().await?;
 
@@ -471,6 +1023,18 @@ impl Offer {
                 query.value(OfferIden::IsFeatured, is_featured);
             }
 
+            if let Some(inventory_policy) = inventory_policy {
+                query.value(OfferIden::InventoryPolicy, inventory_policy);
+            }
+
+            if let Some(tax_category) = tax_category {
+                query.value(OfferIden::TaxCategory, tax_category);
+            }
+
+            if let Some(weight_grams) = weight_grams {
+                query.value(OfferIden::WeightGrams, i64::from(weight_grams));
+            }
+
             query
                 .and_where(Expr::col(OfferIden::UserId).eq(user_id))
                 .and_where(Expr::col(OfferIden::OfferId).eq(*offer_id))
@@ -532,6 +1096,32 @@ impl From<&Row> for Offer {
 
         let prices: Option<OfferPriceAsRelVec> =
             row.try_get(Self::OFFER_PRICES_ALIAS).ok();
+        let mut prices = prices.map(|p| p.0).unwrap_or_default();
+        OfferPriceAsRel::sort(&mut prices);
+
+        let options: Option<OfferOptionAsRelVec> =
+            row.try_get(Self::OFFER_OPTIONS_ALIAS).ok();
+
+        let variant_prices: Option<OfferPriceAsRelVec> =
+            row.try_get(Self::OFFER_VARIANT_PRICES_ALIAS).ok();
+        let variant_prices = variant_prices.map(|p| p.0).unwrap_or_default();
+
+        let variants: Option<OfferVariantAsRelVec> =
+            row.try_get(Self::OFFER_VARIANTS_ALIAS).ok();
+        let mut variants = variants.map(|v| v.0).unwrap_or_default();
+        variants.sort_by(|a, b| a.sku.cmp(&b.sku));
+        for variant in variants.iter_mut() {
+            variant.prices = variant_prices
+                .iter()
+                .filter(|p| {
+                    p.offer_variant_id == Some(variant.offer_variant_id)
+                })
+                .cloned()
+                .collect();
+            OfferPriceAsRel::sort(&mut variant.prices);
+            variant.price =
+                OfferPriceAsRel::select(&variant.prices, None).cloned();
+        }
 
         Self {
             offer_id: row.get(OfferIden::OfferId.to_string().as_str()),
@@ -543,7 +1133,44 @@ impl From<&Row> for Offer {
             description: row.get(OfferIden::Description.to_string().as_str()),
             is_active: row.get(OfferIden::IsActive.to_string().as_str()),
             images,
-            price: prices.and_then(|p| p.0.first().cloned()),
+            price: OfferPriceAsRel::select(&prices, None).cloned(),
+            prices,
+            options: options.map(|o| o.0).unwrap_or_default(),
+            variants,
+            inventory_policy: row
+                .get(OfferIden::InventoryPolicy.to_string().as_str()),
+            is_sold_out: row
+                .try_get(Self::IS_SOLD_OUT_ALIAS)
+                .unwrap_or_default(),
+            tax_category: row.get(OfferIden::TaxCategory.to_string().as_str()),
+            weight_grams: row
+                .get::<&str, Option<i64>>(
+                    OfferIden::WeightGrams.to_string().as_str(),
+                )
+                .map(|w| {
+                    u32::try_from(w)
+                        .expect("Should not be greater than 4294967295")
+                }),
+            shipping_profile_id: row
+                .get(OfferIden::ShippingProfileId.to_string().as_str()),
+            tags: row
+                .try_get::<&str, Option<Vec<String>>>(Self::TAGS_ALIAS)
+                .ok()
+                .flatten()
+                .unwrap_or_default(),
+            attributes: row
+                .try_get::<&str, Option<OfferAttributeAsRelVec>>(
+                    Self::ATTRIBUTES_ALIAS,
+                )
+                .ok()
+                .flatten()
+                .map(|a| a.0)
+                .unwrap_or_default(),
+            category_ids: row
+                .try_get::<&str, Option<Vec<Uuid>>>(Self::CATEGORY_IDS_ALIAS)
+                .ok()
+                .flatten()
+                .unwrap_or_default(),
             shop_name: row.try_get(Self::SHOP_NAME_ALIAS).unwrap_or_default(),
             type_: row.get(OfferIden::Type.to_string().as_str()),
             is_featured: row.get(OfferIden::IsFeatured.to_string().as_str()),
@@ -0,0 +1,274 @@
+use deadpool_postgres::tokio_postgres::types::{private, FromSql, Type};
+use deadpool_postgres::Pool;
+use fallible_iterator::FallibleIterator;
+use postgres_protocol::types;
+use sea_query::{
+    all, Condition, Expr, Func, Iden, PostgresQueryBuilder, Query, SimpleExpr,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::OfferAttributeType;
+use crate::db::{get_type_from_oid, ArrayAgg, DbError};
+
+use super::offer::OfferIden;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_attributes")]
+pub enum OfferAttributeIden {
+    Table,
+    OfferId,
+    Key,
+    AttributeType,
+    Value,
+}
+
+#[derive(Debug, Clone)]
+pub struct OfferAttributeAsRel {
+    pub key: String,
+    pub attribute_type: String,
+    pub value: String,
+}
+
+impl OfferAttributeAsRel {
+    /// Returns the value in the form it is stored for the attribute type, or
+    /// `None` if it is not a valid value of the type. Numbers and booleans
+    /// are stored in their canonical form so that equal values match.
+    pub fn normalize_value(
+        attribute_type: OfferAttributeType,
+        value: &str,
+    ) -> Option<String> {
+        let value = value.trim();
+
+        match attribute_type {
+            OfferAttributeType::Unspecified => None,
+            OfferAttributeType::Text => {
+                Some(value.to_string()).filter(|v| !v.is_empty())
+            }
+            OfferAttributeType::Number => value
+                .parse::<f64>()
+                .ok()
+                .filter(|n| n.is_finite())
+                .map(|n| n.to_string()),
+            OfferAttributeType::Boolean => value
+                .to_lowercase()
+                .parse::<bool>()
+                .ok()
+                .map(|b| b.to_string()),
+        }
+    }
+
+    /// Aggregates one record per attribute for the outer offer row.
+    pub fn get_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg).args([Expr::tuple([
+                    Expr::col((
+                        OfferAttributeIden::Table,
+                        OfferAttributeIden::Key,
+                    ))
+                    .into(),
+                    Expr::col((
+                        OfferAttributeIden::Table,
+                        OfferAttributeIden::AttributeType,
+                    ))
+                    .into(),
+                    Expr::col((
+                        OfferAttributeIden::Table,
+                        OfferAttributeIden::Value,
+                    ))
+                    .into(),
+                ])
+                .into()]),
+            )
+            .from(OfferAttributeIden::Table)
+            .and_where(
+                Expr::col((
+                    OfferAttributeIden::Table,
+                    OfferAttributeIden::OfferId,
+                ))
+                .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    /// Condition on offers that have the attribute with the given value. The
+    /// value is compared in the normalized form of each attribute type it is
+    /// valid for.
+    pub fn get_has_attribute_expr(key: &str, value: &str) -> SimpleExpr {
+        let mut values = Condition::any();
+
+        for attribute_type in [
+            OfferAttributeType::Text,
+            OfferAttributeType::Number,
+            OfferAttributeType::Boolean,
+        ] {
+            if let Some(value) = Self::normalize_value(attribute_type, value) {
+                values = values.add(all![
+                    Expr::col((
+                        OfferAttributeIden::Table,
+                        OfferAttributeIden::AttributeType,
+                    ))
+                    .eq(attribute_type.as_str_name()),
+                    Expr::col((
+                        OfferAttributeIden::Table,
+                        OfferAttributeIden::Value,
+                    ))
+                    .eq(value),
+                ]);
+            }
+        }
+
+        let mut query = Query::select();
+
+        query
+            .expr(Expr::val(1))
+            .from(OfferAttributeIden::Table)
+            .and_where(
+                Expr::col((
+                    OfferAttributeIden::Table,
+                    OfferAttributeIden::OfferId,
+                ))
+                .equals((OfferIden::Table, OfferIden::OfferId)),
+            )
+            .and_where(
+                Expr::col((OfferAttributeIden::Table, OfferAttributeIden::Key))
+                    .eq(key.trim()),
+            )
+            .cond_where(values);
+
+        Expr::exists(query)
+    }
+
+    /// Replaces the attributes of the offer.
+    pub async fn put(
+        pool: &Pool,
+        offer_id: &Uuid,
+        attributes: &[Self],
+    ) -> Result<(), DbError> {
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(OfferAttributeIden::Table)
+            .and_where(Expr::col(OfferAttributeIden::OfferId).eq(*offer_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        if !attributes.is_empty() {
+            let (sql, values) = {
+                let mut query = Query::insert();
+
+                query.into_table(OfferAttributeIden::Table).columns([
+                    OfferAttributeIden::OfferId,
+                    OfferAttributeIden::Key,
+                    OfferAttributeIden::AttributeType,
+                    OfferAttributeIden::Value,
+                ]);
+
+                for attribute in attributes {
+                    query.values([
+                        (*offer_id).into(),
+                        attribute.key.clone().into(),
+                        attribute.attribute_type.clone().into(),
+                        attribute.value.clone().into(),
+                    ])?;
+                }
+
+                query.build_postgres(PostgresQueryBuilder)
+            };
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
+
+impl<'a> FromSql<'a> for OfferAttributeAsRel {
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::RECORD => true,
+            _ => {
+                tracing::log::error!(
+                    "[OfferAttributeAsRel.FromSql.accepts]: postgres type {:?} not implemented",
+                    ty
+                );
+                false
+            }
+        }
+    }
+
+    fn from_sql(
+        _: &Type,
+        mut raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        private::read_be_i32(&mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<String>(oid)?;
+        let key: String = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<String>(oid)?;
+        let attribute_type: String = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<String>(oid)?;
+        let value: String = private::read_value(&ty, &mut raw)?;
+
+        Ok(Self {
+            key,
+            attribute_type,
+            value,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct OfferAttributeAsRelVec(pub Vec<OfferAttributeAsRel>);
+
+impl<'a> FromSql<'a> for OfferAttributeAsRelVec {
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::RECORD_ARRAY => true,
+            _ => {
+                tracing::log::error!("[OfferAttributeAsRelVec::<FromSql>::accepts]: postgres type {:?} not implemented", ty);
+                false
+            }
+        }
+    }
+
+    fn from_sql(
+        _: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let array = types::array_from_sql(raw)?;
+
+        if array.dimensions().count()? > 1 {
+            return Err("array contains too many dimensions".into());
+        }
+
+        let mut attributes: Vec<OfferAttributeAsRel> = array
+            .values()
+            .filter_map(|v| {
+                Ok(OfferAttributeAsRel::from_sql_nullable(&Type::RECORD, v)
+                    .ok())
+            })
+            .collect()?;
+
+        attributes.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Ok(Self(attributes))
+    }
+}
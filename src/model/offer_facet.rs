@@ -0,0 +1,274 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Pool;
+use sea_query::{
+    Alias, Asterisk, CaseStatement, Expr, JoinType, Order,
+    PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
+};
+use sea_query_postgres::{PostgresBinder, PostgresValues};
+
+use crate::db::DbError;
+
+use super::offer::OfferIden;
+use super::offer_tag::OfferTagIden;
+
+#[derive(Debug, Clone)]
+pub struct OfferFacetCount {
+    pub value: String,
+    pub count: i64,
+}
+
+/// Count of offers with a price from `min_price` (inclusive) to `max_price`
+/// (exclusive). The first and last bucket are open-ended.
+#[derive(Debug, Clone)]
+pub struct OfferPriceBucketCount {
+    pub min_price: Option<u32>,
+    pub max_price: Option<u32>,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OfferFacets {
+    pub types: Vec<OfferFacetCount>,
+    pub price_buckets: Vec<OfferPriceBucketCount>,
+    pub tags: Vec<OfferFacetCount>,
+    pub is_featured: Vec<OfferFacetCount>,
+}
+
+/// Built SQL and values of the facet queries, which unlike the statements
+/// can be held across awaits.
+pub struct OfferFacetQueries {
+    types: (String, PostgresValues),
+    is_featured: (String, PostgresValues),
+    tags: (String, PostgresValues),
+    price_buckets: (String, PostgresValues),
+}
+
+impl OfferFacets {
+    const FILTERED_OFFERS_ALIAS: &'static str = "filtered_offers";
+    const PRICE_ALIAS: &'static str = "price";
+    const BUCKET_ALIAS: &'static str = "bucket";
+    const VALUE_ALIAS: &'static str = "value";
+    const COUNT_ALIAS: &'static str = "count";
+    const TAGS_LIMIT: u64 = 20;
+
+    fn get_filtered_offers_alias() -> Alias {
+        Alias::new(Self::FILTERED_OFFERS_ALIAS)
+    }
+
+    pub fn get_price_alias() -> Alias {
+        Alias::new(Self::PRICE_ALIAS)
+    }
+
+    fn get_bucket_alias() -> Alias {
+        Alias::new(Self::BUCKET_ALIAS)
+    }
+
+    fn get_value_alias() -> Alias {
+        Alias::new(Self::VALUE_ALIAS)
+    }
+
+    fn get_count_alias() -> Alias {
+        Alias::new(Self::COUNT_ALIAS)
+    }
+
+    /// Counts the offers selected by `offers` per value of the column.
+    fn select_counts_by(
+        offers: SelectStatement,
+        column: OfferIden,
+    ) -> SelectStatement {
+        let mut query = Query::select();
+
+        query
+            .expr_as(Expr::col(column), Self::get_value_alias())
+            .expr_as(Expr::col(Asterisk).count(), Self::get_count_alias())
+            .from_subquery(offers, Self::get_filtered_offers_alias())
+            .group_by_col(column)
+            .order_by(Self::get_count_alias(), Order::Desc)
+            .order_by(Self::get_value_alias(), Order::Asc);
+
+        query
+    }
+
+    /// Index of the price bucket, where bucket `i` holds prices below the
+    /// `i`-th bound and the last bucket all prices from the highest bound.
+    fn get_bucket_expr(price_bounds: &[u32]) -> SimpleExpr {
+        let price = Expr::col(Self::get_price_alias());
+
+        let mut bucket = CaseStatement::new();
+
+        for (i, bound) in price_bounds.iter().enumerate() {
+            bucket = bucket
+                .case(price.clone().lt(i64::from(*bound)), Expr::val(i as i64));
+        }
+
+        bucket.finally(Expr::val(price_bounds.len() as i64)).into()
+    }
+
+    fn get_counts_from_rows(rows: &[Row]) -> Vec<OfferFacetCount> {
+        rows.iter()
+            .map(|row| OfferFacetCount {
+                value: row.get(Self::VALUE_ALIAS),
+                count: row.get(Self::COUNT_ALIAS),
+            })
+            .collect()
+    }
+
+    /// Counts the most frequent tags of the offers selected by `offers`.
+    fn select_tag_counts(offers: SelectStatement) -> SelectStatement {
+        let mut query = Query::select();
+
+        query
+            .expr_as(
+                Expr::col((OfferTagIden::Table, OfferTagIden::Tag)),
+                Self::get_value_alias(),
+            )
+            .expr_as(Expr::col(Asterisk).count(), Self::get_count_alias())
+            .from(OfferTagIden::Table)
+            .join_subquery(
+                JoinType::InnerJoin,
+                offers,
+                Self::get_filtered_offers_alias(),
+                Expr::col((
+                    Self::get_filtered_offers_alias(),
+                    OfferIden::OfferId,
+                ))
+                .equals((OfferTagIden::Table, OfferTagIden::OfferId)),
+            )
+            .group_by_col((OfferTagIden::Table, OfferTagIden::Tag))
+            .order_by(Self::get_count_alias(), Order::Desc)
+            .order_by(Self::get_value_alias(), Order::Asc)
+            .limit(Self::TAGS_LIMIT);
+
+        query
+    }
+
+    /// Counts the offers selected by `offers` that have a price per bucket.
+    fn select_price_bucket_counts(
+        offers: SelectStatement,
+        price_bounds: &[u32],
+    ) -> SelectStatement {
+        let mut query = Query::select();
+
+        query
+            .expr_as(
+                Self::get_bucket_expr(price_bounds),
+                Self::get_bucket_alias(),
+            )
+            .expr_as(Expr::col(Asterisk).count(), Self::get_count_alias())
+            .from_subquery(offers, Self::get_filtered_offers_alias())
+            .and_where(Expr::col(Self::get_price_alias()).is_not_null())
+            .group_by_col(Self::get_bucket_alias());
+
+        query
+    }
+
+    /// Returns the count of every bucket, including empty buckets.
+    fn get_price_buckets_from_rows(
+        rows: &[Row],
+        price_bounds: &[u32],
+    ) -> Vec<OfferPriceBucketCount> {
+        let mut buckets: Vec<OfferPriceBucketCount> = (0..=price_bounds.len())
+            .map(|i| OfferPriceBucketCount {
+                min_price: i.checked_sub(1).map(|i| price_bounds[i]),
+                max_price: price_bounds.get(i).copied(),
+                count: 0,
+            })
+            .collect();
+
+        for row in rows {
+            let bucket: i64 = row.get(Self::BUCKET_ALIAS);
+            if let Some(bucket) = usize::try_from(bucket)
+                .ok()
+                .and_then(|b| buckets.get_mut(b))
+            {
+                bucket.count = row.get(Self::COUNT_ALIAS);
+            }
+        }
+
+        buckets
+    }
+
+    /// Builds the facet queries over the offers selected by `offers`. The
+    /// query has to select the offer id, type and featured flag, and for
+    /// price buckets the price under the price alias.
+    pub fn build_queries(
+        offers: SelectStatement,
+        price_bounds: &[u32],
+    ) -> OfferFacetQueries {
+        OfferFacetQueries {
+            types: Self::select_counts_by(offers.clone(), OfferIden::Type)
+                .build_postgres(PostgresQueryBuilder),
+            is_featured: Self::select_counts_by(
+                offers.clone(),
+                OfferIden::IsFeatured,
+            )
+            .build_postgres(PostgresQueryBuilder),
+            tags: Self::select_tag_counts(offers.clone())
+                .build_postgres(PostgresQueryBuilder),
+            price_buckets: Self::select_price_bucket_counts(
+                offers,
+                price_bounds,
+            )
+            .build_postgres(PostgresQueryBuilder),
+        }
+    }
+
+    /// Runs the facet queries. Price buckets are only counted with bounds.
+    pub async fn get(
+        pool: &Pool,
+        queries: OfferFacetQueries,
+        price_bounds: &[u32],
+    ) -> Result<Self, DbError> {
+        let OfferFacetQueries {
+            types: (types_sql, types_values),
+            is_featured: (is_featured_sql, is_featured_values),
+            tags: (tags_sql, tags_values),
+            price_buckets: (price_buckets_sql, price_buckets_values),
+        } = queries;
+
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
+
+        let rows = transaction
+            .query(types_sql.as_str(), &types_values.as_params())
+            .await?;
+        let types = Self::get_counts_from_rows(&rows);
+
+        let rows = transaction
+            .query(is_featured_sql.as_str(), &is_featured_values.as_params())
+            .await?;
+        let is_featured = rows
+            .iter()
+            .map(|row| OfferFacetCount {
+                value: row.get::<&str, bool>(Self::VALUE_ALIAS).to_string(),
+                count: row.get(Self::COUNT_ALIAS),
+            })
+            .collect();
+
+        let rows = transaction
+            .query(tags_sql.as_str(), &tags_values.as_params())
+            .await?;
+        let tags = Self::get_counts_from_rows(&rows);
+
+        let price_buckets = if price_bounds.is_empty() {
+            Vec::new()
+        } else {
+            let rows = transaction
+                .query(
+                    price_buckets_sql.as_str(),
+                    &price_buckets_values.as_params(),
+                )
+                .await?;
+            Self::get_price_buckets_from_rows(&rows, price_bounds)
+        };
+
+        transaction.commit().await?;
+
+        Ok(Self {
+            types,
+            price_buckets,
+            tags,
+            is_featured,
+        })
+    }
+}
@@ -11,6 +11,7 @@ use sea_query_postgres::PostgresBinder;
 use uuid::Uuid;
 
 use crate::db::{get_type_from_oid, ArrayAgg, DbError};
+use crate::images::StoredImageVariants;
 
 #[derive(Iden)]
 #[iden(rename = "offer_images")]
@@ -23,6 +24,8 @@ pub enum OfferImageIden {
     UpdatedAt,
     ImageUrlPath,
     Ordering,
+    VariantWidths,
+    VariantContentTypes,
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +37,7 @@ pub struct OfferImage {
     pub updated_at: DateTime<Utc>,
     pub image_url_path: String,
     pub ordering: i64,
+    pub variants: StoredImageVariants,
 }
 
 impl OfferImage {
@@ -117,6 +121,30 @@ impl OfferImage {
         Ok(rows.iter().map(Self::from).collect())
     }
 
+    pub async fn update_variants<'a>(
+        transaction: &Transaction<'a>,
+        offer_image_id: &Uuid,
+        variants: &StoredImageVariants,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::update()
+            .table(OfferImageIden::Table)
+            .value(OfferImageIden::VariantWidths, variants.get_widths_expr())
+            .value(
+                OfferImageIden::VariantContentTypes,
+                variants.get_content_types_expr(),
+            )
+            .and_where(
+                Expr::col(OfferImageIden::OfferImageId).eq(*offer_image_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn delete<'a>(
         transaction: &Transaction<'a>,
         user_id: &String,
@@ -150,6 +178,12 @@ impl From<&Row> for OfferImage {
             image_url_path: row
                 .get(OfferImageIden::ImageUrlPath.to_string().as_str()),
             ordering: row.get(OfferImageIden::Ordering.to_string().as_str()),
+            variants: StoredImageVariants::from_columns(
+                row.get(OfferImageIden::VariantWidths.to_string().as_str()),
+                row.get(
+                    OfferImageIden::VariantContentTypes.to_string().as_str(),
+                ),
+            ),
         }
     }
 }
@@ -165,6 +199,7 @@ pub struct OfferImageAsRel {
     pub offer_image_id: Uuid,
     pub image_url_path: String,
     pub ordering: i64,
+    pub variants: StoredImageVariants,
 }
 
 impl OfferImageAsRel {
@@ -183,6 +218,16 @@ impl OfferImageAsRel {
                 .into(),
                 Expr::col((OfferImageIden::Table, OfferImageIden::Ordering))
                     .into(),
+                Expr::col((
+                    OfferImageIden::Table,
+                    OfferImageIden::VariantWidths,
+                ))
+                .into(),
+                Expr::col((
+                    OfferImageIden::Table,
+                    OfferImageIden::VariantContentTypes,
+                ))
+                .into(),
             ])
             .into()])
             .into()
@@ -218,10 +263,23 @@ impl<'a> FromSql<'a> for OfferImageAsRel {
         let ty = get_type_from_oid::<i64>(oid)?;
         let ordering: i64 = private::read_value(&ty, &mut raw)?;
 
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<i64>>(oid)?;
+        let variant_widths: Vec<i64> = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<String>>(oid)?;
+        let variant_content_types: Vec<String> =
+            private::read_value(&ty, &mut raw)?;
+
         Ok(Self {
             offer_image_id,
             image_url_path,
             ordering,
+            variants: StoredImageVariants::from_columns(
+                variant_widths,
+                variant_content_types,
+            ),
         })
     }
 }
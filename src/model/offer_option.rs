@@ -0,0 +1,467 @@
+use deadpool_postgres::tokio_postgres::types::{private, FromSql, Type};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use fallible_iterator::FallibleIterator;
+use postgres_protocol::types;
+use sea_query::{
+    Asterisk, Expr, Func, Iden, PostgresQueryBuilder, Query, SimpleExpr,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::{get_type_from_oid, ArrayAgg, DbError};
+
+use super::offer::OfferIden;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_options")]
+pub enum OfferOptionIden {
+    Table,
+    OfferOptionId,
+    OfferId,
+    UserId,
+    Name,
+    Ordering,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_option_values")]
+pub enum OfferOptionValueIden {
+    Table,
+    OfferOptionValueId,
+    OfferOptionId,
+    OfferId,
+    UserId,
+    Value,
+    Ordering,
+}
+
+#[derive(Debug, Clone)]
+pub struct OfferOption {
+    pub offer_option_id: Uuid,
+    pub offer_id: Uuid,
+}
+
+impl OfferOption {
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        offer_id: &Uuid,
+        user_id: &String,
+        name: &String,
+        ordering: i64,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(OfferOptionIden::Table)
+            .columns([
+                OfferOptionIden::OfferId,
+                OfferOptionIden::UserId,
+                OfferOptionIden::Name,
+                OfferOptionIden::Ordering,
+            ])
+            .values([
+                (*offer_id).into(),
+                user_id.into(),
+                name.into(),
+                ordering.into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        offer_option_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(OfferOptionIden::Table)
+            .and_where(
+                Expr::col(OfferOptionIden::OfferOptionId).eq(*offer_option_id),
+            )
+            .and_where(Expr::col(OfferOptionIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn update(
+        pool: &Pool,
+        user_id: &String,
+        offer_option_id: &Uuid,
+        name: Option<String>,
+        ordering: Option<i64>,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = {
+            let mut query = Query::update();
+
+            query.table(OfferOptionIden::Table);
+
+            if let Some(name) = name {
+                query.value(OfferOptionIden::Name, name);
+            }
+
+            if let Some(ordering) = ordering {
+                query.value(OfferOptionIden::Ordering, ordering);
+            }
+
+            query
+                .and_where(Expr::col(OfferOptionIden::UserId).eq(user_id))
+                .and_where(
+                    Expr::col(OfferOptionIden::OfferOptionId)
+                        .eq(*offer_option_id),
+                )
+                .returning_all()
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn delete<'a>(
+        transaction: &Transaction<'a>,
+        user_id: &String,
+        offer_option_id: &Uuid,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(OfferOptionIden::Table)
+            .and_where(Expr::col(OfferOptionIden::UserId).eq(user_id))
+            .and_where(
+                Expr::col(OfferOptionIden::OfferOptionId).eq(*offer_option_id),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+}
+
+impl From<&Row> for OfferOption {
+    fn from(row: &Row) -> Self {
+        Self {
+            offer_option_id: row
+                .get(OfferOptionIden::OfferOptionId.to_string().as_str()),
+            offer_id: row.get(OfferOptionIden::OfferId.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for OfferOption {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OfferOptionValue {
+    pub offer_option_value_id: Uuid,
+    pub offer_id: Uuid,
+}
+
+impl OfferOptionValue {
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        offer_option_id: &Uuid,
+        offer_id: &Uuid,
+        user_id: &String,
+        value: &String,
+        ordering: i64,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(OfferOptionValueIden::Table)
+            .columns([
+                OfferOptionValueIden::OfferOptionId,
+                OfferOptionValueIden::OfferId,
+                OfferOptionValueIden::UserId,
+                OfferOptionValueIden::Value,
+                OfferOptionValueIden::Ordering,
+            ])
+            .values([
+                (*offer_option_id).into(),
+                (*offer_id).into(),
+                user_id.into(),
+                value.into(),
+                ordering.into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn delete<'a>(
+        transaction: &Transaction<'a>,
+        user_id: &String,
+        offer_option_value_id: &Uuid,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(OfferOptionValueIden::Table)
+            .and_where(Expr::col(OfferOptionValueIden::UserId).eq(user_id))
+            .and_where(
+                Expr::col(OfferOptionValueIden::OfferOptionValueId)
+                    .eq(*offer_option_value_id),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+}
+
+impl From<&Row> for OfferOptionValue {
+    fn from(row: &Row) -> Self {
+        Self {
+            offer_option_value_id: row.get(
+                OfferOptionValueIden::OfferOptionValueId
+                    .to_string()
+                    .as_str(),
+            ),
+            offer_id: row
+                .get(OfferOptionValueIden::OfferId.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for OfferOptionValue {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OfferOptionValueAsRel {
+    pub offer_option_value_id: Uuid,
+    pub value: String,
+    pub ordering: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct OfferOptionAsRel {
+    pub offer_option_id: Uuid,
+    pub name: String,
+    pub ordering: i64,
+    pub values: Vec<OfferOptionValueAsRel>,
+}
+
+impl OfferOptionAsRel {
+    /// Aggregates one record per option value (or one record without value
+    /// for options that have no values yet) for the outer offer row.
+    pub fn get_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg).args([Expr::tuple([
+                    Expr::col((
+                        OfferOptionIden::Table,
+                        OfferOptionIden::OfferOptionId,
+                    ))
+                    .into(),
+                    Expr::col((OfferOptionIden::Table, OfferOptionIden::Name))
+                        .into(),
+                    Expr::col((
+                        OfferOptionIden::Table,
+                        OfferOptionIden::Ordering,
+                    ))
+                    .into(),
+                    Expr::col((
+                        OfferOptionValueIden::Table,
+                        OfferOptionValueIden::OfferOptionValueId,
+                    ))
+                    .into(),
+                    Expr::col((
+                        OfferOptionValueIden::Table,
+                        OfferOptionValueIden::Value,
+                    ))
+                    .into(),
+                    Expr::col((
+                        OfferOptionValueIden::Table,
+                        OfferOptionValueIden::Ordering,
+                    ))
+                    .into(),
+                ])
+                .into()]),
+            )
+            .from(OfferOptionIden::Table)
+            .left_join(
+                OfferOptionValueIden::Table,
+                Expr::col((
+                    OfferOptionIden::Table,
+                    OfferOptionIden::OfferOptionId,
+                ))
+                .equals((
+                    OfferOptionValueIden::Table,
+                    OfferOptionValueIden::OfferOptionId,
+                )),
+            )
+            .and_where(
+                Expr::col((OfferOptionIden::Table, OfferOptionIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+}
+
+struct OfferOptionValueRecord {
+    offer_option_id: Uuid,
+    name: String,
+    ordering: i64,
+    value: Option<OfferOptionValueAsRel>,
+}
+
+impl<'a> FromSql<'a> for OfferOptionValueRecord {
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::RECORD => true,
+            _ => {
+                tracing::log::error!(
+                    "[OfferOptionValueRecord.FromSql.accepts]: postgres type {:?} not implemented",
+                    ty
+                );
+                false
+            }
+        }
+    }
+
+    fn from_sql(
+        _: &Type,
+        mut raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        private::read_be_i32(&mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Uuid>(oid)?;
+        let offer_option_id: Uuid = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<String>(oid)?;
+        let name: String = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<i64>(oid)?;
+        let ordering: i64 = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<Uuid>>(oid)?;
+        let offer_option_value_id: Option<Uuid> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<String>>(oid)?;
+        let value: Option<String> = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<i64>>(oid)?;
+        let value_ordering: Option<i64> = private::read_value(&ty, &mut raw)?;
+
+        let value = match (offer_option_value_id, value, value_ordering) {
+            (Some(offer_option_value_id), Some(value), Some(ordering)) => {
+                Some(OfferOptionValueAsRel {
+                    offer_option_value_id,
+                    value,
+                    ordering,
+                })
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            offer_option_id,
+            name,
+            ordering,
+            value,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct OfferOptionAsRelVec(pub Vec<OfferOptionAsRel>);
+
+impl<'a> FromSql<'a> for OfferOptionAsRelVec {
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::RECORD_ARRAY => true,
+            _ => {
+                tracing::log::error!("[OfferOptionAsRelVec::<FromSql>::accepts]: postgres type {:?} not implemented", ty);
+                false
+            }
+        }
+    }
+
+    fn from_sql(
+        _: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let array = types::array_from_sql(raw)?;
+
+        if array.dimensions().count()? > 1 {
+            return Err("array contains too many dimensions".into());
+        }
+
+        let records: Vec<OfferOptionValueRecord> = array
+            .values()
+            .filter_map(|v| {
+                Ok(OfferOptionValueRecord::from_sql_nullable(&Type::RECORD, v)
+                    .ok())
+            })
+            .collect()?;
+
+        let mut options: Vec<OfferOptionAsRel> = Vec::new();
+
+        for record in records {
+            let option = match options
+                .iter_mut()
+                .find(|o| o.offer_option_id == record.offer_option_id)
+            {
+                Some(option) => option,
+                None => {
+                    options.push(OfferOptionAsRel {
+                        offer_option_id: record.offer_option_id,
+                        name: record.name,
+                        ordering: record.ordering,
+                        values: Vec::new(),
+                    });
+                    options.last_mut().unwrap()
+                }
+            };
+
+            if let Some(value) = record.value {
+                option.values.push(value);
+            }
+        }
+
+        options.sort_by_key(|o| o.ordering);
+        for option in options.iter_mut() {
+            option.values.sort_by_key(|v| v.ordering);
+        }
+
+        Ok(Self(options))
+    }
+}
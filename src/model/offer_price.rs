@@ -1,16 +1,22 @@
 use chrono::{DateTime, Utc};
 use deadpool_postgres::tokio_postgres::types::{private, FromSql, Type};
 use deadpool_postgres::tokio_postgres::Row;
-use deadpool_postgres::Pool;
+use deadpool_postgres::{Pool, Transaction};
 use fallible_iterator::FallibleIterator;
 use postgres_protocol::types;
 use sea_query::{
-    Asterisk, Expr, Func, Iden, PostgresQueryBuilder, Query, SimpleExpr,
+    all, any, Asterisk, Expr, Func, Iden, PostgresQueryBuilder, Query,
+    SimpleExpr,
 };
 use sea_query_postgres::PostgresBinder;
 use uuid::Uuid;
 
+use crate::api::sited_io::commerce::v1::{Currency, PriceBillingScheme};
 use crate::db::{get_type_from_oid, ArrayAgg, DbError};
+use crate::pricing::{calculate_amount, sort_tiers, PriceSale, PriceTier};
+
+use super::offer::OfferIden;
+use super::offer_price_tier::OfferPriceTierIden;
 
 #[derive(Iden)]
 #[iden(rename = "offer_prices")]
@@ -28,6 +34,11 @@ pub enum OfferPriceIden {
     RecurringInterval,
     RecurringIntervalCount,
     TrialPeriodDays,
+    OfferVariantId,
+    PackageSize,
+    CompareAtAmount,
+    SaleStartsAt,
+    SaleEndsAt,
 }
 
 #[derive(Debug, Clone)]
@@ -44,13 +55,28 @@ pub struct OfferPrice {
     pub recurring_interval: Option<String>,
     pub recurring_interval_count: Option<u32>,
     pub trial_period_days: Option<u32>,
+    pub offer_variant_id: Option<Uuid>,
+    pub package_size: Option<u32>,
+    pub compare_at_amount: Option<u32>,
+    pub sale_starts_at: Option<DateTime<Utc>>,
+    pub sale_ends_at: Option<DateTime<Utc>>,
 }
 
 impl OfferPrice {
+    fn offer_variant_cond(offer_variant_id: Option<&Uuid>) -> SimpleExpr {
+        match offer_variant_id {
+            Some(offer_variant_id) => {
+                Expr::col(OfferPriceIden::OfferVariantId).eq(*offer_variant_id)
+            }
+            None => Expr::col(OfferPriceIden::OfferVariantId).is_null(),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
-    pub async fn create(
-        pool: &Pool,
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
         offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
         user_id: &String,
         currency: &str,
         price_type: &str,
@@ -59,9 +85,9 @@ impl OfferPrice {
         recurring_interval: Option<&str>,
         recurring_interval_count: Option<u32>,
         trial_period_days: Option<u32>,
+        package_size: Option<u32>,
+        sale: Option<&PriceSale>,
     ) -> Result<Self, DbError> {
-        let client = pool.get().await?;
-
         let (sql, values) = Query::insert()
             .into_table(OfferPriceIden::Table)
             .columns([
@@ -74,6 +100,11 @@ impl OfferPrice {
                 OfferPriceIden::RecurringInterval,
                 OfferPriceIden::RecurringIntervalCount,
                 OfferPriceIden::TrialPeriodDays,
+                OfferPriceIden::OfferVariantId,
+                OfferPriceIden::PackageSize,
+                OfferPriceIden::CompareAtAmount,
+                OfferPriceIden::SaleStartsAt,
+                OfferPriceIden::SaleEndsAt,
             ])
             .values([
                 (*offer_id).into(),
@@ -85,11 +116,18 @@ impl OfferPrice {
                 recurring_interval.into(),
                 recurring_interval_count.map(i64::from).into(),
                 trial_period_days.map(i64::from).into(),
+                offer_variant_id.copied().into(),
+                package_size.map(i64::from).into(),
+                sale.map(|s| i64::from(s.compare_at_amount)).into(),
+                sale.and_then(|s| s.starts_at).into(),
+                sale.and_then(|s| s.ends_at).into(),
             ])?
             .returning_all()
             .build_postgres(PostgresQueryBuilder);
 
-        let row = client.query_one(sql.as_str(), &values.as_params()).await?;
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
 
         Ok(Self::from(row))
     }
@@ -97,6 +135,8 @@ impl OfferPrice {
     pub async fn get_by_offer_id(
         pool: &Pool,
         offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        currency: &str,
     ) -> Result<Option<Self>, DbError> {
         let client = pool.get().await?;
 
@@ -104,6 +144,8 @@ impl OfferPrice {
             .column(Asterisk)
             .from(OfferPriceIden::Table)
             .and_where(Expr::col(OfferPriceIden::OfferId).eq(*offer_id))
+            .and_where(Self::offer_variant_cond(offer_variant_id))
+            .and_where(Expr::col(OfferPriceIden::Currency).eq(currency))
             .build_postgres(PostgresQueryBuilder);
 
         Ok(client
@@ -113,10 +155,11 @@ impl OfferPrice {
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub async fn put(
-        pool: &Pool,
+    pub async fn put<'a>(
+        transaction: &Transaction<'a>,
         user_id: &String,
         offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
         currency: &str,
         price_type: &str,
         billing_scheme: &str,
@@ -124,12 +167,11 @@ impl OfferPrice {
         recurring_interval: Option<&str>,
         recurring_interval_count: Option<u32>,
         trial_period_days: Option<u32>,
+        package_size: Option<u32>,
+        sale: Option<&PriceSale>,
     ) -> Result<Self, DbError> {
-        let client = pool.get().await?;
-
         let (sql, values) = Query::update()
             .table(OfferPriceIden::Table)
-            .value(OfferPriceIden::Currency, currency)
             .value(OfferPriceIden::PriceType, price_type)
             .value(OfferPriceIden::BillingScheme, billing_scheme)
             .value(OfferPriceIden::UnitAmount, i64::from(unit_amount))
@@ -142,28 +184,87 @@ impl OfferPrice {
                 OfferPriceIden::TrialPeriodDays,
                 trial_period_days.map(i64::from),
             )
+            .value(OfferPriceIden::PackageSize, package_size.map(i64::from))
+            .value(
+                OfferPriceIden::CompareAtAmount,
+                sale.map(|s| i64::from(s.compare_at_amount)),
+            )
+            .value(OfferPriceIden::SaleStartsAt, sale.and_then(|s| s.starts_at))
+            .value(OfferPriceIden::SaleEndsAt, sale.and_then(|s| s.ends_at))
             .and_where(Expr::col(OfferPriceIden::UserId).eq(user_id))
             .and_where(Expr::col(OfferPriceIden::OfferId).eq(*offer_id))
+            .and_where(Self::offer_variant_cond(offer_variant_id))
+            .and_where(Expr::col(OfferPriceIden::Currency).eq(currency))
             .returning_all()
             .build_postgres(PostgresQueryBuilder);
 
-        let row = client.query_one(sql.as_str(), &values.as_params()).await?;
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
 
         Ok(Self::from(row))
     }
 
+    /// Returns the offers with a sale that started or ended in the given
+    /// period, excluding its start and including its end.
+    pub async fn list_offer_ids_with_sale_change(
+        pool: &Pool,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<Uuid>, DbError> {
+        let client = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .distinct()
+            .column(OfferPriceIden::OfferId)
+            .from(OfferPriceIden::Table)
+            .and_where(Expr::col(OfferPriceIden::CompareAtAmount).is_not_null())
+            .cond_where(any![
+                Expr::col(OfferPriceIden::SaleStartsAt)
+                    .gt(since)
+                    .and(Expr::col(OfferPriceIden::SaleStartsAt).lte(until)),
+                Expr::col(OfferPriceIden::SaleEndsAt)
+                    .gt(since)
+                    .and(Expr::col(OfferPriceIden::SaleEndsAt).lte(until)),
+            ])
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = client.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows
+            .iter()
+            .map(|r| r.get(OfferPriceIden::OfferId.to_string().as_str()))
+            .collect())
+    }
+
+    /// Deletes the price in the given currency or all prices if no currency
+    /// is given.
     pub async fn delete(
         pool: &Pool,
         user_id: &String,
         offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        currency: Option<&str>,
     ) -> Result<(), DbError> {
         let client = pool.get().await?;
 
-        let (sql, values) = Query::delete()
-            .from_table(OfferPriceIden::Table)
-            .and_where(Expr::col(OfferPriceIden::UserId).eq(user_id))
-            .and_where(Expr::col(OfferPriceIden::OfferId).eq(*offer_id))
-            .build_postgres(PostgresQueryBuilder);
+        let (sql, values) = {
+            let mut query = Query::delete();
+
+            query
+                .from_table(OfferPriceIden::Table)
+                .and_where(Expr::col(OfferPriceIden::UserId).eq(user_id))
+                .and_where(Expr::col(OfferPriceIden::OfferId).eq(*offer_id))
+                .and_where(Self::offer_variant_cond(offer_variant_id));
+
+            if let Some(currency) = currency {
+                query.and_where(
+                    Expr::col(OfferPriceIden::Currency).eq(currency),
+                );
+            }
+
+            query.build_postgres(PostgresQueryBuilder)
+        };
 
         client.execute(sql.as_str(), &values.as_params()).await?;
 
@@ -206,6 +307,28 @@ impl From<&Row> for OfferPrice {
                     u32::try_from(c)
                         .expect("Should not be greater than 4294967295")
                 }),
+            offer_variant_id: row
+                .get(OfferPriceIden::OfferVariantId.to_string().as_str()),
+            package_size: row
+                .get::<&str, Option<i64>>(
+                    OfferPriceIden::PackageSize.to_string().as_str(),
+                )
+                .map(|s| {
+                    u32::try_from(s)
+                        .expect("Should not be greater than 4294967295")
+                }),
+            compare_at_amount: row
+                .get::<&str, Option<i64>>(
+                    OfferPriceIden::CompareAtAmount.to_string().as_str(),
+                )
+                .map(|a| {
+                    u32::try_from(a)
+                        .expect("Should not be greater than 4294967295")
+                }),
+            sale_starts_at: row
+                .get(OfferPriceIden::SaleStartsAt.to_string().as_str()),
+            sale_ends_at: row
+                .get(OfferPriceIden::SaleEndsAt.to_string().as_str()),
         }
     }
 }
@@ -226,6 +349,10 @@ pub struct OfferPriceAsRel {
     pub recurring_interval: Option<String>,
     pub recurring_interval_count: Option<u32>,
     pub trial_period_days: Option<u32>,
+    pub offer_variant_id: Option<Uuid>,
+    pub package_size: Option<u32>,
+    pub tiers: Vec<PriceTier>,
+    pub sale: Option<PriceSale>,
 }
 
 impl OfferPriceAsRel {
@@ -263,19 +390,198 @@ impl OfferPriceAsRel {
                     OfferPriceIden::TrialPeriodDays,
                 ))
                 .into(),
+                Expr::col((
+                    OfferPriceIden::Table,
+                    OfferPriceIden::OfferVariantId,
+                ))
+                .into(),
+                Expr::col((OfferPriceIden::Table, OfferPriceIden::PackageSize))
+                    .into(),
+                Expr::col((
+                    OfferPriceIden::Table,
+                    OfferPriceIden::CompareAtAmount,
+                ))
+                .into(),
+                Expr::col((
+                    OfferPriceIden::Table,
+                    OfferPriceIden::SaleStartsAt,
+                ))
+                .into(),
+                Expr::col((OfferPriceIden::Table, OfferPriceIden::SaleEndsAt))
+                    .into(),
+                Expr::col((
+                    OfferPriceTierIden::Table,
+                    OfferPriceTierIden::UpTo,
+                ))
+                .into(),
+                Expr::col((
+                    OfferPriceTierIden::Table,
+                    OfferPriceTierIden::UnitAmount,
+                ))
+                .into(),
+                Expr::col((
+                    OfferPriceTierIden::Table,
+                    OfferPriceTierIden::FlatAmount,
+                ))
+                .into(),
             ])
             .into()])
             .into()
     }
+
+    /// Aggregates one record per tier (or one record without tier for prices
+    /// that have no tiers) for the outer offer row.
+    fn get_sub_query_agg(variant_cond: SimpleExpr) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(Self::get_agg())
+            .from(OfferPriceIden::Table)
+            .left_join(
+                OfferPriceTierIden::Table,
+                Expr::col((
+                    OfferPriceIden::Table,
+                    OfferPriceIden::OfferPriceId,
+                ))
+                .equals((
+                    OfferPriceTierIden::Table,
+                    OfferPriceTierIden::OfferPriceId,
+                )),
+            )
+            .and_where(
+                Expr::col((OfferPriceIden::Table, OfferPriceIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            )
+            .and_where(variant_cond);
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    /// Aggregates the prices of the outer offer row in all currencies.
+    pub fn get_offer_agg() -> SimpleExpr {
+        Self::get_sub_query_agg(
+            Expr::col((OfferPriceIden::Table, OfferPriceIden::OfferVariantId))
+                .is_null(),
+        )
+    }
+
+    /// Aggregates the prices of all variants of the outer offer row.
+    pub fn get_variant_agg() -> SimpleExpr {
+        Self::get_sub_query_agg(
+            Expr::col((OfferPriceIden::Table, OfferPriceIden::OfferVariantId))
+                .is_not_null(),
+        )
+    }
+
+    /// Unit amount charged for the price in the given table, which is the
+    /// compare-at amount outside of an active sale.
+    pub fn get_charged_unit_amount_expr() -> SimpleExpr {
+        let col = |c| Expr::col((OfferPriceIden::Table, c));
+
+        let is_sale_active = all![
+            any![
+                col(OfferPriceIden::SaleStartsAt).is_null(),
+                col(OfferPriceIden::SaleStartsAt)
+                    .lte(Expr::current_timestamp()),
+            ],
+            any![
+                col(OfferPriceIden::SaleEndsAt).is_null(),
+                col(OfferPriceIden::SaleEndsAt).gt(Expr::current_timestamp()),
+            ],
+        ];
+
+        Expr::case(
+            all![
+                col(OfferPriceIden::CompareAtAmount).is_not_null(),
+                is_sale_active.not(),
+            ],
+            col(OfferPriceIden::CompareAtAmount),
+        )
+        .finally(col(OfferPriceIden::UnitAmount))
+        .into()
+    }
+
+    /// Lowest charged unit amount in the given currency among the prices of
+    /// the outer offer row and its variants.
+    pub fn get_min_unit_amount_expr(currency: &str) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(Func::min(Self::get_charged_unit_amount_expr()))
+            .from(OfferPriceIden::Table)
+            .and_where(
+                Expr::col((OfferPriceIden::Table, OfferPriceIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            )
+            .and_where(
+                Expr::col((OfferPriceIden::Table, OfferPriceIden::Currency))
+                    .eq(currency),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    fn get_currency_ordering(&self) -> i32 {
+        Currency::from_str_name(&self.currency)
+            .map(i32::from)
+            .unwrap_or(i32::MAX)
+    }
+
+    /// Sorts prices by the order of the `Currency` enum, so the first price
+    /// is the default price of an offer.
+    pub fn sort(prices: &mut [Self]) {
+        prices.sort_by_key(Self::get_currency_ordering);
+    }
+
+    pub fn is_on_sale(&self, now: DateTime<Utc>) -> bool {
+        self.sale.is_some_and(|s| s.is_active(now))
+    }
+
+    /// Returns the unit amount that applies at the given time, which is the
+    /// compare-at amount if the price has a sale that does not apply.
+    pub fn get_unit_amount(&self, now: DateTime<Utc>) -> u32 {
+        match self.sale {
+            Some(sale) if !sale.is_active(now) => sale.compare_at_amount,
+            _ => self.unit_amount,
+        }
+    }
+
+    /// Calculates the amount for the given quantity at the current unit
+    /// amount, see `calculate_amount`.
+    pub fn get_amount(&self, quantity: u32) -> Option<u64> {
+        calculate_amount(
+            PriceBillingScheme::from_str_name(&self.billing_scheme)?,
+            self.get_unit_amount(Utc::now()),
+            self.package_size,
+            &self.tiers,
+            quantity,
+        )
+    }
+
+    /// Returns the price in the preferred currency and falls back to the
+    /// first price if there is none. Expects sorted prices.
+    pub fn select<'a>(
+        prices: &'a [Self],
+        preferred_currency: Option<&str>,
+    ) -> Option<&'a Self> {
+        preferred_currency
+            .and_then(|c| prices.iter().find(|p| p.currency == c))
+            .or(prices.first())
+    }
 }
 
-impl<'a> FromSql<'a> for OfferPriceAsRel {
+struct OfferPriceTierRecord {
+    price: OfferPriceAsRel,
+    tier: Option<PriceTier>,
+}
+
+impl<'a> FromSql<'a> for OfferPriceTierRecord {
     fn accepts(ty: &deadpool_postgres::tokio_postgres::types::Type) -> bool {
         match *ty {
             Type::RECORD => true,
             _ => {
                 tracing::log::error!(
-                    "[OfferPriceAsRel.FromSql.accepts]: postgres type {:?} not implemented", 
+                    "[OfferPriceTierRecord.FromSql.accepts]: postgres type {:?} not implemented", 
                     ty
                 );
                 false
@@ -334,15 +640,85 @@ impl<'a> FromSql<'a> for OfferPriceAsRel {
             None => None,
         };
 
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<Uuid>>(oid)?;
+        let offer_variant_id: Option<Uuid> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<i64>>(oid)?;
+        let package_size: Option<i64> = private::read_value(&ty, &mut raw)?;
+
+        let package_size = match package_size {
+            Some(s) => Some(u32::try_from(s)?),
+            None => None,
+        };
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<i64>>(oid)?;
+        let compare_at_amount: Option<i64> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<DateTime<Utc>>>(oid)?;
+        let sale_starts_at: Option<DateTime<Utc>> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<DateTime<Utc>>>(oid)?;
+        let sale_ends_at: Option<DateTime<Utc>> =
+            private::read_value(&ty, &mut raw)?;
+
+        let sale = match compare_at_amount {
+            Some(compare_at_amount) => Some(PriceSale {
+                compare_at_amount: u32::try_from(compare_at_amount)?,
+                starts_at: sale_starts_at,
+                ends_at: sale_ends_at,
+            }),
+            None => None,
+        };
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<i64>>(oid)?;
+        let up_to: Option<i64> = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<i64>>(oid)?;
+        let tier_unit_amount: Option<i64> = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<i64>>(oid)?;
+        let flat_amount: Option<i64> = private::read_value(&ty, &mut raw)?;
+
+        // the tier columns are all null for prices without tiers
+        let tier = match (tier_unit_amount, flat_amount) {
+            (Some(tier_unit_amount), Some(flat_amount)) => Some(PriceTier {
+                up_to: match up_to {
+                    Some(u) => Some(u32::try_from(u)?),
+                    None => None,
+                },
+                unit_amount: u32::try_from(tier_unit_amount)?,
+                flat_amount: u32::try_from(flat_amount)?,
+            }),
+            _ => None,
+        };
+
         Ok(Self {
-            offer_price_id,
-            currency,
-            price_type,
-            billing_scheme,
-            unit_amount: u32::try_from(unit_amount)?,
-            recurring_interval,
-            recurring_interval_count,
-            trial_period_days,
+            price: OfferPriceAsRel {
+                offer_price_id,
+                currency,
+                price_type,
+                billing_scheme,
+                unit_amount: u32::try_from(unit_amount)?,
+                recurring_interval,
+                recurring_interval_count,
+                trial_period_days,
+                offer_variant_id,
+                package_size,
+                tiers: Vec::new(),
+                sale,
+            },
+            tier,
         })
     }
 }
@@ -371,14 +747,37 @@ impl<'a> FromSql<'a> for OfferPriceAsRelVec {
             return Err("array contains too many dimensions".into());
         }
 
-        Ok(Self(
-            array
-                .values()
-                .filter_map(|v| {
-                    Ok(OfferPriceAsRel::from_sql_nullable(&Type::RECORD, v)
-                        .ok())
-                })
-                .collect()?,
-        ))
+        let records: Vec<OfferPriceTierRecord> = array
+            .values()
+            .filter_map(|v| {
+                Ok(OfferPriceTierRecord::from_sql_nullable(&Type::RECORD, v)
+                    .ok())
+            })
+            .collect()?;
+
+        let mut prices: Vec<OfferPriceAsRel> = Vec::new();
+
+        for record in records {
+            let price = match prices
+                .iter_mut()
+                .find(|p| p.offer_price_id == record.price.offer_price_id)
+            {
+                Some(price) => price,
+                None => {
+                    prices.push(record.price);
+                    prices.last_mut().unwrap()
+                }
+            };
+
+            if let Some(tier) = record.tier {
+                price.tiers.push(tier);
+            }
+        }
+
+        for price in prices.iter_mut() {
+            sort_tiers(&mut price.tiers);
+        }
+
+        Ok(Self(prices))
     }
 }
@@ -0,0 +1,71 @@
+use deadpool_postgres::Transaction;
+use sea_query::{Expr, Iden, PostgresQueryBuilder, Query};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::DbError;
+use crate::pricing::PriceTier;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_price_tiers")]
+pub enum OfferPriceTierIden {
+    Table,
+    OfferPriceId,
+    UpTo,
+    UnitAmount,
+    FlatAmount,
+}
+
+pub struct OfferPriceTier;
+
+impl OfferPriceTier {
+    /// Replaces all tiers of the price with the given tiers.
+    pub async fn put_all<'a>(
+        transaction: &Transaction<'a>,
+        offer_price_id: &Uuid,
+        tiers: &[PriceTier],
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(OfferPriceTierIden::Table)
+            .and_where(
+                Expr::col(OfferPriceTierIden::OfferPriceId).eq(*offer_price_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        if tiers.is_empty() {
+            return Ok(());
+        }
+
+        let (sql, values) = {
+            let mut query = Query::insert();
+
+            query.into_table(OfferPriceTierIden::Table).columns([
+                OfferPriceTierIden::OfferPriceId,
+                OfferPriceTierIden::UpTo,
+                OfferPriceTierIden::UnitAmount,
+                OfferPriceTierIden::FlatAmount,
+            ]);
+
+            for tier in tiers {
+                query.values([
+                    (*offer_price_id).into(),
+                    tier.up_to.map(i64::from).into(),
+                    i64::from(tier.unit_amount).into(),
+                    i64::from(tier.flat_amount).into(),
+                ])?;
+            }
+
+            query.build_postgres(PostgresQueryBuilder)
+        };
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use deadpool_postgres::Pool;
+use sea_query::{Expr, Func, Iden, PostgresQueryBuilder, Query, SimpleExpr};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::{ArrayAgg, DbError};
+
+use super::offer::OfferIden;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_tags")]
+pub enum OfferTagIden {
+    Table,
+    OfferId,
+    Tag,
+}
+
+pub struct OfferTag;
+
+impl OfferTag {
+    /// Tags are compared case-insensitively and without surrounding
+    /// whitespace.
+    pub fn normalize(tag: &str) -> String {
+        tag.trim().to_lowercase()
+    }
+
+    /// Selects the tags of the offer in the given table.
+    pub fn get_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(Func::cust(ArrayAgg).arg(Expr::col(OfferTagIden::Tag)))
+            .from(OfferTagIden::Table)
+            .and_where(
+                Expr::col((OfferTagIden::Table, OfferTagIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    /// Condition on offers that have the given tag.
+    pub fn get_has_tag_expr(tag: &str) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(Expr::val(1))
+            .from(OfferTagIden::Table)
+            .and_where(
+                Expr::col((OfferTagIden::Table, OfferTagIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            )
+            .and_where(
+                Expr::col((OfferTagIden::Table, OfferTagIden::Tag)).eq(tag),
+            );
+
+        Expr::exists(query)
+    }
+
+    /// Replaces the tags of the offer.
+    pub async fn put(
+        pool: &Pool,
+        offer_id: &Uuid,
+        tags: &[String],
+    ) -> Result<(), DbError> {
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(OfferTagIden::Table)
+            .and_where(Expr::col(OfferTagIden::OfferId).eq(*offer_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        if !tags.is_empty() {
+            let (sql, values) = {
+                let mut query = Query::insert();
+
+                query
+                    .into_table(OfferTagIden::Table)
+                    .columns([OfferTagIden::OfferId, OfferTagIden::Tag]);
+
+                for tag in tags {
+                    query.values([(*offer_id).into(), tag.into()])?;
+                }
+
+                query.build_postgres(PostgresQueryBuilder)
+            };
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
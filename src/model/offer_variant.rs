@@ -0,0 +1,545 @@
+use deadpool_postgres::tokio_postgres::types::{private, FromSql, Type};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use fallible_iterator::FallibleIterator;
+use postgres_protocol::types;
+use sea_query::{
+    Alias, Asterisk, Expr, Func, Iden, PostgresQueryBuilder, Query, SimpleExpr,
+};
+use sea_query_postgres::{PostgresBinder, PostgresValues};
+use uuid::Uuid;
+
+use crate::db::{get_type_from_oid, ArrayAgg, DbError};
+
+use super::inventory::Inventory;
+use super::offer::OfferIden;
+use super::offer_option::OfferOptionValueIden;
+use super::offer_price::OfferPriceAsRel;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_variants")]
+pub enum OfferVariantIden {
+    Table,
+    OfferVariantId,
+    OfferId,
+    UserId,
+    Sku,
+    IsActive,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_variant_option_values")]
+pub enum OfferVariantOptionValueIden {
+    Table,
+    OfferVariantId,
+    OfferOptionValueId,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "offer_variant_images")]
+pub enum OfferVariantImageIden {
+    Table,
+    OfferVariantId,
+    OfferImageId,
+}
+
+#[derive(Debug, Clone)]
+pub struct OfferVariant {
+    pub offer_variant_id: Uuid,
+    pub offer_id: Uuid,
+}
+
+impl OfferVariant {
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        offer_id: &Uuid,
+        user_id: &String,
+        sku: &String,
+        is_active: bool,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(OfferVariantIden::Table)
+            .columns([
+                OfferVariantIden::OfferId,
+                OfferVariantIden::UserId,
+                OfferVariantIden::Sku,
+                OfferVariantIden::IsActive,
+            ])
+            .values([
+                (*offer_id).into(),
+                user_id.into(),
+                sku.into(),
+                is_active.into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        offer_variant_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(OfferVariantIden::Table)
+            .and_where(
+                Expr::col(OfferVariantIden::OfferVariantId)
+                    .eq(*offer_variant_id),
+            )
+            .and_where(Expr::col(OfferVariantIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn update<'a>(
+        transaction: &Transaction<'a>,
+        user_id: &String,
+        offer_variant_id: &Uuid,
+        sku: Option<String>,
+        is_active: Option<bool>,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = {
+            let mut query = Query::update();
+
+            query.table(OfferVariantIden::Table);
+
+            if let Some(sku) = sku {
+                query.value(OfferVariantIden::Sku, sku);
+            }
+
+            if let Some(is_active) = is_active {
+                query.value(OfferVariantIden::IsActive, is_active);
+            }
+
+            query
+                .and_where(Expr::col(OfferVariantIden::UserId).eq(user_id))
+                .and_where(
+                    Expr::col(OfferVariantIden::OfferVariantId)
+                        .eq(*offer_variant_id),
+                )
+                .returning_all()
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn delete(
+        pool: &Pool,
+        user_id: &String,
+        offer_variant_id: &Uuid,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(OfferVariantIden::Table)
+            .and_where(Expr::col(OfferVariantIden::UserId).eq(user_id))
+            .and_where(
+                Expr::col(OfferVariantIden::OfferVariantId)
+                    .eq(*offer_variant_id),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn add_option_values<'a>(
+        transaction: &Transaction<'a>,
+        offer_variant_id: &Uuid,
+        offer_option_value_ids: &[Uuid],
+    ) -> Result<(), DbError> {
+        if offer_option_value_ids.is_empty() {
+            return Ok(());
+        }
+
+        let (sql, values) = {
+            let mut query = Query::insert();
+
+            query
+                .into_table(OfferVariantOptionValueIden::Table)
+                .columns([
+                    OfferVariantOptionValueIden::OfferVariantId,
+                    OfferVariantOptionValueIden::OfferOptionValueId,
+                ]);
+
+            for offer_option_value_id in offer_option_value_ids {
+                query.values([
+                    (*offer_variant_id).into(),
+                    (*offer_option_value_id).into(),
+                ])?;
+            }
+
+            query.build_postgres(PostgresQueryBuilder)
+        };
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Adds the option value to all variants of the offer, so they keep
+    /// selecting one value of every option when an option is added.
+    pub async fn add_option_value_to_all<'a>(
+        transaction: &Transaction<'a>,
+        offer_id: &Uuid,
+        offer_option_value_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(OfferVariantOptionValueIden::Table)
+            .columns([
+                OfferVariantOptionValueIden::OfferVariantId,
+                OfferVariantOptionValueIden::OfferOptionValueId,
+            ])
+            .select_from(
+                Query::select()
+                    .column(OfferVariantIden::OfferVariantId)
+                    .expr(
+                        Expr::val(*offer_option_value_id)
+                            .cast_as(Alias::new("UUID")),
+                    )
+                    .from(OfferVariantIden::Table)
+                    .and_where(
+                        Expr::col(OfferVariantIden::OfferId).eq(*offer_id),
+                    )
+                    .to_owned(),
+            )?
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Builds the query deleting the variants of the user that select any of
+    /// the option values matching the condition.
+    fn get_delete_for_option_values_query(
+        user_id: &String,
+        option_value_cond: SimpleExpr,
+    ) -> (String, PostgresValues) {
+        Query::delete()
+            .from_table(OfferVariantIden::Table)
+            .and_where(Expr::col(OfferVariantIden::UserId).eq(user_id))
+            .and_where(
+                Expr::col(OfferVariantIden::OfferVariantId).in_subquery(
+                    Query::select()
+                        .column(OfferVariantOptionValueIden::OfferVariantId)
+                        .from(OfferVariantOptionValueIden::Table)
+                        .and_where(
+                            Expr::col(
+                                OfferVariantOptionValueIden::OfferOptionValueId,
+                            )
+                            .in_subquery(
+                                Query::select()
+                                    .column(
+                                        OfferOptionValueIden::OfferOptionValueId,
+                                    )
+                                    .from(OfferOptionValueIden::Table)
+                                    .and_where(option_value_cond)
+                                    .to_owned(),
+                            ),
+                        )
+                        .to_owned(),
+                ),
+            )
+            .build_postgres(PostgresQueryBuilder)
+    }
+
+    /// Deletes the variants that select a value of the option, as they would
+    /// no longer differ from each other once the option is removed.
+    pub async fn delete_for_option<'a>(
+        transaction: &Transaction<'a>,
+        user_id: &String,
+        offer_option_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Self::get_delete_for_option_values_query(
+            user_id,
+            Expr::col(OfferOptionValueIden::OfferOptionId).eq(*offer_option_id),
+        );
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the variants that select the option value, as they would no
+    /// longer select a value of every option once it is removed.
+    pub async fn delete_for_option_value<'a>(
+        transaction: &Transaction<'a>,
+        user_id: &String,
+        offer_option_value_id: &Uuid,
+    ) -> Result<(), DbError> {
+        let (sql, values) = Self::get_delete_for_option_values_query(
+            user_id,
+            Expr::col(OfferOptionValueIden::OfferOptionValueId)
+                .eq(*offer_option_value_id),
+        );
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn put_images<'a>(
+        transaction: &Transaction<'a>,
+        offer_variant_id: &Uuid,
+        offer_image_ids: &[Uuid],
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(OfferVariantImageIden::Table)
+            .and_where(
+                Expr::col(OfferVariantImageIden::OfferVariantId)
+                    .eq(*offer_variant_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        if offer_image_ids.is_empty() {
+            return Ok(());
+        }
+
+        let (sql, values) = {
+            let mut query = Query::insert();
+
+            query.into_table(OfferVariantImageIden::Table).columns([
+                OfferVariantImageIden::OfferVariantId,
+                OfferVariantImageIden::OfferImageId,
+            ]);
+
+            for offer_image_id in offer_image_ids {
+                query.values([
+                    (*offer_variant_id).into(),
+                    (*offer_image_id).into(),
+                ])?;
+            }
+
+            query.build_postgres(PostgresQueryBuilder)
+        };
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl From<&Row> for OfferVariant {
+    fn from(row: &Row) -> Self {
+        Self {
+            offer_variant_id: row
+                .get(OfferVariantIden::OfferVariantId.to_string().as_str()),
+            offer_id: row.get(OfferVariantIden::OfferId.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for OfferVariant {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OfferVariantAsRel {
+    pub offer_variant_id: Uuid,
+    pub sku: String,
+    pub is_active: bool,
+    pub offer_option_value_ids: Vec<Uuid>,
+    pub offer_image_ids: Vec<Uuid>,
+    pub price: Option<OfferPriceAsRel>,
+    pub prices: Vec<OfferPriceAsRel>,
+    pub available: Option<i64>,
+}
+
+impl OfferVariantAsRel {
+    fn get_ids_agg<T, C>(
+        table: T,
+        id_column: C,
+        variant_column: C,
+    ) -> SimpleExpr
+    where
+        T: Iden + Copy + 'static,
+        C: Iden + Copy + 'static,
+    {
+        let mut query = Query::select();
+
+        query
+            .expr(Func::cust(ArrayAgg).arg(Expr::col((table, id_column))))
+            .from(table)
+            .and_where(Expr::col((table, variant_column)).equals((
+                OfferVariantIden::Table,
+                OfferVariantIden::OfferVariantId,
+            )));
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    pub fn get_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg).args([Expr::tuple([
+                    Expr::col((
+                        OfferVariantIden::Table,
+                        OfferVariantIden::OfferVariantId,
+                    ))
+                    .into(),
+                    Expr::col((OfferVariantIden::Table, OfferVariantIden::Sku))
+                        .into(),
+                    Expr::col((
+                        OfferVariantIden::Table,
+                        OfferVariantIden::IsActive,
+                    ))
+                    .into(),
+                    Self::get_ids_agg(
+                        OfferVariantOptionValueIden::Table,
+                        OfferVariantOptionValueIden::OfferOptionValueId,
+                        OfferVariantOptionValueIden::OfferVariantId,
+                    ),
+                    Self::get_ids_agg(
+                        OfferVariantImageIden::Table,
+                        OfferVariantImageIden::OfferImageId,
+                        OfferVariantImageIden::OfferVariantId,
+                    ),
+                    Inventory::get_variant_available_expr((
+                        OfferVariantIden::Table,
+                        OfferVariantIden::OfferVariantId,
+                    )),
+                ])
+                .into()]),
+            )
+            .from(OfferVariantIden::Table)
+            .and_where(
+                Expr::col((OfferVariantIden::Table, OfferVariantIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+}
+
+impl<'a> FromSql<'a> for OfferVariantAsRel {
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::RECORD => true,
+            _ => {
+                tracing::log::error!(
+                    "[OfferVariantAsRel.FromSql.accepts]: postgres type {:?} not implemented",
+                    ty
+                );
+                false
+            }
+        }
+    }
+
+    fn from_sql(
+        _: &Type,
+        mut raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        private::read_be_i32(&mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Uuid>(oid)?;
+        let offer_variant_id: Uuid = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<String>(oid)?;
+        let sku: String = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<bool>(oid)?;
+        let is_active: bool = private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<Vec<Uuid>>>(oid)?;
+        let offer_option_value_ids: Option<Vec<Uuid>> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<Vec<Uuid>>>(oid)?;
+        let offer_image_ids: Option<Vec<Uuid>> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Option<i64>>(oid)?;
+        let available: Option<i64> = private::read_value(&ty, &mut raw)?;
+
+        Ok(Self {
+            offer_variant_id,
+            sku,
+            is_active,
+            offer_option_value_ids: offer_option_value_ids.unwrap_or_default(),
+            offer_image_ids: offer_image_ids.unwrap_or_default(),
+            price: None,
+            prices: Vec::new(),
+            available,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct OfferVariantAsRelVec(pub Vec<OfferVariantAsRel>);
+
+impl<'a> FromSql<'a> for OfferVariantAsRelVec {
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::RECORD_ARRAY => true,
+            _ => {
+                tracing::log::error!("[OfferVariantAsRelVec::<FromSql>::accepts]: postgres type {:?} not implemented", ty);
+                false
+            }
+        }
+    }
+
+    fn from_sql(
+        _: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let array = types::array_from_sql(raw)?;
+
+        if array.dimensions().count()? > 1 {
+            return Err("array contains too many dimensions".into());
+        }
+
+        Ok(Self(
+            array
+                .values()
+                .filter_map(|v| {
+                    Ok(OfferVariantAsRel::from_sql_nullable(&Type::RECORD, v)
+                        .ok())
+                })
+                .collect()?,
+        ))
+    }
+}
@@ -0,0 +1,333 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{
+    Asterisk, Expr, Iden, LockType, Order as SortOrder, PostgresQueryBuilder,
+    Query, SelectStatement,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::{get_count_from_rows, DbError};
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "orders")]
+pub enum OrderIden {
+    Table,
+    OrderId,
+    ShopId,
+    ShopName,
+    UserId,
+    BuyerUserId,
+    CreatedAt,
+    UpdatedAt,
+    Status,
+    Currency,
+    SubtotalAmount,
+    ShippingAmount,
+    TotalAmount,
+    ShippingCountry,
+    PlatformFeePercent,
+    MinimumPlatformFeeCent,
+    PlatformFeeAmount,
+    NetAmount,
+    DiscountId,
+    DiscountAmount,
+}
+
+#[derive(Debug, Clone)]
+pub struct Order {
+    pub order_id: Uuid,
+    pub shop_id: Uuid,
+    pub shop_name: String,
+    pub user_id: String,
+    pub buyer_user_id: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub status: String,
+    pub currency: String,
+    pub subtotal_amount: u32,
+    pub shipping_amount: u32,
+    pub total_amount: u32,
+    pub shipping_country: Option<String>,
+    pub platform_fee_percent: u32,
+    pub minimum_platform_fee_cent: u32,
+    pub platform_fee_amount: u32,
+    pub net_amount: u32,
+    pub discount_id: Option<Uuid>,
+    pub discount_amount: u32,
+}
+
+impl Order {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        shop_id: &Uuid,
+        shop_name: &String,
+        user_id: &String,
+        buyer_user_id: &String,
+        status: &str,
+        currency: &str,
+        subtotal_amount: u32,
+        shipping_amount: u32,
+        total_amount: u32,
+        shipping_country: Option<&str>,
+        platform_fee_percent: u32,
+        minimum_platform_fee_cent: u32,
+        platform_fee_amount: u32,
+        net_amount: u32,
+        discount_id: Option<&Uuid>,
+        discount_amount: u32,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(OrderIden::Table)
+            .columns([
+                OrderIden::ShopId,
+                OrderIden::ShopName,
+                OrderIden::UserId,
+                OrderIden::BuyerUserId,
+                OrderIden::Status,
+                OrderIden::Currency,
+                OrderIden::SubtotalAmount,
+                OrderIden::ShippingAmount,
+                OrderIden::TotalAmount,
+                OrderIden::ShippingCountry,
+                OrderIden::PlatformFeePercent,
+                OrderIden::MinimumPlatformFeeCent,
+                OrderIden::PlatformFeeAmount,
+                OrderIden::NetAmount,
+                OrderIden::DiscountId,
+                OrderIden::DiscountAmount,
+            ])
+            .values([
+                (*shop_id).into(),
+                shop_name.into(),
+                user_id.into(),
+                buyer_user_id.into(),
+                status.into(),
+                currency.into(),
+                i64::from(subtotal_amount).into(),
+                i64::from(shipping_amount).into(),
+                i64::from(total_amount).into(),
+                shipping_country.into(),
+                i64::from(platform_fee_percent).into(),
+                i64::from(minimum_platform_fee_cent).into(),
+                i64::from(platform_fee_amount).into(),
+                i64::from(net_amount).into(),
+                discount_id.copied().into(),
+                i64::from(discount_amount).into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        order_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(OrderIden::Table)
+            .and_where(Expr::col(OrderIden::OrderId).eq(*order_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Locks the order row until the transaction ends, so concurrent status
+    /// transitions are serialized.
+    pub async fn get_for_update<'a>(
+        transaction: &Transaction<'a>,
+        order_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(OrderIden::Table)
+            .and_where(Expr::col(OrderIden::OrderId).eq(*order_id))
+            .lock(LockType::Update)
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    fn add_list_conditions(
+        query: &mut SelectStatement,
+        user_id: Option<&String>,
+        buyer_user_id: Option<&String>,
+        shop_id: Option<&Uuid>,
+        status: Option<&str>,
+    ) {
+        if let Some(user_id) = user_id {
+            query.and_where(Expr::col(OrderIden::UserId).eq(user_id));
+        }
+
+        if let Some(buyer_user_id) = buyer_user_id {
+            query
+                .and_where(Expr::col(OrderIden::BuyerUserId).eq(buyer_user_id));
+        }
+
+        if let Some(shop_id) = shop_id {
+            query.and_where(Expr::col(OrderIden::ShopId).eq(*shop_id));
+        }
+
+        if let Some(status) = status {
+            query.and_where(Expr::col(OrderIden::Status).eq(status));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list(
+        pool: &Pool,
+        user_id: Option<&String>,
+        buyer_user_id: Option<&String>,
+        shop_id: Option<&Uuid>,
+        status: Option<&str>,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<Self>, i64), DbError> {
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
+
+        let (sql, values) = {
+            let mut query = Query::select();
+
+            query.column(Asterisk).from(OrderIden::Table);
+
+            Self::add_list_conditions(
+                &mut query,
+                user_id,
+                buyer_user_id,
+                shop_id,
+                status,
+            );
+
+            query
+                .order_by(OrderIden::CreatedAt, SortOrder::Desc)
+                .limit(limit)
+                .offset(offset)
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let (count_sql, count_values) = {
+            let mut query = Query::select();
+
+            query
+                .expr(Expr::col(Asterisk).count())
+                .from(OrderIden::Table);
+
+            Self::add_list_conditions(
+                &mut query,
+                user_id,
+                buyer_user_id,
+                shop_id,
+                status,
+            );
+
+            query.build_postgres(PostgresQueryBuilder)
+        };
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        let count_rows = transaction
+            .query(count_sql.as_str(), &count_values.as_params())
+            .await?;
+
+        let count = get_count_from_rows(&count_rows);
+
+        transaction.commit().await?;
+
+        Ok((rows.iter().map(Self::from).collect(), count))
+    }
+
+    pub async fn update_status<'a>(
+        transaction: &Transaction<'a>,
+        order_id: &Uuid,
+        status: &str,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::update()
+            .table(OrderIden::Table)
+            .value(OrderIden::Status, status)
+            .and_where(Expr::col(OrderIden::OrderId).eq(*order_id))
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+}
+
+impl From<&Row> for Order {
+    fn from(row: &Row) -> Self {
+        Self {
+            order_id: row.get(OrderIden::OrderId.to_string().as_str()),
+            shop_id: row.get(OrderIden::ShopId.to_string().as_str()),
+            shop_name: row.get(OrderIden::ShopName.to_string().as_str()),
+            user_id: row.get(OrderIden::UserId.to_string().as_str()),
+            buyer_user_id: row.get(OrderIden::BuyerUserId.to_string().as_str()),
+            created_at: row.get(OrderIden::CreatedAt.to_string().as_str()),
+            updated_at: row.get(OrderIden::UpdatedAt.to_string().as_str()),
+            status: row.get(OrderIden::Status.to_string().as_str()),
+            currency: row.get(OrderIden::Currency.to_string().as_str()),
+            subtotal_amount: u32::try_from(row.get::<&str, i64>(
+                OrderIden::SubtotalAmount.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            shipping_amount: u32::try_from(row.get::<&str, i64>(
+                OrderIden::ShippingAmount.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            total_amount: u32::try_from(
+                row.get::<&str, i64>(
+                    OrderIden::TotalAmount.to_string().as_str(),
+                ),
+            )
+            .expect("Should not be greater than 4294967295"),
+            shipping_country: row
+                .get(OrderIden::ShippingCountry.to_string().as_str()),
+            platform_fee_percent: u32::try_from(row.get::<&str, i64>(
+                OrderIden::PlatformFeePercent.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            minimum_platform_fee_cent: u32::try_from(row.get::<&str, i64>(
+                OrderIden::MinimumPlatformFeeCent.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            platform_fee_amount: u32::try_from(row.get::<&str, i64>(
+                OrderIden::PlatformFeeAmount.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            net_amount: u32::try_from(
+                row.get::<&str, i64>(OrderIden::NetAmount.to_string().as_str()),
+            )
+            .expect("Should not be greater than 4294967295"),
+            discount_id: row.get(OrderIden::DiscountId.to_string().as_str()),
+            discount_amount: u32::try_from(row.get::<&str, i64>(
+                OrderIden::DiscountAmount.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+        }
+    }
+}
+
+impl From<Row> for Order {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
@@ -0,0 +1,170 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{Asterisk, Expr, Iden, Order, PostgresQueryBuilder, Query};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::DbError;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "order_items")]
+pub enum OrderItemIden {
+    Table,
+    OrderItemId,
+    OrderId,
+    OfferId,
+    OfferVariantId,
+    CreatedAt,
+    OfferName,
+    OfferVariantSku,
+    Quantity,
+    Currency,
+    UnitAmount,
+    Amount,
+    InventoryId,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderItem {
+    pub order_item_id: Uuid,
+    pub order_id: Uuid,
+    pub offer_id: Uuid,
+    pub offer_variant_id: Option<Uuid>,
+    pub offer_name: String,
+    pub offer_variant_sku: Option<String>,
+    pub quantity: u32,
+    pub currency: String,
+    pub unit_amount: u32,
+    pub amount: u32,
+    pub inventory_id: Option<Uuid>,
+}
+
+impl OrderItem {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        order_id: &Uuid,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        offer_name: &String,
+        offer_variant_sku: Option<&String>,
+        quantity: u32,
+        currency: &str,
+        unit_amount: u32,
+        amount: u32,
+        inventory_id: Option<&Uuid>,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(OrderItemIden::Table)
+            .columns([
+                OrderItemIden::OrderId,
+                OrderItemIden::OfferId,
+                OrderItemIden::OfferVariantId,
+                OrderItemIden::OfferName,
+                OrderItemIden::OfferVariantSku,
+                OrderItemIden::Quantity,
+                OrderItemIden::Currency,
+                OrderItemIden::UnitAmount,
+                OrderItemIden::Amount,
+                OrderItemIden::InventoryId,
+            ])
+            .values([
+                (*order_id).into(),
+                (*offer_id).into(),
+                offer_variant_id.copied().into(),
+                offer_name.into(),
+                offer_variant_sku.cloned().into(),
+                i64::from(quantity).into(),
+                currency.into(),
+                i64::from(unit_amount).into(),
+                i64::from(amount).into(),
+                inventory_id.copied().into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    /// Returns the items of the order that took their quantity from an
+    /// inventory, so it can be restored.
+    pub async fn list_with_inventory<'a>(
+        transaction: &Transaction<'a>,
+        order_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(OrderItemIden::Table)
+            .and_where(Expr::col(OrderItemIden::OrderId).eq(*order_id))
+            .and_where(Expr::col(OrderItemIden::InventoryId).is_not_null())
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = transaction.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    /// Returns the items of all given orders.
+    pub async fn list(
+        pool: &Pool,
+        order_ids: &[Uuid],
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(OrderItemIden::Table)
+            .and_where(
+                Expr::col(OrderItemIden::OrderId).is_in(order_ids.to_vec()),
+            )
+            .order_by(OrderItemIden::CreatedAt, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+}
+
+impl From<&Row> for OrderItem {
+    fn from(row: &Row) -> Self {
+        Self {
+            order_item_id: row
+                .get(OrderItemIden::OrderItemId.to_string().as_str()),
+            order_id: row.get(OrderItemIden::OrderId.to_string().as_str()),
+            offer_id: row.get(OrderItemIden::OfferId.to_string().as_str()),
+            offer_variant_id: row
+                .get(OrderItemIden::OfferVariantId.to_string().as_str()),
+            offer_name: row.get(OrderItemIden::OfferName.to_string().as_str()),
+            offer_variant_sku: row
+                .get(OrderItemIden::OfferVariantSku.to_string().as_str()),
+            quantity: u32::try_from(row.get::<&str, i64>(
+                OrderItemIden::Quantity.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            currency: row.get(OrderItemIden::Currency.to_string().as_str()),
+            unit_amount: u32::try_from(row.get::<&str, i64>(
+                OrderItemIden::UnitAmount.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+            amount: u32::try_from(
+                row.get::<&str, i64>(
+                    OrderItemIden::Amount.to_string().as_str(),
+                ),
+            )
+            .expect("Should not be greater than 4294967295"),
+            inventory_id: row
+                .get(OrderItemIden::InventoryId.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for OrderItem {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
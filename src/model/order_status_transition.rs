@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{Asterisk, Expr, Iden, Order, PostgresQueryBuilder, Query};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::DbError;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "order_status_transitions")]
+pub enum OrderStatusTransitionIden {
+    Table,
+    OrderId,
+    UserId,
+    CreatedAt,
+    FromStatus,
+    ToStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderStatusTransition {
+    pub order_id: Uuid,
+    pub user_id: String,
+    pub created_at: DateTime<Utc>,
+    pub from_status: Option<String>,
+    pub to_status: String,
+}
+
+impl OrderStatusTransition {
+    pub async fn create<'a>(
+        transaction: &Transaction<'a>,
+        order_id: &Uuid,
+        user_id: &String,
+        from_status: Option<&str>,
+        to_status: &str,
+    ) -> Result<Self, DbError> {
+        let (sql, values) = Query::insert()
+            .into_table(OrderStatusTransitionIden::Table)
+            .columns([
+                OrderStatusTransitionIden::OrderId,
+                OrderStatusTransitionIden::UserId,
+                OrderStatusTransitionIden::FromStatus,
+                OrderStatusTransitionIden::ToStatus,
+            ])
+            .values([
+                (*order_id).into(),
+                user_id.into(),
+                from_status.into(),
+                to_status.into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(Self::from(row))
+    }
+
+    /// Returns the transitions of all given orders, oldest first.
+    pub async fn list(
+        pool: &Pool,
+        order_ids: &[Uuid],
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(OrderStatusTransitionIden::Table)
+            .and_where(
+                Expr::col(OrderStatusTransitionIden::OrderId)
+                    .is_in(order_ids.to_vec()),
+            )
+            .order_by(OrderStatusTransitionIden::CreatedAt, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+}
+
+impl From<&Row> for OrderStatusTransition {
+    fn from(row: &Row) -> Self {
+        Self {
+            order_id: row
+                .get(OrderStatusTransitionIden::OrderId.to_string().as_str()),
+            user_id: row
+                .get(OrderStatusTransitionIden::UserId.to_string().as_str()),
+            created_at: row
+                .get(OrderStatusTransitionIden::CreatedAt.to_string().as_str()),
+            from_status: row.get(
+                OrderStatusTransitionIden::FromStatus.to_string().as_str(),
+            ),
+            to_status: row
+                .get(OrderStatusTransitionIden::ToStatus.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for OrderStatusTransition {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
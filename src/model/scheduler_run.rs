@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Pool;
+use sea_query::{
+    Asterisk, Expr, Iden, OnConflict, PostgresQueryBuilder, Query,
+};
+use sea_query_postgres::PostgresBinder;
+
+use crate::db::DbError;
+
+#[derive(Iden)]
+#[iden(rename = "scheduler_runs")]
+pub enum SchedulerRunIden {
+    Table,
+    Scheduler,
+    LastRunUntil,
+}
+
+/// End of the last period a scheduler processed successfully, so it can
+/// continue from there after a restart.
+#[derive(Debug, Clone)]
+pub struct SchedulerRun {
+    pub last_run_until: DateTime<Utc>,
+}
+
+impl SchedulerRun {
+    pub async fn put(
+        pool: &Pool,
+        scheduler: &str,
+        last_run_until: DateTime<Utc>,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(SchedulerRunIden::Table)
+            .columns([
+                SchedulerRunIden::Scheduler,
+                SchedulerRunIden::LastRunUntil,
+            ])
+            .values([scheduler.into(), last_run_until.into()])?
+            .on_conflict(
+                OnConflict::column(SchedulerRunIden::Scheduler)
+                    .update_column(SchedulerRunIden::LastRunUntil)
+                    .to_owned(),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        scheduler: &str,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(SchedulerRunIden::Table)
+            .and_where(Expr::col(SchedulerRunIden::Scheduler).eq(scheduler))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+}
+
+impl From<&Row> for SchedulerRun {
+    fn from(row: &Row) -> Self {
+        Self {
+            last_run_until: row
+                .get(SchedulerRunIden::LastRunUntil.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for SchedulerRun {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
@@ -0,0 +1,259 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Pool;
+use sea_query::{Asterisk, Expr, Iden, Order, PostgresQueryBuilder, Query};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::DbError;
+
+use super::offer::OfferIden;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "shipping_profiles")]
+pub enum ShippingProfileIden {
+    Table,
+    ShippingProfileId,
+    ShopId,
+    UserId,
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    Currency,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShippingProfile {
+    pub shipping_profile_id: Uuid,
+    pub shop_id: Uuid,
+    pub user_id: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub name: String,
+    pub currency: String,
+}
+
+/// Shipping profile and weight of an offer.
+#[derive(Debug, Clone)]
+pub struct OfferShipping {
+    pub offer_id: Uuid,
+    pub shipping_profile_id: Option<Uuid>,
+    pub weight_grams: Option<u32>,
+}
+
+impl ShippingProfile {
+    pub async fn create(
+        pool: &Pool,
+        shop_id: &Uuid,
+        user_id: &String,
+        name: &String,
+        currency: &str,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(ShippingProfileIden::Table)
+            .columns([
+                ShippingProfileIden::ShopId,
+                ShippingProfileIden::UserId,
+                ShippingProfileIden::Name,
+                ShippingProfileIden::Currency,
+            ])
+            .values([
+                (*shop_id).into(),
+                user_id.into(),
+                name.into(),
+                currency.into(),
+            ])?
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        shipping_profile_id: &Uuid,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(ShippingProfileIden::Table)
+            .and_where(
+                Expr::col(ShippingProfileIden::ShippingProfileId)
+                    .eq(*shipping_profile_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Returns the profiles of the shop, oldest first.
+    pub async fn list(
+        pool: &Pool,
+        shop_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(ShippingProfileIden::Table)
+            .and_where(Expr::col(ShippingProfileIden::ShopId).eq(*shop_id))
+            .order_by(ShippingProfileIden::CreatedAt, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    pub async fn update(
+        pool: &Pool,
+        shipping_profile_id: &Uuid,
+        user_id: &String,
+        name: Option<String>,
+        currency: Option<&str>,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = {
+            let mut query = Query::update();
+
+            query.table(ShippingProfileIden::Table);
+
+            if let Some(name) = name {
+                query.value(ShippingProfileIden::Name, name);
+            }
+
+            if let Some(currency) = currency {
+                query.value(ShippingProfileIden::Currency, currency);
+            }
+
+            query
+                .and_where(
+                    Expr::col(ShippingProfileIden::ShippingProfileId)
+                        .eq(*shipping_profile_id),
+                )
+                .and_where(Expr::col(ShippingProfileIden::UserId).eq(user_id))
+                .returning_all()
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn delete(
+        pool: &Pool,
+        shipping_profile_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ShippingProfileIden::Table)
+            .and_where(
+                Expr::col(ShippingProfileIden::ShippingProfileId)
+                    .eq(*shipping_profile_id),
+            )
+            .and_where(Expr::col(ShippingProfileIden::UserId).eq(user_id))
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Assigns the offer to the profile or, without a profile, removes its
+    /// assignment. Returns `false` if the user has no such offer.
+    pub async fn put_offer(
+        pool: &Pool,
+        offer_id: &Uuid,
+        user_id: &String,
+        shipping_profile_id: Option<&Uuid>,
+    ) -> Result<bool, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::update()
+            .table(OfferIden::Table)
+            .value(OfferIden::ShippingProfileId, shipping_profile_id.copied())
+            .and_where(Expr::col(OfferIden::OfferId).eq(*offer_id))
+            .and_where(Expr::col(OfferIden::UserId).eq(user_id))
+            .build_postgres(PostgresQueryBuilder);
+
+        let count = conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(count > 0)
+    }
+
+    pub async fn list_offer_shipping(
+        pool: &Pool,
+        offer_ids: &[Uuid],
+    ) -> Result<Vec<OfferShipping>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .columns([
+                OfferIden::OfferId,
+                OfferIden::ShippingProfileId,
+                OfferIden::WeightGrams,
+            ])
+            .from(OfferIden::Table)
+            .and_where(Expr::col(OfferIden::OfferId).is_in(offer_ids.to_vec()))
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(OfferShipping::from).collect())
+    }
+}
+
+impl From<&Row> for ShippingProfile {
+    fn from(row: &Row) -> Self {
+        Self {
+            shipping_profile_id: row.get(
+                ShippingProfileIden::ShippingProfileId.to_string().as_str(),
+            ),
+            shop_id: row.get(ShippingProfileIden::ShopId.to_string().as_str()),
+            user_id: row.get(ShippingProfileIden::UserId.to_string().as_str()),
+            created_at: row
+                .get(ShippingProfileIden::CreatedAt.to_string().as_str()),
+            updated_at: row
+                .get(ShippingProfileIden::UpdatedAt.to_string().as_str()),
+            name: row.get(ShippingProfileIden::Name.to_string().as_str()),
+            currency: row
+                .get(ShippingProfileIden::Currency.to_string().as_str()),
+        }
+    }
+}
+
+impl From<Row> for ShippingProfile {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
+
+impl From<&Row> for OfferShipping {
+    fn from(row: &Row) -> Self {
+        Self {
+            offer_id: row.get(OfferIden::OfferId.to_string().as_str()),
+            shipping_profile_id: row
+                .get(OfferIden::ShippingProfileId.to_string().as_str()),
+            weight_grams: row
+                .get::<&str, Option<i64>>(
+                    OfferIden::WeightGrams.to_string().as_str(),
+                )
+                .map(|w| {
+                    u32::try_from(w)
+                        .expect("Should not be greater than 4294967295")
+                }),
+        }
+    }
+}
@@ -2,12 +2,15 @@ use chrono::{DateTime, Utc};
 use deadpool_postgres::tokio_postgres::Row;
 use deadpool_postgres::{Pool, Transaction};
 use sea_query::{
-    all, Asterisk, Expr, Iden, OnConflict, PostgresQueryBuilder, Query,
+    all, any, Alias, Asterisk, Expr, Func, Iden, OnConflict, Order,
+    PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
 };
 use sea_query_postgres::PostgresBinder;
 use uuid::Uuid;
 
-use crate::db::DbError;
+use crate::db::{ArrayAgg, DbError};
+
+use super::offer::OfferIden;
 
 #[derive(Iden)]
 #[iden(rename = "shipping_rates")]
@@ -21,7 +24,14 @@ pub enum ShippingRateIden {
     Amount,
     Currency,
     AllCountries,
-    SpecificCountries,
+}
+
+#[derive(Iden)]
+#[iden(rename = "shipping_rate_countries")]
+pub enum ShippingRateCountryIden {
+    Table,
+    ShippingRateId,
+    Country,
 }
 
 #[derive(Debug, Clone)]
@@ -34,19 +44,128 @@ pub struct ShippingRate {
     pub amount: u32,
     pub currency: String,
     pub all_countries: bool,
-    pub specific_countries: Option<String>,
+    pub specific_countries: Vec<String>,
 }
 
 impl ShippingRate {
-    const PUT_COLUMNS: [ShippingRateIden; 6] = [
+    const PUT_COLUMNS: [ShippingRateIden; 5] = [
         ShippingRateIden::OfferId,
         ShippingRateIden::UserId,
         ShippingRateIden::Amount,
         ShippingRateIden::Currency,
         ShippingRateIden::AllCountries,
-        ShippingRateIden::SpecificCountries,
     ];
 
+    const SPECIFIC_COUNTRIES_ALIAS: &'static str = "specific_countries";
+
+    fn get_specific_countries_alias() -> Alias {
+        Alias::new(Self::SPECIFIC_COUNTRIES_ALIAS)
+    }
+
+    /// Selects the countries of the rate in the given table, which is a
+    /// subquery of the rates so it can be used in filters of other tables.
+    pub fn get_specific_countries_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg)
+                    .arg(Expr::col(ShippingRateCountryIden::Country)),
+            )
+            .from(ShippingRateCountryIden::Table)
+            .and_where(
+                Expr::col((
+                    ShippingRateCountryIden::Table,
+                    ShippingRateCountryIden::ShippingRateId,
+                ))
+                .equals((
+                    ShippingRateIden::Table,
+                    ShippingRateIden::ShippingRateId,
+                )),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    fn select_with_countries() -> SelectStatement {
+        let mut query = Query::select();
+
+        query
+            .column((ShippingRateIden::Table, Asterisk))
+            .expr_as(
+                Self::get_specific_countries_agg(),
+                Self::get_specific_countries_alias(),
+            )
+            .from(ShippingRateIden::Table);
+
+        query
+    }
+
+    /// Condition on offers that have a rate shipping to the given
+    /// `ShippingCountry` name.
+    pub fn get_ships_to_expr(country: &str) -> SimpleExpr {
+        let mut countries_query = Query::select();
+
+        countries_query
+            .expr(Expr::val(1))
+            .from(ShippingRateCountryIden::Table)
+            .and_where(
+                Expr::col((
+                    ShippingRateCountryIden::Table,
+                    ShippingRateCountryIden::ShippingRateId,
+                ))
+                .equals((
+                    ShippingRateIden::Table,
+                    ShippingRateIden::ShippingRateId,
+                )),
+            )
+            .and_where(
+                Expr::col((
+                    ShippingRateCountryIden::Table,
+                    ShippingRateCountryIden::Country,
+                ))
+                .eq(country),
+            );
+
+        let mut query = Self::select_for_offer();
+
+        query.cond_where(any![
+            Expr::col((
+                ShippingRateIden::Table,
+                ShippingRateIden::AllCountries
+            ))
+            .eq(true),
+            Expr::exists(countries_query),
+        ]);
+
+        Expr::exists(query)
+    }
+
+    /// Condition on offers that have any rate.
+    pub fn get_exists_expr() -> SimpleExpr {
+        Expr::exists(Self::select_for_offer())
+    }
+
+    fn select_for_offer() -> SelectStatement {
+        let mut query = Query::select();
+
+        query
+            .expr(Expr::val(1))
+            .from(ShippingRateIden::Table)
+            .and_where(
+                Expr::col((ShippingRateIden::Table, ShippingRateIden::OfferId))
+                    .equals((OfferIden::Table, OfferIden::OfferId)),
+            );
+
+        query
+    }
+
+    /// Whether this rate applies to the given `ShippingCountry` name.
+    pub fn ships_to(&self, country: &str) -> bool {
+        self.all_countries
+            || self.specific_countries.iter().any(|c| c == country)
+    }
+
     pub async fn put(
         pool: &Pool,
         offer_id: &Uuid,
@@ -54,9 +173,10 @@ impl ShippingRate {
         amount: u32,
         currency: &str,
         all_countries: bool,
-        specific_countries: Option<String>,
+        specific_countries: &[&str],
     ) -> Result<Self, DbError> {
-        let conn = pool.get().await?;
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
 
         let (sql, values) = Query::insert()
             .into_table(ShippingRateIden::Table)
@@ -67,7 +187,6 @@ impl ShippingRate {
                 i64::from(amount).into(),
                 currency.into(),
                 all_countries.into(),
-                specific_countries.into(),
             ])?
             .on_conflict(
                 OnConflict::columns([
@@ -78,10 +197,64 @@ impl ShippingRate {
                 .update_columns(Self::PUT_COLUMNS)
                 .to_owned(),
             )
-            .returning_all()
+            .returning_col(ShippingRateIden::ShippingRateId)
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+        let shipping_rate_id: Uuid =
+            row.get(ShippingRateIden::ShippingRateId.to_string().as_str());
+
+        let (sql, values) = Query::delete()
+            .from_table(ShippingRateCountryIden::Table)
+            .and_where(
+                Expr::col(ShippingRateCountryIden::ShippingRateId)
+                    .eq(shipping_rate_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        if !specific_countries.is_empty() {
+            let (sql, values) = {
+                let mut query = Query::insert();
+
+                query.into_table(ShippingRateCountryIden::Table).columns([
+                    ShippingRateCountryIden::ShippingRateId,
+                    ShippingRateCountryIden::Country,
+                ]);
+
+                for country in specific_countries {
+                    query
+                        .values([shipping_rate_id.into(), (*country).into()])?;
+                }
+
+                query.build_postgres(PostgresQueryBuilder)
+            };
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        let (sql, values) = Self::select_with_countries()
+            .and_where(
+                Expr::col((
+                    ShippingRateIden::Table,
+                    ShippingRateIden::ShippingRateId,
+                ))
+                .eq(shipping_rate_id),
+            )
             .build_postgres(PostgresQueryBuilder);
 
-        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        transaction.commit().await?;
 
         Ok(Self::from(row))
     }
@@ -89,13 +262,22 @@ impl ShippingRate {
     pub async fn get_by_offer_id(
         pool: &Pool,
         offer_id: &Uuid,
+        currency: &str,
     ) -> Result<Option<Self>, DbError> {
         let conn = pool.get().await?;
 
-        let (sql, values) = Query::select()
-            .column(Asterisk)
-            .from(ShippingRateIden::Table)
-            .and_where(Expr::col(ShippingRateIden::OfferId).eq(*offer_id))
+        let (sql, values) = Self::select_with_countries()
+            .and_where(
+                Expr::col((ShippingRateIden::Table, ShippingRateIden::OfferId))
+                    .eq(*offer_id),
+            )
+            .and_where(
+                Expr::col((
+                    ShippingRateIden::Table,
+                    ShippingRateIden::Currency,
+                ))
+                .eq(currency),
+            )
             .build_postgres(PostgresQueryBuilder);
 
         let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
@@ -103,28 +285,69 @@ impl ShippingRate {
         Ok(row.map(Self::from))
     }
 
+    /// Returns the rates of the offer in all currencies, oldest first.
+    pub async fn list_by_offer_id(
+        pool: &Pool,
+        offer_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Self::select_with_countries()
+            .and_where(
+                Expr::col((ShippingRateIden::Table, ShippingRateIden::OfferId))
+                    .eq(*offer_id),
+            )
+            .order_by(
+                (ShippingRateIden::Table, ShippingRateIden::CreatedAt),
+                Order::Asc,
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
     pub async fn delete(
         pool: &Pool,
         shipping_rate_id: &Uuid,
         user_id: &String,
     ) -> Result<Self, DbError> {
-        let conn = pool.get().await?;
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
 
-        let (sql, values) = Query::delete()
-            .from_table(ShippingRateIden::Table)
+        // the countries are selected before they are deleted with the rate
+        let (sql, values) = Self::select_with_countries()
             .cond_where(all![
-                Expr::col(ShippingRateIden::ShippingRateId)
-                    .eq(*shipping_rate_id),
-                Expr::col(ShippingRateIden::UserId).eq(user_id)
+                Expr::col((
+                    ShippingRateIden::Table,
+                    ShippingRateIden::ShippingRateId
+                ))
+                .eq(*shipping_rate_id),
+                Expr::col((ShippingRateIden::Table, ShippingRateIden::UserId))
+                    .eq(user_id)
             ])
-            .returning_all()
             .build_postgres(PostgresQueryBuilder);
 
-        let shipping_rate = conn
+        let shipping_rate: Self = transaction
             .query_one(sql.as_str(), &values.as_params())
             .await?
             .into();
 
+        let (sql, values) = Query::delete()
+            .from_table(ShippingRateIden::Table)
+            .and_where(
+                Expr::col(ShippingRateIden::ShippingRateId)
+                    .eq(*shipping_rate_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        transaction.commit().await?;
+
         Ok(shipping_rate)
     }
 
@@ -166,7 +389,10 @@ impl From<&Row> for ShippingRate {
             all_countries: row
                 .get(ShippingRateIden::AllCountries.to_string().as_str()),
             specific_countries: row
-                .get(ShippingRateIden::SpecificCountries.to_string().as_str()),
+                .get::<&str, Option<Vec<String>>>(
+                    Self::SPECIFIC_COUNTRIES_ALIAS,
+                )
+                .unwrap_or_default(),
         }
     }
 }
@@ -0,0 +1,413 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::{Pool, Transaction};
+use sea_query::{
+    Alias, Asterisk, Expr, Func, Iden, Order, PostgresQueryBuilder, Query,
+    SimpleExpr,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::db::{ArrayAgg, DbError};
+
+use super::offer::OfferIden;
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "shipping_zones")]
+pub enum ShippingZoneIden {
+    Table,
+    ShippingZoneId,
+    ShippingProfileId,
+    UserId,
+    CreatedAt,
+    Name,
+    FreeShippingThreshold,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "shipping_zone_countries")]
+pub enum ShippingZoneCountryIden {
+    Table,
+    ShippingZoneId,
+    Country,
+}
+
+#[derive(Debug, Clone, Copy, Iden)]
+#[iden(rename = "shipping_zone_rules")]
+pub enum ShippingZoneRuleIden {
+    Table,
+    ShippingZoneId,
+    RuleType,
+    MinValue,
+    MaxValue,
+    Amount,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShippingZone {
+    pub shipping_zone_id: Uuid,
+    pub shipping_profile_id: Uuid,
+    pub name: String,
+    pub free_shipping_threshold: Option<u32>,
+    pub countries: Vec<String>,
+    pub rules: Vec<ShippingZoneRule>,
+}
+
+/// Rate of a zone that applies while the weight, order value or item count
+/// is at least `min_value` and below `max_value`.
+#[derive(Debug, Clone)]
+pub struct ShippingZoneRule {
+    pub rule_type: String,
+    pub min_value: u32,
+    pub max_value: Option<u32>,
+    pub amount: u32,
+}
+
+impl ShippingZone {
+    const COUNTRIES_ALIAS: &'static str = "countries";
+
+    fn get_countries_alias() -> Alias {
+        Alias::new(Self::COUNTRIES_ALIAS)
+    }
+
+    fn get_countries_agg() -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(
+                Func::cust(ArrayAgg)
+                    .arg(Expr::col(ShippingZoneCountryIden::Country)),
+            )
+            .from(ShippingZoneCountryIden::Table)
+            .and_where(
+                Expr::col((
+                    ShippingZoneCountryIden::Table,
+                    ShippingZoneCountryIden::ShippingZoneId,
+                ))
+                .equals((
+                    ShippingZoneIden::Table,
+                    ShippingZoneIden::ShippingZoneId,
+                )),
+            );
+
+        SimpleExpr::SubQuery(None, Box::new(query.into_sub_query_statement()))
+    }
+
+    /// Condition on offers whose shipping profile has a zone containing the
+    /// given `ShippingCountry` name.
+    pub fn get_ships_to_expr(country: &str) -> SimpleExpr {
+        let mut query = Query::select();
+
+        query
+            .expr(Expr::val(1))
+            .from(ShippingZoneIden::Table)
+            .inner_join(
+                ShippingZoneCountryIden::Table,
+                Expr::col((
+                    ShippingZoneCountryIden::Table,
+                    ShippingZoneCountryIden::ShippingZoneId,
+                ))
+                .equals((
+                    ShippingZoneIden::Table,
+                    ShippingZoneIden::ShippingZoneId,
+                )),
+            )
+            .and_where(
+                Expr::col((
+                    ShippingZoneIden::Table,
+                    ShippingZoneIden::ShippingProfileId,
+                ))
+                .equals((OfferIden::Table, OfferIden::ShippingProfileId)),
+            )
+            .and_where(
+                Expr::col((
+                    ShippingZoneCountryIden::Table,
+                    ShippingZoneCountryIden::Country,
+                ))
+                .eq(country),
+            );
+
+        Expr::exists(query)
+    }
+
+    /// Creates a zone in the profile or, given a zone id, updates it.
+    /// Returns `None` if the user has no such zone in the profile.
+    pub async fn put<'a>(
+        transaction: &Transaction<'a>,
+        shipping_profile_id: &Uuid,
+        shipping_zone_id: Option<&Uuid>,
+        user_id: &String,
+        name: &String,
+        free_shipping_threshold: Option<u32>,
+    ) -> Result<Option<Uuid>, DbError> {
+        let (sql, values) = match shipping_zone_id {
+            Some(shipping_zone_id) => Query::update()
+                .table(ShippingZoneIden::Table)
+                .values([
+                    (ShippingZoneIden::Name, name.into()),
+                    (
+                        ShippingZoneIden::FreeShippingThreshold,
+                        free_shipping_threshold.map(i64::from).into(),
+                    ),
+                ])
+                .and_where(
+                    Expr::col(ShippingZoneIden::ShippingZoneId)
+                        .eq(*shipping_zone_id),
+                )
+                .and_where(
+                    Expr::col(ShippingZoneIden::ShippingProfileId)
+                        .eq(*shipping_profile_id),
+                )
+                .and_where(Expr::col(ShippingZoneIden::UserId).eq(user_id))
+                .returning_col(ShippingZoneIden::ShippingZoneId)
+                .build_postgres(PostgresQueryBuilder),
+            None => Query::insert()
+                .into_table(ShippingZoneIden::Table)
+                .columns([
+                    ShippingZoneIden::ShippingProfileId,
+                    ShippingZoneIden::UserId,
+                    ShippingZoneIden::Name,
+                    ShippingZoneIden::FreeShippingThreshold,
+                ])
+                .values([
+                    (*shipping_profile_id).into(),
+                    user_id.into(),
+                    name.into(),
+                    free_shipping_threshold.map(i64::from).into(),
+                ])?
+                .returning_col(ShippingZoneIden::ShippingZoneId)
+                .build_postgres(PostgresQueryBuilder),
+        };
+
+        let row = transaction
+            .query_opt(sql.as_str(), &values.as_params())
+            .await?;
+
+        Ok(row.map(|r| {
+            r.get(ShippingZoneIden::ShippingZoneId.to_string().as_str())
+        }))
+    }
+
+    /// Replaces the countries and rules of the zone.
+    pub async fn put_countries_and_rules<'a>(
+        transaction: &Transaction<'a>,
+        shipping_zone_id: &Uuid,
+        countries: &[&str],
+        rules: &[ShippingZoneRule],
+    ) -> Result<(), DbError> {
+        let (sql, values) = Query::delete()
+            .from_table(ShippingZoneCountryIden::Table)
+            .and_where(
+                Expr::col(ShippingZoneCountryIden::ShippingZoneId)
+                    .eq(*shipping_zone_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ShippingZoneRuleIden::Table)
+            .and_where(
+                Expr::col(ShippingZoneRuleIden::ShippingZoneId)
+                    .eq(*shipping_zone_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        transaction
+            .execute(sql.as_str(), &values.as_params())
+            .await?;
+
+        if !countries.is_empty() {
+            let (sql, values) = {
+                let mut query = Query::insert();
+
+                query.into_table(ShippingZoneCountryIden::Table).columns([
+                    ShippingZoneCountryIden::ShippingZoneId,
+                    ShippingZoneCountryIden::Country,
+                ]);
+
+                for country in countries {
+                    query.values([
+                        (*shipping_zone_id).into(),
+                        (*country).into(),
+                    ])?;
+                }
+
+                query.build_postgres(PostgresQueryBuilder)
+            };
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        if !rules.is_empty() {
+            let (sql, values) = {
+                let mut query = Query::insert();
+
+                query.into_table(ShippingZoneRuleIden::Table).columns([
+                    ShippingZoneRuleIden::ShippingZoneId,
+                    ShippingZoneRuleIden::RuleType,
+                    ShippingZoneRuleIden::MinValue,
+                    ShippingZoneRuleIden::MaxValue,
+                    ShippingZoneRuleIden::Amount,
+                ]);
+
+                for rule in rules {
+                    query.values([
+                        (*shipping_zone_id).into(),
+                        rule.rule_type.clone().into(),
+                        i64::from(rule.min_value).into(),
+                        rule.max_value.map(i64::from).into(),
+                        i64::from(rule.amount).into(),
+                    ])?;
+                }
+
+                query.build_postgres(PostgresQueryBuilder)
+            };
+
+            transaction
+                .execute(sql.as_str(), &values.as_params())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the zones of the given profiles with their countries and
+    /// rules, oldest first.
+    pub async fn list(
+        pool: &Pool,
+        shipping_profile_ids: &[Uuid],
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column((ShippingZoneIden::Table, Asterisk))
+            .expr_as(Self::get_countries_agg(), Self::get_countries_alias())
+            .from(ShippingZoneIden::Table)
+            .and_where(
+                Expr::col((
+                    ShippingZoneIden::Table,
+                    ShippingZoneIden::ShippingProfileId,
+                ))
+                .is_in(shipping_profile_ids.to_vec()),
+            )
+            .order_by(
+                (ShippingZoneIden::Table, ShippingZoneIden::CreatedAt),
+                Order::Asc,
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        let mut zones: Vec<Self> = rows.iter().map(Self::from).collect();
+
+        if zones.is_empty() {
+            return Ok(zones);
+        }
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(ShippingZoneRuleIden::Table)
+            .and_where(Expr::col(ShippingZoneRuleIden::ShippingZoneId).is_in(
+                zones.iter().map(|z| z.shipping_zone_id).collect::<Vec<_>>(),
+            ))
+            .order_by(ShippingZoneRuleIden::RuleType, Order::Asc)
+            .order_by(ShippingZoneRuleIden::MinValue, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        for row in rows.iter() {
+            let shipping_zone_id: Uuid = row
+                .get(ShippingZoneRuleIden::ShippingZoneId.to_string().as_str());
+
+            if let Some(zone) = zones
+                .iter_mut()
+                .find(|z| z.shipping_zone_id == shipping_zone_id)
+            {
+                zone.rules.push(ShippingZoneRule::from(row));
+            }
+        }
+
+        Ok(zones)
+    }
+
+    /// Deletes the zone and returns the id of its profile, or `None` if the
+    /// user has no such zone.
+    pub async fn delete(
+        pool: &Pool,
+        shipping_zone_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Option<Uuid>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ShippingZoneIden::Table)
+            .and_where(
+                Expr::col(ShippingZoneIden::ShippingZoneId)
+                    .eq(*shipping_zone_id),
+            )
+            .and_where(Expr::col(ShippingZoneIden::UserId).eq(user_id))
+            .returning_col(ShippingZoneIden::ShippingProfileId)
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(|r| {
+            r.get(ShippingZoneIden::ShippingProfileId.to_string().as_str())
+        }))
+    }
+}
+
+fn get_u32(row: &Row, iden: ShippingZoneRuleIden) -> u32 {
+    u32::try_from(row.get::<&str, i64>(iden.to_string().as_str()))
+        .expect("Should not be greater than 4294967295")
+}
+
+impl From<&Row> for ShippingZone {
+    fn from(row: &Row) -> Self {
+        Self {
+            shipping_zone_id: row
+                .get(ShippingZoneIden::ShippingZoneId.to_string().as_str()),
+            shipping_profile_id: row
+                .get(ShippingZoneIden::ShippingProfileId.to_string().as_str()),
+            name: row.get(ShippingZoneIden::Name.to_string().as_str()),
+            free_shipping_threshold: row
+                .get::<&str, Option<i64>>(
+                    ShippingZoneIden::FreeShippingThreshold
+                        .to_string()
+                        .as_str(),
+                )
+                .map(|t| {
+                    u32::try_from(t)
+                        .expect("Should not be greater than 4294967295")
+                }),
+            countries: row
+                .get::<&str, Option<Vec<String>>>(Self::COUNTRIES_ALIAS)
+                .unwrap_or_default(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl From<&Row> for ShippingZoneRule {
+    fn from(row: &Row) -> Self {
+        Self {
+            rule_type: row
+                .get(ShippingZoneRuleIden::RuleType.to_string().as_str()),
+            min_value: get_u32(row, ShippingZoneRuleIden::MinValue),
+            max_value: row
+                .get::<&str, Option<i64>>(
+                    ShippingZoneRuleIden::MaxValue.to_string().as_str(),
+                )
+                .map(|m| {
+                    u32::try_from(m)
+                        .expect("Should not be greater than 4294967295")
+                }),
+            amount: get_u32(row, ShippingZoneRuleIden::Amount),
+        }
+    }
+}
@@ -1,9 +1,8 @@
 use chrono::{DateTime, Utc};
 use deadpool_postgres::Transaction;
 use deadpool_postgres::{tokio_postgres::Row, Pool};
-use sea_query::extension::postgres::PgExpr;
 use sea_query::{
-    all, any, Alias, Asterisk, Expr, Func, Iden, Order, PgFunc,
+    all, any, Alias, Asterisk, Condition, Expr, Iden, Order,
     PostgresQueryBuilder, Query, SelectStatement, SimpleExpr,
 };
 use sea_query_postgres::PostgresBinder;
@@ -11,8 +10,13 @@ use uuid::Uuid;
 
 use crate::api::sited_io::commerce::v1::{ShopsFilterField, ShopsOrderByField};
 use crate::api::sited_io::types::v1::Direction;
-use crate::db::{build_simple_plain_ts_query, DbError};
+use crate::db::{
+    add_total_count, build_name_search, build_ts_search, get_count_from_rows,
+    get_total_count_from_rows, select_count, Cursor, DbError, FilterGroup,
+    SortKey, SortKeyKind,
+};
 
+use super::offer::Offer;
 use super::shop_customization::{
     ShopCustomizationAsRel, ShopCustomizationAsRelVec, ShopCustomizationIden,
 };
@@ -37,6 +41,8 @@ pub enum ShopIden {
     ContactEmailAddress,
     ClientId,
     WebsiteId,
+    PricesIncludeTax,
+    ContentLanguage,
 }
 
 #[derive(Debug, Clone)]
@@ -56,12 +62,15 @@ pub struct Shop {
     pub contact_email_address: Option<String>,
     pub client_id: Option<String>,
     pub website_id: String,
+    pub prices_include_tax: bool,
+    pub content_language: String,
 }
 
 impl Shop {
     const SHOP_CUSTOMIZATION_ALIAS: &'static str = "shop_customization";
     const NAME_TS_RANK_ALIAS: &'static str = "name_ts_rank";
     const DESCRIPTION_TS_RANK_ALIAS: &'static str = "description_ts_rank";
+    const SEARCH_SCORE_ALIAS: &'static str = "search_score";
 
     fn get_shop_customization_alias() -> Alias {
         Alias::new(Self::SHOP_CUSTOMIZATION_ALIAS)
@@ -75,6 +84,10 @@ impl Shop {
         Alias::new(Self::DESCRIPTION_TS_RANK_ALIAS)
     }
 
+    fn get_search_score_alias() -> Alias {
+        Alias::new(Self::SEARCH_SCORE_ALIAS)
+    }
+
     fn select_with_relations() -> SelectStatement {
         let mut query = Query::select();
 
@@ -98,11 +111,14 @@ impl Shop {
         query
     }
 
-    fn add_order_by(
-        query: &mut SelectStatement,
+    /// Returns the key of the order by the field. Shops are ordered by
+    /// relevance with the search score.
+    fn get_sort_key(
         order_by_field: ShopsOrderByField,
         order_by_direction: Direction,
-    ) {
+        search_score: Option<&SimpleExpr>,
+        seed: &str,
+    ) -> Result<SortKey, DbError> {
         use ShopsOrderByField::*;
 
         let order = match order_by_direction {
@@ -110,86 +126,129 @@ impl Shop {
             Direction::Desc => Order::Desc,
         };
 
-        match order_by_field {
-            Unspecified | CreatedAt => {
-                query.order_by((ShopIden::Table, ShopIden::CreatedAt), order)
-            }
-            UpdatedAt => {
-                query.order_by((ShopIden::Table, ShopIden::UpdatedAt), order)
+        let sort_key = match order_by_field {
+            Unspecified | CreatedAt => SortKey::new(
+                Expr::col((ShopIden::Table, ShopIden::CreatedAt)),
+                order,
+                SortKeyKind::Timestamp,
+            ),
+            UpdatedAt => SortKey::new(
+                Expr::col((ShopIden::Table, ShopIden::UpdatedAt)),
+                order,
+                SortKeyKind::Timestamp,
+            ),
+            Name => SortKey::new(
+                Expr::col((ShopIden::Table, ShopIden::Name)),
+                order,
+                SortKeyKind::Text,
+            ),
+            Random => {
+                SortKey::random(seed, (ShopIden::Table, ShopIden::ShopId))
             }
-            Name => query.order_by((ShopIden::Table, ShopIden::Name), order),
-            Random => query.order_by_expr(
-                SimpleExpr::FunctionCall(Func::random()),
-                Order::Asc,
+            Relevance => SortKey::new(
+                search_score
+                    .ok_or(DbError::Argument("order_by.field"))?
+                    .clone(),
+                order,
+                SortKeyKind::Float,
             ),
         };
+
+        Ok(sort_key)
     }
 
     fn add_filter(
         query: &mut SelectStatement,
+        filter: &FilterGroup<ShopsFilterField>,
+    ) -> Result<Option<SimpleExpr>, DbError> {
+        let mut scores = Vec::new();
+
+        if let Some(condition) =
+            filter.build_condition(&mut |filter_field, filter_query| {
+                Ok::<_, DbError>(Self::get_filter_condition(
+                    query,
+                    &mut scores,
+                    filter_field,
+                    filter_query,
+                ))
+            })?
+        {
+            query.cond_where(condition);
+        }
+
+        // results are ranked by the sum of the ranks of all filters
+        let search_score = scores.into_iter().reduce(|a, b| a.add(b));
+        if let Some(score) = search_score.clone() {
+            query.expr_as(score, Self::get_search_score_alias());
+        }
+
+        Ok(search_score)
+    }
+
+    /// Returns the condition of a single filter, selecting its rank on the
+    /// query and adding it to the search scores.
+    fn get_filter_condition(
+        query: &mut SelectStatement,
+        scores: &mut Vec<SimpleExpr>,
         filter_field: ShopsFilterField,
-        filter_query: String,
-    ) {
+        filter_query: &str,
+    ) -> Option<Condition> {
         use ShopsFilterField::*;
 
-        match filter_field {
-            Unspecified => {}
+        let condition = match filter_field {
+            Unspecified => return None,
             Name => {
-                let column = (ShopIden::Table, ShopIden::NameTs);
-                let tsquery = build_simple_plain_ts_query(&filter_query);
-                query
-                    .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(column),
-                            tsquery.clone(),
-                        )),
-                        Self::get_name_ts_rank_alias(),
-                    )
-                    .cond_where(Expr::col(column).matches(tsquery));
+                let (condition, rank) = build_name_search(
+                    (ShopIden::Table, ShopIden::Name),
+                    (ShopIden::Table, ShopIden::NameTs),
+                    (ShopIden::Table, ShopIden::ContentLanguage),
+                    filter_query,
+                );
+                query.expr_as(rank.clone(), Self::get_name_ts_rank_alias());
+                scores.push(rank);
+                condition
             }
             Description => {
-                let column = (ShopIden::Table, ShopIden::DescriptionTs);
-                let tsquery = build_simple_plain_ts_query(&filter_query);
-                query
-                    .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(column),
-                            tsquery.clone(),
-                        )),
-                        Self::get_description_ts_rank_alias(),
-                    )
-                    .cond_where(Expr::col(column).matches(tsquery));
+                let (condition, rank) = build_ts_search(
+                    (ShopIden::Table, ShopIden::DescriptionTs),
+                    (ShopIden::Table, ShopIden::ContentLanguage),
+                    filter_query,
+                );
+                query.expr_as(
+                    rank.clone(),
+                    Self::get_description_ts_rank_alias(),
+                );
+                scores.push(rank);
+                condition
             }
             NameAndDescription => {
-                let name_col = (ShopIden::Table, ShopIden::NameTs);
-                let description_col =
-                    (ShopIden::Table, ShopIden::DescriptionTs);
-
-                let tsquery = build_simple_plain_ts_query(&filter_query);
-
+                let (name_condition, name_rank) = build_name_search(
+                    (ShopIden::Table, ShopIden::Name),
+                    (ShopIden::Table, ShopIden::NameTs),
+                    (ShopIden::Table, ShopIden::ContentLanguage),
+                    filter_query,
+                );
+                let (description_condition, description_rank) = build_ts_search(
+                    (ShopIden::Table, ShopIden::DescriptionTs),
+                    (ShopIden::Table, ShopIden::ContentLanguage),
+                    filter_query,
+                );
                 query
+                    .expr_as(name_rank.clone(), Self::get_name_ts_rank_alias())
                     .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(name_col),
-                            tsquery.clone(),
-                        )),
-                        Self::get_name_ts_rank_alias(),
-                    )
-                    .expr_as(
-                        Expr::expr(PgFunc::ts_rank(
-                            Expr::col(description_col),
-                            tsquery.clone(),
-                        )),
+                        description_rank.clone(),
                         Self::get_description_ts_rank_alias(),
-                    )
-                    .cond_where(any![
-                        Expr::col(name_col).matches(tsquery.clone()),
-                        Expr::col(description_col).matches(tsquery),
-                    ]);
+                    );
+                scores.push(name_rank);
+                scores.push(description_rank);
+                any![name_condition, description_condition]
             }
-        }
+        };
+
+        Some(condition)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
         pool: &Pool,
         user_id: &String,
@@ -198,6 +257,8 @@ impl Shop {
         description: Option<String>,
         platform_fee_percent: u32,
         minimum_platform_fee_cent: u32,
+        prices_include_tax: bool,
+        content_language: &str,
     ) -> Result<Self, DbError> {
         let client = pool.get().await?;
 
@@ -210,6 +271,8 @@ impl Shop {
                 ShopIden::Description,
                 ShopIden::PlatformFeePercent,
                 ShopIden::MinimumPlatformFeeCent,
+                ShopIden::PricesIncludeTax,
+                ShopIden::ContentLanguage,
             ])
             .values([
                 user_id.into(),
@@ -218,6 +281,8 @@ impl Shop {
                 description.unwrap_or_default().into(),
                 i64::from(platform_fee_percent).into(),
                 i64::from(minimum_platform_fee_cent).into(),
+                prices_include_tax.into(),
+                content_language.into(),
             ])?
             .returning_all()
             .build_postgres(PostgresQueryBuilder);
@@ -375,20 +440,70 @@ impl Shop {
         Ok(row.map(Self::from))
     }
 
+    /// Restricts the query to the shops of a list that are visible to the
+    /// requesting user and match the filter. Returns the search score of the
+    /// filters.
+    fn add_list_conditions(
+        query: &mut SelectStatement,
+        user_id: Option<&String>,
+        filter: &FilterGroup<ShopsFilterField>,
+        request_user_id: Option<&String>,
+    ) -> Result<Option<SimpleExpr>, DbError> {
+        if let Some(user_id) = user_id {
+            query.cond_where(
+                Expr::col((ShopIden::Table, ShopIden::UserId)).eq(user_id),
+            );
+        }
+
+        query.cond_where(any![
+            Expr::col((ShopIden::Table, ShopIden::IsActive)).eq(true),
+            Expr::col((ShopIden::Table, ShopIden::UserId))
+                .eq(request_user_id.cloned())
+        ]);
+
+        Self::add_filter(query, filter)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn list(
         pool: &Pool,
         user_id: Option<&String>,
         limit: u64,
         offset: u64,
-        filter: Option<(ShopsFilterField, String)>,
-        order_by: Option<(ShopsOrderByField, Direction)>,
+        cursor: Option<Cursor>,
+        filter: FilterGroup<ShopsFilterField>,
+        mut order_by: Vec<(ShopsOrderByField, Direction)>,
+        random_seed: Option<&str>,
         extended: bool,
         request_user_id: Option<&String>,
-    ) -> Result<Vec<Self>, DbError> {
+    ) -> Result<(Vec<Self>, i64, Option<Cursor>), DbError> {
+        // all filters are text filters, whose results are ranked by relevance
+        // unless ordered otherwise
+        let is_text_filter = !filter.fields().is_empty();
+        if order_by.is_empty() && is_text_filter {
+            order_by.push((ShopsOrderByField::Relevance, Direction::Desc));
+        }
+        if !is_text_filter
+            && order_by
+                .iter()
+                .any(|(f, _)| *f == ShopsOrderByField::Relevance)
+        {
+            return Err(DbError::Argument("order_by.field"));
+        }
+
+        // random orders are the same for the same seed, which is kept in the
+        // cursor for the next pages
+        let is_random = order_by
+            .iter()
+            .any(|(f, _)| *f == ShopsOrderByField::Random);
+        let seed = random_seed
+            .or_else(|| cursor.as_ref().and_then(|c| c.seed()))
+            .map(String::from)
+            .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
+
         let client = pool.get().await?;
 
-        let (sql, values) = {
+        let (sql, values, sort_key_kinds) = {
             let mut query = if extended {
                 Self::select_with_relations()
             } else {
@@ -398,39 +513,93 @@ impl Shop {
                     .to_owned()
             };
 
-            if let Some(user_id) = user_id {
-                query.cond_where(
-                    Expr::col((ShopIden::Table, ShopIden::UserId)).eq(user_id),
-                );
-            }
-
-            if let Some((filter_field, filter_query)) = filter {
-                Self::add_filter(&mut query, filter_field, filter_query);
-            }
+            let search_score = Self::add_list_conditions(
+                &mut query,
+                user_id,
+                &filter,
+                request_user_id,
+            )?;
+
+            let mut sort_keys = order_by
+                .into_iter()
+                .map(|(order_by_field, order_by_direction)| {
+                    Self::get_sort_key(
+                        order_by_field,
+                        order_by_direction,
+                        search_score.as_ref(),
+                        &seed,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            sort_keys.push(SortKey::new(
+                Expr::col((ShopIden::Table, ShopIden::ShopId)),
+                Order::Asc,
+                SortKeyKind::Uuid,
+            ));
 
-            if let Some((order_by_field, order_by_direction)) = order_by {
-                Self::add_order_by(
-                    &mut query,
-                    order_by_field,
-                    order_by_direction,
-                );
+            if let Some(cursor) = cursor.as_ref() {
+                query.cond_where(cursor.get_condition(&sort_keys)?);
             }
 
-            query.cond_where(any![
-                Expr::col((ShopIden::Table, ShopIden::IsActive)).eq(true),
-                Expr::col((ShopIden::Table, ShopIden::UserId))
-                    .eq(request_user_id.cloned())
-            ]);
+            SortKey::add_to_query(&mut query, &sort_keys);
+            add_total_count(&mut query);
 
-            query
+            let (sql, values) = query
                 .limit(limit)
                 .offset(offset)
-                .build_postgres(PostgresQueryBuilder)
+                .build_postgres(PostgresQueryBuilder);
+
+            let sort_key_kinds: Vec<SortKeyKind> =
+                sort_keys.iter().map(SortKey::kind).collect();
+
+            (sql, values, sort_key_kinds)
+        };
+
+        let (count_sql, count_values) = {
+            let mut query = Query::select();
+
+            query
+                .column((ShopIden::Table, ShopIden::ShopId))
+                .from(ShopIden::Table);
+
+            Self::add_list_conditions(
+                &mut query,
+                user_id,
+                &filter,
+                request_user_id,
+            )?;
+
+            select_count(query).build_postgres(PostgresQueryBuilder)
         };
 
         let rows = client.query(sql.as_str(), &values.as_params()).await?;
 
-        Ok(rows.iter().map(Self::from).collect())
+        // the list is only counted separately if the page does not contain
+        // the count of the whole list
+        let count = match get_total_count_from_rows(&rows) {
+            Some(count) if cursor.is_none() => count,
+            None if cursor.is_none() && offset == 0 => 0,
+            _ => {
+                let count_rows = client
+                    .query(count_sql.as_str(), &count_values.as_params())
+                    .await?;
+                get_count_from_rows(&count_rows)
+            }
+        };
+
+        // full pages are continued after their last shop
+        let next_cursor = rows
+            .last()
+            .filter(|_| u64::try_from(rows.len()) == Ok(limit))
+            .map(|row| {
+                Cursor::from_row(
+                    row,
+                    &sort_key_kinds,
+                    is_random.then_some(seed),
+                )
+            });
+
+        Ok((rows.iter().map(Self::from).collect(), count, next_cursor))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -445,8 +614,11 @@ impl Shop {
         minimum_platform_fee_cent: Option<u32>,
         is_active: Option<bool>,
         contact_email_address: Option<String>,
+        prices_include_tax: Option<bool>,
+        content_language: Option<&str>,
     ) -> Result<Self, DbError> {
-        let client = pool.get().await?;
+        let mut conn = pool.get().await?;
+        let transaction = conn.transaction().await?;
 
         let (sql, values) = {
             let mut query = Query::update();
@@ -483,6 +655,14 @@ impl Shop {
                 );
             }
 
+            if let Some(prices_include_tax) = prices_include_tax {
+                query.value(ShopIden::PricesIncludeTax, prices_include_tax);
+            }
+
+            if let Some(content_language) = content_language {
+                query.value(ShopIden::ContentLanguage, content_language);
+            }
+
             query
                 .and_where(Expr::col(ShopIden::UserId).eq(user_id))
                 .and_where(Expr::col(ShopIden::ShopId).eq(*shop_id))
@@ -491,7 +671,21 @@ impl Shop {
             query.build_postgres(PostgresQueryBuilder)
         };
 
-        let row = client.query_one(sql.as_str(), &values.as_params()).await?;
+        let row = transaction
+            .query_one(sql.as_str(), &values.as_params())
+            .await?;
+
+        // offers are searched in the language of their shop
+        if let Some(content_language) = content_language {
+            Offer::update_content_language(
+                &transaction,
+                shop_id,
+                content_language,
+            )
+            .await?;
+        }
+
+        transaction.commit().await?;
 
         Ok(Self::from(row))
     }
@@ -579,6 +773,10 @@ impl From<&Row> for Shop {
                 .get(ShopIden::ContactEmailAddress.to_string().as_str()),
             client_id: row.get(ShopIden::ClientId.to_string().as_str()),
             website_id: row.get(ShopIden::WebsiteId.to_string().as_str()),
+            prices_include_tax: row
+                .get(ShopIden::PricesIncludeTax.to_string().as_str()),
+            content_language: row
+                .get(ShopIden::ContentLanguage.to_string().as_str()),
         }
     }
 }
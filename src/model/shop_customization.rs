@@ -6,12 +6,13 @@ use fallible_iterator::FallibleIterator;
 use postgres_protocol::types;
 use sea_query::{
     Asterisk, Expr, Func, Iden, OnConflict, PostgresQueryBuilder, Query,
-    SimpleExpr,
+    SimpleExpr, UpdateStatement,
 };
 use sea_query_postgres::PostgresBinder;
 use uuid::Uuid;
 
 use crate::db::{get_type_from_oid, ArrayAgg, DbError};
+use crate::images::StoredImageVariants;
 
 #[derive(Debug, Clone, Copy, Iden)]
 #[iden(rename = "shop_customizations")]
@@ -27,6 +28,14 @@ pub enum ShopCustomizationIden {
     BannerImageDarkUrlPath,
     PrimaryColor,
     LayoutType,
+    LogoImageLightVariantWidths,
+    LogoImageLightVariantContentTypes,
+    LogoImageDarkVariantWidths,
+    LogoImageDarkVariantContentTypes,
+    BannerImageLightVariantWidths,
+    BannerImageLightVariantContentTypes,
+    BannerImageDarkVariantWidths,
+    BannerImageDarkVariantContentTypes,
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +50,10 @@ pub struct ShopCustomization {
     pub banner_image_dark_url_path: Option<String>,
     pub primary_color: Option<String>,
     pub layout_type: String,
+    pub logo_image_light_variants: StoredImageVariants,
+    pub logo_image_dark_variants: StoredImageVariants,
+    pub banner_image_light_variants: StoredImageVariants,
+    pub banner_image_dark_variants: StoredImageVariants,
 }
 
 impl ShopCustomization {
@@ -126,29 +139,58 @@ impl ShopCustomization {
         Ok(row.map(Self::from))
     }
 
-    pub async fn update_logo_image_url_paths<'a>(
+    /// Sets the path and stored variants of an image, or removes the image
+    /// if there is none.
+    fn set_image(
+        query: &mut UpdateStatement,
+        columns: [ShopCustomizationIden; 3],
+        image: Option<(String, StoredImageVariants)>,
+    ) {
+        let [path_column, widths_column, content_types_column] = columns;
+        let (image_path, variants) = match image {
+            Some((image_path, variants)) => (Some(image_path), variants),
+            None => (None, StoredImageVariants::default()),
+        };
+
+        query
+            .value(path_column, image_path)
+            .value(widths_column, variants.get_widths_expr())
+            .value(content_types_column, variants.get_content_types_expr());
+    }
+
+    pub async fn update_logo_images<'a>(
         transaction: &Transaction<'a>,
         shop_id: &Uuid,
         user_id: &String,
-        logo_image_light_url_path: Option<Option<String>>,
-        logo_image_dark_url_path: Option<Option<String>>,
+        logo_image_light: Option<Option<(String, StoredImageVariants)>>,
+        logo_image_dark: Option<Option<(String, StoredImageVariants)>>,
     ) -> Result<Self, DbError> {
         let (sql, values) = {
             let mut query = Query::update();
 
             query.table(ShopCustomizationIden::Table);
 
-            if let Some(logo_image_light_url_path) = logo_image_light_url_path {
-                query.value(
-                    ShopCustomizationIden::LogoImageLightUrlPath,
-                    logo_image_light_url_path,
+            if let Some(logo_image_light) = logo_image_light {
+                Self::set_image(
+                    &mut query,
+                    [
+                        ShopCustomizationIden::LogoImageLightUrlPath,
+                        ShopCustomizationIden::LogoImageLightVariantWidths,
+                        ShopCustomizationIden::LogoImageLightVariantContentTypes,
+                    ],
+                    logo_image_light,
                 );
             }
 
-            if let Some(logo_image_dark_url_path) = logo_image_dark_url_path {
-                query.value(
-                    ShopCustomizationIden::LogoImageDarkUrlPath,
-                    logo_image_dark_url_path,
+            if let Some(logo_image_dark) = logo_image_dark {
+                Self::set_image(
+                    &mut query,
+                    [
+                        ShopCustomizationIden::LogoImageDarkUrlPath,
+                        ShopCustomizationIden::LogoImageDarkVariantWidths,
+                        ShopCustomizationIden::LogoImageDarkVariantContentTypes,
+                    ],
+                    logo_image_dark,
                 );
             }
 
@@ -168,32 +210,39 @@ impl ShopCustomization {
         Ok(Self::from(row))
     }
 
-    pub async fn update_banner_image_url_paths<'a>(
+    pub async fn update_banner_images<'a>(
         transaction: &Transaction<'a>,
         shop_id: &Uuid,
         user_id: &String,
-        banner_image_light_url_path: Option<Option<String>>,
-        banner_image_dark_url_path: Option<Option<String>>,
+        banner_image_light: Option<Option<(String, StoredImageVariants)>>,
+        banner_image_dark: Option<Option<(String, StoredImageVariants)>>,
     ) -> Result<Self, DbError> {
         let (sql, values) = {
             let mut query = Query::update();
 
             query.table(ShopCustomizationIden::Table);
 
-            if let Some(banner_image_light_url_path) =
-                banner_image_light_url_path
-            {
-                query.value(
-                    ShopCustomizationIden::BannerImageLightUrlPath,
-                    banner_image_light_url_path,
+            if let Some(banner_image_light) = banner_image_light {
+                Self::set_image(
+                    &mut query,
+                    [
+                        ShopCustomizationIden::BannerImageLightUrlPath,
+                        ShopCustomizationIden::BannerImageLightVariantWidths,
+                        ShopCustomizationIden::BannerImageLightVariantContentTypes,
+                    ],
+                    banner_image_light,
                 );
             }
 
-            if let Some(banner_image_dark_url_path) = banner_image_dark_url_path
-            {
-                query.value(
-                    ShopCustomizationIden::BannerImageDarkUrlPath,
-                    banner_image_dark_url_path,
+            if let Some(banner_image_dark) = banner_image_dark {
+                Self::set_image(
+                    &mut query,
+                    [
+                        ShopCustomizationIden::BannerImageDarkUrlPath,
+                        ShopCustomizationIden::BannerImageDarkVariantWidths,
+                        ShopCustomizationIden::BannerImageDarkVariantContentTypes,
+                    ],
+                    banner_image_dark,
                 );
             }
 
@@ -265,6 +314,54 @@ impl From<&Row> for ShopCustomization {
                 .get(ShopCustomizationIden::PrimaryColor.to_string().as_str()),
             layout_type: row
                 .get(ShopCustomizationIden::LayoutType.to_string().as_str()),
+            logo_image_light_variants: StoredImageVariants::from_columns(
+                row.get(
+                    ShopCustomizationIden::LogoImageLightVariantWidths
+                        .to_string()
+                        .as_str(),
+                ),
+                row.get(
+                    ShopCustomizationIden::LogoImageLightVariantContentTypes
+                        .to_string()
+                        .as_str(),
+                ),
+            ),
+            logo_image_dark_variants: StoredImageVariants::from_columns(
+                row.get(
+                    ShopCustomizationIden::LogoImageDarkVariantWidths
+                        .to_string()
+                        .as_str(),
+                ),
+                row.get(
+                    ShopCustomizationIden::LogoImageDarkVariantContentTypes
+                        .to_string()
+                        .as_str(),
+                ),
+            ),
+            banner_image_light_variants: StoredImageVariants::from_columns(
+                row.get(
+                    ShopCustomizationIden::BannerImageLightVariantWidths
+                        .to_string()
+                        .as_str(),
+                ),
+                row.get(
+                    ShopCustomizationIden::BannerImageLightVariantContentTypes
+                        .to_string()
+                        .as_str(),
+                ),
+            ),
+            banner_image_dark_variants: StoredImageVariants::from_columns(
+                row.get(
+                    ShopCustomizationIden::BannerImageDarkVariantWidths
+                        .to_string()
+                        .as_str(),
+                ),
+                row.get(
+                    ShopCustomizationIden::BannerImageDarkVariantContentTypes
+                        .to_string()
+                        .as_str(),
+                ),
+            ),
         }
     }
 }
@@ -283,6 +380,10 @@ pub struct ShopCustomizationAsRel {
     pub banner_image_dark_url_path: Option<String>,
     pub primary_color: Option<String>,
     pub layout_type: String,
+    pub logo_image_light_variants: StoredImageVariants,
+    pub logo_image_dark_variants: StoredImageVariants,
+    pub banner_image_light_variants: StoredImageVariants,
+    pub banner_image_dark_variants: StoredImageVariants,
 }
 
 impl ShopCustomizationAsRel {
@@ -319,6 +420,46 @@ impl ShopCustomizationAsRel {
                     ShopCustomizationIden::LayoutType,
                 ))
                 .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::LogoImageLightVariantWidths,
+                ))
+                .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::LogoImageLightVariantContentTypes,
+                ))
+                .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::LogoImageDarkVariantWidths,
+                ))
+                .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::LogoImageDarkVariantContentTypes,
+                ))
+                .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::BannerImageLightVariantWidths,
+                ))
+                .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::BannerImageLightVariantContentTypes,
+                ))
+                .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::BannerImageDarkVariantWidths,
+                ))
+                .into(),
+                Expr::col((
+                    ShopCustomizationIden::Table,
+                    ShopCustomizationIden::BannerImageDarkVariantContentTypes,
+                ))
+                .into(),
             ])
             .into()])
             .into()
@@ -364,6 +505,46 @@ impl<'a> FromSql<'a> for ShopCustomizationAsRel {
         let ty = get_type_from_oid::<String>(oid)?;
         let layout_type: String = private::read_value(&ty, &mut raw)?;
 
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<i64>>(oid)?;
+        let logo_image_light_variant_widths: Vec<i64> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<String>>(oid)?;
+        let logo_image_light_variant_content_types: Vec<String> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<i64>>(oid)?;
+        let logo_image_dark_variant_widths: Vec<i64> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<String>>(oid)?;
+        let logo_image_dark_variant_content_types: Vec<String> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<i64>>(oid)?;
+        let banner_image_light_variant_widths: Vec<i64> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<String>>(oid)?;
+        let banner_image_light_variant_content_types: Vec<String> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<i64>>(oid)?;
+        let banner_image_dark_variant_widths: Vec<i64> =
+            private::read_value(&ty, &mut raw)?;
+
+        let oid = private::read_be_i32(&mut raw)?;
+        let ty = get_type_from_oid::<Vec<String>>(oid)?;
+        let banner_image_dark_variant_content_types: Vec<String> =
+            private::read_value(&ty, &mut raw)?;
+
         Ok(Self {
             logo_image_light_url_path,
             logo_image_dark_url_path,
@@ -371,6 +552,22 @@ impl<'a> FromSql<'a> for ShopCustomizationAsRel {
             banner_image_dark_url_path,
             primary_color,
             layout_type,
+            logo_image_light_variants: StoredImageVariants::from_columns(
+                logo_image_light_variant_widths,
+                logo_image_light_variant_content_types,
+            ),
+            logo_image_dark_variants: StoredImageVariants::from_columns(
+                logo_image_dark_variant_widths,
+                logo_image_dark_variant_content_types,
+            ),
+            banner_image_light_variants: StoredImageVariants::from_columns(
+                banner_image_light_variant_widths,
+                banner_image_light_variant_content_types,
+            ),
+            banner_image_dark_variants: StoredImageVariants::from_columns(
+                banner_image_dark_variant_widths,
+                banner_image_dark_variant_content_types,
+            ),
         })
     }
 }
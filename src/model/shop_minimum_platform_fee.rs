@@ -0,0 +1,188 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Pool;
+use sea_query::{
+    Asterisk, Expr, Iden, OnConflict, Order, PostgresQueryBuilder, Query,
+};
+use sea_query_postgres::PostgresBinder;
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::Currency;
+use crate::db::DbError;
+use crate::fees::AllowedMinimumPlatformFees;
+
+use super::Shop;
+
+#[derive(Iden)]
+#[iden(rename = "shop_minimum_platform_fees")]
+pub enum ShopMinimumPlatformFeeIden {
+    Table,
+    ShopId,
+    Currency,
+    UserId,
+    Amount,
+}
+
+/// Minimum platform fee of a shop in a currency other than EUR, in the minor
+/// unit of that currency. The EUR minimum is `Shop::minimum_platform_fee_cent`.
+#[derive(Debug, Clone)]
+pub struct ShopMinimumPlatformFee {
+    pub shop_id: Uuid,
+    pub currency: String,
+    pub amount: u32,
+}
+
+impl ShopMinimumPlatformFee {
+    pub async fn put(
+        pool: &Pool,
+        shop_id: &Uuid,
+        currency: &str,
+        user_id: &String,
+        amount: u32,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(ShopMinimumPlatformFeeIden::Table)
+            .columns([
+                ShopMinimumPlatformFeeIden::ShopId,
+                ShopMinimumPlatformFeeIden::Currency,
+                ShopMinimumPlatformFeeIden::UserId,
+                ShopMinimumPlatformFeeIden::Amount,
+            ])
+            .values([
+                (*shop_id).into(),
+                currency.into(),
+                user_id.into(),
+                i64::from(amount).into(),
+            ])?
+            .on_conflict(
+                OnConflict::columns([
+                    ShopMinimumPlatformFeeIden::ShopId,
+                    ShopMinimumPlatformFeeIden::Currency,
+                ])
+                .update_column(ShopMinimumPlatformFeeIden::Amount)
+                .to_owned(),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        shop_id: &Uuid,
+        currency: &str,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(ShopMinimumPlatformFeeIden::Table)
+            .and_where(
+                Expr::col(ShopMinimumPlatformFeeIden::ShopId).eq(*shop_id),
+            )
+            .and_where(
+                Expr::col(ShopMinimumPlatformFeeIden::Currency).eq(currency),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    pub async fn list(
+        pool: &Pool,
+        shop_id: &Uuid,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(ShopMinimumPlatformFeeIden::Table)
+            .and_where(
+                Expr::col(ShopMinimumPlatformFeeIden::ShopId).eq(*shop_id),
+            )
+            .order_by(ShopMinimumPlatformFeeIden::Currency, Order::Asc)
+            .build_postgres(PostgresQueryBuilder);
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+
+    pub async fn delete(
+        pool: &Pool,
+        shop_id: &Uuid,
+        currency: &str,
+        user_id: &String,
+    ) -> Result<(), DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::delete()
+            .from_table(ShopMinimumPlatformFeeIden::Table)
+            .and_where(
+                Expr::col(ShopMinimumPlatformFeeIden::ShopId).eq(*shop_id),
+            )
+            .and_where(
+                Expr::col(ShopMinimumPlatformFeeIden::Currency).eq(currency),
+            )
+            .and_where(
+                Expr::col(ShopMinimumPlatformFeeIden::UserId).eq(user_id),
+            )
+            .build_postgres(PostgresQueryBuilder);
+
+        conn.execute(sql.as_str(), &values.as_params()).await?;
+
+        Ok(())
+    }
+
+    /// Returns the minimum platform fee of the shop in the minor unit of the
+    /// currency. Shops without a minimum fee of their own in the currency
+    /// pay the minimum required by the platform, which no shop can go below.
+    pub async fn get_for_shop(
+        pool: &Pool,
+        shop: &Shop,
+        currency: Currency,
+        allowed_minimum_platform_fees: &AllowedMinimumPlatformFees,
+    ) -> Result<u32, DbError> {
+        let allowed_minimum = allowed_minimum_platform_fees.get(currency);
+
+        let minimum_platform_fee = match currency {
+            Currency::Unspecified | Currency::Eur => {
+                Some(shop.minimum_platform_fee_cent)
+            }
+            currency => Self::get(pool, &shop.shop_id, currency.as_str_name())
+                .await?
+                .map(|f| f.amount),
+        };
+
+        Ok(minimum_platform_fee
+            .unwrap_or(allowed_minimum)
+            .max(allowed_minimum))
+    }
+}
+
+impl From<&Row> for ShopMinimumPlatformFee {
+    fn from(row: &Row) -> Self {
+        Self {
+            shop_id: row
+                .get(ShopMinimumPlatformFeeIden::ShopId.to_string().as_str()),
+            currency: row
+                .get(ShopMinimumPlatformFeeIden::Currency.to_string().as_str()),
+            amount: u32::try_from(row.get::<&str, i64>(
+                ShopMinimumPlatformFeeIden::Amount.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+        }
+    }
+}
+
+impl From<Row> for ShopMinimumPlatformFee {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
@@ -0,0 +1,129 @@
+use deadpool_postgres::tokio_postgres::Row;
+use deadpool_postgres::Pool;
+use sea_query::{
+    Asterisk, Expr, Iden, OnConflict, Order, PostgresQueryBuilder, Query,
+};
+use sea_query_postgres::PostgresBinder;
+
+use crate::db::DbError;
+
+#[derive(Iden)]
+#[iden(rename = "tax_rates")]
+pub enum TaxRateIden {
+    Table,
+    Country,
+    TaxCategory,
+    RateBasisPoints,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaxRate {
+    pub country: String,
+    pub tax_category: String,
+    pub rate_basis_points: u32,
+}
+
+impl TaxRate {
+    pub async fn put(
+        pool: &Pool,
+        country: &str,
+        tax_category: &str,
+        rate_basis_points: u32,
+    ) -> Result<Self, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::insert()
+            .into_table(TaxRateIden::Table)
+            .columns([
+                TaxRateIden::Country,
+                TaxRateIden::TaxCategory,
+                TaxRateIden::RateBasisPoints,
+            ])
+            .values([
+                country.into(),
+                tax_category.into(),
+                i64::from(rate_basis_points).into(),
+            ])?
+            .on_conflict(
+                OnConflict::columns([
+                    TaxRateIden::Country,
+                    TaxRateIden::TaxCategory,
+                ])
+                .update_column(TaxRateIden::RateBasisPoints)
+                .to_owned(),
+            )
+            .returning_all()
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_one(sql.as_str(), &values.as_params()).await?;
+
+        Ok(Self::from(row))
+    }
+
+    pub async fn get(
+        pool: &Pool,
+        country: &str,
+        tax_category: &str,
+    ) -> Result<Option<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = Query::select()
+            .column(Asterisk)
+            .from(TaxRateIden::Table)
+            .and_where(Expr::col(TaxRateIden::Country).eq(country))
+            .and_where(Expr::col(TaxRateIden::TaxCategory).eq(tax_category))
+            .build_postgres(PostgresQueryBuilder);
+
+        let row = conn.query_opt(sql.as_str(), &values.as_params()).await?;
+
+        Ok(row.map(Self::from))
+    }
+
+    /// Returns the rates of the given country or of all countries, ordered
+    /// by country and tax category.
+    pub async fn list(
+        pool: &Pool,
+        country: Option<&str>,
+    ) -> Result<Vec<Self>, DbError> {
+        let conn = pool.get().await?;
+
+        let (sql, values) = {
+            let mut query = Query::select();
+
+            query.column(Asterisk).from(TaxRateIden::Table);
+
+            if let Some(country) = country {
+                query.and_where(Expr::col(TaxRateIden::Country).eq(country));
+            }
+
+            query
+                .order_by(TaxRateIden::Country, Order::Asc)
+                .order_by(TaxRateIden::TaxCategory, Order::Asc)
+                .build_postgres(PostgresQueryBuilder)
+        };
+
+        let rows = conn.query(sql.as_str(), &values.as_params()).await?;
+
+        Ok(rows.iter().map(Self::from).collect())
+    }
+}
+
+impl From<&Row> for TaxRate {
+    fn from(row: &Row) -> Self {
+        Self {
+            country: row.get(TaxRateIden::Country.to_string().as_str()),
+            tax_category: row
+                .get(TaxRateIden::TaxCategory.to_string().as_str()),
+            rate_basis_points: u32::try_from(row.get::<&str, i64>(
+                TaxRateIden::RateBasisPoints.to_string().as_str(),
+            ))
+            .expect("Should not be greater than 4294967295"),
+        }
+    }
+}
+
+impl From<Row> for TaxRate {
+    fn from(row: Row) -> Self {
+        Self::from(&row)
+    }
+}
@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+
+use crate::api::sited_io::commerce::v1::PriceBillingScheme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceTier {
+    /// Upper bound of the tier including the quantity itself. `None` marks
+    /// the last tier, which covers all larger quantities.
+    pub up_to: Option<u32>,
+    pub unit_amount: u32,
+    pub flat_amount: u32,
+}
+
+impl PriceTier {
+    fn covers(&self, quantity: u32) -> bool {
+        self.up_to.is_none_or(|up_to| quantity <= up_to)
+    }
+}
+
+/// Sale of a price. While the sale applies, the unit amount of the price is
+/// charged and the compare-at amount is shown as the regular amount. Outside
+/// of the sale window the compare-at amount is charged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceSale {
+    pub compare_at_amount: u32,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+impl PriceSale {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.starts_at.is_none_or(|s| s <= now)
+            && self.ends_at.is_none_or(|e| now < e)
+    }
+}
+
+/// Sorts tiers by their upper bound, the open-ended tier last.
+pub fn sort_tiers(tiers: &mut [PriceTier]) {
+    tiers.sort_by_key(|t| (t.up_to.is_none(), t.up_to));
+}
+
+/// Calculates the amount for `quantity` units of a price, so carts and
+/// orders share the same math. Expects sorted tiers and returns `None` if
+/// the price cannot be applied to the quantity, e.g. if no tier covers it.
+///
+/// - per unit: `quantity` times the unit amount
+/// - package: the unit amount per started package of `package_size` units
+/// - volume: all units at the unit amount of the tier the quantity falls
+///   into, plus its flat amount
+/// - tiered: the units within each tier at that tier's unit amount, plus the
+///   flat amount of every tier that is reached
+pub fn calculate_amount(
+    billing_scheme: PriceBillingScheme,
+    unit_amount: u32,
+    package_size: Option<u32>,
+    tiers: &[PriceTier],
+    quantity: u32,
+) -> Option<u64> {
+    match billing_scheme {
+        PriceBillingScheme::Unspecified => None,
+        PriceBillingScheme::PerUnit => {
+            Some(u64::from(unit_amount) * u64::from(quantity))
+        }
+        PriceBillingScheme::Package => {
+            let package_size = package_size.filter(|s| *s > 0)?;
+            let packages = quantity.div_ceil(package_size);
+            Some(u64::from(unit_amount) * u64::from(packages))
+        }
+        PriceBillingScheme::Volume => {
+            let tier = tiers.iter().find(|t| t.covers(quantity))?;
+            Some(
+                u64::from(tier.unit_amount) * u64::from(quantity)
+                    + u64::from(tier.flat_amount),
+            )
+        }
+        PriceBillingScheme::Tiered => {
+            if !tiers.iter().any(|t| t.covers(quantity)) {
+                return None;
+            }
+
+            let mut amount = 0;
+            let mut lower_bound = 0;
+            for tier in tiers {
+                if quantity <= lower_bound {
+                    break;
+                }
+                let upper_bound = tier.up_to.unwrap_or(quantity).min(quantity);
+                let units = upper_bound.saturating_sub(lower_bound);
+                amount += u64::from(tier.unit_amount) * u64::from(units)
+                    + u64::from(tier.flat_amount);
+                lower_bound = upper_bound;
+            }
+
+            Some(amount)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_tiers() -> Vec<PriceTier> {
+        vec![
+            PriceTier {
+                up_to: Some(10),
+                unit_amount: 100,
+                flat_amount: 0,
+            },
+            PriceTier {
+                up_to: Some(20),
+                unit_amount: 80,
+                flat_amount: 50,
+            },
+            PriceTier {
+                up_to: None,
+                unit_amount: 60,
+                flat_amount: 0,
+            },
+        ]
+    }
+
+    fn calculate(
+        billing_scheme: PriceBillingScheme,
+        quantity: u32,
+    ) -> Option<u64> {
+        calculate_amount(billing_scheme, 250, Some(6), &get_tiers(), quantity)
+    }
+
+    #[test]
+    fn per_unit_multiplies_unit_amount() {
+        assert_eq!(calculate(PriceBillingScheme::PerUnit, 3), Some(750));
+    }
+
+    #[test]
+    fn unspecified_billing_scheme_has_no_amount() {
+        assert_eq!(calculate(PriceBillingScheme::Unspecified, 3), None);
+    }
+
+    #[test]
+    fn package_rounds_up_to_started_packages() {
+        assert_eq!(calculate(PriceBillingScheme::Package, 1), Some(250));
+        assert_eq!(calculate(PriceBillingScheme::Package, 6), Some(250));
+        assert_eq!(calculate(PriceBillingScheme::Package, 7), Some(500));
+        assert_eq!(calculate(PriceBillingScheme::Package, 12), Some(500));
+    }
+
+    #[test]
+    fn package_requires_package_size() {
+        for package_size in [None, Some(0)] {
+            assert_eq!(
+                calculate_amount(
+                    PriceBillingScheme::Package,
+                    250,
+                    package_size,
+                    &[],
+                    1,
+                ),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn volume_includes_tier_upper_bound() {
+        assert_eq!(calculate(PriceBillingScheme::Volume, 10), Some(1000));
+        assert_eq!(calculate(PriceBillingScheme::Volume, 11), Some(930));
+        assert_eq!(calculate(PriceBillingScheme::Volume, 20), Some(1650));
+    }
+
+    #[test]
+    fn volume_uses_open_ended_tier_above_last_bound() {
+        assert_eq!(calculate(PriceBillingScheme::Volume, 25), Some(1500));
+    }
+
+    #[test]
+    fn tiered_includes_tier_upper_bound() {
+        assert_eq!(calculate(PriceBillingScheme::Tiered, 10), Some(1000));
+        assert_eq!(calculate(PriceBillingScheme::Tiered, 11), Some(1130));
+        assert_eq!(calculate(PriceBillingScheme::Tiered, 20), Some(1850));
+    }
+
+    #[test]
+    fn tiered_uses_open_ended_tier_above_last_bound() {
+        assert_eq!(calculate(PriceBillingScheme::Tiered, 25), Some(2150));
+    }
+
+    #[test]
+    fn tiers_without_open_ended_tier_do_not_cover_larger_quantities() {
+        let tiers = &get_tiers()[..2];
+
+        for billing_scheme in
+            [PriceBillingScheme::Volume, PriceBillingScheme::Tiered]
+        {
+            assert_eq!(
+                calculate_amount(billing_scheme, 250, None, tiers, 21),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn sort_tiers_puts_open_ended_tier_last() {
+        let mut tiers = get_tiers();
+        tiers.reverse();
+
+        sort_tiers(&mut tiers);
+
+        assert_eq!(tiers, get_tiers());
+    }
+}
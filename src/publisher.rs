@@ -1,7 +1,8 @@
 use prost::Message;
 
 use crate::api::sited_io::commerce::v1::{
-    OfferResponse, ShippingRateResponse, ShopResponse,
+    InventoryResponse, OfferResponse, OrderResponse, ShippingRateResponse,
+    ShopResponse,
 };
 
 #[derive(Debug, Clone)]
@@ -21,6 +22,11 @@ impl Publisher {
     const SHIPPING_RATE_DELETE_SUBJECT: &'static str =
         "commerce.shipping_rate.delete";
 
+    const INVENTORY_UPSERT_SUBJECT: &'static str = "commerce.inventory.upsert";
+    const INVENTORY_DELETE_SUBJECT: &'static str = "commerce.inventory.delete";
+
+    const ORDER_TRANSITION_SUBJECT: &'static str = "commerce.order.transition";
+
     pub fn new(client: async_nats::Client) -> Self {
         Self { client }
     }
@@ -103,4 +109,49 @@ impl Publisher {
             tracing::error!("[Publisher.publish_delete_shipping_rate]: {err}")
         }
     }
+
+    pub async fn publish_upsert_inventory(
+        &self,
+        inventory: &InventoryResponse,
+    ) {
+        if let Err(err) = self
+            .client
+            .publish(
+                Self::INVENTORY_UPSERT_SUBJECT,
+                inventory.encode_to_vec().into(),
+            )
+            .await
+        {
+            tracing::error!("[Publisher.publish_upsert_inventory]: {err}");
+        }
+    }
+
+    pub async fn publish_delete_inventory(
+        &self,
+        inventory: &InventoryResponse,
+    ) {
+        if let Err(err) = self
+            .client
+            .publish(
+                Self::INVENTORY_DELETE_SUBJECT,
+                inventory.encode_to_vec().into(),
+            )
+            .await
+        {
+            tracing::error!("[Publisher.publish_delete_inventory]: {err}")
+        }
+    }
+
+    pub async fn publish_order_transition(&self, order: &OrderResponse) {
+        if let Err(err) = self
+            .client
+            .publish(
+                Self::ORDER_TRANSITION_SUBJECT,
+                order.encode_to_vec().into(),
+            )
+            .await
+        {
+            tracing::error!("[Publisher.publish_order_transition]: {err}");
+        }
+    }
 }
@@ -0,0 +1,3 @@
+mod sales;
+
+pub use sales::SalesScheduler;
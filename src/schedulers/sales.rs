@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use deadpool_postgres::Pool;
+
+use crate::db::DbError;
+use crate::images::ImageService;
+use crate::model::{Offer, OfferPrice, SchedulerRun};
+use crate::{OfferService, Publisher};
+
+/// Publishes offers whose sale started or ended, so subscribers like search
+/// indices pick up the changed prices without the offer being updated. The
+/// end of the last published period is persisted, so sales that start or end
+/// while the service is down are published after it started again.
+pub struct SalesScheduler {
+    pool: Pool,
+    publisher: Publisher,
+    image_service: ImageService,
+    interval: Duration,
+}
+
+impl SalesScheduler {
+    const SCHEDULER: &'static str = "sales";
+
+    pub fn new(
+        pool: Pool,
+        publisher: Publisher,
+        image_service: ImageService,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            pool,
+            publisher,
+            image_service,
+            interval,
+        }
+    }
+
+    pub async fn run(&self) {
+        let mut interval = tokio::time::interval(self.interval);
+        let mut since = None;
+
+        loop {
+            interval.tick().await;
+
+            let until = Utc::now();
+
+            // the first run continues after the last persisted one, which
+            // is retried on every tick until it can be read
+            let from = match since {
+                Some(since) => since,
+                None => match SchedulerRun::get(&self.pool, Self::SCHEDULER)
+                    .await
+                {
+                    Ok(run) => run.map(|r| r.last_run_until).unwrap_or(until),
+                    Err(err) => {
+                        tracing::log::error!("[SalesScheduler.run]: {err:?}");
+                        continue;
+                    }
+                },
+            };
+
+            // the period is published again on the next tick if it failed
+            if let Err(err) = self.publish_sale_changes(from, until).await {
+                tracing::log::error!("[SalesScheduler.run]: {err:?}");
+                since = Some(from);
+                continue;
+            }
+
+            if let Err(err) =
+                SchedulerRun::put(&self.pool, Self::SCHEDULER, until).await
+            {
+                tracing::log::error!("[SalesScheduler.run]: {err:?}");
+            }
+
+            since = Some(until);
+        }
+    }
+
+    async fn publish_sale_changes(
+        &self,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<(), DbError> {
+        let offer_ids = OfferPrice::list_offer_ids_with_sale_change(
+            &self.pool, since, until,
+        )
+        .await?;
+
+        for offer_id in offer_ids {
+            // inactive offers are not found and need no update
+            let offer = match Offer::get(&self.pool, &offer_id, None).await? {
+                Some(offer) => offer,
+                None => continue,
+            };
+
+            match OfferService::build_offer_response(&self.image_service, offer)
+            {
+                Ok(offer_response) => {
+                    self.publisher.publish_upsert_offer(&offer_response).await
+                }
+                Err(err) => {
+                    tracing::log::error!("[SalesScheduler.run]: {err:?}")
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,707 @@
+use chrono::{DateTime, Duration, Utc};
+use deadpool_postgres::Pool;
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Code, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::cart_service_server::{
+    self, CartServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    AddItemToCartRequest, AddItemToCartResponse, CartItemResponse,
+    CartResponse, CartTotalsResponse, CreateCartRequest, CreateCartResponse,
+    Currency, DeleteCartRequest, DeleteCartResponse, GetCartRequest,
+    GetCartResponse, MergeCartRequest, MergeCartResponse,
+    RemoveItemFromCartRequest, RemoveItemFromCartResponse, ShippingCountry,
+    UpdateCartItemRequest, UpdateCartItemResponse,
+};
+use crate::auth::get_user_id;
+use crate::db::DbError;
+use crate::model::{Cart, CartItem, Offer, OfferPriceAsRel, Shop};
+use crate::parse_uuid;
+
+use super::discount::DiscountService;
+use super::{get_item_amounts, get_shipping_amount, ShippingLine};
+
+pub struct CartService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+}
+
+impl CartService {
+    const CART_TTL_DAYS: i64 = 30;
+
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+    ) -> CartServiceServer<Self> {
+        CartServiceServer::new(Self { pool, verifier })
+    }
+
+    fn get_expires_at() -> DateTime<Utc> {
+        Utc::now() + Duration::days(Self::CART_TTL_DAYS)
+    }
+
+    fn parse_currency(currency: &str) -> Result<Currency, Status> {
+        Currency::from_str_name(currency).ok_or(Status::internal(format!(
+            "error parsing currency '{currency}'"
+        )))
+    }
+
+    fn parse_shipping_country(
+        shipping_country: Option<i32>,
+    ) -> Result<Option<ShippingCountry>, Status> {
+        match shipping_country {
+            Some(shipping_country) => {
+                if shipping_country < 1 {
+                    return Err(Status::invalid_argument("shipping_country"));
+                }
+                ShippingCountry::from_i32(shipping_country)
+                    .map(Some)
+                    .ok_or(Status::invalid_argument("shipping_country"))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Anonymous carts can be accessed by anyone knowing their id, carts of
+    /// a user only by that user.
+    fn check_access(
+        cart: &Cart,
+        user_id: Option<&String>,
+    ) -> Result<(), Status> {
+        match &cart.user_id {
+            Some(owner) if Some(owner) != user_id => {
+                Err(Status::not_found("cart"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn get_cart_for_user(
+        &self,
+        cart_id: &Uuid,
+        user_id: Option<&String>,
+    ) -> Result<Cart, Status> {
+        let cart = Cart::get(&self.pool, cart_id)
+            .await?
+            .ok_or_else(|| Status::not_found("cart"))?;
+
+        Self::check_access(&cart, user_id)?;
+
+        Ok(cart)
+    }
+
+    /// Returns the price of the offer or its variant in the currency of the
+    /// cart, used to recalculate amounts when quantities change.
+    async fn get_item_price(
+        &self,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        currency: &str,
+    ) -> Result<OfferPriceAsRel, Status> {
+        let offer = Offer::get(&self.pool, offer_id, None)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        let variant = match offer_variant_id {
+            Some(offer_variant_id) => Some(
+                offer
+                    .variants
+                    .iter()
+                    .find(|v| v.offer_variant_id == *offer_variant_id)
+                    .ok_or_else(|| Status::not_found("offer_variant"))?,
+            ),
+            None => None,
+        };
+
+        offer
+            .get_price(variant, Some(currency))
+            .cloned()
+            .ok_or_else(|| Status::failed_precondition("offer.price"))
+    }
+
+    /// Builds the cart response including totals. Shipping is only added
+    /// once the buyer's country is known.
+    async fn to_response(
+        &self,
+        cart: Cart,
+        shipping_country: Option<ShippingCountry>,
+    ) -> Result<CartResponse, Status> {
+        let cart_items = CartItem::list(&self.pool, &cart.cart_id).await?;
+
+        let currency = match cart_items.first() {
+            Some(cart_item) => Self::parse_currency(&cart_item.currency)?,
+            None => Currency::Unspecified,
+        };
+
+        let mut items = Vec::with_capacity(cart_items.len());
+        let mut subtotal_amount: u64 = 0;
+        for cart_item in cart_items.iter() {
+            subtotal_amount += u64::from(cart_item.amount);
+            items.push(CartItemResponse {
+                cart_item_id: cart_item.cart_item_id.to_string(),
+                offer_id: cart_item.offer_id.to_string(),
+                offer_variant_id: cart_item
+                    .offer_variant_id
+                    .map(|v| v.to_string()),
+                quantity: cart_item.quantity,
+                unit_amount: cart_item.unit_amount,
+                currency: Self::parse_currency(&cart_item.currency)?.into(),
+                amount: cart_item.amount,
+            });
+        }
+
+        let (shipping_amount, unshippable_offer_ids) = match shipping_country {
+            Some(shipping_country) => {
+                let lines: Vec<ShippingLine> = cart_items
+                    .iter()
+                    .map(|i| ShippingLine {
+                        offer_id: i.offer_id,
+                        quantity: i.quantity,
+                        amount: i.amount,
+                    })
+                    .collect();
+                get_shipping_amount(
+                    &self.pool,
+                    &lines,
+                    Some(shipping_country),
+                    currency.as_str_name(),
+                )
+                .await?
+            }
+            None => (0, Vec::new()),
+        };
+
+        // a discount that no longer applies is left out of the totals, it
+        // is reported when the order is created
+        let lines =
+            DiscountService::get_cart_lines(&self.pool, &cart_items).await?;
+        let discount = match DiscountService::resolve(
+            &self.pool,
+            &cart.shop_id,
+            None,
+            cart.discount_id.as_ref(),
+            &lines,
+            currency.as_str_name(),
+            cart.user_id.as_ref(),
+        )
+        .await
+        {
+            Ok(discount) => discount,
+            Err(err)
+                if matches!(
+                    err.code(),
+                    Code::NotFound | Code::FailedPrecondition
+                ) =>
+            {
+                None
+            }
+            Err(err) => return Err(err),
+        };
+        let discount_amount = discount.as_ref().map(|(_, a)| *a).unwrap_or(0);
+
+        let total_amount = u32::try_from(
+            subtotal_amount - u64::from(discount_amount) + shipping_amount,
+        )
+        .map_err(|_| Status::failed_precondition("quantity"))?;
+
+        Ok(CartResponse {
+            cart_id: cart.cart_id.to_string(),
+            shop_id: cart.shop_id.to_string(),
+            user_id: cart.user_id,
+            created_at: cart.created_at.timestamp(),
+            updated_at: cart.updated_at.timestamp(),
+            expires_at: cart.expires_at.timestamp(),
+            items,
+            totals: Some(CartTotalsResponse {
+                currency: currency.into(),
+                subtotal_amount: u32::try_from(subtotal_amount)
+                    .map_err(|_| Status::failed_precondition("quantity"))?,
+                shipping_amount: u32::try_from(shipping_amount)
+                    .map_err(|_| Status::failed_precondition("quantity"))?,
+                total_amount,
+                shipping_country: shipping_country.map(|c| c.into()),
+                unshippable_offer_ids: unshippable_offer_ids
+                    .iter()
+                    .map(|o| o.to_string())
+                    .collect(),
+                discount_amount,
+                discount_id: discount.map(|(d, _)| d.discount_id.to_string()),
+            }),
+        })
+    }
+}
+
+#[async_trait]
+impl cart_service_server::CartService for CartService {
+    async fn create_cart(
+        &self,
+        request: Request<CreateCartRequest>,
+    ) -> Result<Response<CreateCartResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let shop_id = parse_uuid(&request.into_inner().shop_id, "shop_id")?;
+
+        Shop::get(&self.pool, &shop_id, None, false)
+            .await?
+            .ok_or_else(|| Status::not_found("shop"))?;
+
+        Cart::delete_expired(&self.pool, &shop_id).await?;
+
+        let found_cart = match &user_id {
+            Some(user_id) => {
+                Cart::get_for_user(&self.pool, &shop_id, user_id).await?
+            }
+            None => None,
+        };
+
+        let cart = match found_cart {
+            Some(cart) => cart,
+            None => {
+                Cart::create(
+                    &self.pool,
+                    &shop_id,
+                    user_id.as_ref(),
+                    Self::get_expires_at(),
+                )
+                .await?
+            }
+        };
+
+        Ok(Response::new(CreateCartResponse {
+            cart: Some(self.to_response(cart, None).await?),
+        }))
+    }
+
+    async fn get_cart(
+        &self,
+        request: Request<GetCartRequest>,
+    ) -> Result<Response<GetCartResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let GetCartRequest {
+            cart_id,
+            shipping_country,
+        } = request.into_inner();
+
+        let cart_id = parse_uuid(&cart_id, "cart_id")?;
+        let shipping_country = Self::parse_shipping_country(shipping_country)?;
+
+        let cart = self.get_cart_for_user(&cart_id, user_id.as_ref()).await?;
+
+        Ok(Response::new(GetCartResponse {
+            cart: Some(self.to_response(cart, shipping_country).await?),
+        }))
+    }
+
+    async fn add_item_to_cart(
+        &self,
+        request: Request<AddItemToCartRequest>,
+    ) -> Result<Response<AddItemToCartResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let AddItemToCartRequest {
+            cart_id,
+            offer_id,
+            offer_variant_id,
+            quantity,
+            shipping_country,
+            currency,
+        } = request.into_inner();
+
+        let cart_id = parse_uuid(&cart_id, "cart_id")?;
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let offer_variant_id = match offer_variant_id {
+            Some(id) => Some(parse_uuid(&id, "offer_variant_id")?),
+            None => None,
+        };
+        let shipping_country = Self::parse_shipping_country(shipping_country)?;
+        let currency = match currency {
+            Some(currency) => Some(
+                Currency::from_i32(currency)
+                    .filter(|c| *c != Currency::Unspecified)
+                    .ok_or_else(|| Status::invalid_argument("currency"))?
+                    .as_str_name(),
+            ),
+            None => None,
+        };
+
+        if quantity == 0 {
+            return Err(Status::invalid_argument("quantity"));
+        }
+
+        let offer = Offer::get(&self.pool, &offer_id, None)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        if offer.is_sold_out {
+            return Err(Status::failed_precondition("offer.is_sold_out"));
+        }
+
+        let variant = match offer_variant_id {
+            Some(offer_variant_id) => Some(
+                offer
+                    .variants
+                    .iter()
+                    .find(|v| v.offer_variant_id == offer_variant_id)
+                    .filter(|v| v.is_active)
+                    .ok_or_else(|| Status::not_found("offer_variant"))?,
+            ),
+            None => None,
+        };
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let cart = Cart::get_for_update(&transaction, &cart_id)
+            .await?
+            .ok_or_else(|| Status::not_found("cart"))?;
+
+        Self::check_access(&cart, user_id.as_ref())?;
+
+        if cart.shop_id != offer.shop_id {
+            return Err(Status::invalid_argument("offer_id"));
+        }
+
+        let cart_items =
+            CartItem::list_for_update(&transaction, &cart_id).await?;
+
+        // all items of a cart share the currency of the first item
+        let cart_currency = cart_items.first().map(|i| i.currency.as_str());
+        if cart_currency.is_some_and(|c| currency.is_some_and(|r| r != c)) {
+            return Err(Status::failed_precondition("currency"));
+        }
+
+        let price = offer
+            .get_price(variant, cart_currency.or(currency))
+            .ok_or_else(|| Status::failed_precondition("offer.price"))?;
+
+        let found_item = cart_items.iter().find(|i| {
+            i.offer_id == offer_id && i.offer_variant_id == offer_variant_id
+        });
+
+        if let Some(found_item) = found_item {
+            let quantity = found_item
+                .quantity
+                .checked_add(quantity)
+                .ok_or_else(|| Status::invalid_argument("quantity"))?;
+            let (unit_amount, amount) = get_item_amounts(price, quantity)?;
+            CartItem::update_quantity(
+                &transaction,
+                &cart_id,
+                &found_item.cart_item_id,
+                quantity,
+                unit_amount,
+                amount,
+            )
+            .await?;
+        } else {
+            let (unit_amount, amount) = get_item_amounts(price, quantity)?;
+            CartItem::create(
+                &transaction,
+                &cart_id,
+                &offer_id,
+                offer_variant_id.as_ref(),
+                quantity,
+                &price.currency,
+                unit_amount,
+                amount,
+            )
+            .await?;
+        }
+
+        let cart = Cart::update_expires_at(
+            &transaction,
+            &cart_id,
+            Self::get_expires_at(),
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("cart"))?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(AddItemToCartResponse {
+            cart: Some(self.to_response(cart, shipping_country).await?),
+        }))
+    }
+
+    async fn update_cart_item(
+        &self,
+        request: Request<UpdateCartItemRequest>,
+    ) -> Result<Response<UpdateCartItemResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let UpdateCartItemRequest {
+            cart_id,
+            cart_item_id,
+            quantity,
+            shipping_country,
+        } = request.into_inner();
+
+        let cart_id = parse_uuid(&cart_id, "cart_id")?;
+        let cart_item_id = parse_uuid(&cart_item_id, "cart_item_id")?;
+        let shipping_country = Self::parse_shipping_country(shipping_country)?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let cart = Cart::get_for_update(&transaction, &cart_id)
+            .await?
+            .ok_or_else(|| Status::not_found("cart"))?;
+
+        Self::check_access(&cart, user_id.as_ref())?;
+
+        // a quantity of zero removes the item from the cart
+        let found = if quantity == 0 {
+            CartItem::delete(&transaction, &cart_id, &cart_item_id).await?
+        } else {
+            let cart_item = CartItem::list_for_update(&transaction, &cart_id)
+                .await?
+                .into_iter()
+                .find(|i| i.cart_item_id == cart_item_id)
+                .ok_or_else(|| Status::not_found("cart_item"))?;
+
+            let price = self
+                .get_item_price(
+                    &cart_item.offer_id,
+                    cart_item.offer_variant_id.as_ref(),
+                    &cart_item.currency,
+                )
+                .await?;
+            let (unit_amount, amount) = get_item_amounts(&price, quantity)?;
+
+            CartItem::update_quantity(
+                &transaction,
+                &cart_id,
+                &cart_item_id,
+                quantity,
+                unit_amount,
+                amount,
+            )
+            .await?
+            .is_some()
+        };
+
+        if !found {
+            return Err(Status::not_found("cart_item"));
+        }
+
+        let cart = Cart::update_expires_at(
+            &transaction,
+            &cart_id,
+            Self::get_expires_at(),
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("cart"))?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(UpdateCartItemResponse {
+            cart: Some(self.to_response(cart, shipping_country).await?),
+        }))
+    }
+
+    async fn remove_item_from_cart(
+        &self,
+        request: Request<RemoveItemFromCartRequest>,
+    ) -> Result<Response<RemoveItemFromCartResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let RemoveItemFromCartRequest {
+            cart_id,
+            cart_item_id,
+            shipping_country,
+        } = request.into_inner();
+
+        let cart_id = parse_uuid(&cart_id, "cart_id")?;
+        let cart_item_id = parse_uuid(&cart_item_id, "cart_item_id")?;
+        let shipping_country = Self::parse_shipping_country(shipping_country)?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let cart = Cart::get_for_update(&transaction, &cart_id)
+            .await?
+            .ok_or_else(|| Status::not_found("cart"))?;
+
+        Self::check_access(&cart, user_id.as_ref())?;
+
+        if !CartItem::delete(&transaction, &cart_id, &cart_item_id).await? {
+            return Err(Status::not_found("cart_item"));
+        }
+
+        let cart = Cart::update_expires_at(
+            &transaction,
+            &cart_id,
+            Self::get_expires_at(),
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("cart"))?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(RemoveItemFromCartResponse {
+            cart: Some(self.to_response(cart, shipping_country).await?),
+        }))
+    }
+
+    async fn merge_cart(
+        &self,
+        request: Request<MergeCartRequest>,
+    ) -> Result<Response<MergeCartResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let MergeCartRequest {
+            anonymous_cart_id,
+            shipping_country,
+        } = request.into_inner();
+
+        let anonymous_cart_id =
+            parse_uuid(&anonymous_cart_id, "anonymous_cart_id")?;
+        let shipping_country = Self::parse_shipping_country(shipping_country)?;
+
+        let anonymous_cart = Cart::get(&self.pool, &anonymous_cart_id)
+            .await?
+            .ok_or_else(|| Status::not_found("cart"))?;
+
+        if anonymous_cart.user_id.is_some() {
+            return Err(Status::failed_precondition("cart.user_id"));
+        }
+
+        Cart::delete_expired(&self.pool, &anonymous_cart.shop_id).await?;
+
+        let user_cart =
+            Cart::get_for_user(&self.pool, &anonymous_cart.shop_id, &user_id)
+                .await?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let anonymous_cart =
+            Cart::get_for_update(&transaction, &anonymous_cart_id)
+                .await?
+                .ok_or_else(|| Status::not_found("cart"))?;
+
+        if anonymous_cart.user_id.is_some() {
+            return Err(Status::failed_precondition("cart.user_id"));
+        }
+
+        let cart = match user_cart {
+            // without a cart of the user the anonymous cart is taken over
+            None => {
+                Cart::update_user_id(
+                    &transaction,
+                    &anonymous_cart_id,
+                    &user_id,
+                    Self::get_expires_at(),
+                )
+                .await?
+            }
+            // otherwise quantities of items for the same offer are added up
+            // and the remaining items are moved over, dropping those priced
+            // in a different currency than the user's cart
+            Some(user_cart) => {
+                let user_cart =
+                    Cart::get_for_update(&transaction, &user_cart.cart_id)
+                        .await?
+                        .ok_or_else(|| Status::not_found("cart"))?;
+
+                let user_items =
+                    CartItem::list_for_update(&transaction, &user_cart.cart_id)
+                        .await?;
+                let anonymous_items =
+                    CartItem::list_for_update(&transaction, &anonymous_cart_id)
+                        .await?;
+
+                for anonymous_item in anonymous_items {
+                    let found_item = user_items.iter().find(|i| {
+                        i.offer_id == anonymous_item.offer_id
+                            && i.offer_variant_id
+                                == anonymous_item.offer_variant_id
+                    });
+
+                    if let Some(found_item) = found_item {
+                        let quantity = found_item
+                            .quantity
+                            .saturating_add(anonymous_item.quantity);
+                        let price = self
+                            .get_item_price(
+                                &found_item.offer_id,
+                                found_item.offer_variant_id.as_ref(),
+                                &found_item.currency,
+                            )
+                            .await?;
+                        let (unit_amount, amount) =
+                            get_item_amounts(&price, quantity)?;
+                        CartItem::update_quantity(
+                            &transaction,
+                            &user_cart.cart_id,
+                            &found_item.cart_item_id,
+                            quantity,
+                            unit_amount,
+                            amount,
+                        )
+                        .await?;
+                    } else if user_items
+                        .iter()
+                        .all(|i| i.currency == anonymous_item.currency)
+                    {
+                        CartItem::update_cart_id(
+                            &transaction,
+                            &anonymous_item.cart_item_id,
+                            &user_cart.cart_id,
+                        )
+                        .await?;
+                    }
+                }
+
+                Cart::delete(&transaction, &anonymous_cart_id).await?;
+
+                Cart::update_expires_at(
+                    &transaction,
+                    &user_cart.cart_id,
+                    Self::get_expires_at(),
+                )
+                .await?
+                .ok_or_else(|| Status::not_found("cart"))?
+            }
+        };
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(MergeCartResponse {
+            cart: Some(self.to_response(cart, shipping_country).await?),
+        }))
+    }
+
+    async fn delete_cart(
+        &self,
+        request: Request<DeleteCartRequest>,
+    ) -> Result<Response<DeleteCartResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let cart_id = parse_uuid(&request.into_inner().cart_id, "cart_id")?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let cart = Cart::get_for_update(&transaction, &cart_id)
+            .await?
+            .ok_or_else(|| Status::not_found("cart"))?;
+
+        Self::check_access(&cart, user_id.as_ref())?;
+
+        Cart::delete(&transaction, &cart_id).await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(DeleteCartResponse {}))
+    }
+}
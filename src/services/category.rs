@@ -0,0 +1,239 @@
+use deadpool_postgres::Pool;
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::category_service_server::{
+    self, CategoryServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    CategoryResponse, CreateCategoryRequest, CreateCategoryResponse,
+    DeleteCategoryRequest, DeleteCategoryResponse, GetCategoryRequest,
+    GetCategoryResponse, ListCategoriesRequest, ListCategoriesResponse,
+    UpdateCategoryRequest, UpdateCategoryResponse,
+};
+use crate::auth::verify_service_user;
+use crate::model::Category;
+use crate::parse_uuid;
+
+use super::validate_slug;
+
+pub struct CategoryService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+}
+
+impl CategoryService {
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+    ) -> CategoryServiceServer<Self> {
+        CategoryServiceServer::new(Self { pool, verifier })
+    }
+
+    fn to_response(category: Category) -> CategoryResponse {
+        CategoryResponse {
+            category_id: category.category_id.to_string(),
+            parent_id: category.parent_id.map(|p| p.to_string()),
+            ancestor_ids: category
+                .ancestor_ids
+                .iter()
+                .map(Uuid::to_string)
+                .collect(),
+            created_at: category.created_at.timestamp(),
+            updated_at: category.updated_at.timestamp(),
+            name: category.name,
+            slug: category.slug,
+            ordering: category.ordering,
+        }
+    }
+
+    fn validate_name_and_slug(
+        name: Option<&String>,
+        slug: Option<&String>,
+    ) -> Result<(), Status> {
+        if name.is_some_and(|n| n.trim().is_empty()) {
+            return Err(Status::invalid_argument("name"));
+        }
+
+        if let Some(slug) = slug {
+            if slug.is_empty() {
+                return Err(Status::invalid_argument("slug"));
+            }
+            validate_slug(slug)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl category_service_server::CategoryService for CategoryService {
+    async fn create_category(
+        &self,
+        request: Request<CreateCategoryRequest>,
+    ) -> Result<Response<CreateCategoryResponse>, Status> {
+        verify_service_user(request.metadata(), &self.verifier).await?;
+
+        let CreateCategoryRequest {
+            parent_id,
+            name,
+            slug,
+            ordering,
+        } = request.into_inner();
+
+        Self::validate_name_and_slug(Some(&name), Some(&slug))?;
+
+        let parent_id = match parent_id {
+            Some(id) => Some(parse_uuid(&id, "parent_id")?),
+            None => None,
+        };
+
+        if let Some(parent_id) = parent_id.as_ref() {
+            Category::get(&self.pool, parent_id)
+                .await?
+                .ok_or_else(|| Status::not_found("parent"))?;
+        }
+
+        let created_category = Category::create(
+            &self.pool,
+            parent_id.as_ref(),
+            &name,
+            &slug,
+            ordering,
+        )
+        .await?;
+
+        Ok(Response::new(CreateCategoryResponse {
+            category: Some(Self::to_response(created_category)),
+        }))
+    }
+
+    async fn get_category(
+        &self,
+        request: Request<GetCategoryRequest>,
+    ) -> Result<Response<GetCategoryResponse>, Status> {
+        let GetCategoryRequest { category_id, slug } = request.into_inner();
+
+        let found_category = match (category_id, slug) {
+            (Some(category_id), _) => {
+                let category_id = parse_uuid(&category_id, "category_id")?;
+                Category::get(&self.pool, &category_id).await?
+            }
+            (_, Some(slug)) => Category::get_by_slug(&self.pool, &slug).await?,
+            (None, None) => {
+                return Err(Status::invalid_argument(
+                    "provide one of category_id or slug",
+                ))
+            }
+        }
+        .ok_or_else(|| Status::not_found("category"))?;
+
+        Ok(Response::new(GetCategoryResponse {
+            category: Some(Self::to_response(found_category)),
+        }))
+    }
+
+    async fn list_categories(
+        &self,
+        request: Request<ListCategoriesRequest>,
+    ) -> Result<Response<ListCategoriesResponse>, Status> {
+        let ListCategoriesRequest { parent_id } = request.into_inner();
+
+        let parent_id = match parent_id {
+            Some(id) => Some(parse_uuid(&id, "parent_id")?),
+            None => None,
+        };
+
+        let found_categories =
+            Category::list(&self.pool, parent_id.as_ref()).await?;
+
+        Ok(Response::new(ListCategoriesResponse {
+            categories: found_categories
+                .into_iter()
+                .map(Self::to_response)
+                .collect(),
+        }))
+    }
+
+    async fn update_category(
+        &self,
+        request: Request<UpdateCategoryRequest>,
+    ) -> Result<Response<UpdateCategoryResponse>, Status> {
+        verify_service_user(request.metadata(), &self.verifier).await?;
+
+        let UpdateCategoryRequest {
+            category_id,
+            name,
+            slug,
+            ordering,
+            parent_id,
+            is_root,
+        } = request.into_inner();
+
+        let category_id = parse_uuid(&category_id, "category_id")?;
+
+        Self::validate_name_and_slug(name.as_ref(), slug.as_ref())?;
+
+        let parent_id = match (parent_id, is_root) {
+            (Some(_), Some(true)) => {
+                return Err(Status::invalid_argument("parent_id,is_root"))
+            }
+            (Some(id), _) => Some(Some(parse_uuid(&id, "parent_id")?)),
+            (None, Some(true)) => Some(None),
+            (None, _) => None,
+        };
+
+        // a category cannot be moved below itself or its descendants
+        if let Some(Some(parent_id)) = parent_id.as_ref() {
+            let parent = Category::get(&self.pool, parent_id)
+                .await?
+                .ok_or_else(|| Status::not_found("parent"))?;
+            if parent.category_id == category_id
+                || parent.ancestor_ids.contains(&category_id)
+            {
+                return Err(Status::failed_precondition("parent_id"));
+            }
+        }
+
+        let updated_category = if name.is_none()
+            && slug.is_none()
+            && ordering.is_none()
+            && parent_id.is_none()
+        {
+            Category::get(&self.pool, &category_id).await?
+        } else {
+            Category::update(
+                &self.pool,
+                &category_id,
+                name,
+                slug,
+                ordering,
+                parent_id,
+            )
+            .await?
+        }
+        .ok_or_else(|| Status::not_found("category"))?;
+
+        Ok(Response::new(UpdateCategoryResponse {
+            category: Some(Self::to_response(updated_category)),
+        }))
+    }
+
+    async fn delete_category(
+        &self,
+        request: Request<DeleteCategoryRequest>,
+    ) -> Result<Response<DeleteCategoryResponse>, Status> {
+        verify_service_user(request.metadata(), &self.verifier).await?;
+
+        let DeleteCategoryRequest { category_id } = request.into_inner();
+
+        let category_id = parse_uuid(&category_id, "category_id")?;
+
+        Category::delete(&self.pool, &category_id)
+            .await?
+            .ok_or_else(|| Status::not_found("category"))?;
+
+        Ok(Response::new(DeleteCategoryResponse {}))
+    }
+}
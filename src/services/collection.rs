@@ -0,0 +1,477 @@
+use deadpool_postgres::Pool;
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::collection_service_server::{
+    self, CollectionServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    CollectionOfferResponse, CollectionResponse, CollectionRule,
+    CollectionType, CreateCollectionRequest, CreateCollectionResponse,
+    Currency, DeleteCollectionRequest, DeleteCollectionResponse,
+    GetCollectionRequest, GetCollectionResponse, ListCollectionsRequest,
+    ListCollectionsResponse, OfferType, PutOfferToCollectionRequest,
+    PutOfferToCollectionResponse, RemoveOfferFromCollectionRequest,
+    RemoveOfferFromCollectionResponse, UpdateCollectionRequest,
+    UpdateCollectionResponse,
+};
+use crate::auth::get_user_id;
+use crate::model::{
+    Category, Collection, CollectionOffer, CollectionRuleValues, Offer, Shop,
+};
+use crate::parse_uuid;
+
+use super::validate_slug;
+
+pub struct CollectionService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+}
+
+impl CollectionService {
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+    ) -> CollectionServiceServer<Self> {
+        CollectionServiceServer::new(Self { pool, verifier })
+    }
+
+    fn validate_name_and_slug(
+        name: Option<&String>,
+        slug: Option<&String>,
+    ) -> Result<(), Status> {
+        if name.is_some_and(|n| n.trim().is_empty()) {
+            return Err(Status::invalid_argument("name"));
+        }
+
+        if let Some(slug) = slug {
+            if slug.is_empty() {
+                return Err(Status::invalid_argument("slug"));
+            }
+            validate_slug(slug)?;
+        }
+
+        Ok(())
+    }
+
+    fn parse_collection_type(
+        collection_type: i32,
+    ) -> Result<CollectionType, Status> {
+        if collection_type < 1 {
+            return Err(Status::invalid_argument("collection_type"));
+        }
+        CollectionType::from_i32(collection_type)
+            .ok_or(Status::invalid_argument("collection_type"))
+    }
+
+    /// Validates the rule. Price bounds require a currency and the category
+    /// has to exist.
+    async fn parse_rule(
+        &self,
+        rule: CollectionRule,
+    ) -> Result<CollectionRuleValues, Status> {
+        let offer_type = match rule.offer_type {
+            Some(t) if t < 1 => {
+                return Err(Status::invalid_argument("rule.offer_type"))
+            }
+            Some(t) => Some(
+                OfferType::from_i32(t)
+                    .ok_or(Status::invalid_argument("rule.offer_type"))?
+                    .as_str_name()
+                    .to_string(),
+            ),
+            None => None,
+        };
+
+        let category_id = match rule.category_id {
+            Some(id) => {
+                let category_id = parse_uuid(&id, "rule.category_id")?;
+                Category::get(&self.pool, &category_id)
+                    .await?
+                    .ok_or_else(|| Status::not_found("rule.category_id"))?;
+                Some(category_id)
+            }
+            None => None,
+        };
+
+        let currency = match rule.currency {
+            Some(c) if c < 1 => {
+                return Err(Status::invalid_argument("rule.currency"))
+            }
+            Some(c) => Some(
+                Currency::from_i32(c)
+                    .ok_or(Status::invalid_argument("rule.currency"))?
+                    .as_str_name()
+                    .to_string(),
+            ),
+            None => None,
+        };
+
+        if (rule.min_price.is_some() || rule.max_price.is_some())
+            && currency.is_none()
+        {
+            return Err(Status::invalid_argument("rule.currency"));
+        }
+
+        if let (Some(min_price), Some(max_price)) =
+            (rule.min_price, rule.max_price)
+        {
+            if max_price <= min_price {
+                return Err(Status::invalid_argument("rule.max_price"));
+            }
+        }
+
+        Ok(CollectionRuleValues {
+            offer_type,
+            category_id,
+            is_featured: rule.is_featured,
+            currency,
+            min_price: rule.min_price,
+            max_price: rule.max_price,
+        })
+    }
+
+    fn to_response(
+        collection: Collection,
+        offers: &[CollectionOffer],
+    ) -> Result<CollectionResponse, Status> {
+        let collection_type = CollectionType::from_str_name(
+            &collection.collection_type,
+        )
+        .ok_or(Status::internal(format!(
+            "error parsing collection type '{}'",
+            collection.collection_type
+        )))?;
+
+        let rule = if collection_type == CollectionType::Rule {
+            let rule = collection.rule;
+            Some(CollectionRule {
+                offer_type: rule
+                    .offer_type
+                    .and_then(|t| OfferType::from_str_name(&t))
+                    .map(i32::from),
+                category_id: rule.category_id.map(|c| c.to_string()),
+                is_featured: rule.is_featured,
+                currency: rule
+                    .currency
+                    .and_then(|c| Currency::from_str_name(&c))
+                    .map(i32::from),
+                min_price: rule.min_price,
+                max_price: rule.max_price,
+            })
+        } else {
+            None
+        };
+
+        Ok(CollectionResponse {
+            collection_id: collection.collection_id.to_string(),
+            shop_id: collection.shop_id.to_string(),
+            user_id: collection.user_id,
+            created_at: collection.created_at.timestamp(),
+            updated_at: collection.updated_at.timestamp(),
+            name: collection.name,
+            slug: collection.slug,
+            ordering: collection.ordering,
+            collection_type: collection_type.into(),
+            rule,
+            offers: offers
+                .iter()
+                .filter(|o| o.collection_id == collection.collection_id)
+                .map(|o| CollectionOfferResponse {
+                    offer_id: o.offer_id.to_string(),
+                    ordering: o.ordering,
+                })
+                .collect(),
+        })
+    }
+
+    async fn get_response(
+        &self,
+        collection: Collection,
+    ) -> Result<CollectionResponse, Status> {
+        let offers =
+            Collection::list_offers(&self.pool, &[collection.collection_id])
+                .await?;
+
+        Self::to_response(collection, &offers)
+    }
+
+    async fn get_for_user(
+        &self,
+        collection_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Collection, Status> {
+        Collection::get(&self.pool, collection_id)
+            .await?
+            .filter(|c| c.user_id == *user_id)
+            .ok_or_else(|| Status::not_found("collection"))
+    }
+
+    /// Returns the manual collection of the user. Offers of rule-based
+    /// collections are given by their rule.
+    async fn get_manual_for_user(
+        &self,
+        collection_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Collection, Status> {
+        let collection = self.get_for_user(collection_id, user_id).await?;
+
+        if collection.collection_type != CollectionType::Manual.as_str_name() {
+            return Err(Status::failed_precondition("collection_type"));
+        }
+
+        Ok(collection)
+    }
+}
+
+#[async_trait]
+impl collection_service_server::CollectionService for CollectionService {
+    async fn create_collection(
+        &self,
+        request: Request<CreateCollectionRequest>,
+    ) -> Result<Response<CreateCollectionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let CreateCollectionRequest {
+            shop_id,
+            name,
+            slug,
+            ordering,
+            collection_type,
+            rule,
+        } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        Self::validate_name_and_slug(Some(&name), Some(&slug))?;
+
+        let collection_type = Self::parse_collection_type(collection_type)?;
+
+        let rule = match (collection_type, rule) {
+            (CollectionType::Rule, Some(rule)) => self.parse_rule(rule).await?,
+            (CollectionType::Manual, None) => Default::default(),
+            _ => return Err(Status::invalid_argument("rule")),
+        };
+
+        Shop::get(&self.pool, &shop_id, Some(&user_id), false)
+            .await?
+            .filter(|s| s.user_id == user_id)
+            .ok_or_else(|| Status::not_found("shop"))?;
+
+        let created_collection = Collection::create(
+            &self.pool,
+            &shop_id,
+            &user_id,
+            &name,
+            &slug,
+            ordering,
+            collection_type.as_str_name(),
+            &rule,
+        )
+        .await?;
+
+        Ok(Response::new(CreateCollectionResponse {
+            collection: Some(Self::to_response(created_collection, &[])?),
+        }))
+    }
+
+    async fn get_collection(
+        &self,
+        request: Request<GetCollectionRequest>,
+    ) -> Result<Response<GetCollectionResponse>, Status> {
+        let GetCollectionRequest {
+            collection_id,
+            shop_id,
+            slug,
+        } = request.into_inner();
+
+        let found_collection = match (collection_id, shop_id, slug) {
+            (Some(collection_id), _, _) => {
+                let collection_id =
+                    parse_uuid(&collection_id, "collection_id")?;
+                Collection::get(&self.pool, &collection_id).await?
+            }
+            (_, Some(shop_id), Some(slug)) => {
+                let shop_id = parse_uuid(&shop_id, "shop_id")?;
+                Collection::get_by_slug(&self.pool, &shop_id, &slug).await?
+            }
+            _ => {
+                return Err(Status::invalid_argument(
+                    "provide one of collection_id or shop_id and slug",
+                ))
+            }
+        }
+        .ok_or_else(|| Status::not_found("collection"))?;
+
+        Ok(Response::new(GetCollectionResponse {
+            collection: Some(self.get_response(found_collection).await?),
+        }))
+    }
+
+    async fn list_collections(
+        &self,
+        request: Request<ListCollectionsRequest>,
+    ) -> Result<Response<ListCollectionsResponse>, Status> {
+        let ListCollectionsRequest { shop_id } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        let found_collections = Collection::list(&self.pool, &shop_id).await?;
+
+        let offers = if found_collections.is_empty() {
+            Vec::new()
+        } else {
+            Collection::list_offers(
+                &self.pool,
+                &found_collections
+                    .iter()
+                    .map(|c| c.collection_id)
+                    .collect::<Vec<_>>(),
+            )
+            .await?
+        };
+
+        let mut collections = Vec::with_capacity(found_collections.len());
+        for collection in found_collections {
+            collections.push(Self::to_response(collection, &offers)?);
+        }
+
+        Ok(Response::new(ListCollectionsResponse { collections }))
+    }
+
+    async fn update_collection(
+        &self,
+        request: Request<UpdateCollectionRequest>,
+    ) -> Result<Response<UpdateCollectionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let UpdateCollectionRequest {
+            collection_id,
+            name,
+            slug,
+            ordering,
+            rule,
+        } = request.into_inner();
+
+        let collection_id = parse_uuid(&collection_id, "collection_id")?;
+
+        Self::validate_name_and_slug(name.as_ref(), slug.as_ref())?;
+
+        let found_collection =
+            self.get_for_user(&collection_id, &user_id).await?;
+
+        let rule = match rule {
+            Some(rule) => {
+                if found_collection.collection_type
+                    != CollectionType::Rule.as_str_name()
+                {
+                    return Err(Status::failed_precondition("collection_type"));
+                }
+                Some(self.parse_rule(rule).await?)
+            }
+            None => None,
+        };
+
+        let updated_collection = if name.is_none()
+            && slug.is_none()
+            && ordering.is_none()
+            && rule.is_none()
+        {
+            found_collection
+        } else {
+            Collection::update(
+                &self.pool,
+                &collection_id,
+                &user_id,
+                name,
+                slug,
+                ordering,
+                rule.as_ref(),
+            )
+            .await?
+            .ok_or_else(|| Status::not_found("collection"))?
+        };
+
+        Ok(Response::new(UpdateCollectionResponse {
+            collection: Some(self.get_response(updated_collection).await?),
+        }))
+    }
+
+    async fn delete_collection(
+        &self,
+        request: Request<DeleteCollectionRequest>,
+    ) -> Result<Response<DeleteCollectionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let DeleteCollectionRequest { collection_id } = request.into_inner();
+
+        let collection_id = parse_uuid(&collection_id, "collection_id")?;
+
+        Collection::delete(&self.pool, &collection_id, &user_id)
+            .await?
+            .ok_or_else(|| Status::not_found("collection"))?;
+
+        Ok(Response::new(DeleteCollectionResponse {}))
+    }
+
+    async fn put_offer_to_collection(
+        &self,
+        request: Request<PutOfferToCollectionRequest>,
+    ) -> Result<Response<PutOfferToCollectionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutOfferToCollectionRequest {
+            collection_id,
+            offer_id,
+            ordering,
+        } = request.into_inner();
+
+        let collection_id = parse_uuid(&collection_id, "collection_id")?;
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+
+        let collection =
+            self.get_manual_for_user(&collection_id, &user_id).await?;
+
+        // collections contain offers of their own shop only
+        Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .filter(|o| o.shop_id == collection.shop_id)
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        Collection::put_offer(&self.pool, &collection_id, &offer_id, ordering)
+            .await?;
+
+        Ok(Response::new(PutOfferToCollectionResponse {
+            collection: Some(self.get_response(collection).await?),
+        }))
+    }
+
+    async fn remove_offer_from_collection(
+        &self,
+        request: Request<RemoveOfferFromCollectionRequest>,
+    ) -> Result<Response<RemoveOfferFromCollectionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let RemoveOfferFromCollectionRequest {
+            collection_id,
+            offer_id,
+        } = request.into_inner();
+
+        let collection_id = parse_uuid(&collection_id, "collection_id")?;
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+
+        let collection =
+            self.get_manual_for_user(&collection_id, &user_id).await?;
+
+        if !Collection::remove_offer(&self.pool, &collection_id, &offer_id)
+            .await?
+        {
+            return Err(Status::not_found("offer"));
+        }
+
+        Ok(Response::new(RemoveOfferFromCollectionResponse {
+            collection: Some(self.get_response(collection).await?),
+        }))
+    }
+}
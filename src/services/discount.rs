@@ -0,0 +1,834 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeZone, Utc};
+use deadpool_postgres::{Pool, Transaction};
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::discount_service_server::{
+    self, DiscountServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    ApplyDiscountRequest, ApplyDiscountResponse, CreateDiscountRequest,
+    CreateDiscountResponse, Currency, DeleteDiscountRequest,
+    DeleteDiscountResponse, DiscountResponse, DiscountScope, DiscountType,
+    GetDiscountRequest, GetDiscountResponse, ListDiscountsRequest,
+    ListDiscountsResponse, OfferType, UpdateDiscountRequest,
+    UpdateDiscountResponse, ValidateDiscountRequest, ValidateDiscountResponse,
+};
+use crate::auth::get_user_id;
+use crate::db::DbError;
+use crate::model::{
+    Cart, CartItem, Discount, DiscountRedemption, DiscountValues, Offer, Shop,
+};
+use crate::parse_uuid;
+
+use super::get_limit_offset_from_pagination;
+
+/// Offer ids and offer types a discount is limited to.
+type Scope = (Vec<Uuid>, Vec<&'static str>);
+
+pub struct DiscountService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+}
+
+/// Line of a cart or order a discount is calculated on.
+pub(super) struct DiscountLine {
+    pub offer_id: Uuid,
+    pub offer_type: Option<String>,
+    pub amount: u32,
+}
+
+impl DiscountService {
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+    ) -> DiscountServiceServer<Self> {
+        DiscountServiceServer::new(Self { pool, verifier })
+    }
+
+    fn parse_timestamp(
+        timestamp: Option<i64>,
+        field: &str,
+    ) -> Result<Option<DateTime<Utc>>, Status> {
+        match timestamp {
+            Some(timestamp) => Utc
+                .timestamp_opt(timestamp, 0)
+                .single()
+                .map(Some)
+                .ok_or_else(|| Status::invalid_argument(field)),
+            None => Ok(None),
+        }
+    }
+
+    fn parse_scope(
+        scope: Option<DiscountScope>,
+    ) -> Result<Option<Scope>, Status> {
+        let Some(scope) = scope else {
+            return Ok(None);
+        };
+
+        let mut offer_ids = Vec::with_capacity(scope.offer_ids.len());
+        for offer_id in scope.offer_ids.iter() {
+            offer_ids.push(parse_uuid(offer_id, "scope.offer_ids")?);
+        }
+        offer_ids.sort();
+        offer_ids.dedup();
+
+        let mut offer_types = Vec::with_capacity(scope.offer_types.len());
+        for offer_type in scope.offer_types {
+            if offer_type < 1 {
+                return Err(Status::invalid_argument("scope.offer_types"));
+            }
+            offer_types.push(
+                OfferType::from_i32(offer_type)
+                    .ok_or(Status::invalid_argument("scope.offer_types"))?
+                    .as_str_name(),
+            );
+        }
+        offer_types.sort();
+        offer_types.dedup();
+
+        Ok(Some((offer_ids, offer_types)))
+    }
+
+    /// Percentage discounts need a percent between 1 and 100, fixed amount
+    /// discounts an amount and its currency. Codes must not be empty, as
+    /// discounts without a code are applied automatically.
+    fn validate_values(
+        discount_type: DiscountType,
+        currency: Option<Currency>,
+        values: &DiscountValues,
+    ) -> Result<(), Status> {
+        match discount_type {
+            DiscountType::Unspecified => {
+                return Err(Status::invalid_argument("type"))
+            }
+            DiscountType::Percentage => {
+                if !values.percent.is_some_and(|p| (1..=100).contains(&p)) {
+                    return Err(Status::invalid_argument("percent"));
+                }
+                if values.amount.is_some() {
+                    return Err(Status::invalid_argument("amount"));
+                }
+                if currency.is_some() {
+                    return Err(Status::invalid_argument("currency"));
+                }
+            }
+            DiscountType::FixedAmount => {
+                if values.amount.is_none_or(|a| a == 0) {
+                    return Err(Status::invalid_argument("amount"));
+                }
+                if values.percent.is_some() {
+                    return Err(Status::invalid_argument("percent"));
+                }
+                if currency.is_none() {
+                    return Err(Status::invalid_argument("currency"));
+                }
+            }
+        }
+
+        if values.code.as_ref().is_some_and(|c| c.trim().is_empty()) {
+            return Err(Status::invalid_argument("code"));
+        }
+
+        if let (Some(starts_at), Some(ends_at)) =
+            (values.starts_at, values.ends_at)
+        {
+            if starts_at >= ends_at {
+                return Err(Status::invalid_argument("ends_at"));
+            }
+        }
+
+        if values.max_redemptions == Some(0) {
+            return Err(Status::invalid_argument("max_redemptions"));
+        }
+
+        if values.max_redemptions_per_user == Some(0) {
+            return Err(Status::invalid_argument("max_redemptions_per_user"));
+        }
+
+        Ok(())
+    }
+
+    fn to_response(discount: Discount) -> Result<DiscountResponse, Status> {
+        let mut offer_types = Vec::with_capacity(discount.offer_types.len());
+        for offer_type in discount.offer_types.iter() {
+            offer_types.push(
+                OfferType::from_str_name(offer_type)
+                    .ok_or(Status::internal(""))?
+                    .into(),
+            );
+        }
+
+        Ok(DiscountResponse {
+            discount_id: discount.discount_id.to_string(),
+            shop_id: discount.shop_id.to_string(),
+            user_id: discount.user_id,
+            created_at: discount.created_at.timestamp(),
+            updated_at: discount.updated_at.timestamp(),
+            code: discount.code,
+            r#type: DiscountType::from_str_name(&discount.discount_type)
+                .ok_or(Status::internal(""))?
+                .into(),
+            percent: discount.percent,
+            amount: discount.amount,
+            currency: match discount.currency {
+                Some(currency) => Some(
+                    Currency::from_str_name(&currency)
+                        .ok_or(Status::internal(""))?
+                        .into(),
+                ),
+                None => None,
+            },
+            starts_at: discount.starts_at.map(|s| s.timestamp()),
+            ends_at: discount.ends_at.map(|e| e.timestamp()),
+            max_redemptions: discount.max_redemptions,
+            max_redemptions_per_user: discount.max_redemptions_per_user,
+            minimum_order_amount: discount.minimum_order_amount,
+            scope: Some(DiscountScope {
+                offer_ids: discount
+                    .offer_ids
+                    .iter()
+                    .map(|o| o.to_string())
+                    .collect(),
+                offer_types,
+            }),
+            redemption_count: discount.redemption_count,
+            is_active: discount.is_active,
+        })
+    }
+
+    /// Builds the lines of the cart items, taking offer types from the
+    /// offers.
+    pub(super) async fn get_cart_lines(
+        pool: &Pool,
+        cart_items: &[CartItem],
+    ) -> Result<Vec<DiscountLine>, Status> {
+        let mut offer_types: HashMap<Uuid, Option<String>> = HashMap::new();
+        let mut lines = Vec::with_capacity(cart_items.len());
+
+        for cart_item in cart_items {
+            if let Entry::Vacant(entry) = offer_types.entry(cart_item.offer_id)
+            {
+                let offer = Offer::get(pool, &cart_item.offer_id, None)
+                    .await?
+                    .ok_or_else(|| Status::not_found("offer"))?;
+                entry.insert(offer.type_);
+            }
+
+            lines.push(DiscountLine {
+                offer_id: cart_item.offer_id,
+                offer_type: offer_types[&cart_item.offer_id].clone(),
+                amount: cart_item.amount,
+            });
+        }
+
+        Ok(lines)
+    }
+
+    /// Checks that the discount can be used for the lines and returns the
+    /// discount amount. The minimum order amount is compared against the
+    /// sum of all lines, the discount itself only applies to the lines in
+    /// its scope. Redemptions per user are only checked if the user is
+    /// known.
+    pub(super) async fn check(
+        pool: &Pool,
+        discount: &Discount,
+        lines: &[DiscountLine],
+        currency: &str,
+        user_id: Option<&String>,
+    ) -> Result<u32, Status> {
+        if !discount.is_active {
+            return Err(Status::not_found("discount"));
+        }
+
+        if lines.is_empty() {
+            return Err(Status::failed_precondition("cart.items"));
+        }
+
+        let now = Utc::now();
+        if discount.starts_at.is_some_and(|s| now < s) {
+            return Err(Status::failed_precondition("discount.starts_at"));
+        }
+        if discount.ends_at.is_some_and(|e| now >= e) {
+            return Err(Status::failed_precondition("discount.ends_at"));
+        }
+
+        if discount
+            .max_redemptions
+            .is_some_and(|m| discount.redemption_count >= m)
+        {
+            return Err(Status::failed_precondition(
+                "discount.max_redemptions",
+            ));
+        }
+
+        if discount.currency.as_ref().is_some_and(|c| c != currency) {
+            return Err(Status::failed_precondition("discount.currency"));
+        }
+
+        let subtotal_amount: u64 =
+            lines.iter().map(|l| u64::from(l.amount)).sum();
+        if discount
+            .minimum_order_amount
+            .is_some_and(|m| subtotal_amount < u64::from(m))
+        {
+            return Err(Status::failed_precondition(
+                "discount.minimum_order_amount",
+            ));
+        }
+
+        let eligible_amount: u64 = lines
+            .iter()
+            .filter(|l| {
+                discount.applies_to(&l.offer_id, l.offer_type.as_deref())
+            })
+            .map(|l| u64::from(l.amount))
+            .sum();
+        if eligible_amount == 0 {
+            return Err(Status::failed_precondition("discount.offer_ids"));
+        }
+
+        if let (Some(max), Some(user_id)) =
+            (discount.max_redemptions_per_user, user_id)
+        {
+            let count = DiscountRedemption::count_for_user(
+                pool,
+                &discount.discount_id,
+                user_id,
+            )
+            .await?;
+            if count >= i64::from(max) {
+                return Err(Status::failed_precondition(
+                    "discount.max_redemptions_per_user",
+                ));
+            }
+        }
+
+        u32::try_from(discount.calculate_amount(eligible_amount))
+            .map_err(|_| Status::failed_precondition("quantity"))
+    }
+
+    /// Returns the automatic discount of the shop with the highest amount
+    /// for the lines. Automatic discounts that cannot be used are skipped.
+    pub(super) async fn get_best_automatic(
+        pool: &Pool,
+        shop_id: &Uuid,
+        lines: &[DiscountLine],
+        currency: &str,
+        user_id: Option<&String>,
+    ) -> Result<Option<(Discount, u32)>, Status> {
+        let mut best: Option<(Discount, u32)> = None;
+
+        for discount in Discount::list_automatic(pool, shop_id).await? {
+            let Ok(amount) =
+                Self::check(pool, &discount, lines, currency, user_id).await
+            else {
+                continue;
+            };
+
+            if best.as_ref().is_none_or(|(_, a)| amount > *a) {
+                best = Some((discount, amount));
+            }
+        }
+
+        Ok(best)
+    }
+
+    /// Returns the discount of the shop for the entered code, the discount
+    /// previously applied to the cart, or the best automatic discount, in
+    /// that order. Entered and applied discounts must be valid.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn resolve(
+        pool: &Pool,
+        shop_id: &Uuid,
+        code: Option<&String>,
+        discount_id: Option<&Uuid>,
+        lines: &[DiscountLine],
+        currency: &str,
+        user_id: Option<&String>,
+    ) -> Result<Option<(Discount, u32)>, Status> {
+        let discount = match (code, discount_id) {
+            (Some(code), _) => Some(
+                Discount::get_by_code(pool, shop_id, code)
+                    .await?
+                    .ok_or_else(|| Status::not_found("discount"))?,
+            ),
+            (None, Some(discount_id)) => Some(
+                Discount::get(pool, discount_id)
+                    .await?
+                    .filter(|d| d.shop_id == *shop_id)
+                    .ok_or_else(|| Status::not_found("discount"))?,
+            ),
+            (None, None) => None,
+        };
+
+        match discount {
+            Some(discount) => {
+                let amount =
+                    Self::check(pool, &discount, lines, currency, user_id)
+                        .await?;
+                Ok(Some((discount, amount)))
+            }
+            None => {
+                Self::get_best_automatic(
+                    pool, shop_id, lines, currency, user_id,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Counts the redemption of the discount for the order. Both limits are
+    /// checked again while the discount row is locked, so concurrent orders
+    /// cannot redeem it more often than allowed.
+    pub(super) async fn redeem<'a>(
+        transaction: &Transaction<'a>,
+        discount: &Discount,
+        order_id: &Uuid,
+        user_id: &String,
+        amount: u32,
+    ) -> Result<(), Status> {
+        if !Discount::increment_redemption_count(
+            transaction,
+            &discount.discount_id,
+        )
+        .await?
+        {
+            return Err(Status::failed_precondition(
+                "discount.max_redemptions",
+            ));
+        }
+
+        if let Some(max) = discount.max_redemptions_per_user {
+            let count = DiscountRedemption::count_for_user_in_transaction(
+                transaction,
+                &discount.discount_id,
+                user_id,
+            )
+            .await?;
+            if count >= i64::from(max) {
+                return Err(Status::failed_precondition(
+                    "discount.max_redemptions_per_user",
+                ));
+            }
+        }
+
+        DiscountRedemption::create(
+            transaction,
+            &discount.discount_id,
+            order_id,
+            user_id,
+            amount,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Makes the discounts redeemed by the order available again.
+    pub(super) async fn release<'a>(
+        transaction: &Transaction<'a>,
+        order_id: &Uuid,
+    ) -> Result<(), Status> {
+        let redemptions =
+            DiscountRedemption::delete_for_order(transaction, order_id).await?;
+
+        for redemption in redemptions {
+            Discount::decrement_redemption_count(
+                transaction,
+                &redemption.discount_id,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the unexpired cart if the user may access it, see
+    /// `CartService`.
+    async fn get_cart_for_user(
+        &self,
+        cart_id: &Uuid,
+        user_id: Option<&String>,
+    ) -> Result<Cart, Status> {
+        Cart::get(&self.pool, cart_id)
+            .await?
+            .filter(|c| c.user_id.is_none() || c.user_id.as_ref() == user_id)
+            .ok_or_else(|| Status::not_found("cart"))
+    }
+
+    async fn get_for_owner(
+        &self,
+        discount_id: &Uuid,
+        user_id: &String,
+    ) -> Result<Discount, Status> {
+        Discount::get(&self.pool, discount_id)
+            .await?
+            .filter(|d| d.user_id == *user_id)
+            .ok_or_else(|| Status::not_found("discount"))
+    }
+}
+
+#[async_trait]
+impl discount_service_server::DiscountService for DiscountService {
+    async fn create_discount(
+        &self,
+        request: Request<CreateDiscountRequest>,
+    ) -> Result<Response<CreateDiscountResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let CreateDiscountRequest {
+            shop_id,
+            code,
+            r#type,
+            percent,
+            amount,
+            currency,
+            starts_at,
+            ends_at,
+            max_redemptions,
+            max_redemptions_per_user,
+            minimum_order_amount,
+            scope,
+        } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        if r#type < 1 {
+            return Err(Status::invalid_argument("type"));
+        }
+        let discount_type = DiscountType::from_i32(r#type)
+            .ok_or(Status::invalid_argument("type"))?;
+
+        let currency = match currency {
+            Some(currency) => Some(
+                Currency::from_i32(currency)
+                    .filter(|c| *c != Currency::Unspecified)
+                    .ok_or_else(|| Status::invalid_argument("currency"))?,
+            ),
+            None => None,
+        };
+
+        let values = DiscountValues {
+            code,
+            percent,
+            amount,
+            starts_at: Self::parse_timestamp(starts_at, "starts_at")?,
+            ends_at: Self::parse_timestamp(ends_at, "ends_at")?,
+            max_redemptions,
+            max_redemptions_per_user,
+            minimum_order_amount,
+        };
+
+        Self::validate_values(discount_type, currency, &values)?;
+
+        let (offer_ids, offer_types) =
+            Self::parse_scope(scope)?.unwrap_or_default();
+
+        Shop::get(&self.pool, &shop_id, Some(&user_id), false)
+            .await?
+            .filter(|s| s.user_id == user_id)
+            .ok_or_else(|| Status::not_found("shop"))?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let discount_id = Discount::create(
+            &transaction,
+            &shop_id,
+            &user_id,
+            discount_type.as_str_name(),
+            currency.map(|c| c.as_str_name()),
+            &values,
+        )
+        .await?;
+
+        Discount::put_scope(
+            &transaction,
+            &discount_id,
+            &offer_ids,
+            &offer_types,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        let discount = Discount::get(&self.pool, &discount_id)
+            .await?
+            .ok_or_else(|| Status::not_found("discount"))?;
+
+        Ok(Response::new(CreateDiscountResponse {
+            discount: Some(Self::to_response(discount)?),
+        }))
+    }
+
+    async fn get_discount(
+        &self,
+        request: Request<GetDiscountRequest>,
+    ) -> Result<Response<GetDiscountResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let discount_id =
+            parse_uuid(&request.into_inner().discount_id, "discount_id")?;
+
+        let discount = self.get_for_owner(&discount_id, &user_id).await?;
+
+        Ok(Response::new(GetDiscountResponse {
+            discount: Some(Self::to_response(discount)?),
+        }))
+    }
+
+    async fn list_discounts(
+        &self,
+        request: Request<ListDiscountsRequest>,
+    ) -> Result<Response<ListDiscountsResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let ListDiscountsRequest {
+            shop_id,
+            pagination,
+        } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        let (limit, offset, mut pagination) =
+            get_limit_offset_from_pagination(pagination)?;
+
+        let (discounts, count) = Discount::list(
+            &self.pool,
+            &shop_id,
+            &user_id,
+            limit.into(),
+            offset.into(),
+        )
+        .await?;
+
+        pagination.total_elements =
+            count.try_into().map_err(|_| Status::internal(""))?;
+
+        let mut discount_responses = Vec::with_capacity(discounts.len());
+        for discount in discounts {
+            discount_responses.push(Self::to_response(discount)?);
+        }
+
+        Ok(Response::new(ListDiscountsResponse {
+            discounts: discount_responses,
+            pagination: Some(pagination),
+        }))
+    }
+
+    async fn update_discount(
+        &self,
+        request: Request<UpdateDiscountRequest>,
+    ) -> Result<Response<UpdateDiscountResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let UpdateDiscountRequest {
+            discount_id,
+            code,
+            percent,
+            amount,
+            starts_at,
+            ends_at,
+            max_redemptions,
+            max_redemptions_per_user,
+            minimum_order_amount,
+            scope,
+            is_active,
+        } = request.into_inner();
+
+        let discount_id = parse_uuid(&discount_id, "discount_id")?;
+        let scope = Self::parse_scope(scope)?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let discount =
+            Discount::get_for_user(&transaction, &discount_id, &user_id)
+                .await?
+                .ok_or_else(|| Status::not_found("discount"))?;
+
+        // only given fields are changed, type and currency stay as created
+        let values = DiscountValues {
+            code: code.or(discount.code),
+            percent: percent.or(discount.percent),
+            amount: amount.or(discount.amount),
+            starts_at: Self::parse_timestamp(starts_at, "starts_at")?
+                .or(discount.starts_at),
+            ends_at: Self::parse_timestamp(ends_at, "ends_at")?
+                .or(discount.ends_at),
+            max_redemptions: max_redemptions.or(discount.max_redemptions),
+            max_redemptions_per_user: max_redemptions_per_user
+                .or(discount.max_redemptions_per_user),
+            minimum_order_amount: minimum_order_amount
+                .or(discount.minimum_order_amount),
+        };
+
+        let discount_type =
+            DiscountType::from_str_name(&discount.discount_type)
+                .ok_or(Status::internal(""))?;
+        let currency = match &discount.currency {
+            Some(currency) => Some(
+                Currency::from_str_name(currency)
+                    .ok_or(Status::internal(""))?,
+            ),
+            None => None,
+        };
+
+        Self::validate_values(discount_type, currency, &values)?;
+
+        Discount::update(
+            &transaction,
+            &discount_id,
+            &user_id,
+            &values,
+            is_active.unwrap_or(discount.is_active),
+        )
+        .await?;
+
+        if let Some((offer_ids, offer_types)) = scope {
+            Discount::put_scope(
+                &transaction,
+                &discount_id,
+                &offer_ids,
+                &offer_types,
+            )
+            .await?;
+        }
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        let discount = self.get_for_owner(&discount_id, &user_id).await?;
+
+        Ok(Response::new(UpdateDiscountResponse {
+            discount: Some(Self::to_response(discount)?),
+        }))
+    }
+
+    async fn delete_discount(
+        &self,
+        request: Request<DeleteDiscountRequest>,
+    ) -> Result<Response<DeleteDiscountResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let discount_id =
+            parse_uuid(&request.into_inner().discount_id, "discount_id")?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        Discount::delete(&transaction, &discount_id, &user_id).await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(DeleteDiscountResponse {}))
+    }
+
+    async fn validate_discount(
+        &self,
+        request: Request<ValidateDiscountRequest>,
+    ) -> Result<Response<ValidateDiscountResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let ValidateDiscountRequest { cart_id, code } = request.into_inner();
+
+        let cart_id = parse_uuid(&cart_id, "cart_id")?;
+
+        let cart = self.get_cart_for_user(&cart_id, user_id.as_ref()).await?;
+
+        let cart_items = CartItem::list(&self.pool, &cart_id).await?;
+        let lines = Self::get_cart_lines(&self.pool, &cart_items).await?;
+        let currency = cart_items
+            .first()
+            .map(|i| i.currency.clone())
+            .unwrap_or_default();
+
+        let (discount, discount_amount) = Self::resolve(
+            &self.pool,
+            &cart.shop_id,
+            code.as_ref(),
+            None,
+            &lines,
+            &currency,
+            user_id.as_ref(),
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("discount"))?;
+
+        Ok(Response::new(ValidateDiscountResponse {
+            discount: Some(Self::to_response(discount)?),
+            discount_amount,
+        }))
+    }
+
+    async fn apply_discount(
+        &self,
+        request: Request<ApplyDiscountRequest>,
+    ) -> Result<Response<ApplyDiscountResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let ApplyDiscountRequest { cart_id, code } = request.into_inner();
+
+        let cart_id = parse_uuid(&cart_id, "cart_id")?;
+
+        let cart = self.get_cart_for_user(&cart_id, user_id.as_ref()).await?;
+
+        let cart_items = CartItem::list(&self.pool, &cart_id).await?;
+        let lines = Self::get_cart_lines(&self.pool, &cart_items).await?;
+        let currency = cart_items
+            .first()
+            .map(|i| i.currency.clone())
+            .unwrap_or_default();
+
+        // without a code the entered discount is removed from the cart and
+        // the best automatic discount applies again
+        let resolved = Self::resolve(
+            &self.pool,
+            &cart.shop_id,
+            code.as_ref(),
+            None,
+            &lines,
+            &currency,
+            user_id.as_ref(),
+        )
+        .await?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        Cart::get_for_update(&transaction, &cart_id)
+            .await?
+            .ok_or_else(|| Status::not_found("cart"))?;
+
+        Cart::update_discount_id(
+            &transaction,
+            &cart_id,
+            resolved
+                .as_ref()
+                .filter(|_| code.is_some())
+                .map(|(d, _)| &d.discount_id),
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        let (discount, discount_amount) = match resolved {
+            Some((discount, amount)) => {
+                (Some(Self::to_response(discount)?), amount)
+            }
+            None => (None, 0),
+        };
+
+        Ok(Response::new(ApplyDiscountResponse {
+            discount,
+            discount_amount,
+        }))
+    }
+}
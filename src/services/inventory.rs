@@ -0,0 +1,445 @@
+use chrono::{Duration, Utc};
+use deadpool_postgres::Pool;
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::inventory_service_server::{
+    self, InventoryServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    CommitInventoryReservationRequest, CommitInventoryReservationResponse,
+    DeleteInventoryRequest, DeleteInventoryResponse, GetInventoryRequest,
+    GetInventoryResponse, InventoryPolicy, InventoryReservationResponse,
+    InventoryReservationStatus, InventoryResponse, ListInventoriesRequest,
+    ListInventoriesResponse, PutInventoryRequest, PutInventoryResponse,
+    ReleaseInventoryReservationRequest, ReleaseInventoryReservationResponse,
+    ReserveInventoryRequest, ReserveInventoryResponse,
+};
+use crate::auth::get_user_id;
+use crate::db::DbError;
+use crate::images::ImageService;
+use crate::model::{Inventory, InventoryReservation, Offer};
+use crate::{parse_uuid, OfferService, Publisher};
+
+pub struct InventoryService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+    publisher: Publisher,
+    image_service: ImageService,
+}
+
+impl InventoryService {
+    const DEFAULT_RESERVATION_TTL_SECONDS: u32 = 15 * 60;
+    const MAX_RESERVATION_TTL_SECONDS: u32 = 30 * 60;
+    const MAX_RESERVATION_QUANTITY: u32 = 100;
+    const MAX_ACTIVE_RESERVATIONS_PER_USER: i64 = 20;
+
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+        publisher: Publisher,
+        image_service: ImageService,
+    ) -> InventoryServiceServer<Self> {
+        InventoryServiceServer::new(Self {
+            pool,
+            verifier,
+            publisher,
+            image_service,
+        })
+    }
+
+    /// Publishes the offer, as changes in stock can flip whether it is sold
+    /// out.
+    async fn publish_offer(&self, offer_id: &Uuid) {
+        OfferService::publish_offer(
+            &self.pool,
+            &self.publisher,
+            &self.image_service,
+            offer_id,
+        )
+        .await
+    }
+
+    fn to_response(inventory: Inventory) -> InventoryResponse {
+        InventoryResponse {
+            inventory_id: inventory.inventory_id.to_string(),
+            offer_id: inventory.offer_id.to_string(),
+            offer_variant_id: inventory.offer_variant_id.map(|v| v.to_string()),
+            user_id: inventory.user_id.clone(),
+            on_hand: inventory.on_hand,
+            reserved: inventory.reserved,
+            available: inventory.available(),
+        }
+    }
+
+    fn reservation_to_response(
+        reservation: InventoryReservation,
+    ) -> Result<InventoryReservationResponse, Status> {
+        Ok(InventoryReservationResponse {
+            inventory_reservation_id: reservation
+                .inventory_reservation_id
+                .to_string(),
+            inventory_id: reservation.inventory_id.to_string(),
+            offer_id: reservation.offer_id.to_string(),
+            offer_variant_id: reservation
+                .offer_variant_id
+                .map(|v| v.to_string()),
+            quantity: reservation.quantity,
+            status: InventoryReservationStatus::from_str_name(
+                &reservation.status,
+            )
+            .ok_or(Status::internal(""))?
+            .into(),
+            expires_at: reservation.expires_at.timestamp(),
+        })
+    }
+
+    fn parse_offer_variant_id(
+        offer_variant_id: Option<String>,
+    ) -> Result<Option<Uuid>, Status> {
+        match offer_variant_id {
+            Some(id) => Ok(Some(parse_uuid(&id, "offer_variant_id")?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Checks that the reservation is still reserved and, unless
+    /// `allow_expired` is set, has not expired yet.
+    fn validate_reservation(
+        reservation: &InventoryReservation,
+        allow_expired: bool,
+    ) -> Result<(), Status> {
+        if reservation.status
+            != InventoryReservationStatus::Reserved.as_str_name()
+        {
+            return Err(Status::failed_precondition(
+                "inventory_reservation.status",
+            ));
+        }
+
+        if !allow_expired && reservation.expires_at <= Utc::now() {
+            return Err(Status::failed_precondition(
+                "inventory_reservation.expires_at",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl inventory_service_server::InventoryService for InventoryService {
+    async fn put_inventory(
+        &self,
+        request: Request<PutInventoryRequest>,
+    ) -> Result<Response<PutInventoryResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutInventoryRequest {
+            offer_id,
+            offer_variant_id,
+            on_hand,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let offer_variant_id = Self::parse_offer_variant_id(offer_variant_id)?;
+
+        if on_hand < 0 {
+            return Err(Status::invalid_argument("on_hand"));
+        }
+
+        let offer = Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        if let Some(offer_variant_id) = offer_variant_id {
+            if !offer
+                .variants
+                .iter()
+                .any(|v| v.offer_variant_id == offer_variant_id)
+            {
+                return Err(Status::not_found("offer_variant"));
+            }
+        }
+
+        let found_inventory = Inventory::get(
+            &self.pool,
+            &offer_id,
+            offer_variant_id.as_ref(),
+            &user_id,
+        )
+        .await?;
+
+        if let Some(found_inventory) = found_inventory {
+            Inventory::update_on_hand(
+                &self.pool,
+                &user_id,
+                &found_inventory.inventory_id,
+                on_hand,
+            )
+            .await?;
+        } else {
+            Inventory::create(
+                &self.pool,
+                &offer_id,
+                offer_variant_id.as_ref(),
+                &user_id,
+                on_hand,
+            )
+            .await?;
+        }
+
+        let inventory = Inventory::get(
+            &self.pool,
+            &offer_id,
+            offer_variant_id.as_ref(),
+            &user_id,
+        )
+        .await?
+        .ok_or_else(|| Status::internal(""))?;
+
+        let inventory_response = Self::to_response(inventory);
+
+        self.publisher
+            .publish_upsert_inventory(&inventory_response)
+            .await;
+        self.publish_offer(&offer_id).await;
+
+        Ok(Response::new(PutInventoryResponse {
+            inventory: Some(inventory_response),
+        }))
+    }
+
+    async fn get_inventory(
+        &self,
+        request: Request<GetInventoryRequest>,
+    ) -> Result<Response<GetInventoryResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let GetInventoryRequest {
+            offer_id,
+            offer_variant_id,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let offer_variant_id = Self::parse_offer_variant_id(offer_variant_id)?;
+
+        let inventory = Inventory::get(
+            &self.pool,
+            &offer_id,
+            offer_variant_id.as_ref(),
+            &user_id,
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("inventory"))?;
+
+        Ok(Response::new(GetInventoryResponse {
+            inventory: Some(Self::to_response(inventory)),
+        }))
+    }
+
+    async fn list_inventories(
+        &self,
+        request: Request<ListInventoriesRequest>,
+    ) -> Result<Response<ListInventoriesResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let offer_id = parse_uuid(&request.into_inner().offer_id, "offer_id")?;
+
+        let inventories = Inventory::list(&self.pool, &offer_id, &user_id)
+            .await?
+            .into_iter()
+            .map(Self::to_response)
+            .collect();
+
+        Ok(Response::new(ListInventoriesResponse { inventories }))
+    }
+
+    async fn delete_inventory(
+        &self,
+        request: Request<DeleteInventoryRequest>,
+    ) -> Result<Response<DeleteInventoryResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let inventory_id =
+            parse_uuid(&request.into_inner().inventory_id, "inventory_id")?;
+
+        let inventory =
+            Inventory::get_by_id(&self.pool, &inventory_id, &user_id)
+                .await?
+                .ok_or_else(|| Status::not_found("inventory"))?;
+
+        let offer_id = inventory.offer_id;
+
+        Inventory::delete(&self.pool, &user_id, &inventory_id).await?;
+
+        self.publisher
+            .publish_delete_inventory(&Self::to_response(inventory))
+            .await;
+        self.publish_offer(&offer_id).await;
+
+        Ok(Response::new(DeleteInventoryResponse {}))
+    }
+
+    async fn reserve_inventory(
+        &self,
+        request: Request<ReserveInventoryRequest>,
+    ) -> Result<Response<ReserveInventoryResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let ReserveInventoryRequest {
+            offer_id,
+            offer_variant_id,
+            quantity,
+            ttl_seconds,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let offer_variant_id = Self::parse_offer_variant_id(offer_variant_id)?;
+
+        if quantity == 0 || quantity > Self::MAX_RESERVATION_QUANTITY {
+            return Err(Status::invalid_argument("quantity"));
+        }
+
+        let ttl_seconds =
+            ttl_seconds.unwrap_or(Self::DEFAULT_RESERVATION_TTL_SECONDS);
+        if ttl_seconds == 0 || ttl_seconds > Self::MAX_RESERVATION_TTL_SECONDS {
+            return Err(Status::invalid_argument("ttl_seconds"));
+        }
+
+        let offer = Offer::get(&self.pool, &offer_id, Some(&user_id))
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        let allows_backorder = offer.inventory_policy
+            == InventoryPolicy::AllowBackorder.as_str_name();
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        if InventoryReservation::count_active_for_user(&transaction, &user_id)
+            .await?
+            >= Self::MAX_ACTIVE_RESERVATIONS_PER_USER
+        {
+            return Err(Status::resource_exhausted("inventory_reservations"));
+        }
+
+        let inventory = Inventory::get_for_update(
+            &transaction,
+            &offer_id,
+            offer_variant_id.as_ref(),
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("inventory"))?;
+
+        if !allows_backorder && inventory.available() < i64::from(quantity) {
+            return Err(Status::failed_precondition("inventory.available"));
+        }
+
+        let reservation = InventoryReservation::create(
+            &transaction,
+            &inventory,
+            &user_id,
+            quantity,
+            InventoryReservationStatus::Reserved.as_str_name(),
+            Utc::now() + Duration::seconds(i64::from(ttl_seconds)),
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        self.publish_offer(&reservation.offer_id).await;
+
+        Ok(Response::new(ReserveInventoryResponse {
+            reservation: Some(Self::reservation_to_response(reservation)?),
+        }))
+    }
+
+    async fn commit_inventory_reservation(
+        &self,
+        request: Request<CommitInventoryReservationRequest>,
+    ) -> Result<Response<CommitInventoryReservationResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let inventory_reservation_id = parse_uuid(
+            &request.into_inner().inventory_reservation_id,
+            "inventory_reservation_id",
+        )?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let reservation = InventoryReservation::get_for_update(
+            &transaction,
+            &inventory_reservation_id,
+            &user_id,
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("inventory_reservation"))?;
+
+        Self::validate_reservation(&reservation, false)?;
+
+        Inventory::decrement_on_hand(
+            &transaction,
+            &reservation.inventory_id,
+            reservation.quantity,
+        )
+        .await?;
+
+        let reservation = InventoryReservation::update_status(
+            &transaction,
+            &inventory_reservation_id,
+            InventoryReservationStatus::Committed.as_str_name(),
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        self.publish_offer(&reservation.offer_id).await;
+
+        Ok(Response::new(CommitInventoryReservationResponse {
+            reservation: Some(Self::reservation_to_response(reservation)?),
+        }))
+    }
+
+    async fn release_inventory_reservation(
+        &self,
+        request: Request<ReleaseInventoryReservationRequest>,
+    ) -> Result<Response<ReleaseInventoryReservationResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let inventory_reservation_id = parse_uuid(
+            &request.into_inner().inventory_reservation_id,
+            "inventory_reservation_id",
+        )?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let reservation = InventoryReservation::get_for_update(
+            &transaction,
+            &inventory_reservation_id,
+            &user_id,
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("inventory_reservation"))?;
+
+        Self::validate_reservation(&reservation, true)?;
+
+        let reservation = InventoryReservation::update_status(
+            &transaction,
+            &inventory_reservation_id,
+            InventoryReservationStatus::Released.as_str_name(),
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        self.publish_offer(&reservation.offer_id).await;
+
+        Ok(Response::new(ReleaseInventoryReservationResponse {
+            reservation: Some(Self::reservation_to_response(reservation)?),
+        }))
+    }
+}
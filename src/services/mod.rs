@@ -1,19 +1,43 @@
+mod cart;
+mod category;
+mod collection;
+mod discount;
+mod inventory;
 mod offer;
+mod order;
+mod shipping_profile;
 mod shipping_rate;
 mod shop;
 mod shop_customization;
 mod shop_domain;
+mod tax;
 
+pub use cart::CartService;
+pub use category::CategoryService;
+pub use collection::CollectionService;
+pub use discount::DiscountService;
+pub use inventory::InventoryService;
 pub use offer::OfferService;
+pub use order::OrderService;
+pub use shipping_profile::ShippingProfileService;
 pub use shipping_rate::ShippingRateService;
 pub use shop::ShopService;
 pub use shop_customization::ShopCustomizationService;
 pub use shop_domain::ShopDomainService;
+pub use tax::TaxService;
 
+use std::collections::{HashMap, HashSet};
+
+use deadpool_postgres::Pool;
 use tonic::Status;
 use uuid::Uuid;
 
+use crate::api::sited_io::commerce::v1::{FilterOperator, ShippingCountry};
 use crate::api::sited_io::types::v1::{PaginationRequest, PaginationResponse};
+use crate::db::{Cursor, FilterGroup};
+use crate::model::{OfferPriceAsRel, ShippingProfile, ShippingRate};
+
+use self::shipping_profile::ShippingTotals;
 
 pub fn uuid_err_to_grpc_status(field: &str) -> Status {
     Status::invalid_argument(format!("field {field} is not a valid UUID v4"))
@@ -25,6 +49,27 @@ pub fn parse_uuid(uuid_string: &str, field: &str) -> Result<Uuid, Status> {
         .map_err(|_| uuid_err_to_grpc_status(field))
 }
 
+const SLUG_CHARS: [char; 65] = [
+    '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
+    'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u',
+    'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J',
+    'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y',
+    'Z', '-', '+', '_', '!',
+];
+
+fn validate_slug(slug: &str) -> Result<(), Status> {
+    for char in slug.chars() {
+        if !SLUG_CHARS.contains(&char) {
+            return Err(Status::invalid_argument(format!(
+                "invalid character '{}'",
+                char
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 /// Returns limit and offset from PaginationRequest
 fn get_limit_offset_from_pagination(
     request: Option<PaginationRequest>,
@@ -35,6 +80,7 @@ fn get_limit_offset_from_pagination(
         page: 1,
         size: limit,
         total_elements: 0,
+        next_cursor: None,
     };
 
     if let Some(request) = request {
@@ -51,3 +97,191 @@ fn get_limit_offset_from_pagination(
 
     Ok((limit, offset, pagination))
 }
+
+/// Returns limit, offset and cursor from PaginationRequest of lists that can
+/// be paged by cursor. Pages are ignored when paging by cursor.
+fn get_limit_offset_cursor_from_pagination(
+    request: Option<PaginationRequest>,
+) -> Result<(u32, u32, Option<Cursor>, PaginationResponse), Status> {
+    match request {
+        Some(PaginationRequest {
+            size,
+            cursor: Some(cursor),
+            ..
+        }) => Ok((
+            size,
+            0,
+            Some(Cursor::decode(&cursor)?),
+            PaginationResponse {
+                page: 0,
+                size,
+                total_elements: 0,
+                next_cursor: None,
+            },
+        )),
+        request => {
+            let (limit, offset, pagination) =
+                get_limit_offset_from_pagination(request)?;
+            Ok((limit, offset, None, pagination))
+        }
+    }
+}
+
+const MAX_RANDOM_SEED_LENGTH: usize = 64;
+
+/// Checks the seed of random orders of a list request, which clients choose
+/// freely, for example per day or per visitor.
+fn validate_random_seed(random_seed: Option<&String>) -> Result<(), Status> {
+    if matches!(
+        random_seed,
+        Some(seed) if seed.is_empty() || seed.len() > MAX_RANDOM_SEED_LENGTH,
+    ) {
+        return Err(Status::invalid_argument("random_seed"));
+    }
+
+    Ok(())
+}
+
+/// Limits of the filters of a list request, counting the group that joins
+/// the top-level filters of the request.
+const MAX_FILTER_GROUP_DEPTH: usize = 4;
+const MAX_FILTERS: usize = 20;
+
+fn get_filter_operator(operator: i32) -> Result<FilterOperator, Status> {
+    FilterOperator::from_i32(operator)
+        .ok_or_else(|| Status::invalid_argument("filter_group.operator"))
+}
+
+/// Checks that the filters of a list request stay within the limits.
+fn validate_filter_group<F: Copy>(
+    filter: &FilterGroup<F>,
+) -> Result<(), Status> {
+    fn get_depth<F>(group: &FilterGroup<F>) -> usize {
+        1 + group.groups.iter().map(get_depth).max().unwrap_or(0)
+    }
+
+    if get_depth(filter) > MAX_FILTER_GROUP_DEPTH {
+        return Err(Status::invalid_argument("filter_group"));
+    }
+
+    if filter.fields().len() > MAX_FILTERS {
+        return Err(Status::invalid_argument("filters"));
+    }
+
+    Ok(())
+}
+
+/// Returns unit amount and amount of `quantity` units of the price. The unit
+/// amount is the average over all units, rounded down, as tiered, volume and
+/// package prices have no single amount per unit.
+fn get_item_amounts(
+    price: &OfferPriceAsRel,
+    quantity: u32,
+) -> Result<(u32, u32), Status> {
+    let amount = price
+        .get_amount(quantity)
+        .and_then(|a| u32::try_from(a).ok())
+        .ok_or_else(|| Status::invalid_argument("quantity"))?;
+
+    Ok((amount.checked_div(quantity).unwrap_or_default(), amount))
+}
+
+/// Line of a cart or order shipping is calculated for.
+struct ShippingLine {
+    offer_id: Uuid,
+    quantity: u32,
+    amount: u32,
+}
+
+/// Sums the shipping amounts of the given lines for the buyer's country in
+/// the given currency. Offers with shipping rates of their own are charged
+/// once per offer. The other offers are charged by the shipping profile they
+/// are assigned to, once per profile for the combined weight, value and
+/// number of items. Offers with neither ship for free. Returns the amount
+/// and the offers that do not ship to the country or have no rate in the
+/// currency, which are all offers with a rate or profile if no country is
+/// given.
+async fn get_shipping_amount(
+    pool: &Pool,
+    lines: &[ShippingLine],
+    shipping_country: Option<ShippingCountry>,
+    currency: &str,
+) -> Result<(u64, Vec<Uuid>), Status> {
+    let mut shipping_amount = 0;
+    let mut unshippable_offer_ids = Vec::new();
+    let mut profile_offer_ids = Vec::new();
+
+    let offer_ids: HashSet<Uuid> = lines.iter().map(|l| l.offer_id).collect();
+
+    for offer_id in offer_ids {
+        let shipping_rates =
+            ShippingRate::list_by_offer_id(pool, &offer_id).await?;
+
+        if shipping_rates.is_empty() {
+            profile_offer_ids.push(offer_id);
+            continue;
+        }
+
+        let shipping_rate =
+            shipping_rates.into_iter().find(|r| r.currency == currency);
+
+        match (shipping_rate, shipping_country) {
+            (Some(shipping_rate), Some(country))
+                if shipping_rate.ships_to(country.as_str_name()) =>
+            {
+                shipping_amount += u64::from(shipping_rate.amount);
+            }
+            _ => unshippable_offer_ids.push(offer_id),
+        }
+    }
+
+    if !profile_offer_ids.is_empty() {
+        let mut profiles: HashMap<Uuid, (ShippingTotals, Vec<Uuid>)> =
+            HashMap::new();
+
+        for offer_shipping in
+            ShippingProfile::list_offer_shipping(pool, &profile_offer_ids)
+                .await?
+        {
+            let Some(shipping_profile_id) = offer_shipping.shipping_profile_id
+            else {
+                continue;
+            };
+
+            let (totals, offer_ids) =
+                profiles.entry(shipping_profile_id).or_default();
+
+            for line in lines
+                .iter()
+                .filter(|l| l.offer_id == offer_shipping.offer_id)
+            {
+                totals.weight_grams +=
+                    u64::from(offer_shipping.weight_grams.unwrap_or_default())
+                        * u64::from(line.quantity);
+                totals.order_value += u64::from(line.amount);
+                totals.item_count += u64::from(line.quantity);
+            }
+
+            offer_ids.push(offer_shipping.offer_id);
+        }
+
+        for (shipping_profile_id, (totals, offer_ids)) in profiles {
+            match ShippingProfileService::get_amount(
+                pool,
+                &shipping_profile_id,
+                &totals,
+                shipping_country,
+                currency,
+            )
+            .await?
+            {
+                Some(amount) => shipping_amount += u64::from(amount),
+                None => unshippable_offer_ids.extend(offer_ids),
+            }
+        }
+    }
+
+    unshippable_offer_ids.sort();
+
+    Ok((shipping_amount, unshippable_offer_ids))
+}
@@ -1,4 +1,7 @@
-use deadpool_postgres::Pool;
+use std::collections::HashSet;
+
+use chrono::{DateTime, TimeZone, Utc};
+use deadpool_postgres::{Pool, Transaction};
 use jwtk::jwk::RemoteJwksVerifier;
 use tonic::{async_trait, Request, Response, Status};
 use uuid::Uuid;
@@ -7,26 +10,52 @@ use crate::api::sited_io::commerce::v1::offer_service_server::{
     self, OfferServiceServer,
 };
 use crate::api::sited_io::commerce::v1::{
-    AddImageToOfferRequest, AddImageToOfferResponse, CreateOfferRequest,
-    CreateOfferResponse, Currency, DeleteOfferRequest, DeleteOfferResponse,
-    GetMyOfferRequest, GetMyOfferResponse, GetOfferRequest, GetOfferResponse,
-    ListOffersRequest, ListOffersResponse, OfferImageResponse, OfferResponse,
-    OfferType, Price, PriceBillingScheme, PriceType, PutPriceToOfferRequest,
-    PutPriceToOfferResponse, Recurring, RecurringInterval,
-    RemoveImageFromOfferRequest, RemoveImageFromOfferResponse,
-    RemovePriceFromOfferRequest, RemovePriceFromOfferResponse,
-    UpdateOfferRequest, UpdateOfferResponse,
+    AddCategoryToOfferRequest, AddCategoryToOfferResponse,
+    AddImageToOfferRequest, AddImageToOfferResponse, AddOptionToOfferRequest,
+    AddOptionToOfferResponse, AddValueToOfferOptionRequest,
+    AddValueToOfferOptionResponse, AutocompleteOffersRequest,
+    AutocompleteOffersResponse, CreateOfferRequest, CreateOfferResponse,
+    CreateOfferVariantRequest, CreateOfferVariantResponse, Currency,
+    DeleteOfferRequest, DeleteOfferResponse, DeleteOfferVariantRequest,
+    DeleteOfferVariantResponse, FilterOperator, GenerateOfferVariantsRequest,
+    GenerateOfferVariantsResponse, GetMyOfferRequest, GetMyOfferResponse,
+    GetOfferRequest, GetOfferResponse, InventoryPolicy, ListOffersRequest,
+    ListOffersResponse, OfferAttribute, OfferAttributeType, OfferImageResponse,
+    OfferNameSuggestion, OfferOptionResponse, OfferOptionValueResponse,
+    OfferResponse, OfferType, OfferVariantResponse, OffersFacetCount,
+    OffersFacets, OffersFilterField, OffersFilterGroup, OffersPriceBucketCount,
+    Price, PriceBillingScheme, PriceTier, PriceType,
+    PutAttributesToOfferRequest, PutAttributesToOfferResponse,
+    PutImagesToOfferVariantRequest, PutImagesToOfferVariantResponse,
+    PutPriceToOfferRequest, PutPriceToOfferResponse,
+    PutShippingProfileToOfferRequest, PutShippingProfileToOfferResponse,
+    PutTagsToOfferRequest, PutTagsToOfferResponse, Recurring,
+    RecurringInterval, RemoveCategoryFromOfferRequest,
+    RemoveCategoryFromOfferResponse, RemoveImageFromOfferRequest,
+    RemoveImageFromOfferResponse, RemoveOptionFromOfferRequest,
+    RemoveOptionFromOfferResponse, RemovePriceFromOfferRequest,
+    RemovePriceFromOfferResponse, RemoveShippingProfileFromOfferRequest,
+    RemoveShippingProfileFromOfferResponse, RemoveValueFromOfferOptionRequest,
+    RemoveValueFromOfferOptionResponse, TaxCategory, UpdateOfferOptionRequest,
+    UpdateOfferOptionResponse, UpdateOfferRequest, UpdateOfferResponse,
+    UpdateOfferVariantRequest, UpdateOfferVariantResponse,
 };
 use crate::auth::get_user_id;
-use crate::db::DbError;
-use crate::images::ImageService;
+use crate::currency::get_minor_unit_exponent;
+use crate::db::{DbError, FilterGroup};
+use crate::images::{ImageKind, ImageService};
 use crate::model::{
-    Offer, OfferImage, OfferImageAsRel, OfferPrice, OfferPriceAsRel,
+    Category, Offer, OfferAttributeAsRel, OfferFacetCount, OfferFacets,
+    OfferImage, OfferImageAsRel, OfferOption, OfferOptionValue, OfferPrice,
+    OfferPriceAsRel, OfferPriceTier, OfferTag, OfferVariant, ShippingProfile,
     ShippingRate,
 };
-use crate::{parse_uuid, Publisher};
+use crate::{parse_uuid, pricing, Publisher};
 
-use super::get_limit_offset_from_pagination;
+use super::{
+    get_filter_operator, get_limit_offset_cursor_from_pagination,
+    validate_filter_group, validate_random_seed,
+};
 
 pub struct OfferService {
     pool: Pool,
@@ -36,6 +65,12 @@ pub struct OfferService {
 }
 
 impl OfferService {
+    /// Default price bucket bounds in the major unit of the currency.
+    const DEFAULT_FACET_PRICE_BOUNDS: [u32; 5] = [10, 25, 50, 100, 250];
+    const MAX_FACET_PRICE_BOUNDS: usize = 10;
+    const DEFAULT_AUTOCOMPLETE_LIMIT: u32 = 10;
+    const MAX_AUTOCOMPLETE_LIMIT: u32 = 20;
+
     pub fn build(
         pool: Pool,
         verifier: RemoteJwksVerifier,
@@ -51,16 +86,102 @@ impl OfferService {
     }
 
     fn offer_to_response(&self, offer: Offer) -> Result<OfferResponse, Status> {
+        Self::build_offer_response(&self.image_service, offer)
+    }
+
+    /// Builds the response with prices as they apply at the time of the
+    /// call. Also used to publish offers outside of requests.
+    pub(crate) fn build_offer_response(
+        image_service: &ImageService,
+        offer: Offer,
+    ) -> Result<OfferResponse, Status> {
+        let now = Utc::now();
+
         let price = match offer.price {
-            Some(p) => Some(self.offer_price_to_response(p)?),
+            Some(p) => Some(Self::offer_price_to_response(p, now)?),
             None => None,
         };
 
+        let prices = Self::offer_prices_to_response(offer.prices, now)?;
+
         let r#type = offer
             .type_
             .and_then(|t| OfferType::from_str_name(&t).map(i32::from))
             .unwrap_or(0);
 
+        let attributes = offer
+            .attributes
+            .into_iter()
+            .map(|a| OfferAttribute {
+                key: a.key,
+                attribute_type: OfferAttributeType::from_str_name(
+                    &a.attribute_type,
+                )
+                .map(i32::from)
+                .unwrap_or(0),
+                value: a.value,
+            })
+            .collect();
+
+        let options = offer
+            .options
+            .into_iter()
+            .map(|o| OfferOptionResponse {
+                offer_option_id: o.offer_option_id.to_string(),
+                name: o.name,
+                ordering: o.ordering,
+                values: o
+                    .values
+                    .into_iter()
+                    .map(|v| OfferOptionValueResponse {
+                        offer_option_value_id: v
+                            .offer_option_value_id
+                            .to_string(),
+                        value: v.value,
+                        ordering: v.ordering,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let inventory_policy =
+            InventoryPolicy::from_str_name(&offer.inventory_policy)
+                .ok_or(Status::internal(""))?;
+        let tax_category = TaxCategory::from_str_name(&offer.tax_category)
+            .ok_or(Status::internal(""))?;
+        let allows_backorder =
+            inventory_policy == InventoryPolicy::AllowBackorder;
+
+        let mut variants = Vec::with_capacity(offer.variants.len());
+        for variant in offer.variants {
+            let price = match variant.price {
+                Some(p) => Some(Self::offer_price_to_response(p, now)?),
+                None => None,
+            };
+
+            let prices = Self::offer_prices_to_response(variant.prices, now)?;
+
+            variants.push(OfferVariantResponse {
+                offer_variant_id: variant.offer_variant_id.to_string(),
+                sku: variant.sku,
+                is_active: variant.is_active,
+                price,
+                offer_option_value_ids: variant
+                    .offer_option_value_ids
+                    .iter()
+                    .map(Uuid::to_string)
+                    .collect(),
+                offer_image_ids: variant
+                    .offer_image_ids
+                    .iter()
+                    .map(Uuid::to_string)
+                    .collect(),
+                is_sold_out: !allows_backorder
+                    && variant.available.is_some_and(|a| a <= 0),
+                prices,
+            });
+        }
+
         Ok(OfferResponse {
             offer_id: offer.offer_id.to_string(),
             shop_id: offer.shop_id.to_string(),
@@ -71,33 +192,100 @@ impl OfferService {
             name: offer.name,
             description: offer.description,
             is_active: offer.is_active,
-            images: self.offer_images_to_response(offer.images),
+            images: Self::offer_images_to_response(image_service, offer.images),
             price,
             r#type,
             is_featured: offer.is_featured,
             shop_slug: offer.shop_slug,
             shop_domain: offer.shop_domain,
+            options,
+            variants,
+            inventory_policy: inventory_policy.into(),
+            is_sold_out: offer.is_sold_out,
+            prices,
+            tax_category: tax_category.into(),
+            weight_grams: offer.weight_grams,
+            shipping_profile_id: offer
+                .shipping_profile_id
+                .map(|s| s.to_string()),
+            category_ids: offer
+                .category_ids
+                .iter()
+                .map(Uuid::to_string)
+                .collect(),
+            tags: offer.tags,
+            attributes,
         })
     }
 
-    fn offer_images_to_response(
+    /// Publishes the current state of an active offer outside of offer
+    /// requests, for example when changes in stock decide whether it is sold
+    /// out. Inactive offers are not published and errors are only logged.
+    pub(crate) async fn publish_offer(
+        pool: &Pool,
+        publisher: &Publisher,
+        image_service: &ImageService,
+        offer_id: &Uuid,
+    ) {
+        let offer = match Offer::get(pool, offer_id, None).await {
+            Ok(Some(offer)) => offer,
+            Ok(None) => return,
+            Err(err) => {
+                tracing::log::error!("[OfferService.publish_offer]: {err:?}");
+                return;
+            }
+        };
+
+        match Self::build_offer_response(image_service, offer) {
+            Ok(offer_response) => {
+                publisher.publish_upsert_offer(&offer_response).await
+            }
+            Err(err) => {
+                tracing::log::error!("[OfferService.publish_offer]: {err:?}")
+            }
+        }
+    }
+
+    /// Loads the current state of the offer, publishes it and returns it.
+    async fn publish_offer_for_user(
         &self,
+        user_id: &String,
+        offer_id: &Uuid,
+    ) -> Result<OfferResponse, Status> {
+        let offer = Offer::get_for_user(&self.pool, user_id, offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        let offer_response = self.offer_to_response(offer)?;
+
+        self.publisher.publish_upsert_offer(&offer_response).await;
+
+        Ok(offer_response)
+    }
+
+    fn offer_images_to_response(
+        image_service: &ImageService,
         offer_images: Vec<OfferImageAsRel>,
     ) -> Vec<OfferImageResponse> {
         offer_images
             .into_iter()
             .map(|oi| OfferImageResponse {
                 offer_image_id: oi.offer_image_id.to_string(),
-                image_url: self.image_service.get_image_url(&oi.image_url_path),
+                image_url: image_service.get_image_url(&oi.image_url_path),
                 ordering: oi.ordering,
+                image_variants: image_service
+                    .get_image_variants(&oi.image_url_path, &oi.variants),
             })
             .collect()
     }
 
     fn offer_price_to_response(
-        &self,
         offer_price: OfferPriceAsRel,
+        now: DateTime<Utc>,
     ) -> Result<Price, Status> {
+        let is_on_sale = offer_price.is_on_sale(now);
+        let effective_unit_amount = offer_price.get_unit_amount(now);
+
         let recurring = match (
             offer_price.recurring_interval,
             offer_price.recurring_interval_count,
@@ -129,9 +317,50 @@ impl OfferService {
             .into(),
             unit_amount: offer_price.unit_amount,
             recurring,
+            tiers: offer_price
+                .tiers
+                .into_iter()
+                .map(|t| PriceTier {
+                    up_to: t.up_to,
+                    unit_amount: t.unit_amount,
+                    flat_amount: t.flat_amount,
+                })
+                .collect(),
+            package_size: offer_price.package_size,
+            compare_at_amount: offer_price.sale.map(|s| s.compare_at_amount),
+            sale_starts_at: offer_price
+                .sale
+                .and_then(|s| s.starts_at)
+                .map(|s| s.timestamp()),
+            sale_ends_at: offer_price
+                .sale
+                .and_then(|s| s.ends_at)
+                .map(|e| e.timestamp()),
+            is_on_sale,
+            effective_unit_amount,
         })
     }
 
+    fn offer_prices_to_response(
+        offer_prices: Vec<OfferPriceAsRel>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Price>, Status> {
+        let mut prices = Vec::with_capacity(offer_prices.len());
+        for offer_price in offer_prices {
+            prices.push(Self::offer_price_to_response(offer_price, now)?);
+        }
+        Ok(prices)
+    }
+
+    fn get_currency(currency: i32) -> Result<Currency, Status> {
+        if currency < 1 {
+            Err(Status::invalid_argument("currency"))
+        } else {
+            Currency::from_i32(currency)
+                .ok_or(Status::invalid_argument("currency"))
+        }
+    }
+
     fn get_offer_type(type_: i32) -> Result<OfferType, Status> {
         if type_ < 1 {
             Err(Status::invalid_argument("type"))
@@ -140,6 +369,26 @@ impl OfferService {
         }
     }
 
+    fn get_inventory_policy(
+        inventory_policy: i32,
+    ) -> Result<InventoryPolicy, Status> {
+        if inventory_policy < 1 {
+            Err(Status::invalid_argument("inventory_policy"))
+        } else {
+            InventoryPolicy::from_i32(inventory_policy)
+                .ok_or(Status::invalid_argument("inventory_policy"))
+        }
+    }
+
+    fn get_tax_category(tax_category: i32) -> Result<TaxCategory, Status> {
+        if tax_category < 1 {
+            Err(Status::invalid_argument("tax_category"))
+        } else {
+            TaxCategory::from_i32(tax_category)
+                .ok_or(Status::invalid_argument("tax_category"))
+        }
+    }
+
     fn validate_price(price: &Price) -> Result<(), Status> {
         if price.currency() == Currency::Unspecified {
             return Err(Status::invalid_argument("price.currency"));
@@ -153,6 +402,10 @@ impl OfferService {
             return Err(Status::invalid_argument("price.billing_scheme"));
         }
 
+        Self::validate_price_tiers(price)?;
+
+        Self::get_price_sale(price)?;
+
         if price.price_type == i32::from(PriceType::Recurring) {
             if let Some(recurring) = price.recurring.as_ref() {
                 if recurring.interval < 1 {
@@ -168,6 +421,375 @@ impl OfferService {
         }
     }
 
+    /// Tiered and volume prices need tiers with increasing upper bounds,
+    /// the last one open-ended, package prices a package size. Other fields
+    /// must not be set for the respective billing scheme.
+    fn validate_price_tiers(price: &Price) -> Result<(), Status> {
+        let tiers = Self::get_price_tiers(price);
+
+        match price.billing_scheme() {
+            PriceBillingScheme::Tiered | PriceBillingScheme::Volume => {
+                if price.package_size.is_some() {
+                    return Err(Status::invalid_argument("price.package_size"));
+                }
+
+                let (last, rest) = tiers
+                    .split_last()
+                    .ok_or(Status::invalid_argument("price.tiers"))?;
+
+                if last.up_to.is_some() {
+                    return Err(Status::invalid_argument("price.tiers.up_to"));
+                }
+
+                let mut lower_bound = 0;
+                for tier in rest {
+                    match tier.up_to {
+                        Some(up_to) if up_to > lower_bound => {
+                            lower_bound = up_to
+                        }
+                        _ => {
+                            return Err(Status::invalid_argument(
+                                "price.tiers.up_to",
+                            ))
+                        }
+                    }
+                }
+            }
+            PriceBillingScheme::Package => {
+                if !tiers.is_empty() {
+                    return Err(Status::invalid_argument("price.tiers"));
+                }
+
+                if price.package_size.unwrap_or_default() < 1 {
+                    return Err(Status::invalid_argument("price.package_size"));
+                }
+            }
+            PriceBillingScheme::Unspecified | PriceBillingScheme::PerUnit => {
+                if !tiers.is_empty() {
+                    return Err(Status::invalid_argument("price.tiers"));
+                }
+
+                if price.package_size.is_some() {
+                    return Err(Status::invalid_argument("price.package_size"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_sale_timestamp(
+        timestamp: Option<i64>,
+        field: &str,
+    ) -> Result<Option<DateTime<Utc>>, Status> {
+        match timestamp {
+            Some(timestamp) => Utc
+                .timestamp_opt(timestamp, 0)
+                .single()
+                .map(Some)
+                .ok_or_else(|| Status::invalid_argument(field)),
+            None => Ok(None),
+        }
+    }
+
+    /// A sale needs a compare-at amount above the unit amount and is only
+    /// possible for prices with a unit amount, i.e. per unit and package
+    /// prices. A sale window without a compare-at amount is rejected.
+    fn get_price_sale(
+        price: &Price,
+    ) -> Result<Option<pricing::PriceSale>, Status> {
+        let starts_at = Self::parse_sale_timestamp(
+            price.sale_starts_at,
+            "price.sale_starts_at",
+        )?;
+        let ends_at = Self::parse_sale_timestamp(
+            price.sale_ends_at,
+            "price.sale_ends_at",
+        )?;
+
+        let Some(compare_at_amount) = price.compare_at_amount else {
+            if starts_at.is_some() || ends_at.is_some() {
+                return Err(Status::invalid_argument(
+                    "price.compare_at_amount",
+                ));
+            }
+            return Ok(None);
+        };
+
+        if !matches!(
+            price.billing_scheme(),
+            PriceBillingScheme::PerUnit | PriceBillingScheme::Package
+        ) || compare_at_amount <= price.unit_amount
+        {
+            return Err(Status::invalid_argument("price.compare_at_amount"));
+        }
+
+        if let (Some(starts_at), Some(ends_at)) = (starts_at, ends_at) {
+            if starts_at >= ends_at {
+                return Err(Status::invalid_argument("price.sale_ends_at"));
+            }
+        }
+
+        Ok(Some(pricing::PriceSale {
+            compare_at_amount,
+            starts_at,
+            ends_at,
+        }))
+    }
+
+    fn get_price_tiers(price: &Price) -> Vec<pricing::PriceTier> {
+        let mut tiers: Vec<pricing::PriceTier> = price
+            .tiers
+            .iter()
+            .map(|t| pricing::PriceTier {
+                up_to: t.up_to,
+                unit_amount: t.unit_amount,
+                flat_amount: t.flat_amount,
+            })
+            .collect();
+
+        pricing::sort_tiers(&mut tiers);
+
+        tiers
+    }
+
+    async fn put_price(
+        &self,
+        user_id: &String,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        price: Price,
+    ) -> Result<(), Status> {
+        Self::validate_price(&price)?;
+
+        let currency = price.currency().as_str_name();
+
+        let found_price = OfferPrice::get_by_offer_id(
+            &self.pool,
+            offer_id,
+            offer_variant_id,
+            currency,
+        )
+        .await?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        Self::put_price_in_transaction(
+            &transaction,
+            user_id,
+            offer_id,
+            offer_variant_id,
+            price,
+            found_price.is_some(),
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(())
+    }
+
+    /// Writes the price and its tiers within the transaction, updating the
+    /// price of the currency if it already exists.
+    async fn put_price_in_transaction<'a>(
+        transaction: &Transaction<'a>,
+        user_id: &String,
+        offer_id: &Uuid,
+        offer_variant_id: Option<&Uuid>,
+        price: Price,
+        exists: bool,
+    ) -> Result<(), Status> {
+        let tiers = Self::get_price_tiers(&price);
+        let sale = Self::get_price_sale(&price)?;
+        let package_size = price.package_size;
+        let currency = price.currency().as_str_name();
+        let price_type = price.price_type().as_str_name();
+        let billing_scheme = price.billing_scheme().as_str_name();
+        let unit_amount = price.unit_amount;
+        let recurring_interval =
+            price.recurring.as_ref().map(|r| r.interval().as_str_name());
+        let recurring_interval_count =
+            price.recurring.as_ref().map(|r| r.interval_count);
+        let trial_period_days =
+            price.recurring.and_then(|r| r.trial_period_days);
+
+        let offer_price = if exists {
+            OfferPrice::put(
+                transaction,
+                user_id,
+                offer_id,
+                offer_variant_id,
+                currency,
+                price_type,
+                billing_scheme,
+                unit_amount,
+                recurring_interval,
+                recurring_interval_count,
+                trial_period_days,
+                package_size,
+                sale.as_ref(),
+            )
+            .await?
+        } else {
+            OfferPrice::create(
+                transaction,
+                offer_id,
+                offer_variant_id,
+                user_id,
+                currency,
+                price_type,
+                billing_scheme,
+                unit_amount,
+                recurring_interval,
+                recurring_interval_count,
+                trial_period_days,
+                package_size,
+                sale.as_ref(),
+            )
+            .await?
+        };
+
+        OfferPriceTier::put_all(
+            transaction,
+            &offer_price.offer_price_id,
+            &tiers,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Returns the SKU, suffixed with a number if it is already taken, and
+    /// marks it as taken.
+    fn get_unique_sku(skus: &mut HashSet<String>, sku: String) -> String {
+        let mut unique_sku = sku.clone();
+        let mut suffix = 2;
+        while skus.contains(&unique_sku) {
+            unique_sku = format!("{sku}-{suffix}");
+            suffix += 1;
+        }
+        skus.insert(unique_sku.clone());
+        unique_sku
+    }
+
+    /// Checks that the option values select exactly one value of every option
+    /// of the offer.
+    fn validate_variant_option_values(
+        offer: &Offer,
+        offer_option_value_ids: &[Uuid],
+    ) -> Result<(), Status> {
+        let all_covered = offer.options.iter().all(|option| {
+            option
+                .values
+                .iter()
+                .filter(|v| {
+                    offer_option_value_ids.contains(&v.offer_option_value_id)
+                })
+                .count()
+                == 1
+        });
+
+        if !all_covered || offer_option_value_ids.len() != offer.options.len() {
+            return Err(Status::invalid_argument("offer_option_value_ids"));
+        }
+
+        Ok(())
+    }
+
+    fn validate_variant_images(
+        offer: &Offer,
+        offer_image_ids: &[Uuid],
+    ) -> Result<(), Status> {
+        if offer_image_ids
+            .iter()
+            .all(|id| offer.images.iter().any(|i| i.offer_image_id == *id))
+        {
+            Ok(())
+        } else {
+            Err(Status::invalid_argument("offer_image_ids"))
+        }
+    }
+
+    fn facets_to_response(facets: OfferFacets) -> OffersFacets {
+        let to_response = |counts: Vec<OfferFacetCount>| {
+            counts
+                .into_iter()
+                .map(|c| OffersFacetCount {
+                    value: c.value,
+                    count: c.count.try_into().unwrap_or_default(),
+                })
+                .collect()
+        };
+
+        OffersFacets {
+            types: to_response(facets.types),
+            price_buckets: facets
+                .price_buckets
+                .into_iter()
+                .map(|b| OffersPriceBucketCount {
+                    min_price: b.min_price,
+                    max_price: b.max_price,
+                    count: b.count.try_into().unwrap_or_default(),
+                })
+                .collect(),
+            tags: to_response(facets.tags),
+            is_featured: to_response(facets.is_featured),
+        }
+    }
+
+    /// Price bucket bounds have to be ascending. Without bounds the default
+    /// bounds are converted to the minor unit of the currency the prices are
+    /// compared in, which is EUR without a preferred currency.
+    fn get_facet_price_bounds(
+        bounds: Vec<u32>,
+        currency: Option<Currency>,
+    ) -> Result<Vec<u32>, Status> {
+        if bounds.is_empty() {
+            let factor = 10u32.pow(get_minor_unit_exponent(
+                currency.unwrap_or(Currency::Eur),
+            ));
+            return Ok(Self::DEFAULT_FACET_PRICE_BOUNDS
+                .iter()
+                .map(|b| b * factor)
+                .collect());
+        }
+
+        if bounds.len() > Self::MAX_FACET_PRICE_BOUNDS
+            || bounds.windows(2).any(|w| w[0] >= w[1])
+        {
+            return Err(Status::invalid_argument("facet_price_bounds"));
+        }
+
+        Ok(bounds)
+    }
+
+    fn get_filter_group(
+        group: OffersFilterGroup,
+    ) -> Result<FilterGroup<OffersFilterField>, Status> {
+        Ok(FilterGroup {
+            operator: get_filter_operator(group.operator)?,
+            filters: group
+                .filters
+                .into_iter()
+                .map(|f| (f.field(), f.query))
+                .collect(),
+            groups: group
+                .groups
+                .into_iter()
+                .map(Self::get_filter_group)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn parse_uuids(ids: &[String], field: &str) -> Result<Vec<Uuid>, Status> {
+        let mut uuids = Vec::with_capacity(ids.len());
+        for id in ids {
+            uuids.push(parse_uuid(id, field)?);
+        }
+        Ok(uuids)
+    }
+
     fn build_image_path(
         user_id: &String,
         shop_id: &Uuid,
@@ -192,12 +814,19 @@ impl offer_service_server::OfferService for OfferService {
             description,
             r#type,
             is_featured,
+            tax_category,
+            weight_grams,
         } = request.into_inner();
 
         let shop_id = parse_uuid(&shop_id, "shop_id")?;
 
         let type_ = Self::get_offer_type(r#type)?;
 
+        let tax_category = match tax_category {
+            Some(c) => Self::get_tax_category(c)?,
+            None => TaxCategory::Standard,
+        };
+
         let created_offer = Offer::create(
             &self.pool,
             shop_id,
@@ -206,6 +835,8 @@ impl offer_service_server::OfferService for OfferService {
             description,
             type_.as_str_name(),
             is_featured,
+            tax_category.as_str_name(),
+            weight_grams,
         )
         .await?;
 
@@ -224,11 +855,27 @@ impl offer_service_server::OfferService for OfferService {
     ) -> Result<Response<GetOfferResponse>, Status> {
         let user_id =
             get_user_id(request.metadata(), &self.verifier).await.ok();
-        let offer_id = parse_uuid(&request.into_inner().offer_id, "offer_id")?;
 
-        let found_offer = Offer::get(&self.pool, &offer_id, user_id.as_ref())
-            .await?
-            .ok_or(Status::not_found(""))?;
+        let GetOfferRequest {
+            offer_id,
+            preferred_currency,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let preferred_currency = match preferred_currency {
+            Some(c) => Some(Self::get_currency(c)?),
+            None => None,
+        };
+
+        let mut found_offer =
+            Offer::get(&self.pool, &offer_id, user_id.as_ref())
+                .await?
+                .ok_or(Status::not_found(""))?;
+
+        if let Some(preferred_currency) = preferred_currency {
+            found_offer
+                .set_preferred_currency(preferred_currency.as_str_name());
+        }
 
         Ok(Response::new(GetOfferResponse {
             offer: Some(self.offer_to_response(found_offer)?),
@@ -245,6 +892,7 @@ impl offer_service_server::OfferService for OfferService {
 
         let mut req = Request::new(GetOfferRequest {
             offer_id: request.into_inner().offer_id,
+            preferred_currency: None,
         });
 
         *req.metadata_mut() = metadata;
@@ -274,33 +922,82 @@ impl offer_service_server::OfferService for OfferService {
             pagination,
             filter,
             order_by,
+            preferred_currency,
+            filters,
+            filter_group,
+            order_bys,
+            with_facets,
+            facet_price_bounds,
+            random_seed,
         } = request.into_inner();
 
-        let (limit, offset, mut pagination) =
-            get_limit_offset_from_pagination(pagination)?;
-
-        if filter.is_none() && order_by.is_none() && shop_id.is_none() {
-            return Err(Status::invalid_argument("filter,order_by"));
-        }
-
-        let filter = filter.map(|f| (f.field(), f.query));
+        let (limit, offset, cursor, mut pagination) =
+            get_limit_offset_cursor_from_pagination(pagination)?;
+
+        // the filters and the filter group all have to match
+        let filter = FilterGroup {
+            operator: FilterOperator::And,
+            filters: filter
+                .into_iter()
+                .chain(filters)
+                .map(|f| (f.field(), f.query))
+                .collect(),
+            groups: match filter_group {
+                Some(group) => vec![Self::get_filter_group(group)?],
+                None => Vec::new(),
+            },
+        };
+        validate_filter_group(&filter)?;
+        validate_random_seed(random_seed.as_ref())?;
 
-        let order_by = order_by.map(|o| (o.field(), o.direction()));
+        let order_by = order_by
+            .into_iter()
+            .chain(order_bys)
+            .map(|o| (o.field(), o.direction()))
+            .collect();
 
         let shop_id = match shop_id {
             Some(id) => Some(parse_uuid(&id, "shop_id")?),
             None => None,
         };
 
-        let (found_offers, count) = Offer::list(
+        let preferred_currency = match preferred_currency {
+            Some(c) => Some(Self::get_currency(c)?),
+            None => None,
+        };
+
+        let facets = if with_facets.unwrap_or(false) {
+            let price_bounds = Self::get_facet_price_bounds(
+                facet_price_bounds,
+                preferred_currency,
+            )?;
+            let facets = Offer::list_facets(
+                &self.pool,
+                shop_id,
+                user_id.as_ref(),
+                &filter,
+                request_user_id.as_ref(),
+                preferred_currency.map(|c| c.as_str_name()),
+                &price_bounds,
+            )
+            .await?;
+            Some(Self::facets_to_response(facets))
+        } else {
+            None
+        };
+
+        let (found_offers, count, next_cursor) = Offer::list(
             &self.pool,
             shop_id,
             user_id.as_ref(),
             limit.into(),
             offset.into(),
+            cursor,
             filter,
             order_by,
+            random_seed.as_deref(),
             request_user_id.as_ref(),
+            preferred_currency.map(|c| c.as_str_name()),
         )
         .await?;
 
@@ -312,10 +1009,57 @@ impl offer_service_server::OfferService for OfferService {
 
         pagination.total_elements =
             count.try_into().map_err(|_| Status::internal(""))?;
+        pagination.next_cursor = next_cursor.map(|c| c.encode());
 
         Ok(Response::new(ListOffersResponse {
             offers,
             pagination: Some(pagination),
+            facets,
+        }))
+    }
+
+    async fn autocomplete_offers(
+        &self,
+        request: Request<AutocompleteOffersRequest>,
+    ) -> Result<Response<AutocompleteOffersResponse>, Status> {
+        let request_user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let AutocompleteOffersRequest {
+            shop_id,
+            query,
+            limit,
+        } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(Status::invalid_argument("query"));
+        }
+
+        let limit = limit.unwrap_or(Self::DEFAULT_AUTOCOMPLETE_LIMIT);
+        if !(1..=Self::MAX_AUTOCOMPLETE_LIMIT).contains(&limit) {
+            return Err(Status::invalid_argument("limit"));
+        }
+
+        let suggestions = Offer::autocomplete(
+            &self.pool,
+            &shop_id,
+            query,
+            limit.into(),
+            request_user_id.as_ref(),
+        )
+        .await?;
+
+        Ok(Response::new(AutocompleteOffersResponse {
+            suggestions: suggestions
+                .into_iter()
+                .map(|(offer_id, name)| OfferNameSuggestion {
+                    offer_id: offer_id.to_string(),
+                    name,
+                })
+                .collect(),
         }))
     }
 
@@ -332,6 +1076,9 @@ impl offer_service_server::OfferService for OfferService {
             is_active,
             r#type,
             is_featured,
+            inventory_policy,
+            tax_category,
+            weight_grams,
         } = request.into_inner();
 
         let offer_id = parse_uuid(&offer_id, "offer_id")?;
@@ -341,6 +1088,16 @@ impl offer_service_server::OfferService for OfferService {
             None => None,
         };
 
+        let inventory_policy = match inventory_policy {
+            Some(p) => Some(Self::get_inventory_policy(p)?.as_str_name()),
+            None => None,
+        };
+
+        let tax_category = match tax_category {
+            Some(c) => Some(Self::get_tax_category(c)?.as_str_name()),
+            None => None,
+        };
+
         let updated_offer = Offer::update(
             &self.pool,
             &user_id,
@@ -350,6 +1107,9 @@ impl offer_service_server::OfferService for OfferService {
             is_active,
             type_,
             is_featured,
+            inventory_policy,
+            tax_category,
+            weight_grams,
         )
         .await?;
 
@@ -384,7 +1144,10 @@ impl offer_service_server::OfferService for OfferService {
             .await?;
 
             self.image_service
-                .remove_image(&found_image.image_url_path)
+                .remove_image(
+                    &found_image.image_url_path,
+                    &found_image.variants,
+                )
                 .await?;
         }
 
@@ -445,8 +1208,12 @@ impl offer_service_server::OfferService for OfferService {
         )
         .await?;
 
-        self.image_service
-            .put_image(image_path, &image.data)
+        let variants = self
+            .image_service
+            .put_image(image_path, &image.data, ImageKind::Offer)
+            .await?;
+
+        OfferImage::update_variants(&transaction, &offer_image_id, &variants)
             .await?;
 
         transaction.commit().await.map_err(DbError::from)?;
@@ -474,7 +1241,7 @@ impl offer_service_server::OfferService for OfferService {
         OfferImage::delete(&transaction, &user_id, &offer_image_id).await?;
 
         self.image_service
-            .remove_image(&offer_image.image_url_path)
+            .remove_image(&offer_image.image_url_path, &offer_image.variants)
             .await?;
 
         transaction.commit().await.map_err(DbError::from)?;
@@ -493,67 +1260,831 @@ impl offer_service_server::OfferService for OfferService {
         let offer_id = parse_uuid(&offer_id, "offer_id")?;
         let price = price.ok_or(Status::invalid_argument("price"))?;
 
-        Self::validate_price(&price)?;
+        self.put_price(&user_id, &offer_id, None, price).await?;
 
-        let currency = price.currency().as_str_name();
-        let price_type = price.price_type().as_str_name();
-        let billing_scheme = price.billing_scheme().as_str_name();
-        let unit_amount = price.unit_amount;
-        let recurring_interval =
-            price.recurring.as_ref().map(|r| r.interval().as_str_name());
-        let recurring_interval_count =
-            price.recurring.as_ref().map(|r| r.interval_count);
-        let trial_period_days =
-            price.recurring.and_then(|r| r.trial_period_days);
+        Ok(Response::new(PutPriceToOfferResponse {}))
+    }
+
+    async fn remove_price_from_offer(
+        &self,
+        request: Request<RemovePriceFromOfferRequest>,
+    ) -> Result<Response<RemovePriceFromOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
 
-        let found_price =
-            OfferPrice::get_by_offer_id(&self.pool, &offer_id).await?;
+        let RemovePriceFromOfferRequest { offer_id, currency } =
+            request.into_inner();
 
-        if found_price.is_some() {
-            OfferPrice::put(
-                &self.pool,
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let currency = match currency {
+            Some(c) => Some(Self::get_currency(c)?),
+            None => None,
+        };
+
+        OfferPrice::delete(
+            &self.pool,
+            &user_id,
+            &offer_id,
+            None,
+            currency.map(|c| c.as_str_name()),
+        )
+        .await?;
+
+        Ok(Response::new(RemovePriceFromOfferResponse {}))
+    }
+
+    async fn put_shipping_profile_to_offer(
+        &self,
+        request: Request<PutShippingProfileToOfferRequest>,
+    ) -> Result<Response<PutShippingProfileToOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutShippingProfileToOfferRequest {
+            offer_id,
+            shipping_profile_id,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let shipping_profile_id =
+            parse_uuid(&shipping_profile_id, "shipping_profile_id")?;
+
+        let offer = Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        // profiles are shared by the offers of a single shop
+        ShippingProfile::get(&self.pool, &shipping_profile_id)
+            .await?
+            .filter(|p| p.shop_id == offer.shop_id)
+            .ok_or_else(|| Status::not_found("shipping_profile"))?;
+
+        ShippingProfile::put_offer(
+            &self.pool,
+            &offer_id,
+            &user_id,
+            Some(&shipping_profile_id),
+        )
+        .await?;
+
+        self.publish_offer_for_user(&user_id, &offer_id).await?;
+
+        Ok(Response::new(PutShippingProfileToOfferResponse {}))
+    }
+
+    async fn remove_shipping_profile_from_offer(
+        &self,
+        request: Request<RemoveShippingProfileFromOfferRequest>,
+    ) -> Result<Response<RemoveShippingProfileFromOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let RemoveShippingProfileFromOfferRequest { offer_id } =
+            request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+
+        if !ShippingProfile::put_offer(&self.pool, &offer_id, &user_id, None)
+            .await?
+        {
+            return Err(Status::not_found("offer"));
+        }
+
+        self.publish_offer_for_user(&user_id, &offer_id).await?;
+
+        Ok(Response::new(RemoveShippingProfileFromOfferResponse {}))
+    }
+
+    async fn add_category_to_offer(
+        &self,
+        request: Request<AddCategoryToOfferRequest>,
+    ) -> Result<Response<AddCategoryToOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let AddCategoryToOfferRequest {
+            offer_id,
+            category_id,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let category_id = parse_uuid(&category_id, "category_id")?;
+
+        Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        Category::get(&self.pool, &category_id)
+            .await?
+            .ok_or_else(|| Status::not_found("category"))?;
+
+        Category::add_offer(&self.pool, &offer_id, &category_id).await?;
+
+        self.publish_offer_for_user(&user_id, &offer_id).await?;
+
+        Ok(Response::new(AddCategoryToOfferResponse {}))
+    }
+
+    async fn remove_category_from_offer(
+        &self,
+        request: Request<RemoveCategoryFromOfferRequest>,
+    ) -> Result<Response<RemoveCategoryFromOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let RemoveCategoryFromOfferRequest {
+            offer_id,
+            category_id,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let category_id = parse_uuid(&category_id, "category_id")?;
+
+        Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        if !Category::remove_offer(&self.pool, &offer_id, &category_id).await? {
+            return Err(Status::not_found("category"));
+        }
+
+        self.publish_offer_for_user(&user_id, &offer_id).await?;
+
+        Ok(Response::new(RemoveCategoryFromOfferResponse {}))
+    }
+
+    async fn put_tags_to_offer(
+        &self,
+        request: Request<PutTagsToOfferRequest>,
+    ) -> Result<Response<PutTagsToOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutTagsToOfferRequest { offer_id, tags } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+
+        let mut normalized_tags: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags.iter() {
+            let tag = OfferTag::normalize(tag);
+            if tag.is_empty() {
+                return Err(Status::invalid_argument("tags"));
+            }
+            if !normalized_tags.contains(&tag) {
+                normalized_tags.push(tag);
+            }
+        }
+
+        Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        OfferTag::put(&self.pool, &offer_id, &normalized_tags).await?;
+
+        self.publish_offer_for_user(&user_id, &offer_id).await?;
+
+        Ok(Response::new(PutTagsToOfferResponse {}))
+    }
+
+    async fn put_attributes_to_offer(
+        &self,
+        request: Request<PutAttributesToOfferRequest>,
+    ) -> Result<Response<PutAttributesToOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutAttributesToOfferRequest {
+            offer_id,
+            attributes,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+
+        let mut offer_attributes: Vec<OfferAttributeAsRel> =
+            Vec::with_capacity(attributes.len());
+        for attribute in attributes {
+            let key = attribute.key.trim().to_string();
+            // `=` separates key and value in filters
+            if key.is_empty()
+                || key.contains('=')
+                || offer_attributes.iter().any(|a| a.key == key)
+            {
+                return Err(Status::invalid_argument("attributes.key"));
+            }
+
+            if attribute.attribute_type < 1 {
+                return Err(Status::invalid_argument(
+                    "attributes.attribute_type",
+                ));
+            }
+            let attribute_type = OfferAttributeType::from_i32(
+                attribute.attribute_type,
+            )
+            .ok_or(Status::invalid_argument("attributes.attribute_type"))?;
+
+            let value = OfferAttributeAsRel::normalize_value(
+                attribute_type,
+                &attribute.value,
+            )
+            .ok_or(Status::invalid_argument("attributes.value"))?;
+
+            offer_attributes.push(OfferAttributeAsRel {
+                key,
+                attribute_type: attribute_type.as_str_name().to_string(),
+                value,
+            });
+        }
+
+        Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        OfferAttributeAsRel::put(&self.pool, &offer_id, &offer_attributes)
+            .await?;
+
+        self.publish_offer_for_user(&user_id, &offer_id).await?;
+
+        Ok(Response::new(PutAttributesToOfferResponse {}))
+    }
+
+    async fn add_option_to_offer(
+        &self,
+        request: Request<AddOptionToOfferRequest>,
+    ) -> Result<Response<AddOptionToOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let AddOptionToOfferRequest {
+            offer_id,
+            name,
+            ordering,
+            values,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+
+        if name.trim().is_empty() {
+            return Err(Status::invalid_argument("name"));
+        }
+
+        let offer = Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        // existing variants get the first value of the new option, so they
+        // keep selecting one value of every option
+        if !offer.variants.is_empty() && values.is_empty() {
+            return Err(Status::failed_precondition("values"));
+        }
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let offer_option = OfferOption::create(
+            &transaction,
+            &offer_id,
+            &user_id,
+            &name,
+            ordering,
+        )
+        .await?;
+
+        for (value_ordering, value) in values.iter().enumerate() {
+            let offer_option_value = OfferOptionValue::create(
+                &transaction,
+                &offer_option.offer_option_id,
+                &offer_id,
+                &user_id,
+                value,
+                i64::try_from(value_ordering)
+                    .map_err(|_| Status::invalid_argument("values"))?,
+            )
+            .await?;
+
+            if value_ordering == 0 && !offer.variants.is_empty() {
+                OfferVariant::add_option_value_to_all(
+                    &transaction,
+                    &offer_id,
+                    &offer_option_value.offer_option_value_id,
+                )
+                .await?;
+            }
+        }
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(AddOptionToOfferResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &offer_id).await?,
+            ),
+        }))
+    }
+
+    async fn update_offer_option(
+        &self,
+        request: Request<UpdateOfferOptionRequest>,
+    ) -> Result<Response<UpdateOfferOptionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let UpdateOfferOptionRequest {
+            offer_option_id,
+            name,
+            ordering,
+        } = request.into_inner();
+
+        let offer_option_id = parse_uuid(&offer_option_id, "offer_option_id")?;
+
+        OfferOption::get(&self.pool, &offer_option_id, &user_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer_option"))?;
+
+        let updated_option = OfferOption::update(
+            &self.pool,
+            &user_id,
+            &offer_option_id,
+            name,
+            ordering,
+        )
+        .await?;
+
+        Ok(Response::new(UpdateOfferOptionResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &updated_option.offer_id)
+                    .await?,
+            ),
+        }))
+    }
+
+    async fn remove_option_from_offer(
+        &self,
+        request: Request<RemoveOptionFromOfferRequest>,
+    ) -> Result<Response<RemoveOptionFromOfferResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let offer_option_id = parse_uuid(
+            &request.into_inner().offer_option_id,
+            "offer_option_id",
+        )?;
+
+        OfferOption::get(&self.pool, &offer_option_id, &user_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer_option"))?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        OfferVariant::delete_for_option(
+            &transaction,
+            &user_id,
+            &offer_option_id,
+        )
+        .await?;
+
+        let deleted_option =
+            OfferOption::delete(&transaction, &user_id, &offer_option_id)
+                .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(RemoveOptionFromOfferResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &deleted_option.offer_id)
+                    .await?,
+            ),
+        }))
+    }
+
+    async fn add_value_to_offer_option(
+        &self,
+        request: Request<AddValueToOfferOptionRequest>,
+    ) -> Result<Response<AddValueToOfferOptionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let AddValueToOfferOptionRequest {
+            offer_option_id,
+            value,
+            ordering,
+        } = request.into_inner();
+
+        let offer_option_id = parse_uuid(&offer_option_id, "offer_option_id")?;
+
+        if value.trim().is_empty() {
+            return Err(Status::invalid_argument("value"));
+        }
+
+        let offer_option =
+            OfferOption::get(&self.pool, &offer_option_id, &user_id)
+                .await?
+                .ok_or_else(|| Status::not_found("offer_option"))?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        OfferOptionValue::create(
+            &transaction,
+            &offer_option_id,
+            &offer_option.offer_id,
+            &user_id,
+            &value,
+            ordering,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(AddValueToOfferOptionResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &offer_option.offer_id)
+                    .await?,
+            ),
+        }))
+    }
+
+    async fn remove_value_from_offer_option(
+        &self,
+        request: Request<RemoveValueFromOfferOptionRequest>,
+    ) -> Result<Response<RemoveValueFromOfferOptionResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let offer_option_value_id = parse_uuid(
+            &request.into_inner().offer_option_value_id,
+            "offer_option_value_id",
+        )?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        OfferVariant::delete_for_option_value(
+            &transaction,
+            &user_id,
+            &offer_option_value_id,
+        )
+        .await?;
+
+        let deleted_value = OfferOptionValue::delete(
+            &transaction,
+            &user_id,
+            &offer_option_value_id,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(RemoveValueFromOfferOptionResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &deleted_value.offer_id)
+                    .await?,
+            ),
+        }))
+    }
+
+    async fn create_offer_variant(
+        &self,
+        request: Request<CreateOfferVariantRequest>,
+    ) -> Result<Response<CreateOfferVariantResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let CreateOfferVariantRequest {
+            offer_id,
+            sku,
+            price,
+            is_active,
+            offer_option_value_ids,
+            offer_image_ids,
+        } = request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let mut offer_option_value_ids = Self::parse_uuids(
+            &offer_option_value_ids,
+            "offer_option_value_ids",
+        )?;
+        let offer_image_ids =
+            Self::parse_uuids(&offer_image_ids, "offer_image_ids")?;
+
+        if sku.trim().is_empty() {
+            return Err(Status::invalid_argument("sku"));
+        }
+
+        if let Some(price) = price.as_ref() {
+            Self::validate_price(price)?;
+        }
+
+        let offer = Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        Self::validate_variant_option_values(&offer, &offer_option_value_ids)?;
+        Self::validate_variant_images(&offer, &offer_image_ids)?;
+
+        offer_option_value_ids.sort();
+        if offer.variants.iter().any(|v| {
+            let mut ids = v.offer_option_value_ids.clone();
+            ids.sort();
+            ids == offer_option_value_ids
+        }) {
+            return Err(Status::already_exists("offer_option_value_ids"));
+        }
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let created_variant = OfferVariant::create(
+            &transaction,
+            &offer_id,
+            &user_id,
+            &sku,
+            is_active,
+        )
+        .await?;
+
+        OfferVariant::add_option_values(
+            &transaction,
+            &created_variant.offer_variant_id,
+            &offer_option_value_ids,
+        )
+        .await?;
+
+        OfferVariant::put_images(
+            &transaction,
+            &created_variant.offer_variant_id,
+            &offer_image_ids,
+        )
+        .await?;
+
+        if let Some(price) = price {
+            Self::put_price_in_transaction(
+                &transaction,
                 &user_id,
                 &offer_id,
-                currency,
-                price_type,
-                billing_scheme,
-                unit_amount,
-                recurring_interval,
-                recurring_interval_count,
-                trial_period_days,
+                Some(&created_variant.offer_variant_id),
+                price,
+                false,
             )
             .await?;
-        } else {
-            OfferPrice::create(
-                &self.pool,
+        }
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(CreateOfferVariantResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &offer_id).await?,
+            ),
+        }))
+    }
+
+    async fn generate_offer_variants(
+        &self,
+        request: Request<GenerateOfferVariantsRequest>,
+    ) -> Result<Response<GenerateOfferVariantsResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let GenerateOfferVariantsRequest { offer_id, price } =
+            request.into_inner();
+
+        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+
+        if let Some(price) = price.as_ref() {
+            Self::validate_price(price)?;
+        }
+
+        let offer = Offer::get_for_user(&self.pool, &user_id, &offer_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer"))?;
+
+        if offer.options.is_empty() {
+            return Err(Status::failed_precondition("offer.options"));
+        }
+
+        // cartesian product of all option values, in option and value ordering
+        let mut combinations: Vec<Vec<(Uuid, &String)>> = vec![Vec::new()];
+        for option in offer.options.iter() {
+            let mut next = Vec::new();
+            for combination in combinations.iter() {
+                for value in option.values.iter() {
+                    let mut combination = combination.clone();
+                    combination
+                        .push((value.offer_option_value_id, &value.value));
+                    next.push(combination);
+                }
+            }
+            combinations = next;
+        }
+
+        let existing: Vec<Vec<Uuid>> = offer
+            .variants
+            .iter()
+            .map(|v| {
+                let mut ids = v.offer_option_value_ids.clone();
+                ids.sort();
+                ids
+            })
+            .collect();
+        let mut skus: HashSet<String> =
+            offer.variants.iter().map(|v| v.sku.clone()).collect();
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        for combination in combinations {
+            let mut offer_option_value_ids: Vec<Uuid> =
+                combination.iter().map(|(id, _)| *id).collect();
+            offer_option_value_ids.sort();
+
+            if existing.contains(&offer_option_value_ids) {
+                continue;
+            }
+
+            let sku = Self::get_unique_sku(
+                &mut skus,
+                combination
+                    .iter()
+                    .map(|(_, value)| value.as_str())
+                    .collect::<Vec<_>>()
+                    .join("-"),
+            );
+
+            let created_variant = OfferVariant::create(
+                &transaction,
                 &offer_id,
                 &user_id,
-                currency,
-                price_type,
-                billing_scheme,
-                unit_amount,
-                recurring_interval,
-                recurring_interval_count,
-                trial_period_days,
+                &sku,
+                true,
+            )
+            .await?;
+
+            OfferVariant::add_option_values(
+                &transaction,
+                &created_variant.offer_variant_id,
+                &offer_option_value_ids,
             )
             .await?;
+
+            if let Some(price) = price.as_ref() {
+                Self::put_price_in_transaction(
+                    &transaction,
+                    &user_id,
+                    &offer_id,
+                    Some(&created_variant.offer_variant_id),
+                    price.clone(),
+                    false,
+                )
+                .await?;
+            }
         }
 
-        Ok(Response::new(PutPriceToOfferResponse {}))
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(GenerateOfferVariantsResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &offer_id).await?,
+            ),
+        }))
     }
 
-    async fn remove_price_from_offer(
+    async fn update_offer_variant(
         &self,
-        request: Request<RemovePriceFromOfferRequest>,
-    ) -> Result<Response<RemovePriceFromOfferResponse>, Status> {
+        request: Request<UpdateOfferVariantRequest>,
+    ) -> Result<Response<UpdateOfferVariantResponse>, Status> {
         let user_id = get_user_id(request.metadata(), &self.verifier).await?;
 
-        let RemovePriceFromOfferRequest { offer_id } = request.into_inner();
+        let UpdateOfferVariantRequest {
+            offer_variant_id,
+            sku,
+            price,
+            is_active,
+        } = request.into_inner();
 
-        let offer_id = parse_uuid(&offer_id, "offer_id")?;
+        let offer_variant_id =
+            parse_uuid(&offer_variant_id, "offer_variant_id")?;
 
-        OfferPrice::delete(&self.pool, &user_id, &offer_id).await?;
+        if sku.as_ref().is_some_and(|s| s.trim().is_empty()) {
+            return Err(Status::invalid_argument("sku"));
+        }
 
-        Ok(Response::new(RemovePriceFromOfferResponse {}))
+        let offer_variant =
+            OfferVariant::get(&self.pool, &offer_variant_id, &user_id)
+                .await?
+                .ok_or_else(|| Status::not_found("offer_variant"))?;
+
+        let price_exists = match price.as_ref() {
+            Some(price) => {
+                Self::validate_price(price)?;
+
+                OfferPrice::get_by_offer_id(
+                    &self.pool,
+                    &offer_variant.offer_id,
+                    Some(&offer_variant_id),
+                    price.currency().as_str_name(),
+                )
+                .await?
+                .is_some()
+            }
+            None => false,
+        };
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        if sku.is_some() || is_active.is_some() {
+            OfferVariant::update(
+                &transaction,
+                &user_id,
+                &offer_variant_id,
+                sku,
+                is_active,
+            )
+            .await?;
+        }
+
+        if let Some(price) = price {
+            Self::put_price_in_transaction(
+                &transaction,
+                &user_id,
+                &offer_variant.offer_id,
+                Some(&offer_variant_id),
+                price,
+                price_exists,
+            )
+            .await?;
+        }
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(UpdateOfferVariantResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &offer_variant.offer_id)
+                    .await?,
+            ),
+        }))
+    }
+
+    async fn put_images_to_offer_variant(
+        &self,
+        request: Request<PutImagesToOfferVariantRequest>,
+    ) -> Result<Response<PutImagesToOfferVariantResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutImagesToOfferVariantRequest {
+            offer_variant_id,
+            offer_image_ids,
+        } = request.into_inner();
+
+        let offer_variant_id =
+            parse_uuid(&offer_variant_id, "offer_variant_id")?;
+        let offer_image_ids =
+            Self::parse_uuids(&offer_image_ids, "offer_image_ids")?;
+
+        let offer_variant =
+            OfferVariant::get(&self.pool, &offer_variant_id, &user_id)
+                .await?
+                .ok_or_else(|| Status::not_found("offer_variant"))?;
+
+        let offer =
+            Offer::get_for_user(&self.pool, &user_id, &offer_variant.offer_id)
+                .await?
+                .ok_or_else(|| Status::not_found("offer"))?;
+
+        Self::validate_variant_images(&offer, &offer_image_ids)?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        OfferVariant::put_images(
+            &transaction,
+            &offer_variant_id,
+            &offer_image_ids,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(PutImagesToOfferVariantResponse {
+            offer: Some(
+                self.publish_offer_for_user(&user_id, &offer_variant.offer_id)
+                    .await?,
+            ),
+        }))
+    }
+
+    async fn delete_offer_variant(
+        &self,
+        request: Request<DeleteOfferVariantRequest>,
+    ) -> Result<Response<DeleteOfferVariantResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let offer_variant_id = parse_uuid(
+            &request.into_inner().offer_variant_id,
+            "offer_variant_id",
+        )?;
+
+        OfferVariant::get(&self.pool, &offer_variant_id, &user_id)
+            .await?
+            .ok_or_else(|| Status::not_found("offer_variant"))?;
+
+        let deleted_variant =
+            OfferVariant::delete(&self.pool, &user_id, &offer_variant_id)
+                .await?;
+
+        Ok(Response::new(DeleteOfferVariantResponse {
+            offer: Some(
+                self.publish_offer_for_user(
+                    &user_id,
+                    &deleted_variant.offer_id,
+                )
+                .await?,
+            ),
+        }))
     }
 }
@@ -0,0 +1,835 @@
+use deadpool_postgres::{Pool, Transaction};
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::order_service_server::{
+    self, OrderServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    CreateOrderRequest, CreateOrderResponse, Currency, GetOrderRequest,
+    GetOrderResponse, InventoryPolicy, InventoryReservationStatus,
+    ListOrdersRequest, ListOrdersResponse, OrderDirectPurchase,
+    OrderItemResponse, OrderResponse, OrderStatus,
+    OrderStatusTransitionResponse, ShippingCountry, UpdateOrderStatusRequest,
+    UpdateOrderStatusResponse,
+};
+use crate::auth::get_user_id;
+use crate::db::DbError;
+use crate::fees::{AllowedMinimumPlatformFees, FeeBreakdown};
+use crate::images::ImageService;
+use crate::model::{
+    Cart, CartItem, Inventory, InventoryReservation, Offer, Order, OrderItem,
+    OrderStatusTransition, Shop, ShopMinimumPlatformFee,
+};
+use crate::{parse_uuid, OfferService, Publisher};
+
+use super::discount::{DiscountLine, DiscountService};
+use super::{
+    get_item_amounts, get_limit_offset_from_pagination, get_shipping_amount,
+    ShippingLine,
+};
+
+pub struct OrderService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+    publisher: Publisher,
+    image_service: ImageService,
+    allowed_minimum_platform_fees: AllowedMinimumPlatformFees,
+}
+
+/// Line of an order that is about to be created, with names and prices
+/// already taken from the offer.
+struct NewOrderItem {
+    offer_id: Uuid,
+    offer_variant_id: Option<Uuid>,
+    offer_name: String,
+    offer_type: Option<String>,
+    offer_variant_sku: Option<String>,
+    quantity: u32,
+    currency: String,
+    unit_amount: u32,
+    amount: u32,
+    allows_backorder: bool,
+}
+
+impl NewOrderItem {
+    /// Takes name and price from the offer, preferring the price of the
+    /// variant over the price of the offer. Without a currency the default
+    /// price is used.
+    fn from_offer(
+        offer: &Offer,
+        offer_variant_id: Option<Uuid>,
+        quantity: u32,
+        currency: Option<&str>,
+    ) -> Result<Self, Status> {
+        if offer.is_sold_out {
+            return Err(Status::failed_precondition("offer.is_sold_out"));
+        }
+
+        let variant = match offer_variant_id {
+            Some(offer_variant_id) => Some(
+                offer
+                    .variants
+                    .iter()
+                    .find(|v| v.offer_variant_id == offer_variant_id)
+                    .filter(|v| v.is_active)
+                    .ok_or_else(|| Status::not_found("offer_variant"))?,
+            ),
+            None => None,
+        };
+
+        let price = offer
+            .get_price(variant, currency)
+            .ok_or_else(|| Status::failed_precondition("offer.price"))?;
+
+        let (unit_amount, amount) = get_item_amounts(price, quantity)?;
+
+        Ok(Self {
+            offer_id: offer.offer_id,
+            offer_variant_id,
+            offer_name: offer.name.clone(),
+            offer_type: offer.type_.clone(),
+            offer_variant_sku: variant.map(|v| v.sku.clone()),
+            quantity,
+            currency: price.currency.clone(),
+            unit_amount,
+            amount,
+            allows_backorder: offer.inventory_policy
+                == InventoryPolicy::AllowBackorder.as_str_name(),
+        })
+    }
+}
+
+impl OrderService {
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+        publisher: Publisher,
+        image_service: ImageService,
+        allowed_minimum_platform_fees: AllowedMinimumPlatformFees,
+    ) -> OrderServiceServer<Self> {
+        OrderServiceServer::new(Self {
+            pool,
+            verifier,
+            publisher,
+            image_service,
+            allowed_minimum_platform_fees,
+        })
+    }
+
+    /// Takes the ordered quantity from the inventory of the item if its stock
+    /// is tracked and returns the inventory. The inventory row stays locked
+    /// until the transaction ends, so concurrent orders cannot sell the same
+    /// units. Active reservations of the buyer for the inventory are
+    /// committed with the order, so they count towards the ordered quantity
+    /// instead of against it. Any reserved quantity exceeding the ordered
+    /// quantity becomes available again.
+    async fn take_stock<'a>(
+        transaction: &Transaction<'a>,
+        new_item: &NewOrderItem,
+        user_id: &String,
+    ) -> Result<Option<Uuid>, Status> {
+        let inventory = match Inventory::get_for_update(
+            transaction,
+            &new_item.offer_id,
+            new_item.offer_variant_id.as_ref(),
+        )
+        .await?
+        {
+            Some(inventory) => inventory,
+            None => return Ok(None),
+        };
+
+        let reservations = InventoryReservation::list_active_for_update(
+            transaction,
+            &inventory.inventory_id,
+            user_id,
+        )
+        .await?;
+
+        let reserved_by_buyer: i64 =
+            reservations.iter().map(|r| i64::from(r.quantity)).sum();
+
+        if !new_item.allows_backorder
+            && inventory.available() + reserved_by_buyer
+                < i64::from(new_item.quantity)
+        {
+            return Err(Status::failed_precondition("inventory.available"));
+        }
+
+        for reservation in reservations.iter() {
+            InventoryReservation::update_status(
+                transaction,
+                &reservation.inventory_reservation_id,
+                InventoryReservationStatus::Committed.as_str_name(),
+            )
+            .await?;
+        }
+
+        Inventory::decrement_on_hand(
+            transaction,
+            &inventory.inventory_id,
+            new_item.quantity,
+        )
+        .await?;
+
+        Ok(Some(inventory.inventory_id))
+    }
+
+    /// Publishes the offers whose stock changed, as it can flip whether they
+    /// are sold out.
+    async fn publish_offers(&self, mut offer_ids: Vec<Uuid>) {
+        offer_ids.sort();
+        offer_ids.dedup();
+
+        for offer_id in offer_ids.iter() {
+            OfferService::publish_offer(
+                &self.pool,
+                &self.publisher,
+                &self.image_service,
+                offer_id,
+            )
+            .await;
+        }
+    }
+
+    fn parse_status(status: &str) -> Result<OrderStatus, Status> {
+        OrderStatus::from_str_name(status).ok_or(Status::internal(""))
+    }
+
+    fn parse_currency(currency: &str) -> Result<Currency, Status> {
+        Currency::from_str_name(currency).ok_or(Status::internal(format!(
+            "error parsing currency '{currency}'"
+        )))
+    }
+
+    fn parse_shipping_country(
+        shipping_country: Option<i32>,
+    ) -> Result<Option<ShippingCountry>, Status> {
+        match shipping_country {
+            Some(shipping_country) => {
+                if shipping_country < 1 {
+                    return Err(Status::invalid_argument("shipping_country"));
+                }
+                ShippingCountry::from_i32(shipping_country)
+                    .map(Some)
+                    .ok_or(Status::invalid_argument("shipping_country"))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Orders start as pending. Pending orders are either paid or cancelled,
+    /// paid orders are fulfilled, shipped or refunded, and fulfilled or
+    /// shipped orders can still be refunded. Cancelled and refunded orders
+    /// are final.
+    fn is_valid_transition(from: OrderStatus, to: OrderStatus) -> bool {
+        use OrderStatus::*;
+
+        matches!(
+            (from, to),
+            (Pending, Paid)
+                | (Pending, Cancelled)
+                | (Paid, Fulfilled)
+                | (Paid, Shipped)
+                | (Paid, Refunded)
+                | (Fulfilled, Shipped)
+                | (Fulfilled, Refunded)
+                | (Shipped, Refunded)
+        )
+    }
+
+    fn to_response(
+        order: Order,
+        items: Vec<OrderItem>,
+        transitions: Vec<OrderStatusTransition>,
+    ) -> Result<OrderResponse, Status> {
+        let mut item_responses = Vec::with_capacity(items.len());
+        for item in items {
+            item_responses.push(OrderItemResponse {
+                order_item_id: item.order_item_id.to_string(),
+                offer_id: item.offer_id.to_string(),
+                offer_variant_id: item.offer_variant_id.map(|v| v.to_string()),
+                offer_name: item.offer_name,
+                offer_variant_sku: item.offer_variant_sku,
+                quantity: item.quantity,
+                unit_amount: item.unit_amount,
+                currency: Self::parse_currency(&item.currency)?.into(),
+                amount: item.amount,
+            });
+        }
+
+        let mut transition_responses = Vec::with_capacity(transitions.len());
+        for transition in transitions {
+            transition_responses.push(OrderStatusTransitionResponse {
+                from_status: match transition.from_status {
+                    Some(from_status) => {
+                        Some(Self::parse_status(&from_status)?.into())
+                    }
+                    None => None,
+                },
+                to_status: Self::parse_status(&transition.to_status)?.into(),
+                user_id: transition.user_id,
+                created_at: transition.created_at.timestamp(),
+            });
+        }
+
+        Ok(OrderResponse {
+            order_id: order.order_id.to_string(),
+            shop_id: order.shop_id.to_string(),
+            shop_name: order.shop_name,
+            user_id: order.user_id,
+            buyer_user_id: order.buyer_user_id,
+            created_at: order.created_at.timestamp(),
+            updated_at: order.updated_at.timestamp(),
+            status: Self::parse_status(&order.status)?.into(),
+            currency: Self::parse_currency(&order.currency)?.into(),
+            subtotal_amount: order.subtotal_amount,
+            shipping_amount: order.shipping_amount,
+            total_amount: order.total_amount,
+            shipping_country: match order.shipping_country {
+                Some(shipping_country) => Some(
+                    ShippingCountry::from_str_name(&shipping_country)
+                        .ok_or(Status::internal(""))?
+                        .into(),
+                ),
+                None => None,
+            },
+            platform_fee_percent: order.platform_fee_percent,
+            minimum_platform_fee_cent: order.minimum_platform_fee_cent,
+            items: item_responses,
+            transitions: transition_responses,
+            platform_fee_amount: order.platform_fee_amount,
+            net_amount: order.net_amount,
+            discount_id: order.discount_id.map(|d| d.to_string()),
+            discount_amount: order.discount_amount,
+        })
+    }
+
+    /// Loads items and transitions of the orders and builds the responses.
+    async fn to_responses(
+        &self,
+        orders: Vec<Order>,
+    ) -> Result<Vec<OrderResponse>, Status> {
+        let order_ids: Vec<Uuid> = orders.iter().map(|o| o.order_id).collect();
+
+        let mut items = OrderItem::list(&self.pool, &order_ids).await?;
+        let mut transitions =
+            OrderStatusTransition::list(&self.pool, &order_ids).await?;
+
+        let mut responses = Vec::with_capacity(orders.len());
+        for order in orders {
+            let (order_items, other_items) = items
+                .into_iter()
+                .partition(|i| i.order_id == order.order_id);
+            items = other_items;
+
+            let (order_transitions, other_transitions) = transitions
+                .into_iter()
+                .partition(|t| t.order_id == order.order_id);
+            transitions = other_transitions;
+
+            responses.push(Self::to_response(
+                order,
+                order_items,
+                order_transitions,
+            )?);
+        }
+
+        Ok(responses)
+    }
+
+    async fn to_single_response(
+        &self,
+        order: Order,
+    ) -> Result<OrderResponse, Status> {
+        self.to_responses(vec![order])
+            .await?
+            .pop()
+            .ok_or_else(|| Status::internal(""))
+    }
+}
+
+#[async_trait]
+impl order_service_server::OrderService for OrderService {
+    async fn create_order(
+        &self,
+        request: Request<CreateOrderRequest>,
+    ) -> Result<Response<CreateOrderResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let CreateOrderRequest {
+            cart_id,
+            direct_purchase,
+            shipping_country,
+            discount_code,
+        } = request.into_inner();
+
+        let shipping_country = Self::parse_shipping_country(shipping_country)?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let (shop_id, new_items, cart_id, cart_discount_id) =
+            match (cart_id, direct_purchase) {
+                (Some(cart_id), None) => {
+                    let cart_id = parse_uuid(&cart_id, "cart_id")?;
+
+                    let cart = Cart::get_for_update(&transaction, &cart_id)
+                        .await?
+                        .ok_or_else(|| Status::not_found("cart"))?;
+
+                    if cart.user_id.as_ref().is_some_and(|u| *u != user_id) {
+                        return Err(Status::not_found("cart"));
+                    }
+
+                    let cart_items =
+                        CartItem::list_for_update(&transaction, &cart_id)
+                            .await?;
+
+                    if cart_items.is_empty() {
+                        return Err(Status::failed_precondition("cart.items"));
+                    }
+
+                    // names and prices are taken from the offers, the buyer
+                    // has to review the cart if a price changed since adding
+                    let mut new_items = Vec::with_capacity(cart_items.len());
+                    for cart_item in cart_items {
+                        let offer =
+                            Offer::get(&self.pool, &cart_item.offer_id, None)
+                                .await?
+                                .ok_or_else(|| Status::not_found("offer"))?;
+
+                        let new_item = NewOrderItem::from_offer(
+                            &offer,
+                            cart_item.offer_variant_id,
+                            cart_item.quantity,
+                            Some(&cart_item.currency),
+                        )?;
+
+                        if new_item.currency != cart_item.currency
+                            || new_item.unit_amount != cart_item.unit_amount
+                            || new_item.amount != cart_item.amount
+                        {
+                            return Err(Status::failed_precondition(
+                                "cart.items.price",
+                            ));
+                        }
+
+                        new_items.push(new_item);
+                    }
+
+                    (cart.shop_id, new_items, Some(cart_id), cart.discount_id)
+                }
+                (None, Some(direct_purchase)) => {
+                    let OrderDirectPurchase {
+                        offer_id,
+                        offer_variant_id,
+                        quantity,
+                        currency,
+                    } = direct_purchase;
+
+                    let offer_id = parse_uuid(&offer_id, "offer_id")?;
+                    let offer_variant_id = match offer_variant_id {
+                        Some(id) => Some(parse_uuid(&id, "offer_variant_id")?),
+                        None => None,
+                    };
+
+                    let currency = match currency {
+                        Some(currency) => Some(
+                            Currency::from_i32(currency)
+                                .filter(|c| *c != Currency::Unspecified)
+                                .ok_or_else(|| {
+                                    Status::invalid_argument(
+                                        "direct_purchase.currency",
+                                    )
+                                })?
+                                .as_str_name(),
+                        ),
+                        None => None,
+                    };
+
+                    if quantity == 0 {
+                        return Err(Status::invalid_argument("quantity"));
+                    }
+
+                    let offer = Offer::get(&self.pool, &offer_id, None)
+                        .await?
+                        .ok_or_else(|| Status::not_found("offer"))?;
+
+                    let new_item = NewOrderItem::from_offer(
+                        &offer,
+                        offer_variant_id,
+                        quantity,
+                        currency,
+                    )?;
+
+                    (offer.shop_id, vec![new_item], None, None)
+                }
+                _ => {
+                    return Err(Status::invalid_argument(
+                        "cart_id,direct_purchase",
+                    ))
+                }
+            };
+
+        let shop = Shop::get(&self.pool, &shop_id, None, false)
+            .await?
+            .ok_or_else(|| Status::not_found("shop"))?;
+
+        let currency = new_items[0].currency.clone();
+        if new_items.iter().any(|i| i.currency != currency) {
+            return Err(Status::failed_precondition("currency"));
+        }
+
+        let subtotal_amount: u64 =
+            new_items.iter().map(|i| u64::from(i.amount)).sum();
+
+        let shipping_lines: Vec<ShippingLine> = new_items
+            .iter()
+            .map(|i| ShippingLine {
+                offer_id: i.offer_id,
+                quantity: i.quantity,
+                amount: i.amount,
+            })
+            .collect();
+        let (shipping_amount, unshippable_offer_ids) = get_shipping_amount(
+            &self.pool,
+            &shipping_lines,
+            shipping_country,
+            &currency,
+        )
+        .await?;
+
+        if !unshippable_offer_ids.is_empty() {
+            return Err(match shipping_country {
+                Some(_) => Status::failed_precondition("shipping_country"),
+                None => Status::invalid_argument("shipping_country"),
+            });
+        }
+
+        // an entered code takes precedence over the discount applied to the
+        // cart, without either the best automatic discount is used
+        let lines: Vec<DiscountLine> = new_items
+            .iter()
+            .map(|i| DiscountLine {
+                offer_id: i.offer_id,
+                offer_type: i.offer_type.clone(),
+                amount: i.amount,
+            })
+            .collect();
+        let discount = DiscountService::resolve(
+            &self.pool,
+            &shop_id,
+            discount_code.as_ref(),
+            cart_discount_id.as_ref(),
+            &lines,
+            &currency,
+            Some(&user_id),
+        )
+        .await?;
+        let discount_amount = discount.as_ref().map(|(_, a)| *a).unwrap_or(0);
+
+        let total_amount = u32::try_from(
+            subtotal_amount - u64::from(discount_amount) + shipping_amount,
+        )
+        .map_err(|_| Status::invalid_argument("quantity"))?;
+
+        // fees are stored with the order, so later changes to the fee
+        // settings of the shop do not alter existing orders
+        let minimum_platform_fee = ShopMinimumPlatformFee::get_for_shop(
+            &self.pool,
+            &shop,
+            Currency::from_str_name(&currency).ok_or_else(|| {
+                Status::internal(format!(
+                    "error parsing currency '{}'",
+                    currency
+                ))
+            })?,
+            &self.allowed_minimum_platform_fees,
+        )
+        .await?;
+        let fees = FeeBreakdown::from_gross_amount(
+            total_amount,
+            shop.platform_fee_percent,
+            minimum_platform_fee,
+        );
+
+        let order = Order::create(
+            &transaction,
+            &shop.shop_id,
+            &shop.name,
+            &shop.user_id,
+            &user_id,
+            OrderStatus::Pending.as_str_name(),
+            &currency,
+            u32::try_from(subtotal_amount)
+                .map_err(|_| Status::invalid_argument("quantity"))?,
+            u32::try_from(shipping_amount)
+                .map_err(|_| Status::invalid_argument("quantity"))?,
+            total_amount,
+            shipping_country.map(|c| c.as_str_name()),
+            shop.platform_fee_percent,
+            minimum_platform_fee,
+            fees.platform_fee_amount,
+            fees.net_amount,
+            discount.as_ref().map(|(d, _)| &d.discount_id),
+            discount_amount,
+        )
+        .await?;
+
+        if let Some((discount, discount_amount)) = &discount {
+            DiscountService::redeem(
+                &transaction,
+                discount,
+                &order.order_id,
+                &user_id,
+                *discount_amount,
+            )
+            .await?;
+        }
+
+        // inventories are locked in a fixed order, so concurrent orders of
+        // the same offers cannot deadlock
+        let mut stock_order: Vec<usize> = (0..new_items.len()).collect();
+        stock_order.sort_by_key(|i| {
+            (new_items[*i].offer_id, new_items[*i].offer_variant_id)
+        });
+        let mut inventory_ids = vec![None; new_items.len()];
+        for i in stock_order {
+            inventory_ids[i] =
+                Self::take_stock(&transaction, &new_items[i], &user_id).await?;
+        }
+
+        for (new_item, inventory_id) in
+            new_items.iter().zip(inventory_ids.iter())
+        {
+            OrderItem::create(
+                &transaction,
+                &order.order_id,
+                &new_item.offer_id,
+                new_item.offer_variant_id.as_ref(),
+                &new_item.offer_name,
+                new_item.offer_variant_sku.as_ref(),
+                new_item.quantity,
+                &new_item.currency,
+                new_item.unit_amount,
+                new_item.amount,
+                inventory_id.as_ref(),
+            )
+            .await?;
+        }
+
+        OrderStatusTransition::create(
+            &transaction,
+            &order.order_id,
+            &user_id,
+            None,
+            OrderStatus::Pending.as_str_name(),
+        )
+        .await?;
+
+        if let Some(cart_id) = cart_id {
+            Cart::delete(&transaction, &cart_id).await?;
+        }
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        self.publish_offers(
+            new_items
+                .iter()
+                .zip(inventory_ids.iter())
+                .filter(|(_, inventory_id)| inventory_id.is_some())
+                .map(|(new_item, _)| new_item.offer_id)
+                .collect(),
+        )
+        .await;
+
+        let order_response = self.to_single_response(order).await?;
+
+        self.publisher
+            .publish_order_transition(&order_response)
+            .await;
+
+        Ok(Response::new(CreateOrderResponse {
+            order: Some(order_response),
+        }))
+    }
+
+    async fn get_order(
+        &self,
+        request: Request<GetOrderRequest>,
+    ) -> Result<Response<GetOrderResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let order_id = parse_uuid(&request.into_inner().order_id, "order_id")?;
+
+        let order = Order::get(&self.pool, &order_id)
+            .await?
+            .filter(|o| o.user_id == user_id || o.buyer_user_id == user_id)
+            .ok_or_else(|| Status::not_found("order"))?;
+
+        Ok(Response::new(GetOrderResponse {
+            order: Some(self.to_single_response(order).await?),
+        }))
+    }
+
+    async fn list_orders(
+        &self,
+        request: Request<ListOrdersRequest>,
+    ) -> Result<Response<ListOrdersResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let ListOrdersRequest {
+            shop_id,
+            status,
+            pagination,
+        } = request.into_inner();
+
+        let (limit, offset, mut pagination) =
+            get_limit_offset_from_pagination(pagination)?;
+
+        let status = match status {
+            Some(status) => {
+                if status < 1 {
+                    return Err(Status::invalid_argument("status"));
+                }
+                Some(
+                    OrderStatus::from_i32(status)
+                        .ok_or(Status::invalid_argument("status"))?
+                        .as_str_name(),
+                )
+            }
+            None => None,
+        };
+
+        // with a shop given the orders of the shop are listed for its owner,
+        // otherwise the orders placed by the user
+        let (orders, count) = match shop_id {
+            Some(shop_id) => {
+                let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+                Shop::get(&self.pool, &shop_id, Some(&user_id), false)
+                    .await?
+                    .filter(|s| s.user_id == user_id)
+                    .ok_or_else(|| Status::not_found("shop"))?;
+
+                Order::list(
+                    &self.pool,
+                    Some(&user_id),
+                    None,
+                    Some(&shop_id),
+                    status,
+                    limit.into(),
+                    offset.into(),
+                )
+                .await?
+            }
+            None => {
+                Order::list(
+                    &self.pool,
+                    None,
+                    Some(&user_id),
+                    None,
+                    status,
+                    limit.into(),
+                    offset.into(),
+                )
+                .await?
+            }
+        };
+
+        pagination.total_elements =
+            count.try_into().map_err(|_| Status::internal(""))?;
+
+        Ok(Response::new(ListOrdersResponse {
+            orders: self.to_responses(orders).await?,
+            pagination: Some(pagination),
+        }))
+    }
+
+    async fn update_order_status(
+        &self,
+        request: Request<UpdateOrderStatusRequest>,
+    ) -> Result<Response<UpdateOrderStatusResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let UpdateOrderStatusRequest { order_id, status } =
+            request.into_inner();
+
+        let order_id = parse_uuid(&order_id, "order_id")?;
+
+        if status < 1 {
+            return Err(Status::invalid_argument("status"));
+        }
+        let status = OrderStatus::from_i32(status)
+            .ok_or(Status::invalid_argument("status"))?;
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let order = Order::get_for_update(&transaction, &order_id)
+            .await?
+            .filter(|o| o.user_id == user_id || o.buyer_user_id == user_id)
+            .ok_or_else(|| Status::not_found("order"))?;
+
+        let current_status = Self::parse_status(&order.status)?;
+
+        if !Self::is_valid_transition(current_status, status) {
+            return Err(Status::failed_precondition("status"));
+        }
+
+        // buyers may only cancel their pending orders
+        if order.user_id != user_id && status != OrderStatus::Cancelled {
+            return Err(Status::permission_denied("status"));
+        }
+
+        let order =
+            Order::update_status(&transaction, &order_id, status.as_str_name())
+                .await?;
+
+        OrderStatusTransition::create(
+            &transaction,
+            &order_id,
+            &user_id,
+            Some(current_status.as_str_name()),
+            status.as_str_name(),
+        )
+        .await?;
+
+        // cancelled orders give their discount redemptions and stock back
+        let mut restocked_offer_ids = Vec::new();
+        if status == OrderStatus::Cancelled {
+            DiscountService::release(&transaction, &order_id).await?;
+
+            for item in
+                OrderItem::list_with_inventory(&transaction, &order_id).await?
+            {
+                if let Some(inventory_id) = item.inventory_id {
+                    Inventory::increment_on_hand(
+                        &transaction,
+                        &inventory_id,
+                        item.quantity,
+                    )
+                    .await?;
+                    restocked_offer_ids.push(item.offer_id);
+                }
+            }
+        }
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        self.publish_offers(restocked_offer_ids).await;
+
+        let order_response = self.to_single_response(order).await?;
+
+        self.publisher
+            .publish_order_transition(&order_response)
+            .await;
+
+        Ok(Response::new(UpdateOrderStatusResponse {
+            order: Some(order_response),
+        }))
+    }
+}
@@ -0,0 +1,517 @@
+use std::collections::HashSet;
+
+use deadpool_postgres::Pool;
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::shipping_profile_service_server::{
+    self, ShippingProfileServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    CreateShippingProfileRequest, CreateShippingProfileResponse, Currency,
+    DeleteShippingProfileRequest, DeleteShippingProfileResponse,
+    DeleteShippingZoneRequest, DeleteShippingZoneResponse,
+    GetShippingProfileRequest, GetShippingProfileResponse,
+    ListShippingProfilesRequest, ListShippingProfilesResponse,
+    PutShippingZoneRequest, PutShippingZoneResponse, ShippingCountry,
+    ShippingProfileResponse, ShippingRule, ShippingRuleType,
+    ShippingZoneResponse, UpdateShippingProfileRequest,
+    UpdateShippingProfileResponse,
+};
+use crate::auth::get_user_id;
+use crate::db::DbError;
+use crate::model::{ShippingProfile, ShippingZone, ShippingZoneRule, Shop};
+use crate::parse_uuid;
+
+pub struct ShippingProfileService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+}
+
+/// Weight, value and number of items of the offers of a cart or order that
+/// ship by the same profile.
+#[derive(Debug, Default)]
+pub(super) struct ShippingTotals {
+    pub weight_grams: u64,
+    pub order_value: u64,
+    pub item_count: u64,
+}
+
+impl ShippingProfileService {
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+    ) -> ShippingProfileServiceServer<Self> {
+        ShippingProfileServiceServer::new(Self { pool, verifier })
+    }
+
+    fn parse_currency(currency: i32) -> Result<Currency, Status> {
+        if currency < 1 {
+            return Err(Status::invalid_argument("currency"));
+        }
+        Currency::from_i32(currency).ok_or(Status::invalid_argument("currency"))
+    }
+
+    fn parse_rules(
+        rules: Vec<ShippingRule>,
+    ) -> Result<Vec<ShippingZoneRule>, Status> {
+        let mut zone_rules = Vec::with_capacity(rules.len());
+
+        for rule in rules {
+            if rule.rule_type < 1 {
+                return Err(Status::invalid_argument("rules.rule_type"));
+            }
+            let rule_type = ShippingRuleType::from_i32(rule.rule_type)
+                .ok_or(Status::invalid_argument("rules.rule_type"))?;
+
+            if rule.max_value.is_some_and(|m| m <= rule.min_value) {
+                return Err(Status::invalid_argument("rules.max_value"));
+            }
+
+            zone_rules.push(ShippingZoneRule {
+                rule_type: rule_type.as_str_name().to_string(),
+                min_value: rule.min_value,
+                max_value: rule.max_value,
+                amount: rule.amount,
+            });
+        }
+
+        Ok(zone_rules)
+    }
+
+    fn to_response(
+        shipping_profile: ShippingProfile,
+        zones: &[ShippingZone],
+    ) -> Result<ShippingProfileResponse, Status> {
+        let currency = Currency::from_str_name(&shipping_profile.currency)
+            .ok_or(Status::internal(format!(
+                "error parsing currency '{}'",
+                shipping_profile.currency
+            )))?;
+
+        let mut zone_responses = Vec::new();
+        for zone in zones.iter().filter(|z| {
+            z.shipping_profile_id == shipping_profile.shipping_profile_id
+        }) {
+            let mut rules = Vec::with_capacity(zone.rules.len());
+            for rule in zone.rules.iter() {
+                let rule_type = ShippingRuleType::from_str_name(
+                    &rule.rule_type,
+                )
+                .ok_or(Status::internal(format!(
+                    "error parsing shipping rule type '{}'",
+                    rule.rule_type
+                )))?;
+
+                rules.push(ShippingRule {
+                    rule_type: rule_type.into(),
+                    min_value: rule.min_value,
+                    max_value: rule.max_value,
+                    amount: rule.amount,
+                });
+            }
+
+            zone_responses.push(ShippingZoneResponse {
+                shipping_zone_id: zone.shipping_zone_id.to_string(),
+                name: zone.name.clone(),
+                countries: zone
+                    .countries
+                    .iter()
+                    .filter_map(|c| ShippingCountry::from_str_name(c))
+                    .map(i32::from)
+                    .collect(),
+                free_shipping_threshold: zone.free_shipping_threshold,
+                rules,
+            });
+        }
+
+        Ok(ShippingProfileResponse {
+            shipping_profile_id: shipping_profile
+                .shipping_profile_id
+                .to_string(),
+            shop_id: shipping_profile.shop_id.to_string(),
+            user_id: shipping_profile.user_id,
+            created_at: shipping_profile.created_at.timestamp(),
+            updated_at: shipping_profile.updated_at.timestamp(),
+            name: shipping_profile.name,
+            currency: currency.into(),
+            zones: zone_responses,
+        })
+    }
+
+    async fn get_response(
+        &self,
+        shipping_profile: ShippingProfile,
+    ) -> Result<ShippingProfileResponse, Status> {
+        let zones = ShippingZone::list(
+            &self.pool,
+            &[shipping_profile.shipping_profile_id],
+        )
+        .await?;
+
+        Self::to_response(shipping_profile, &zones)
+    }
+
+    async fn get_for_user(
+        &self,
+        shipping_profile_id: &Uuid,
+        user_id: &String,
+    ) -> Result<ShippingProfile, Status> {
+        ShippingProfile::get(&self.pool, shipping_profile_id)
+            .await?
+            .filter(|p| p.user_id == *user_id)
+            .ok_or_else(|| Status::not_found("shipping_profile"))
+    }
+
+    /// The zone amount is free once the order value reaches the free
+    /// shipping threshold. Otherwise it is the lowest amount of all rules
+    /// whose band contains the weight, order value or item count, and
+    /// `None` if no rule does.
+    fn get_zone_amount(
+        zone: &ShippingZone,
+        totals: &ShippingTotals,
+    ) -> Option<u32> {
+        if zone
+            .free_shipping_threshold
+            .is_some_and(|t| totals.order_value >= u64::from(t))
+        {
+            return Some(0);
+        }
+
+        zone.rules
+            .iter()
+            .filter(|rule| {
+                let value =
+                    match ShippingRuleType::from_str_name(&rule.rule_type) {
+                        Some(ShippingRuleType::Weight) => totals.weight_grams,
+                        Some(ShippingRuleType::OrderValue) => {
+                            totals.order_value
+                        }
+                        Some(ShippingRuleType::ItemCount) => totals.item_count,
+                        _ => return false,
+                    };
+
+                value >= u64::from(rule.min_value)
+                    && rule.max_value.is_none_or(|m| value < u64::from(m))
+            })
+            .map(|rule| rule.amount)
+            .min()
+    }
+
+    /// Returns the shipping amount of the profile for the given totals, or
+    /// `None` if the profile does not ship to the country or has no rate in
+    /// the currency. The first zone containing the country is used.
+    pub(super) async fn get_amount(
+        pool: &Pool,
+        shipping_profile_id: &Uuid,
+        totals: &ShippingTotals,
+        shipping_country: Option<ShippingCountry>,
+        currency: &str,
+    ) -> Result<Option<u32>, Status> {
+        let Some(shipping_country) = shipping_country else {
+            return Ok(None);
+        };
+
+        let Some(shipping_profile) =
+            ShippingProfile::get(pool, shipping_profile_id).await?
+        else {
+            return Ok(None);
+        };
+
+        if shipping_profile.currency != currency {
+            return Ok(None);
+        }
+
+        let zones = ShippingZone::list(pool, &[*shipping_profile_id]).await?;
+
+        Ok(zones
+            .iter()
+            .find(|z| {
+                z.countries
+                    .iter()
+                    .any(|c| c == shipping_country.as_str_name())
+            })
+            .and_then(|zone| Self::get_zone_amount(zone, totals)))
+    }
+}
+
+#[async_trait]
+impl shipping_profile_service_server::ShippingProfileService
+    for ShippingProfileService
+{
+    async fn create_shipping_profile(
+        &self,
+        request: Request<CreateShippingProfileRequest>,
+    ) -> Result<Response<CreateShippingProfileResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let CreateShippingProfileRequest {
+            shop_id,
+            name,
+            currency,
+        } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        if name.trim().is_empty() {
+            return Err(Status::invalid_argument("name"));
+        }
+
+        let currency = Self::parse_currency(currency)?;
+
+        Shop::get(&self.pool, &shop_id, Some(&user_id), false)
+            .await?
+            .filter(|s| s.user_id == user_id)
+            .ok_or_else(|| Status::not_found("shop"))?;
+
+        let created_shipping_profile = ShippingProfile::create(
+            &self.pool,
+            &shop_id,
+            &user_id,
+            &name,
+            currency.as_str_name(),
+        )
+        .await?;
+
+        Ok(Response::new(CreateShippingProfileResponse {
+            shipping_profile: Some(Self::to_response(
+                created_shipping_profile,
+                &[],
+            )?),
+        }))
+    }
+
+    async fn get_shipping_profile(
+        &self,
+        request: Request<GetShippingProfileRequest>,
+    ) -> Result<Response<GetShippingProfileResponse>, Status> {
+        let GetShippingProfileRequest {
+            shipping_profile_id,
+        } = request.into_inner();
+
+        let shipping_profile_id =
+            parse_uuid(&shipping_profile_id, "shipping_profile_id")?;
+
+        let found_shipping_profile =
+            ShippingProfile::get(&self.pool, &shipping_profile_id)
+                .await?
+                .ok_or_else(|| Status::not_found("shipping_profile"))?;
+
+        Ok(Response::new(GetShippingProfileResponse {
+            shipping_profile: Some(
+                self.get_response(found_shipping_profile).await?,
+            ),
+        }))
+    }
+
+    async fn list_shipping_profiles(
+        &self,
+        request: Request<ListShippingProfilesRequest>,
+    ) -> Result<Response<ListShippingProfilesResponse>, Status> {
+        let ListShippingProfilesRequest { shop_id } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        let found_shipping_profiles =
+            ShippingProfile::list(&self.pool, &shop_id).await?;
+
+        let zones = if found_shipping_profiles.is_empty() {
+            Vec::new()
+        } else {
+            ShippingZone::list(
+                &self.pool,
+                &found_shipping_profiles
+                    .iter()
+                    .map(|p| p.shipping_profile_id)
+                    .collect::<Vec<_>>(),
+            )
+            .await?
+        };
+
+        let mut shipping_profiles =
+            Vec::with_capacity(found_shipping_profiles.len());
+        for shipping_profile in found_shipping_profiles {
+            shipping_profiles
+                .push(Self::to_response(shipping_profile, &zones)?);
+        }
+
+        Ok(Response::new(ListShippingProfilesResponse {
+            shipping_profiles,
+        }))
+    }
+
+    async fn update_shipping_profile(
+        &self,
+        request: Request<UpdateShippingProfileRequest>,
+    ) -> Result<Response<UpdateShippingProfileResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let UpdateShippingProfileRequest {
+            shipping_profile_id,
+            name,
+            currency,
+        } = request.into_inner();
+
+        let shipping_profile_id =
+            parse_uuid(&shipping_profile_id, "shipping_profile_id")?;
+
+        if name.as_ref().is_some_and(|n| n.trim().is_empty()) {
+            return Err(Status::invalid_argument("name"));
+        }
+
+        let currency = match currency {
+            Some(c) => Some(Self::parse_currency(c)?.as_str_name()),
+            None => None,
+        };
+
+        let updated_shipping_profile = if name.is_none() && currency.is_none() {
+            self.get_for_user(&shipping_profile_id, &user_id).await?
+        } else {
+            ShippingProfile::update(
+                &self.pool,
+                &shipping_profile_id,
+                &user_id,
+                name,
+                currency,
+            )
+            .await?
+            .ok_or_else(|| Status::not_found("shipping_profile"))?
+        };
+
+        Ok(Response::new(UpdateShippingProfileResponse {
+            shipping_profile: Some(
+                self.get_response(updated_shipping_profile).await?,
+            ),
+        }))
+    }
+
+    async fn delete_shipping_profile(
+        &self,
+        request: Request<DeleteShippingProfileRequest>,
+    ) -> Result<Response<DeleteShippingProfileResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let DeleteShippingProfileRequest {
+            shipping_profile_id,
+        } = request.into_inner();
+
+        let shipping_profile_id =
+            parse_uuid(&shipping_profile_id, "shipping_profile_id")?;
+
+        // offers of the profile are left without one and ship for free
+        // unless they have a shipping rate of their own
+        ShippingProfile::delete(&self.pool, &shipping_profile_id, &user_id)
+            .await?
+            .ok_or_else(|| Status::not_found("shipping_profile"))?;
+
+        Ok(Response::new(DeleteShippingProfileResponse {}))
+    }
+
+    async fn put_shipping_zone(
+        &self,
+        request: Request<PutShippingZoneRequest>,
+    ) -> Result<Response<PutShippingZoneResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutShippingZoneRequest {
+            shipping_profile_id,
+            shipping_zone_id,
+            name,
+            countries,
+            free_shipping_threshold,
+            rules,
+        } = request.into_inner();
+
+        let shipping_profile_id =
+            parse_uuid(&shipping_profile_id, "shipping_profile_id")?;
+        let shipping_zone_id = match shipping_zone_id {
+            Some(id) => Some(parse_uuid(&id, "shipping_zone_id")?),
+            None => None,
+        };
+
+        if name.trim().is_empty() {
+            return Err(Status::invalid_argument("name"));
+        }
+
+        if countries.is_empty() {
+            return Err(Status::invalid_argument("countries"));
+        }
+        let mut zone_countries = HashSet::with_capacity(countries.len());
+        for country in countries {
+            if country < 1 {
+                return Err(Status::invalid_argument("countries"));
+            }
+            let country = ShippingCountry::from_i32(country)
+                .ok_or(Status::invalid_argument("countries"))?;
+            zone_countries.insert(country.as_str_name());
+        }
+        let mut zone_countries: Vec<&str> =
+            zone_countries.into_iter().collect();
+        zone_countries.sort();
+
+        let rules = Self::parse_rules(rules)?;
+
+        let shipping_profile =
+            self.get_for_user(&shipping_profile_id, &user_id).await?;
+
+        // a country belongs to a single zone of the profile
+        let zones =
+            ShippingZone::list(&self.pool, &[shipping_profile_id]).await?;
+        if zones.iter().any(|z| {
+            Some(z.shipping_zone_id) != shipping_zone_id
+                && z.countries
+                    .iter()
+                    .any(|c| zone_countries.contains(&c.as_str()))
+        }) {
+            return Err(Status::failed_precondition("countries"));
+        }
+
+        let mut conn = self.pool.get().await.map_err(DbError::from)?;
+        let transaction = conn.transaction().await.map_err(DbError::from)?;
+
+        let put_shipping_zone_id = ShippingZone::put(
+            &transaction,
+            &shipping_profile_id,
+            shipping_zone_id.as_ref(),
+            &user_id,
+            &name,
+            free_shipping_threshold,
+        )
+        .await?
+        .ok_or_else(|| Status::not_found("shipping_zone"))?;
+
+        ShippingZone::put_countries_and_rules(
+            &transaction,
+            &put_shipping_zone_id,
+            &zone_countries,
+            &rules,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(DbError::from)?;
+
+        Ok(Response::new(PutShippingZoneResponse {
+            shipping_profile: Some(self.get_response(shipping_profile).await?),
+        }))
+    }
+
+    async fn delete_shipping_zone(
+        &self,
+        request: Request<DeleteShippingZoneRequest>,
+    ) -> Result<Response<DeleteShippingZoneResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let DeleteShippingZoneRequest { shipping_zone_id } =
+            request.into_inner();
+
+        let shipping_zone_id =
+            parse_uuid(&shipping_zone_id, "shipping_zone_id")?;
+
+        ShippingZone::delete(&self.pool, &shipping_zone_id, &user_id)
+            .await?
+            .ok_or_else(|| Status::not_found("shipping_zone"))?;
+
+        Ok(Response::new(DeleteShippingZoneResponse {}))
+    }
+}
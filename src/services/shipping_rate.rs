@@ -1,6 +1,7 @@
 use deadpool_postgres::Pool;
 use jwtk::jwk::RemoteJwksVerifier;
 use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
 
 use crate::api::sited_io::commerce::v1::shipping_rate_service_server::{
     self, ShippingRateServiceServer,
@@ -8,12 +9,16 @@ use crate::api::sited_io::commerce::v1::shipping_rate_service_server::{
 use crate::api::sited_io::commerce::v1::{
     Currency, DeleteShippingRateRequest, DeleteShippingRateResponse,
     GetShippingRateRequest, GetShippingRateResponse, PutShippingRateRequest,
-    PutShippingRateResponse, ShippingRateResponse,
+    PutShippingRateResponse, QuoteShippingRequest, QuoteShippingResponse,
+    ShippingCountry, ShippingQuoteErrorCode, ShippingQuoteErrorResponse,
+    ShippingQuoteItem, ShippingQuoteShopResponse, ShippingRateResponse,
 };
 use crate::auth::get_user_id;
-use crate::model::ShippingRate;
+use crate::model::{Offer, ShippingRate};
 use crate::{parse_uuid, Publisher};
 
+use super::{get_item_amounts, get_shipping_amount, ShippingLine};
+
 pub struct ShippingRateService {
     pool: Pool,
     verifier: RemoteJwksVerifier,
@@ -21,8 +26,6 @@ pub struct ShippingRateService {
 }
 
 impl ShippingRateService {
-    const COUNTRIES_SEPARATOR: &'static str = ",";
-
     pub fn build(
         pool: Pool,
         verifier: RemoteJwksVerifier,
@@ -59,30 +62,43 @@ impl ShippingRateService {
         })
     }
 
-    fn encode_countries(country_codes: Vec<i32>) -> Option<String> {
-        if country_codes.is_empty() {
-            None
-        } else {
-            Some(
-                country_codes
-                    .iter()
-                    .map(|c| c.to_string())
-                    .collect::<Vec<String>>()
-                    .join(Self::COUNTRIES_SEPARATOR),
-            )
+    fn encode_countries(
+        country_codes: Vec<i32>,
+    ) -> Result<Vec<&'static str>, Status> {
+        let mut countries = Vec::with_capacity(country_codes.len());
+
+        for country_code in country_codes {
+            if country_code < 1 {
+                return Err(Status::invalid_argument("specific_countries"));
+            }
+            let country = ShippingCountry::from_i32(country_code)
+                .ok_or(Status::invalid_argument("specific_countries"))?
+                .as_str_name();
+            if !countries.contains(&country) {
+                countries.push(country);
+            }
         }
+
+        Ok(countries)
     }
 
-    fn decode_countries(countries: Option<String>) -> Vec<i32> {
-        if let Some(countries) = countries {
-            countries
-                .split(Self::COUNTRIES_SEPARATOR)
-                .map(|c| c.parse().unwrap())
-                .collect()
-        } else {
-            Vec::with_capacity(0)
+    fn quote_error(
+        offer_id: String,
+        code: ShippingQuoteErrorCode,
+    ) -> ShippingQuoteErrorResponse {
+        ShippingQuoteErrorResponse {
+            offer_id,
+            code: code.into(),
         }
     }
+
+    fn decode_countries(countries: Vec<String>) -> Vec<i32> {
+        countries
+            .iter()
+            .filter_map(|c| ShippingCountry::from_str_name(c))
+            .map(i32::from)
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -103,11 +119,14 @@ impl shipping_rate_service_server::ShippingRateService for ShippingRateService {
 
         let offer_uuid = parse_uuid(&offer_id, "offer_id")?;
 
+        if currency < 1 {
+            return Err(Status::invalid_argument("currency"));
+        }
         let currency = Currency::from_i32(currency)
             .ok_or(Status::invalid_argument("currency"))?
             .as_str_name();
 
-        let specific_countries = Self::encode_countries(specific_countries);
+        let specific_countries = Self::encode_countries(specific_countries)?;
 
         let shipping_rate = ShippingRate::put(
             &self.pool,
@@ -116,7 +135,7 @@ impl shipping_rate_service_server::ShippingRateService for ShippingRateService {
             amount,
             currency,
             all_countries,
-            specific_countries,
+            &specific_countries,
         )
         .await?;
 
@@ -131,15 +150,30 @@ impl shipping_rate_service_server::ShippingRateService for ShippingRateService {
         &self,
         request: Request<GetShippingRateRequest>,
     ) -> Result<Response<GetShippingRateResponse>, Status> {
-        let GetShippingRateRequest { offer_id } = request.into_inner();
+        let GetShippingRateRequest { offer_id, currency } =
+            request.into_inner();
 
         let offer_id = offer_id.ok_or(Status::invalid_argument("offer_id"))?;
         let offer_uuid = parse_uuid(&offer_id, "offer_id")?;
 
-        let found_shipping_rate =
-            ShippingRate::get_by_offer_id(&self.pool, &offer_uuid)
+        // without a currency the oldest rate of the offer is returned
+        let found_shipping_rate = match currency {
+            Some(currency) => {
+                let currency = Currency::from_i32(currency)
+                    .ok_or(Status::invalid_argument("currency"))?;
+                ShippingRate::get_by_offer_id(
+                    &self.pool,
+                    &offer_uuid,
+                    currency.as_str_name(),
+                )
+                .await?
+            }
+            None => ShippingRate::list_by_offer_id(&self.pool, &offer_uuid)
                 .await?
-                .ok_or(Status::not_found(&offer_id))?;
+                .into_iter()
+                .next(),
+        }
+        .ok_or(Status::not_found(&offer_id))?;
 
         Ok(Response::new(GetShippingRateResponse {
             shipping_rate: Some(self.to_response(found_shipping_rate)?),
@@ -170,4 +204,171 @@ impl shipping_rate_service_server::ShippingRateService for ShippingRateService {
 
         Ok(Response::new(DeleteShippingRateResponse {}))
     }
+
+    async fn quote_shipping(
+        &self,
+        request: Request<QuoteShippingRequest>,
+    ) -> Result<Response<QuoteShippingResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let QuoteShippingRequest {
+            items,
+            shipping_country,
+            currency,
+        } = request.into_inner();
+
+        if items.is_empty() {
+            return Err(Status::invalid_argument("items"));
+        }
+
+        if shipping_country < 1 {
+            return Err(Status::invalid_argument("shipping_country"));
+        }
+        let shipping_country = ShippingCountry::from_i32(shipping_country)
+            .ok_or(Status::invalid_argument("shipping_country"))?;
+
+        let mut currency = match currency {
+            Some(currency) => Some(
+                Currency::from_i32(currency)
+                    .filter(|c| *c != Currency::Unspecified)
+                    .ok_or(Status::invalid_argument("currency"))?,
+            ),
+            None => None,
+        };
+
+        // offers that cannot be quoted are reported instead of failing the
+        // whole quote, the others are grouped by shop in request order
+        let mut errors = Vec::new();
+        let mut shop_lines: Vec<(Uuid, Vec<ShippingLine>)> = Vec::new();
+
+        for item in items {
+            let ShippingQuoteItem {
+                offer_id,
+                offer_variant_id,
+                quantity,
+            } = item;
+
+            let offer_uuid = parse_uuid(&offer_id, "items.offer_id")?;
+            let offer_variant_uuid = match offer_variant_id {
+                Some(id) => Some(parse_uuid(&id, "items.offer_variant_id")?),
+                None => None,
+            };
+
+            if quantity == 0 {
+                return Err(Status::invalid_argument("items.quantity"));
+            }
+
+            let Some(offer) =
+                Offer::get(&self.pool, &offer_uuid, user_id.as_ref()).await?
+            else {
+                errors.push(Self::quote_error(
+                    offer_id,
+                    ShippingQuoteErrorCode::OfferNotFound,
+                ));
+                continue;
+            };
+
+            let variant = match offer_variant_uuid {
+                Some(offer_variant_uuid) => match offer
+                    .variants
+                    .iter()
+                    .find(|v| v.offer_variant_id == offer_variant_uuid)
+                {
+                    Some(variant) => Some(variant),
+                    None => {
+                        errors.push(Self::quote_error(
+                            offer_id,
+                            ShippingQuoteErrorCode::OfferVariantNotFound,
+                        ));
+                        continue;
+                    }
+                },
+                None => None,
+            };
+
+            // without a currency the quote is in the currency of the first
+            // offer with a price
+            let Some(price) =
+                offer.get_price(variant, currency.map(|c| c.as_str_name()))
+            else {
+                errors.push(Self::quote_error(
+                    offer_id,
+                    ShippingQuoteErrorCode::NoPrice,
+                ));
+                continue;
+            };
+            if currency.is_none() {
+                currency =
+                    Some(Currency::from_str_name(&price.currency).ok_or(
+                        Status::internal(format!(
+                            "error parsing currency '{}'",
+                            price.currency
+                        )),
+                    )?);
+            }
+
+            let (_, amount) = get_item_amounts(price, quantity)?;
+
+            let line = ShippingLine {
+                offer_id: offer_uuid,
+                quantity,
+                amount,
+            };
+
+            match shop_lines.iter_mut().find(|(s, _)| *s == offer.shop_id) {
+                Some((_, lines)) => lines.push(line),
+                None => shop_lines.push((offer.shop_id, vec![line])),
+            }
+        }
+
+        let currency = currency.unwrap_or(Currency::Unspecified);
+
+        let mut shops = Vec::with_capacity(shop_lines.len());
+        let mut total_amount: u64 = 0;
+
+        for (shop_id, lines) in shop_lines {
+            let (amount, unshippable_offer_ids) = get_shipping_amount(
+                &self.pool,
+                &lines,
+                Some(shipping_country),
+                currency.as_str_name(),
+            )
+            .await?;
+
+            let mut offer_ids: Vec<String> = Vec::with_capacity(lines.len());
+            for line in lines.iter() {
+                let offer_id = line.offer_id.to_string();
+                if unshippable_offer_ids.contains(&line.offer_id) {
+                    errors.push(Self::quote_error(
+                        offer_id,
+                        ShippingQuoteErrorCode::NotShippable,
+                    ));
+                } else if !offer_ids.contains(&offer_id) {
+                    offer_ids.push(offer_id);
+                }
+            }
+
+            if offer_ids.is_empty() {
+                continue;
+            }
+
+            total_amount += amount;
+
+            shops.push(ShippingQuoteShopResponse {
+                shop_id: shop_id.to_string(),
+                amount: u32::try_from(amount)
+                    .map_err(|_| Status::invalid_argument("items"))?,
+                offer_ids,
+            });
+        }
+
+        Ok(Response::new(QuoteShippingResponse {
+            currency: currency.into(),
+            shops,
+            total_amount: u32::try_from(total_amount)
+                .map_err(|_| Status::invalid_argument("items"))?,
+            errors,
+        }))
+    }
 }
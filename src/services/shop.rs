@@ -6,19 +6,29 @@ use crate::api::sited_io::commerce::v1::shop_service_server::{
     self, ShopServiceServer,
 };
 use crate::api::sited_io::commerce::v1::{
-    CreateShopRequest, CreateShopResponse, DeleteShopRequest,
-    DeleteShopResponse, GetShopRequest, GetShopResponse, ListShopsRequest,
-    ListShopsResponse, ShopCustomizationResponse, ShopLayoutType, ShopResponse,
-    ShopsFilterField, ShopsOrderByField, UpdateShopRequest, UpdateShopResponse,
+    CalculateFeesRequest, CalculateFeesResponse, ContentLanguage,
+    CreateShopRequest, CreateShopResponse, Currency, DeleteShopRequest,
+    DeleteShopResponse, FilterOperator, GetShopRequest, GetShopResponse,
+    ListMinimumPlatformFeesRequest, ListMinimumPlatformFeesResponse,
+    ListShopsRequest, ListShopsResponse, MinimumPlatformFeeResponse,
+    PutMinimumPlatformFeeRequest, PutMinimumPlatformFeeResponse,
+    RemoveMinimumPlatformFeeRequest, RemoveMinimumPlatformFeeResponse,
+    ShopCustomizationResponse, ShopLayoutType, ShopResponse, ShopsFilter,
+    ShopsFilterField, ShopsFilterGroup, ShopsOrderBy, ShopsOrderByField,
+    UpdateShopRequest, UpdateShopResponse,
 };
 use crate::api::sited_io::types::v1::Direction;
 use crate::auth::get_user_id;
-use crate::db::DbError;
+use crate::db::{DbError, FilterGroup};
+use crate::fees::{AllowedMinimumPlatformFees, FeeBreakdown};
 use crate::images::ImageService;
-use crate::model::{Offer, Shop, ShopCustomization};
+use crate::model::{Offer, Shop, ShopCustomization, ShopMinimumPlatformFee};
 use crate::{parse_uuid, Publisher};
 
-use super::get_limit_offset_from_pagination;
+use super::{
+    get_filter_operator, get_limit_offset_cursor_from_pagination,
+    validate_filter_group, validate_random_seed, validate_slug,
+};
 
 pub struct ShopService {
     pool: Pool,
@@ -26,25 +36,17 @@ pub struct ShopService {
     publisher: Publisher,
     image_service: ImageService,
     allowed_min_platform_fee_percent: u32,
-    allowed_min_minimum_platform_fee_cent: u32,
+    allowed_minimum_platform_fees: AllowedMinimumPlatformFees,
 }
 
 impl ShopService {
-    const SLUG_CHARS: [char; 65] = [
-        '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e',
-        'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
-        't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
-        'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U',
-        'V', 'W', 'X', 'Y', 'Z', '-', '+', '_', '!',
-    ];
-
     pub fn build(
         pool: Pool,
         verifier: RemoteJwksVerifier,
         publisher: Publisher,
         image_service: ImageService,
         allowed_min_platform_fee_percent: u32,
-        allowed_min_minimum_platform_fee_cent: u32,
+        allowed_minimum_platform_fees: AllowedMinimumPlatformFees,
     ) -> ShopServiceServer<Self> {
         ShopServiceServer::new(Self {
             pool,
@@ -52,7 +54,7 @@ impl ShopService {
             publisher,
             image_service,
             allowed_min_platform_fee_percent,
-            allowed_min_minimum_platform_fee_cent,
+            allowed_minimum_platform_fees,
         })
     }
 
@@ -75,9 +77,60 @@ impl ShopService {
             contact_email_address: shop.contact_email_address,
             client_id: shop.client_id,
             website_id: shop.website_id,
+            prices_include_tax: shop.prices_include_tax,
+            content_language: ContentLanguage::from_str_name(
+                &shop.content_language,
+            )
+            .map(i32::from)
+            .unwrap_or(0),
         }
     }
 
+    fn minimum_platform_fee_to_response(
+        minimum_platform_fee: ShopMinimumPlatformFee,
+    ) -> MinimumPlatformFeeResponse {
+        MinimumPlatformFeeResponse {
+            shop_id: minimum_platform_fee.shop_id.to_string(),
+            currency: Currency::from_str_name(&minimum_platform_fee.currency)
+                .map(i32::from)
+                .unwrap_or(0),
+            amount: minimum_platform_fee.amount,
+        }
+    }
+
+    /// Returns the currency of a minimum platform fee. The EUR minimum is the
+    /// `minimum_platform_fee_cent` of the shop.
+    fn get_minimum_platform_fee_currency(
+        currency: i32,
+    ) -> Result<Currency, Status> {
+        match Currency::from_i32(currency) {
+            Some(Currency::Unspecified) | Some(Currency::Eur) | None => {
+                Err(Status::invalid_argument("currency"))
+            }
+            Some(currency) => Ok(currency),
+        }
+    }
+
+    async fn get_shop_for_owner(
+        &self,
+        shop_id: &str,
+        user_id: &String,
+    ) -> Result<Shop, Status> {
+        let shop_id = parse_uuid(shop_id, "shop_id")?;
+
+        Shop::get(&self.pool, &shop_id, Some(user_id), false)
+            .await?
+            .filter(|s| s.user_id == *user_id)
+            .ok_or_else(|| Status::not_found("shop"))
+    }
+
+    fn get_content_language(
+        content_language: i32,
+    ) -> Result<ContentLanguage, Status> {
+        ContentLanguage::from_i32(content_language)
+            .ok_or_else(|| Status::invalid_argument("content_language"))
+    }
+
     fn customization_to_response(
         &self,
         shop: &Shop,
@@ -88,6 +141,27 @@ impl ShopService {
                     .map(i32::from)
                     .unwrap_or(0);
 
+            let logo_image_light_variants =
+                self.image_service.get_opt_image_variants(
+                    customization.logo_image_light_url_path.as_ref(),
+                    &customization.logo_image_light_variants,
+                );
+            let logo_image_dark_variants =
+                self.image_service.get_opt_image_variants(
+                    customization.logo_image_dark_url_path.as_ref(),
+                    &customization.logo_image_dark_variants,
+                );
+            let banner_image_light_variants =
+                self.image_service.get_opt_image_variants(
+                    customization.banner_image_light_url_path.as_ref(),
+                    &customization.banner_image_light_variants,
+                );
+            let banner_image_dark_variants =
+                self.image_service.get_opt_image_variants(
+                    customization.banner_image_dark_url_path.as_ref(),
+                    &customization.banner_image_dark_variants,
+                );
+
             ShopCustomizationResponse {
                 shop_id: shop.shop_id.to_string(),
                 user_id: shop.user_id.to_string(),
@@ -107,21 +181,58 @@ impl ShopService {
                 ),
                 primary_color: customization.primary_color,
                 layout_type,
+                logo_image_light_variants,
+                logo_image_dark_variants,
+                banner_image_light_variants,
+                banner_image_dark_variants,
             }
         })
     }
 
-    fn validate_slug(slug: &str) -> Result<(), Status> {
-        for char in slug.chars() {
-            if !Self::SLUG_CHARS.contains(&char) {
-                return Err(Status::invalid_argument(format!(
-                    "invalid character '{}'",
-                    char
-                )));
-            }
+    fn get_filter(
+        filter: ShopsFilter,
+    ) -> Result<(ShopsFilterField, String), Status> {
+        if filter.field < 1 {
+            return Err(Status::invalid_argument("filter.field"));
         }
+        if filter.query.trim().is_empty() {
+            return Err(Status::invalid_argument("filter.query"));
+        }
+
+        Ok((
+            ShopsFilterField::from_i32(filter.field)
+                .ok_or(Status::invalid_argument("filter.field"))?,
+            filter.query,
+        ))
+    }
 
-        Ok(())
+    fn get_filter_group(
+        group: ShopsFilterGroup,
+    ) -> Result<FilterGroup<ShopsFilterField>, Status> {
+        Ok(FilterGroup {
+            operator: get_filter_operator(group.operator)?,
+            filters: group
+                .filters
+                .into_iter()
+                .map(Self::get_filter)
+                .collect::<Result<_, _>>()?,
+            groups: group
+                .groups
+                .into_iter()
+                .map(Self::get_filter_group)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn get_order_by(
+        order_by: ShopsOrderBy,
+    ) -> Result<(ShopsOrderByField, Direction), Status> {
+        Ok((
+            ShopsOrderByField::from_i32(order_by.field)
+                .ok_or(Status::invalid_argument("order_by.field"))?,
+            Direction::from_i32(order_by.direction)
+                .ok_or(Status::invalid_argument("order_by.direction"))?,
+        ))
     }
 }
 
@@ -139,9 +250,16 @@ impl shop_service_server::ShopService for ShopService {
             description,
             platform_fee_percent,
             minimum_platform_fee_cent,
+            prices_include_tax,
+            content_language,
         } = request.into_inner();
 
-        Self::validate_slug(&slug)?;
+        validate_slug(&slug)?;
+
+        let content_language = content_language
+            .map(Self::get_content_language)
+            .transpose()?
+            .unwrap_or(ContentLanguage::Unspecified);
 
         let platform_fee_percent = match platform_fee_percent {
             Some(pfp) => {
@@ -155,16 +273,19 @@ impl shop_service_server::ShopService for ShopService {
             None => self.allowed_min_platform_fee_percent,
         };
 
+        let allowed_min_minimum_platform_fee_cent =
+            self.allowed_minimum_platform_fees.get(Currency::Eur);
+
         let minimum_platform_fee_cent = match minimum_platform_fee_cent {
             Some(mpfc) => {
-                if mpfc < self.allowed_min_minimum_platform_fee_cent {
+                if mpfc < allowed_min_minimum_platform_fee_cent {
                     return Err(Status::invalid_argument(
                         "minimum_platform_fee_cent",
                     ));
                 }
                 mpfc
             }
-            None => self.allowed_min_minimum_platform_fee_cent,
+            None => allowed_min_minimum_platform_fee_cent,
         };
 
         let created_shop = Shop::create(
@@ -175,6 +296,8 @@ impl shop_service_server::ShopService for ShopService {
             description,
             platform_fee_percent,
             minimum_platform_fee_cent,
+            prices_include_tax.unwrap_or(true),
+            content_language.as_str_name(),
         )
         .await?;
 
@@ -260,56 +383,57 @@ impl shop_service_server::ShopService for ShopService {
             filter,
             order_by,
             extended,
+            filters,
+            filter_group,
+            order_bys,
+            random_seed,
         } = request.into_inner();
 
-        let (limit, offset, pagination) =
-            get_limit_offset_from_pagination(pagination)?;
-
-        if filter.is_none() && order_by.is_none() {
-            return Err(Status::invalid_argument("filter,order_by"));
-        }
+        let (limit, offset, cursor, mut pagination) =
+            get_limit_offset_cursor_from_pagination(pagination)?;
 
-        let filter = match filter {
-            Some(f) => {
-                if f.field < 1 {
-                    return Err(Status::invalid_argument("filter.field"));
-                } else if f.query.trim().is_empty() {
-                    return Err(Status::invalid_argument("filter.query"));
-                } else {
-                    Some((
-                        ShopsFilterField::from_i32(f.field)
-                            .ok_or(Status::invalid_argument("filter.field"))?,
-                        f.query,
-                    ))
-                }
-            }
-            None => None,
+        // the filters and the filter group all have to match
+        let filter = FilterGroup {
+            operator: FilterOperator::And,
+            filters: filter
+                .into_iter()
+                .chain(filters)
+                .map(Self::get_filter)
+                .collect::<Result<_, _>>()?,
+            groups: match filter_group {
+                Some(group) => vec![Self::get_filter_group(group)?],
+                None => Vec::new(),
+            },
         };
+        validate_filter_group(&filter)?;
+        validate_random_seed(random_seed.as_ref())?;
 
-        let order_by = match order_by {
-            Some(o) => Some((
-                ShopsOrderByField::from_i32(o.field)
-                    .ok_or(Status::invalid_argument("order_by.field"))?,
-                Direction::from_i32(o.direction)
-                    .ok_or(Status::invalid_argument("order_by.direction"))?,
-            )),
-            None => None,
-        };
+        let order_by = order_by
+            .into_iter()
+            .chain(order_bys)
+            .map(Self::get_order_by)
+            .collect::<Result<_, _>>()?;
 
         let extended = extended.unwrap_or(false);
 
-        let found_shops = Shop::list(
+        let (found_shops, count, next_cursor) = Shop::list(
             &self.pool,
             user_id.as_ref(),
             limit.into(),
             offset.into(),
+            cursor,
             filter,
             order_by,
+            random_seed.as_deref(),
             extended,
             request_user_id.as_ref(),
         )
         .await?;
 
+        pagination.total_elements =
+            count.try_into().map_err(|_| Status::internal(""))?;
+        pagination.next_cursor = next_cursor.map(|c| c.encode());
+
         Ok(Response::new(ListShopsResponse {
             shops: found_shops
                 .into_iter()
@@ -334,10 +458,12 @@ impl shop_service_server::ShopService for ShopService {
             slug,
             is_active,
             contact_email_address,
+            prices_include_tax,
+            content_language,
         } = request.into_inner();
 
         if let Some(ref slug) = slug {
-            Self::validate_slug(slug)?;
+            validate_slug(slug)?;
         }
 
         let shop_id = parse_uuid(&shop_id, "shop_id")?;
@@ -351,15 +477,19 @@ impl shop_service_server::ShopService for ShopService {
 
         if matches!(
             minimum_platform_fee_cent,
-            Some(mpfc) if mpfc < self.allowed_min_minimum_platform_fee_cent,
+            Some(mpfc) if mpfc < self.allowed_minimum_platform_fees.get(Currency::Eur),
         ) {
             return Err(Status::invalid_argument("minimum_platform_fee_cent"));
         }
 
         if let Some(slug) = slug.as_ref() {
-            Self::validate_slug(slug)?;
+            validate_slug(slug)?;
         }
 
+        let content_language = content_language
+            .map(Self::get_content_language)
+            .transpose()?;
+
         if matches!(is_active, Some(false)) {
             Offer::deactivate_for_shop(&self.pool, &user_id, &shop_id).await?;
         }
@@ -375,6 +505,8 @@ impl shop_service_server::ShopService for ShopService {
             minimum_platform_fee_cent,
             is_active,
             contact_email_address,
+            prices_include_tax,
+            content_language.map(|c| c.as_str_name()),
         )
         .await?;
 
@@ -405,14 +537,24 @@ impl shop_service_server::ShopService for ShopService {
             ShopCustomization::delete(&transaction, &shop_id, &user_id).await?;
 
             if let Some(image_path) =
-                found_shop_customization.logo_image_light_url_path
+                &found_shop_customization.logo_image_light_url_path
             {
-                self.image_service.remove_image(&image_path).await?;
+                self.image_service
+                    .remove_image(
+                        image_path,
+                        &found_shop_customization.logo_image_light_variants,
+                    )
+                    .await?;
             }
             if let Some(image_path) =
-                found_shop_customization.banner_image_light_url_path
+                &found_shop_customization.banner_image_light_url_path
             {
-                self.image_service.remove_image(&image_path).await?;
+                self.image_service
+                    .remove_image(
+                        image_path,
+                        &found_shop_customization.banner_image_light_variants,
+                    )
+                    .await?;
             }
         }
 
@@ -427,4 +569,141 @@ impl shop_service_server::ShopService for ShopService {
 
         Ok(Response::new(DeleteShopResponse {}))
     }
+
+    async fn calculate_fees(
+        &self,
+        request: Request<CalculateFeesRequest>,
+    ) -> Result<Response<CalculateFeesResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let CalculateFeesRequest {
+            shop_id,
+            unit_amount,
+            quantity,
+            shipping_amount,
+            currency,
+        } = request.into_inner();
+
+        let shop_id = parse_uuid(&shop_id, "shop_id")?;
+
+        // requests without a currency are calculated in EUR, the only
+        // currency supported before
+        let currency = match Currency::from_i32(currency) {
+            Some(Currency::Unspecified) => Currency::Eur,
+            Some(currency) => currency,
+            None => return Err(Status::invalid_argument("currency")),
+        };
+
+        let shop = Shop::get(&self.pool, &shop_id, user_id.as_ref(), false)
+            .await?
+            .ok_or_else(|| Status::not_found("shop"))?;
+
+        let minimum_platform_fee = ShopMinimumPlatformFee::get_for_shop(
+            &self.pool,
+            &shop,
+            currency,
+            &self.allowed_minimum_platform_fees,
+        )
+        .await?;
+
+        let fees = FeeBreakdown::calculate(
+            unit_amount,
+            quantity,
+            shipping_amount,
+            shop.platform_fee_percent,
+            minimum_platform_fee,
+        )
+        .ok_or_else(|| Status::invalid_argument("unit_amount,quantity"))?;
+
+        Ok(Response::new(CalculateFeesResponse {
+            gross_amount: fees.gross_amount,
+            platform_fee_amount: fees.platform_fee_amount,
+            net_amount: fees.net_amount,
+        }))
+    }
+
+    async fn put_minimum_platform_fee(
+        &self,
+        request: Request<PutMinimumPlatformFeeRequest>,
+    ) -> Result<Response<PutMinimumPlatformFeeResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let PutMinimumPlatformFeeRequest {
+            shop_id,
+            currency,
+            amount,
+        } = request.into_inner();
+
+        let currency = Self::get_minimum_platform_fee_currency(currency)?;
+
+        if amount < self.allowed_minimum_platform_fees.get(currency) {
+            return Err(Status::invalid_argument("amount"));
+        }
+
+        let shop = self.get_shop_for_owner(&shop_id, &user_id).await?;
+
+        let minimum_platform_fee = ShopMinimumPlatformFee::put(
+            &self.pool,
+            &shop.shop_id,
+            currency.as_str_name(),
+            &user_id,
+            amount,
+        )
+        .await?;
+
+        Ok(Response::new(PutMinimumPlatformFeeResponse {
+            minimum_platform_fee: Some(Self::minimum_platform_fee_to_response(
+                minimum_platform_fee,
+            )),
+        }))
+    }
+
+    async fn remove_minimum_platform_fee(
+        &self,
+        request: Request<RemoveMinimumPlatformFeeRequest>,
+    ) -> Result<Response<RemoveMinimumPlatformFeeResponse>, Status> {
+        let user_id = get_user_id(request.metadata(), &self.verifier).await?;
+
+        let RemoveMinimumPlatformFeeRequest { shop_id, currency } =
+            request.into_inner();
+
+        let currency = Self::get_minimum_platform_fee_currency(currency)?;
+        let shop = self.get_shop_for_owner(&shop_id, &user_id).await?;
+
+        ShopMinimumPlatformFee::delete(
+            &self.pool,
+            &shop.shop_id,
+            currency.as_str_name(),
+            &user_id,
+        )
+        .await?;
+
+        Ok(Response::new(RemoveMinimumPlatformFeeResponse {}))
+    }
+
+    async fn list_minimum_platform_fees(
+        &self,
+        request: Request<ListMinimumPlatformFeesRequest>,
+    ) -> Result<Response<ListMinimumPlatformFeesResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let shop_id = parse_uuid(&request.into_inner().shop_id, "shop_id")?;
+
+        let shop = Shop::get(&self.pool, &shop_id, user_id.as_ref(), false)
+            .await?
+            .ok_or_else(|| Status::not_found("shop"))?;
+
+        let minimum_platform_fees =
+            ShopMinimumPlatformFee::list(&self.pool, &shop.shop_id)
+                .await?
+                .into_iter()
+                .map(Self::minimum_platform_fee_to_response)
+                .collect();
+
+        Ok(Response::new(ListMinimumPlatformFeesResponse {
+            minimum_platform_fees,
+        }))
+    }
 }
@@ -18,7 +18,7 @@ use crate::api::sited_io::commerce::v1::{
 };
 use crate::auth::get_user_id;
 use crate::db::DbError;
-use crate::images::ImageService;
+use crate::images::{ImageKind, ImageService};
 use crate::model::ShopCustomization;
 use crate::parse_uuid;
 
@@ -60,6 +60,27 @@ impl ShopCustomizationService {
                 .map(i32::from)
                 .unwrap_or(0);
 
+        let logo_image_light_variants =
+            self.image_service.get_opt_image_variants(
+                shop_customization.logo_image_light_url_path.as_ref(),
+                &shop_customization.logo_image_light_variants,
+            );
+        let logo_image_dark_variants =
+            self.image_service.get_opt_image_variants(
+                shop_customization.logo_image_dark_url_path.as_ref(),
+                &shop_customization.logo_image_dark_variants,
+            );
+        let banner_image_light_variants =
+            self.image_service.get_opt_image_variants(
+                shop_customization.banner_image_light_url_path.as_ref(),
+                &shop_customization.banner_image_light_variants,
+            );
+        let banner_image_dark_variants =
+            self.image_service.get_opt_image_variants(
+                shop_customization.banner_image_dark_url_path.as_ref(),
+                &shop_customization.banner_image_dark_variants,
+            );
+
         ShopCustomizationResponse {
             shop_id: shop_customization.shop_id.to_string(),
             user_id: shop_customization.user_id,
@@ -86,6 +107,10 @@ impl ShopCustomizationService {
             ),
             primary_color: shop_customization.primary_color,
             layout_type,
+            logo_image_light_variants,
+            logo_image_dark_variants,
+            banner_image_light_variants,
+            banner_image_dark_variants,
         }
     }
 
@@ -171,12 +196,24 @@ impl shop_customization_service_server::ShopCustomizationService
 
         ShopCustomization::delete(&transaction, &shop_uuid, &user_id).await?;
 
-        if let Some(image_path) = shop_customization.logo_image_light_url_path {
-            self.image_service.remove_image(&image_path).await?;
+        if let Some(image_path) = &shop_customization.logo_image_light_url_path
+        {
+            self.image_service
+                .remove_image(
+                    image_path,
+                    &shop_customization.logo_image_light_variants,
+                )
+                .await?;
         }
-        if let Some(image_path) = shop_customization.banner_image_light_url_path
+        if let Some(image_path) =
+            &shop_customization.banner_image_light_url_path
         {
-            self.image_service.remove_image(&image_path).await?;
+            self.image_service
+                .remove_image(
+                    image_path,
+                    &shop_customization.banner_image_light_variants,
+                )
+                .await?;
         }
 
         transaction.commit().await.map_err(DbError::from)?;
@@ -202,67 +239,69 @@ impl shop_customization_service_server::ShopCustomizationService
             .await?
             .ok_or_else(|| Status::not_found(shop_id))?;
 
-        let mut image_light_update_path = None;
-        let mut image_light_update = None;
-        if let Some(image) = image {
+        if let Some(image) = &image {
             self.image_service.validate_image(&image.data)?;
+        }
+        if let Some(image) = &image_dark {
+            self.image_service.validate_image(&image.data)?;
+        }
 
+        let mut image_light_update = None;
+        if let Some(image) = image {
             if let Some(existing) =
-                shop_customization.banner_image_light_url_path
+                &shop_customization.banner_image_light_url_path
             {
-                self.image_service.remove_image(&existing).await?;
+                self.image_service
+                    .remove_image(
+                        existing,
+                        &shop_customization.banner_image_light_variants,
+                    )
+                    .await?;
             }
 
             let image_path = Self::gen_image_path(&user_id, &shop_uuid);
+            let variants = self
+                .image_service
+                .put_image(&image_path, &image.data, ImageKind::Banner)
+                .await?;
 
-            image_light_update_path = Some(Some(image_path));
-            image_light_update = Some(image);
-        };
+            image_light_update = Some(Some((image_path, variants)));
+        }
 
-        let mut image_dark_update_path = None;
         let mut image_dark_update = None;
         if let Some(image) = image_dark {
-            self.image_service.validate_image(&image.data)?;
-
             if let Some(existing) =
-                shop_customization.banner_image_dark_url_path
+                &shop_customization.banner_image_dark_url_path
             {
-                self.image_service.remove_image(&existing).await?;
+                self.image_service
+                    .remove_image(
+                        existing,
+                        &shop_customization.banner_image_dark_variants,
+                    )
+                    .await?;
             }
 
             let image_path = Self::gen_image_path(&user_id, &shop_uuid);
+            let variants = self
+                .image_service
+                .put_image(&image_path, &image.data, ImageKind::Banner)
+                .await?;
 
-            image_dark_update_path = Some(Some(image_path));
-            image_dark_update = Some(image);
-        };
+            image_dark_update = Some(Some((image_path, variants)));
+        }
 
         let mut conn = self.pool.get().await.map_err(DbError::from)?;
         let transaction = conn.transaction().await.map_err(DbError::from)?;
 
-        ShopCustomization::update_banner_image_url_paths(
+        ShopCustomization::update_banner_images(
             &transaction,
             &shop_uuid,
             &user_id,
-            image_light_update_path.clone(),
-            image_dark_update_path.clone(),
+            image_light_update,
+            image_dark_update,
         )
         .await?;
 
-        if let (Some(Some(image_path)), Some(image)) =
-            (image_light_update_path, image_light_update)
-        {
-            self.image_service
-                .put_image(&image_path, &image.data)
-                .await?;
-        }
-        if let (Some(Some(image_path)), Some(image)) =
-            (image_dark_update_path, image_dark_update)
-        {
-            self.image_service
-                .put_image(&image_path, &image.data)
-                .await?;
-        }
-
         transaction.commit().await.map_err(DbError::from)?;
 
         Ok(Response::new(PutBannerImageToShopResponse {}))
@@ -285,7 +324,7 @@ impl shop_customization_service_server::ShopCustomizationService
         let mut conn = self.pool.get().await.map_err(DbError::from)?;
         let transaction = conn.transaction().await.map_err(DbError::from)?;
 
-        ShopCustomization::update_banner_image_url_paths(
+        ShopCustomization::update_banner_images(
             &transaction,
             &shop_id,
             &user_id,
@@ -294,14 +333,24 @@ impl shop_customization_service_server::ShopCustomizationService
         )
         .await?;
 
-        if let Some(image_light_path) =
-            shop_customization.banner_image_light_url_path
+        if let Some(image_path) =
+            &shop_customization.banner_image_light_url_path
         {
-            self.image_service.remove_image(&image_light_path).await?;
+            self.image_service
+                .remove_image(
+                    image_path,
+                    &shop_customization.banner_image_light_variants,
+                )
+                .await?;
         }
-        if let Some(image_dark) = shop_customization.banner_image_dark_url_path
+        if let Some(image_path) = &shop_customization.banner_image_dark_url_path
         {
-            self.image_service.remove_image(&image_dark).await?;
+            self.image_service
+                .remove_image(
+                    image_path,
+                    &shop_customization.banner_image_dark_variants,
+                )
+                .await?;
         }
 
         transaction.commit().await.map_err(DbError::from)?;
@@ -327,63 +376,68 @@ impl shop_customization_service_server::ShopCustomizationService
             .await?
             .ok_or_else(|| Status::not_found(shop_id))?;
 
-        let mut image_light_update_path = None;
-        let mut image_light_update = None;
-        if let Some(image) = image {
+        if let Some(image) = &image {
             self.image_service.validate_image(&image.data)?;
+        }
+        if let Some(image) = &image_dark {
+            self.image_service.validate_image(&image.data)?;
+        }
 
-            if let Some(existing) = shop_customization.logo_image_light_url_path
+        let mut image_light_update = None;
+        if let Some(image) = image {
+            if let Some(existing) =
+                &shop_customization.logo_image_light_url_path
             {
-                self.image_service.remove_image(&existing).await?;
+                self.image_service
+                    .remove_image(
+                        existing,
+                        &shop_customization.logo_image_light_variants,
+                    )
+                    .await?;
             }
 
             let image_path = Self::gen_image_path(&user_id, &shop_uuid);
-            image_light_update_path = Some(Some(image_path));
-            image_light_update = Some(image);
+            let variants = self
+                .image_service
+                .put_image(&image_path, &image.data, ImageKind::Logo)
+                .await?;
+
+            image_light_update = Some(Some((image_path, variants)));
         }
 
-        let mut image_dark_update_path = None;
         let mut image_dark_update = None;
         if let Some(image) = image_dark {
-            self.image_service.validate_image(&image.data)?;
-
-            if let Some(existing) = shop_customization.logo_image_dark_url_path
+            if let Some(existing) = &shop_customization.logo_image_dark_url_path
             {
-                self.image_service.remove_image(&existing).await?;
+                self.image_service
+                    .remove_image(
+                        existing,
+                        &shop_customization.logo_image_dark_variants,
+                    )
+                    .await?;
             }
 
             let image_path = Self::gen_image_path(&user_id, &shop_uuid);
-            image_dark_update_path = Some(Some(image_path));
-            image_dark_update = Some(image);
+            let variants = self
+                .image_service
+                .put_image(&image_path, &image.data, ImageKind::Logo)
+                .await?;
+
+            image_dark_update = Some(Some((image_path, variants)));
         }
 
         let mut conn = self.pool.get().await.map_err(DbError::from)?;
         let transaction = conn.transaction().await.map_err(DbError::from)?;
 
-        ShopCustomization::update_logo_image_url_paths(
+        ShopCustomization::update_logo_images(
             &transaction,
             &shop_uuid,
             &user_id,
-            image_light_update_path.clone(),
-            image_dark_update_path.clone(),
+            image_light_update,
+            image_dark_update,
         )
         .await?;
 
-        if let (Some(Some(image_path)), Some(image)) =
-            (image_light_update_path, image_light_update)
-        {
-            self.image_service
-                .put_image(&image_path, &image.data)
-                .await?;
-        }
-        if let (Some(Some(image_path)), Some(image)) =
-            (image_dark_update_path, image_dark_update)
-        {
-            self.image_service
-                .put_image(&image_path, &image.data)
-                .await?;
-        }
-
         transaction.commit().await.map_err(DbError::from)?;
 
         Ok(Response::new(PutLogoImageToShopResponse {}))
@@ -406,7 +460,7 @@ impl shop_customization_service_server::ShopCustomizationService
         let mut conn = self.pool.get().await.map_err(DbError::from)?;
         let transaction = conn.transaction().await.map_err(DbError::from)?;
 
-        ShopCustomization::update_logo_image_url_paths(
+        ShopCustomization::update_logo_images(
             &transaction,
             &shop_id,
             &user_id,
@@ -415,11 +469,22 @@ impl shop_customization_service_server::ShopCustomizationService
         )
         .await?;
 
-        if let Some(image_path) = shop_customization.logo_image_light_url_path {
-            self.image_service.remove_image(&image_path).await?;
+        if let Some(image_path) = &shop_customization.logo_image_light_url_path
+        {
+            self.image_service
+                .remove_image(
+                    image_path,
+                    &shop_customization.logo_image_light_variants,
+                )
+                .await?;
         }
-        if let Some(image_path) = shop_customization.logo_image_dark_url_path {
-            self.image_service.remove_image(&image_path).await?;
+        if let Some(image_path) = &shop_customization.logo_image_dark_url_path {
+            self.image_service
+                .remove_image(
+                    image_path,
+                    &shop_customization.logo_image_dark_variants,
+                )
+                .await?;
         }
 
         transaction.commit().await.map_err(DbError::from)?;
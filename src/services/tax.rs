@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+
+use deadpool_postgres::Pool;
+use jwtk::jwk::RemoteJwksVerifier;
+use tonic::{async_trait, Request, Response, Status};
+use uuid::Uuid;
+
+use crate::api::sited_io::commerce::v1::tax_service_server::{
+    self, TaxServiceServer,
+};
+use crate::api::sited_io::commerce::v1::{
+    Currency, GetTaxQuoteRequest, GetTaxQuoteResponse, ListTaxRatesRequest,
+    ListTaxRatesResponse, PutTaxRateRequest, PutTaxRateResponse,
+    ShippingCountry, TaxCategory, TaxLineResponse, TaxQuoteItem,
+    TaxRateResponse,
+};
+use crate::auth::{get_user_id, verify_service_user};
+use crate::model::{Offer, Shop, TaxRate};
+use crate::parse_uuid;
+use crate::tax::TaxBreakdown;
+
+use super::get_item_amounts;
+
+pub struct TaxService {
+    pool: Pool,
+    verifier: RemoteJwksVerifier,
+}
+
+impl TaxService {
+    pub fn build(
+        pool: Pool,
+        verifier: RemoteJwksVerifier,
+    ) -> TaxServiceServer<Self> {
+        TaxServiceServer::new(Self { pool, verifier })
+    }
+
+    fn to_response(tax_rate: TaxRate) -> Result<TaxRateResponse, Status> {
+        let country = ShippingCountry::from_str_name(&tax_rate.country).ok_or(
+            Status::internal(format!(
+                "error parsing country '{}'",
+                tax_rate.country
+            )),
+        )?;
+        let tax_category = TaxCategory::from_str_name(&tax_rate.tax_category)
+            .ok_or(Status::internal(format!(
+            "error parsing tax category '{}'",
+            tax_rate.tax_category
+        )))?;
+
+        Ok(TaxRateResponse {
+            country: country.into(),
+            tax_category: tax_category.into(),
+            rate_basis_points: tax_rate.rate_basis_points,
+        })
+    }
+
+    fn parse_country(
+        country: i32,
+        field: &str,
+    ) -> Result<ShippingCountry, Status> {
+        if country < 1 {
+            return Err(Status::invalid_argument(field));
+        }
+        ShippingCountry::from_i32(country)
+            .ok_or_else(|| Status::invalid_argument(field))
+    }
+
+    fn parse_tax_category(tax_category: i32) -> Result<TaxCategory, Status> {
+        if tax_category < 1 {
+            return Err(Status::invalid_argument("tax_category"));
+        }
+        TaxCategory::from_i32(tax_category)
+            .ok_or(Status::invalid_argument("tax_category"))
+    }
+
+    /// Returns the rate of the tax category in the country. Countries
+    /// without a rate for the category are not taxed.
+    async fn get_rate_basis_points(
+        &self,
+        country: ShippingCountry,
+        tax_category: &str,
+    ) -> Result<u32, Status> {
+        Ok(
+            TaxRate::get(&self.pool, country.as_str_name(), tax_category)
+                .await?
+                .map(|r| r.rate_basis_points)
+                .unwrap_or(0),
+        )
+    }
+}
+
+#[async_trait]
+impl tax_service_server::TaxService for TaxService {
+    async fn put_tax_rate(
+        &self,
+        request: Request<PutTaxRateRequest>,
+    ) -> Result<Response<PutTaxRateResponse>, Status> {
+        verify_service_user(request.metadata(), &self.verifier).await?;
+
+        let PutTaxRateRequest {
+            country,
+            tax_category,
+            rate_basis_points,
+        } = request.into_inner();
+
+        let country = Self::parse_country(country, "country")?;
+        let tax_category = Self::parse_tax_category(tax_category)?;
+
+        // rates are below 100%
+        if rate_basis_points >= 10_000 {
+            return Err(Status::invalid_argument("rate_basis_points"));
+        }
+
+        let tax_rate = TaxRate::put(
+            &self.pool,
+            country.as_str_name(),
+            tax_category.as_str_name(),
+            rate_basis_points,
+        )
+        .await?;
+
+        Ok(Response::new(PutTaxRateResponse {
+            tax_rate: Some(Self::to_response(tax_rate)?),
+        }))
+    }
+
+    async fn list_tax_rates(
+        &self,
+        request: Request<ListTaxRatesRequest>,
+    ) -> Result<Response<ListTaxRatesResponse>, Status> {
+        let ListTaxRatesRequest { country } = request.into_inner();
+
+        let country = match country {
+            Some(country) => Some(Self::parse_country(country, "country")?),
+            None => None,
+        };
+
+        let found_tax_rates =
+            TaxRate::list(&self.pool, country.map(|c| c.as_str_name())).await?;
+
+        let mut tax_rates = Vec::with_capacity(found_tax_rates.len());
+        for tax_rate in found_tax_rates {
+            tax_rates.push(Self::to_response(tax_rate)?);
+        }
+
+        Ok(Response::new(ListTaxRatesResponse { tax_rates }))
+    }
+
+    async fn get_tax_quote(
+        &self,
+        request: Request<GetTaxQuoteRequest>,
+    ) -> Result<Response<GetTaxQuoteResponse>, Status> {
+        let user_id =
+            get_user_id(request.metadata(), &self.verifier).await.ok();
+
+        let GetTaxQuoteRequest {
+            items,
+            shipping_country,
+            currency,
+        } = request.into_inner();
+
+        if items.is_empty() {
+            return Err(Status::invalid_argument("items"));
+        }
+
+        let shipping_country =
+            Self::parse_country(shipping_country, "shipping_country")?;
+
+        let currency = match currency {
+            Some(currency) => Some(
+                Currency::from_i32(currency)
+                    .filter(|c| *c != Currency::Unspecified)
+                    .ok_or(Status::invalid_argument("currency"))?
+                    .as_str_name(),
+            ),
+            None => None,
+        };
+
+        let mut prices_include_tax: HashMap<Uuid, bool> = HashMap::new();
+        let mut rates: HashMap<String, u32> = HashMap::new();
+        let mut quote_currency: Option<String> = None;
+        let mut lines = Vec::with_capacity(items.len());
+        let (mut net_amount, mut tax_amount, mut gross_amount) =
+            (0u32, 0u32, 0u32);
+
+        for item in items {
+            let TaxQuoteItem {
+                offer_id,
+                offer_variant_id,
+                quantity,
+            } = item;
+
+            let offer_uuid = parse_uuid(&offer_id, "items.offer_id")?;
+            let offer_variant_uuid = match offer_variant_id.as_ref() {
+                Some(id) => Some(parse_uuid(id, "items.offer_variant_id")?),
+                None => None,
+            };
+
+            if quantity == 0 {
+                return Err(Status::invalid_argument("items.quantity"));
+            }
+
+            let offer = Offer::get(&self.pool, &offer_uuid, user_id.as_ref())
+                .await?
+                .ok_or_else(|| Status::not_found("offer"))?;
+
+            let variant = match offer_variant_uuid {
+                Some(offer_variant_uuid) => Some(
+                    offer
+                        .variants
+                        .iter()
+                        .find(|v| v.offer_variant_id == offer_variant_uuid)
+                        .ok_or_else(|| Status::not_found("offer_variant"))?,
+                ),
+                None => None,
+            };
+
+            let price = offer
+                .get_price(variant, currency)
+                .ok_or_else(|| Status::failed_precondition("offer.price"))?;
+
+            match quote_currency.as_ref() {
+                Some(c) if *c != price.currency => {
+                    return Err(Status::failed_precondition("currency"));
+                }
+                Some(_) => {}
+                None => quote_currency = Some(price.currency.clone()),
+            }
+
+            let (_, amount) = get_item_amounts(price, quantity)?;
+
+            let line_prices_include_tax =
+                match prices_include_tax.get(&offer.shop_id) {
+                    Some(p) => *p,
+                    None => {
+                        let shop =
+                            Shop::get(&self.pool, &offer.shop_id, None, false)
+                                .await?
+                                .ok_or_else(|| Status::not_found("shop"))?;
+                        prices_include_tax
+                            .insert(offer.shop_id, shop.prices_include_tax);
+                        shop.prices_include_tax
+                    }
+                };
+
+            let rate_basis_points = match rates.get(&offer.tax_category) {
+                Some(r) => *r,
+                None => {
+                    let rate = self
+                        .get_rate_basis_points(
+                            shipping_country,
+                            &offer.tax_category,
+                        )
+                        .await?;
+                    rates.insert(offer.tax_category.clone(), rate);
+                    rate
+                }
+            };
+
+            let tax = TaxBreakdown::calculate(
+                amount,
+                rate_basis_points,
+                line_prices_include_tax,
+            )
+            .ok_or_else(|| Status::invalid_argument("items.quantity"))?;
+
+            net_amount = net_amount
+                .checked_add(tax.net_amount)
+                .ok_or_else(|| Status::invalid_argument("items"))?;
+            tax_amount = tax_amount
+                .checked_add(tax.tax_amount)
+                .ok_or_else(|| Status::invalid_argument("items"))?;
+            gross_amount = gross_amount
+                .checked_add(tax.gross_amount)
+                .ok_or_else(|| Status::invalid_argument("items"))?;
+
+            let tax_category = TaxCategory::from_str_name(&offer.tax_category)
+                .ok_or_else(|| {
+                    Status::internal(format!(
+                        "error parsing tax category '{}'",
+                        offer.tax_category
+                    ))
+                })?;
+
+            lines.push(TaxLineResponse {
+                offer_id,
+                offer_variant_id,
+                quantity,
+                tax_category: tax_category.into(),
+                rate_basis_points,
+                prices_include_tax: line_prices_include_tax,
+                net_amount: tax.net_amount,
+                tax_amount: tax.tax_amount,
+                gross_amount: tax.gross_amount,
+            });
+        }
+
+        let currency = quote_currency
+            .as_deref()
+            .and_then(Currency::from_str_name)
+            .ok_or_else(|| {
+                Status::internal(format!(
+                    "error parsing currency '{}'",
+                    quote_currency.as_deref().unwrap_or_default()
+                ))
+            })?;
+
+        Ok(Response::new(GetTaxQuoteResponse {
+            currency: currency.into(),
+            lines,
+            net_amount,
+            tax_amount,
+            gross_amount,
+        }))
+    }
+}
@@ -0,0 +1,127 @@
+/// Tax breakdown of an amount in the minor unit of its currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxBreakdown {
+    pub net_amount: u32,
+    pub tax_amount: u32,
+    pub gross_amount: u32,
+}
+
+impl TaxBreakdown {
+    const BASIS_POINTS: u64 = 10_000;
+
+    /// Calculates the tax of `amount` at `rate_basis_points`, so 1900 is a
+    /// rate of 19%. Tax-inclusive amounts are the gross amount and the net
+    /// amount is derived from it, otherwise the tax is added on top. Either
+    /// way the net amount or the tax is rounded half up to whole minor units
+    /// and net plus tax always equals gross. Returns `None` if the gross
+    /// amount does not fit into an `u32`.
+    pub fn calculate(
+        amount: u32,
+        rate_basis_points: u32,
+        prices_include_tax: bool,
+    ) -> Option<Self> {
+        let amount = u64::from(amount);
+        let rate = u64::from(rate_basis_points);
+
+        let (net_amount, gross_amount) = if prices_include_tax {
+            let divisor = Self::BASIS_POINTS + rate;
+            let net_amount =
+                (amount * Self::BASIS_POINTS * 2 + divisor) / (divisor * 2);
+            (net_amount, amount)
+        } else {
+            let tax_amount =
+                (amount * rate + Self::BASIS_POINTS / 2) / Self::BASIS_POINTS;
+            (amount, amount + tax_amount)
+        };
+
+        Some(Self {
+            net_amount: u32::try_from(net_amount).ok()?,
+            tax_amount: u32::try_from(gross_amount - net_amount).ok()?,
+            gross_amount: u32::try_from(gross_amount).ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_tax_to_exclusive_amount() {
+        assert_eq!(
+            TaxBreakdown::calculate(1000, 1900, false),
+            Some(TaxBreakdown {
+                net_amount: 1000,
+                tax_amount: 190,
+                gross_amount: 1190,
+            })
+        );
+    }
+
+    #[test]
+    fn rounds_exclusive_tax_half_up() {
+        // 2.5 cents
+        assert_eq!(
+            TaxBreakdown::calculate(25, 1000, false).map(|t| t.tax_amount),
+            Some(3)
+        );
+        // 2.4 cents
+        assert_eq!(
+            TaxBreakdown::calculate(24, 1000, false).map(|t| t.tax_amount),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn derives_net_amount_from_inclusive_amount() {
+        assert_eq!(
+            TaxBreakdown::calculate(1190, 1900, true),
+            Some(TaxBreakdown {
+                net_amount: 1000,
+                tax_amount: 190,
+                gross_amount: 1190,
+            })
+        );
+    }
+
+    #[test]
+    fn rounds_inclusive_net_amount_half_up() {
+        // net amount of 2.5 cents
+        assert_eq!(
+            TaxBreakdown::calculate(5, 10_000, true),
+            Some(TaxBreakdown {
+                net_amount: 3,
+                tax_amount: 2,
+                gross_amount: 5,
+            })
+        );
+        // net amount of 1.33 cents
+        assert_eq!(
+            TaxBreakdown::calculate(4, 20_000, true),
+            Some(TaxBreakdown {
+                net_amount: 1,
+                tax_amount: 3,
+                gross_amount: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn has_no_tax_at_zero_rate() {
+        for prices_include_tax in [false, true] {
+            assert_eq!(
+                TaxBreakdown::calculate(999, 0, prices_include_tax),
+                Some(TaxBreakdown {
+                    net_amount: 999,
+                    tax_amount: 0,
+                    gross_amount: 999,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn fails_if_gross_amount_overflows() {
+        assert_eq!(TaxBreakdown::calculate(u32::MAX, 1900, false), None);
+    }
+}